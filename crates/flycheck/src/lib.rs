@@ -19,7 +19,7 @@ pub use cargo_metadata::diagnostic::{
     Applicability, Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticSpan,
     DiagnosticSpanMacroExpansion,
 };
-use toolchain::Tool;
+use toolchain::{Tool, VerusToolchain};
 
 mod command;
 mod test_runner;
@@ -94,6 +94,8 @@ pub enum FlycheckConfig {
         invocation_location: InvocationLocation,
     },
     VerusCommand {
+        /// The `verus` binary, or a Verus release directory, to use instead of discovering one.
+        verus_binary: Option<Utf8PathBuf>,
         verus_args: Vec<String>,
         cargo_verus_enable: bool,
         cargo_options: CargoOptions,
@@ -108,13 +110,33 @@ impl fmt::Display for FlycheckConfig {
             FlycheckConfig::CustomCommand { command, args, .. } => {
                 write!(f, "{command} {}", args.join(" "))
             }
-            FlycheckConfig::VerusCommand { verus_args, cargo_verus_enable, cargo_options, report_all_errors } => {
+            FlycheckConfig::VerusCommand { verus_args, cargo_verus_enable, cargo_options, report_all_errors, .. } => {
                 write!(f, "verus {} (cargo_verus enabled: {}, cargo_verus options {:?}, report all errors: {})", verus_args.join(" "), cargo_verus_enable, cargo_options, report_all_errors)
             }
         }
     }
 }
 
+/// Verus settings of the package being verified, from its `Cargo.toml` metadata.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerusPackageOptions {
+    /// Extra arguments passed to Verus.
+    pub extra_args: Vec<String>,
+    /// Modules to verify when the crate root itself is edited, instead of the root module.
+    pub verify_modules: Vec<String>,
+}
+
+/// The Verus arguments selecting the crate rooted at `root_file`. Anything but a binary is
+/// verified as a library, so that Verus doesn't complain about a missing `fn main()`.
+pub fn verus_crate_args(root_file: &str, is_bin: bool) -> Vec<String> {
+    let mut args = vec![root_file.to_owned()];
+    if !is_bin {
+        args.push("--crate-type".to_owned());
+        args.push("lib".to_owned());
+    }
+    args
+}
+
 /// Flycheck wraps the shared state and communication machinery used for
 /// running `cargo check` (or other compatible command) and providing
 /// diagnostics based on the output.
@@ -159,9 +181,23 @@ impl FlycheckHandle {
     }
 
     /// Schedule a re-start of the cargo check worker.
-    pub fn restart_verus(&self, file: String) {
+    pub fn restart_verus(&self, file: String, package: VerusPackageOptions) {
         tracing::debug!("restart verus for {:?}", file);
-        self.sender.send(StateChange::RestartVerus(file)).unwrap();
+        self.sender.send(StateChange::RestartVerus { file, package, module_args: None }).unwrap();
+    }
+
+    /// Schedule a Verus run for `file`, restricted by `module_args` (e.g. `--verify-module m`)
+    /// instead of the module that `file` defines.
+    pub fn restart_verus_module(
+        &self,
+        file: String,
+        package: VerusPackageOptions,
+        module_args: Vec<String>,
+    ) {
+        tracing::debug!("restart verus for {:?} with {:?}", file, module_args);
+        self.sender
+            .send(StateChange::RestartVerus { file, package, module_args: Some(module_args) })
+            .unwrap();
     }
 
     /// Stop this cargo check worker.
@@ -221,7 +257,7 @@ pub enum Progress {
 enum StateChange {
     Restart { package: Option<String>, saved_file: Option<AbsPathBuf> },
     Cancel,
-    RestartVerus(String),
+    RestartVerus { file: String, package: VerusPackageOptions, module_args: Option<Vec<String>> },
 }
 
 /// A [`FlycheckActor`] is a single check instance of a workspace.
@@ -341,7 +377,7 @@ impl FlycheckActor {
                         }
                     }
                 }
-                Event::RequestStateChange(StateChange::RestartVerus(filename)) => {
+                Event::RequestStateChange(StateChange::RestartVerus { file, package, module_args }) => {
                     // verus: copied from above `Event::RequestStateChange(StateChange::Restart)`
                     // Cancel the previously spawned process
                     self.cancel_check_process();
@@ -352,7 +388,7 @@ impl FlycheckActor {
                         }
                     }
 
-                    let command = self.run_verus(filename.clone());
+                    let command = self.run_verus(file, &package, module_args);
                     let formatted_command = format!("{command:?}");
                     tracing::info!(?command, "will restart flycheck");
                     let (sender, receiver) = unbounded();
@@ -547,58 +583,22 @@ impl FlycheckActor {
     }
 
     // copied from above check_command
-    fn run_cargo_verus(&self, file: String, verus_args: &Vec<String>, cargo_options: &CargoOptions, report_all_errors: bool) -> Command {
+    fn run_cargo_verus(&self, file: String, package: &VerusPackageOptions, module_args_override: Option<Vec<String>>, verus_args: &Vec<String>, cargo_options: &CargoOptions, report_all_errors: bool) -> Command {
         // Find the `cargo-verus` binary
-        let verus_binary_str = match std::env::var("VERUS_BINARY_PATH") {
-            Ok(path) => path,
-            Err(_) => {
-                tracing::warn!("VERUS_BINARY_PATH was not set!");
-                "verus".to_string() // Hope that it's in the PATH
+        let cargo_verus_exec = match self.verus_toolchain() {
+            Ok(VerusToolchain { cargo_verus: Some(cargo_verus), .. }) => cargo_verus,
+            Ok(_) => {
+                tracing::warn!("cargo-verus was not found next to verus or in $PATH");
+                Utf8PathBuf::from("cargo-verus")
+            }
+            Err(e) => {
+                tracing::warn!("{e}");
+                Utf8PathBuf::from("cargo-verus")
             }
         };
-        dbg!(&verus_binary_str);
-        tracing::info!("Using Verus binary: {}", &verus_binary_str);
-        let verus_exec_path = Path::new(&verus_binary_str)
-            .canonicalize()
-            .expect("We expect to succeed with canonicalizing the Verus binary path");
-        let verus_exec_dir = verus_exec_path.parent().unwrap();
-        let cargo_verus_exec = verus_exec_dir.join("cargo-verus");
-        dbg!(&cargo_verus_exec);
+        tracing::info!("Using cargo-verus binary: {}", &cargo_verus_exec);
         let mut cmd = Command::new(cargo_verus_exec);
 
-        // Find the toml file to check for additional arguments
-        let mut extra_args_from_toml = Vec::new();
-        if std::fs::metadata(self.root.join("Cargo.toml")).is_ok_and(|f| f.is_file()) {
-            let toml = std::fs::read_to_string(self.root.join("Cargo.toml")).unwrap();
-            let mut found_verus_settings = false;
-            for line in toml.lines() {
-                if found_verus_settings {
-                    if line.contains("extra_args") {
-                        let start = "extra_args".len() + 1;
-                        let mut arguments = line[start..line.len() - 1].trim().to_string();
-                        if arguments.starts_with("=") {
-                            arguments.remove(0);
-                            arguments = arguments.trim().to_string();
-                        }
-                        if arguments.starts_with("\"") {
-                            arguments.remove(0);
-                        }
-                        if arguments.ends_with("\"") {
-                            arguments.remove(arguments.len() - 1);
-                        }
-
-                        let arguments_vec =
-                            arguments.split(" ").map(|it| it.to_string()).collect::<Vec<_>>();
-                        extra_args_from_toml.extend(arguments_vec);
-                    }
-                    break;
-                }
-                if line.contains("[package.metadata.verus.ide]") {
-                    found_verus_settings = true;
-                }
-            }
-        }
-
         // Convert the file name into a module name, so we only receive errors for the file the developer is working on
         let mut module_args = Vec::new();
         let file = std::path::absolute(Path::new(&file)).unwrap();
@@ -624,9 +624,17 @@ impl FlycheckActor {
                 .to_string();
             module_args.push("--verify-module".to_string());
             module_args.push(file_as_module);
+        } else if !package.verify_modules.is_empty() {
+            for module in &package.verify_modules {
+                module_args.push("--verify-module".to_string());
+                module_args.push(module.clone());
+            }
         } else {
             module_args.push("--verify-root".to_string());
         }
+        if let Some(module_args_override) = module_args_override {
+            module_args = module_args_override;
+        }
 
         // Cargo command
         cmd.arg("verify".to_string());
@@ -637,7 +645,7 @@ impl FlycheckActor {
         // Provide the Verus arguments
         cmd.arg("--".to_string());
         cmd.args(verus_args);
-        cmd.args(extra_args_from_toml);
+        cmd.args(&package.extra_args);
         if !report_all_errors {
             cmd.args(module_args);
         }
@@ -648,60 +656,18 @@ impl FlycheckActor {
     }
 
     // copied from above check_command
-    fn run_verus_direct(&self, file: String, verus_args: &Vec<String>, report_all_errors: bool) -> Command {
-        let verus_binary_str = match std::env::var("VERUS_BINARY_PATH") {
-            Ok(path) => path,
-            Err(_) => {
-                tracing::warn!("VERUS_BINARY_PATH was not set!");
-                "verus".to_string() // Hope that it's in the PATH
-            }
-        };
-        dbg!(&verus_binary_str);
-        tracing::info!("Using Verus binary: {}", &verus_binary_str);
-
-        let verus_exec_path = Path::new(&verus_binary_str)
-            .canonicalize()
-            .expect("We expect to succeed with canonicalizing the Verus binary path");
+    fn run_verus_direct(&self, file: String, package: &VerusPackageOptions, module_args_override: Option<Vec<String>>, verus_args: &Vec<String>, report_all_errors: bool) -> Command {
+        let verus_exec_path = self.verus_toolchain().map(|it| it.verus).unwrap_or_else(|e| {
+            tracing::warn!("{e}");
+            Utf8PathBuf::from("verus") // Hope that it's in the PATH
+        });
+        tracing::info!("Using Verus binary: {}", &verus_exec_path);
         let mut cmd = Command::new(verus_exec_path);
 
-        // Try to locate a Cargo.toml file that might contain custom Verus arguments
+        // Try to locate the Cargo.toml file of the project this file belongs to
         let file = Path::new(&file);
-        let mut toml_dir: Option<std::path::PathBuf> = None;
-        let mut extra_args_from_toml = Vec::new();
-        for ans in file.ancestors() {
-            if ans.join("Cargo.toml").exists() {
-                let toml = std::fs::read_to_string(ans.join("Cargo.toml")).unwrap();
-                let mut found_verus_settings = false;
-                for line in toml.lines() {
-                    if found_verus_settings {
-                        if line.contains("extra_args") {
-                            let start = "extra_args".len() + 1;
-                            let mut arguments = line[start..line.len() - 1].trim().to_string();
-                            if arguments.starts_with("=") {
-                                arguments.remove(0);
-                                arguments = arguments.trim().to_string();
-                            }
-                            if arguments.starts_with("\"") {
-                                arguments.remove(0);
-                            }
-                            if arguments.ends_with("\"") {
-                                arguments.remove(arguments.len() - 1);
-                            }
-
-                            let arguments_vec =
-                                arguments.split(" ").map(|it| it.to_string()).collect::<Vec<_>>();
-                            extra_args_from_toml.extend(arguments_vec);
-                        }
-                        break;
-                    }
-                    if line.contains("[package.metadata.verus.ide]") {
-                        found_verus_settings = true;
-                    }
-                }
-                toml_dir = Some(ans.to_path_buf());
-                break;
-            }
-        }
+        let toml_dir =
+            file.ancestors().find(|it| it.join("Cargo.toml").exists()).map(Path::to_path_buf);
 
         // We may need to add additional configuration arguments
         let mut config_args = vec![];   // File name and crate type
@@ -711,25 +677,22 @@ impl FlycheckActor {
                 // This file doesn't appear to be part of a larger project
                 // Try to invoke Verus on it directly, but try to avoid
                 // complaints about missing `fn main()`
-                config_args.push("--crate-type".to_string());
-                config_args.push("lib".to_string());
+                config_args = verus_crate_args(file.to_str().unwrap(), false);
             }
             Some(toml_dir) => {
                 // This file appears to be part of a Rust project.
                 // If it's not the root file, then we need to
                 // invoke Verus on the root file and then filter for results in the current file
                 let root_file = if toml_dir.join("src").join("main.rs").exists() {
-                    Some(toml_dir.join("src").join("main.rs"))
+                    Some((toml_dir.join("src").join("main.rs"), true))
                 } else if toml_dir.join("src").join("lib.rs").exists() {
-                    config_args.push("--crate-type".to_string());
-                    config_args.push("lib".to_string());
-                    Some(toml_dir.join("src").join("lib.rs"))
+                    Some((toml_dir.join("src").join("lib.rs"), false))
                 } else {
                     None
                 };
 
                 match root_file {
-                    Some(root_file) => {
+                    Some((root_file, is_bin)) => {
                         let file_as_module = file
                             .strip_prefix(toml_dir.join("src"))
                             .unwrap()
@@ -743,9 +706,13 @@ impl FlycheckActor {
                             .trim_end_matches("::mod")
                             .to_string();
 
-                        config_args.insert(0, root_file.to_str().unwrap().to_string());
+                        config_args = verus_crate_args(root_file.to_str().unwrap(), is_bin);
                         if file == root_file {
                             tracing::info!("file == root_file");
+                            for module in &package.verify_modules {
+                                module_args.push("--verify-module".to_string());
+                                module_args.push(module.clone());
+                            }
                         } else {
                             tracing::info!(?root_file, "root_file");
                             module_args.push("--verify-module".to_string());
@@ -755,31 +722,39 @@ impl FlycheckActor {
                     None => {
                         // Puzzling -- we found a Cargo.toml but no root file.
                         // Do our best by trying to run directly on the file supplied
-                        config_args.insert(0, file.to_str().unwrap().to_string());
-                        config_args.push("--crate-type".to_string());
-                        config_args.push("lib".to_string());
+                        config_args = verus_crate_args(file.to_str().unwrap(), false);
                     }
                 }
             }
         }
-        
+        if let Some(module_args_override) = module_args_override {
+            module_args = module_args_override;
+        }
+
         // Apply all of the argument collections
         cmd.args(verus_args);
         cmd.args(config_args);
-        cmd.args(extra_args_from_toml);
+        cmd.args(&package.extra_args);
         if !report_all_errors {
             cmd.args(module_args);
         }
 
         // Apply arguments that go to rustc instead of Verus
-        cmd.arg("--".to_string());
-        cmd.arg("--error-format=json".to_string());
+        cmd.args(toolchain::VERUS_JSON_ARGS);
 
         cmd.current_dir(&self.root);
         cmd
     }
 
-    fn run_verus(&self, file: String) -> Command {
+    fn verus_toolchain(&self) -> Result<VerusToolchain, String> {
+        let verus_binary = match &self.config {
+            FlycheckConfig::VerusCommand { verus_binary, .. } => verus_binary.as_deref(),
+            _ => None,
+        };
+        VerusToolchain::discover(verus_binary)
+    }
+
+    fn run_verus(&self, file: String, package: &VerusPackageOptions, module_args: Option<Vec<String>>) -> Command {
         let cmd = match &self.config {
             FlycheckConfig::CargoCommand { .. } => {
                 panic!("verus analyzer does not yet support cargo commands")
@@ -787,11 +762,11 @@ impl FlycheckActor {
             FlycheckConfig::CustomCommand { .. } => {
                 panic!("verus analyzer does not yet support custom commands")
             }
-            FlycheckConfig::VerusCommand { verus_args, cargo_verus_enable, cargo_options, report_all_errors } => {
+            FlycheckConfig::VerusCommand { verus_args, cargo_verus_enable, cargo_options, report_all_errors, .. } => {
                 if *cargo_verus_enable {
-                    self.run_cargo_verus(file, verus_args, cargo_options, *report_all_errors)
+                    self.run_cargo_verus(file, package, module_args, verus_args, cargo_options, *report_all_errors)
                 } else {
-                    self.run_verus_direct(file, verus_args, *report_all_errors)
+                    self.run_verus_direct(file, package, module_args, verus_args, *report_all_errors)
                 }
             }
         };
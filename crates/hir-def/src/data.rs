@@ -144,6 +144,18 @@ impl FunctionData {
     pub fn is_varargs(&self) -> bool {
         self.flags.contains(FnFlags::IS_VARARGS)
     }
+
+    pub fn is_spec_mode(&self) -> bool {
+        self.flags.contains(FnFlags::IS_SPEC_MODE)
+    }
+
+    pub fn is_proof_mode(&self) -> bool {
+        self.flags.contains(FnFlags::IS_PROOF_MODE)
+    }
+
+    pub fn has_broadcast_kw(&self) -> bool {
+        self.flags.contains(FnFlags::HAS_BROADCAST_KW)
+    }
 }
 
 fn parse_rustc_legacy_const_generics(tt: &crate::tt::Subtree) -> Box<[u32]> {
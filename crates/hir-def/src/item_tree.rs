@@ -760,7 +760,7 @@ pub enum ParamAstId {
 
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
-    pub(crate) struct FnFlags: u8 {
+    pub(crate) struct FnFlags: u16 {
         const HAS_SELF_PARAM = 1 << 0;
         const HAS_BODY = 1 << 1;
         const HAS_DEFAULT_KW = 1 << 2;
@@ -768,6 +768,10 @@ bitflags::bitflags! {
         const HAS_ASYNC_KW = 1 << 4;
         const HAS_UNSAFE_KW = 1 << 5;
         const IS_VARARGS = 1 << 6;
+        // Verus: function modes. A function with neither flag set is `exec`.
+        const IS_SPEC_MODE = 1 << 7;
+        const IS_PROOF_MODE = 1 << 8;
+        const HAS_BROADCAST_KW = 1 << 9;
     }
 }
 
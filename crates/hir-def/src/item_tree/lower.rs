@@ -423,6 +423,16 @@ impl<'a> Ctx<'a> {
         if func.unsafe_token().is_some() {
             flags |= FnFlags::HAS_UNSAFE_KW;
         }
+        if func.broadcast_token().is_some() {
+            flags |= FnFlags::HAS_BROADCAST_KW;
+        }
+        if let Some(fn_mode) = func.fn_mode() {
+            if fn_mode.spec_token().is_some() || fn_mode.mode_spec_checked().is_some() {
+                flags |= FnFlags::IS_SPEC_MODE;
+            } else if fn_mode.proof_token().is_some() || fn_mode.axiom_token().is_some() {
+                flags |= FnFlags::IS_PROOF_MODE;
+            }
+        }
 
         let res = Function {
             name,
@@ -275,6 +275,15 @@ impl Printer<'_> {
                 if let Some(abi) = abi {
                     w!(self, "extern \"{}\" ", abi);
                 }
+                if flags.contains(FnFlags::HAS_BROADCAST_KW) {
+                    w!(self, "broadcast ");
+                }
+                if flags.contains(FnFlags::IS_SPEC_MODE) {
+                    w!(self, "spec ");
+                }
+                if flags.contains(FnFlags::IS_PROOF_MODE) {
+                    w!(self, "proof ");
+                }
                 w!(self, "fn {}", name.display(self.db.upcast()));
                 self.print_generic_params(explicit_generic_params, it.into());
                 w!(self, "(");
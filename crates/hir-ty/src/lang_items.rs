@@ -47,6 +47,8 @@ pub fn lang_items_for_bin_op(op: syntax::ast::BinaryOp) -> Option<(Name, LangIte
         BinaryOp::CmpOp(cop) => match cop {
             CmpOp::Eq { negated: false } => (name![eq], LangItem::PartialEq),
             CmpOp::Eq { negated: true } => (name![ne], LangItem::PartialEq),
+            // verus
+            CmpOp::ExtEq { .. } => (name![eq], LangItem::PartialEq),
             CmpOp::Ord { ordering: Ordering::Less, strict: false } => {
                 (name![le], LangItem::PartialOrd)
             }
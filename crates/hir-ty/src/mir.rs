@@ -806,6 +806,8 @@ impl From<hir_def::hir::CmpOp> for BinOp {
         match value {
             hir_def::hir::CmpOp::Eq { negated: false } => BinOp::Eq,
             hir_def::hir::CmpOp::Eq { negated: true } => BinOp::Ne,
+            // verus
+            hir_def::hir::CmpOp::ExtEq { .. } => BinOp::Eq,
             hir_def::hir::CmpOp::Ord { ordering: Ordering::Greater, strict: false } => BinOp::Ge,
             hir_def::hir::CmpOp::Ord { ordering: Ordering::Greater, strict: true } => BinOp::Gt,
             hir_def::hir::CmpOp::Ord { ordering: Ordering::Less, strict: false } => BinOp::Le,
@@ -2125,6 +2125,23 @@ impl Function {
         db.function_data(self.id).has_body()
     }
 
+    /// The Verus mode of this function. Functions without an explicit mode are `exec`.
+    pub fn mode(self, db: &dyn HirDatabase) -> FnMode {
+        let data = db.function_data(self.id);
+        if data.is_spec_mode() {
+            FnMode::Spec
+        } else if data.is_proof_mode() {
+            FnMode::Proof
+        } else {
+            FnMode::Exec
+        }
+    }
+
+    /// Is this a `broadcast proof fn`?
+    pub fn is_broadcast(self, db: &dyn HirDatabase) -> bool {
+        db.function_data(self.id).has_broadcast_kw()
+    }
+
     pub fn as_proc_macro(self, db: &dyn HirDatabase) -> Option<Macro> {
         let function_data = db.function_data(self.id);
         let attrs = &function_data.attrs;
@@ -2179,6 +2196,14 @@ impl Function {
     }
 }
 
+/// Verus function mode, see <https://verus-lang.github.io/verus/guide/modes.html>.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FnMode {
+    Spec,
+    Proof,
+    Exec,
+}
+
 // Note: logically, this belongs to `hir_ty`, but we are not using it there yet.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Access {
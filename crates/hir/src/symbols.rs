@@ -12,7 +12,7 @@ use hir_expand::{HirFileId, InFile};
 use hir_ty::{db::HirDatabase, display::HirDisplay};
 use syntax::{ast::HasName, AstNode, AstPtr, SmolStr, SyntaxNode, SyntaxNodePtr};
 
-use crate::{Module, ModuleDef, Semantics};
+use crate::{FnMode, Module, ModuleDef, Semantics};
 
 /// The actual data that is stored in the index. It should be as compact as
 /// possible.
@@ -25,6 +25,10 @@ pub struct FileSymbol {
     /// Whether this symbol is a doc alias for the original symbol.
     pub is_alias: bool,
     pub is_assoc: bool,
+    /// The Verus mode, if this symbol is a function.
+    pub fn_mode: Option<FnMode>,
+    /// Whether this symbol is a `broadcast` function.
+    pub is_broadcast: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -184,6 +188,7 @@ impl<'a> SymbolCollector<'a> {
                     name_ptr: AstPtr::new(&name),
                 };
 
+                let (fn_mode, is_broadcast) = self.fn_mode_of(def);
                 self.symbols.push(FileSymbol {
                     name: name.text().into(),
                     def,
@@ -191,6 +196,8 @@ impl<'a> SymbolCollector<'a> {
                     loc: dec_loc,
                     is_alias: false,
                     is_assoc: false,
+                    fn_mode,
+                    is_broadcast,
                 });
             });
         }
@@ -266,6 +273,13 @@ impl<'a> SymbolCollector<'a> {
         }
     }
 
+    fn fn_mode_of(&self, def: ModuleDef) -> (Option<FnMode>, bool) {
+        match def {
+            ModuleDef::Function(func) => (Some(func.mode(self.db)), func.is_broadcast(self.db)),
+            _ => (None, false),
+        }
+    }
+
     fn push_assoc_item(&mut self, assoc_item_id: AssocItemId) {
         match assoc_item_id {
             AssocItemId::FunctionId(id) => self.push_decl(id, true),
@@ -284,6 +298,7 @@ impl<'a> SymbolCollector<'a> {
         let source = loc.source(self.db.upcast());
         let Some(name_node) = source.value.name() else { return };
         let def = ModuleDef::from(id.into());
+        let (fn_mode, is_broadcast) = self.fn_mode_of(def);
         let dec_loc = DeclarationLocation {
             hir_file_id: source.file_id,
             ptr: SyntaxNodePtr::new(source.value.syntax()),
@@ -299,6 +314,8 @@ impl<'a> SymbolCollector<'a> {
                     container_name: self.current_container_name.clone(),
                     is_alias: true,
                     is_assoc,
+                    fn_mode,
+                    is_broadcast,
                 });
             }
         }
@@ -310,6 +327,8 @@ impl<'a> SymbolCollector<'a> {
             loc: dec_loc,
             is_alias: false,
             is_assoc,
+            fn_mode,
+            is_broadcast,
         });
     }
 
@@ -336,6 +355,8 @@ impl<'a> SymbolCollector<'a> {
                     container_name: self.current_container_name.clone(),
                     is_alias: true,
                     is_assoc: false,
+                    fn_mode: None,
+                    is_broadcast: false,
                 });
             }
         }
@@ -347,6 +368,8 @@ impl<'a> SymbolCollector<'a> {
             loc: dec_loc,
             is_alias: false,
             is_assoc: false,
+            fn_mode: None,
+            is_broadcast: false,
         });
     }
 }
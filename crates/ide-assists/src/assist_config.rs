@@ -4,11 +4,16 @@
 //! module, and we use to statically check that we only produce snippet
 //! assists if we are allowed to.
 
+use std::sync::Arc;
+
 use ide_db::{imports::insert_use::InsertUseConfig, SnippetCap};
 
-use crate::AssistKind;
+use crate::{
+    proof_plumber_api::{verification_cache::VerificationCache, verifier::Verifier},
+    AssistKind,
+};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct AssistConfig {
     pub snippet_cap: Option<SnippetCap>,
     pub allowed: Option<Vec<AssistKind>>,
@@ -17,4 +22,8 @@ pub struct AssistConfig {
     pub prefer_prelude: bool,
     pub assist_emit_must_use: bool,
     pub term_search_fuel: u64,
+    /// The verifier proof actions consult, if one is available.
+    pub verifier: Option<Arc<dyn Verifier>>,
+    /// Where proof actions look up verification results before asking the verifier.
+    pub verification_cache: Option<Arc<VerificationCache>>,
 }
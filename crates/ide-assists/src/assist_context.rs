@@ -172,6 +172,23 @@ impl Assists {
         label: impl Into<String>,
         target: TextRange,
         f: impl FnOnce(&mut SourceChangeBuilder),
+    ) -> Option<()> {
+        let mut f = Some(f);
+        self.add_impl(None, id, label.into(), target, &mut |it| {
+            f.take().unwrap()(it);
+            Some(())
+        })
+    }
+
+    /// Like [`Assists::add`], for the [`crate::VERIFYING_ASSISTS`], which run Verus to compute
+    /// their edit. Verus must only be run by `f`, which returns `None` if no edit was found; the
+    /// assist then resolves without a source change.
+    pub(crate) fn add_verifying(
+        &mut self,
+        id: AssistId,
+        label: impl Into<String>,
+        target: TextRange,
+        f: impl FnOnce(&mut SourceChangeBuilder) -> Option<()>,
     ) -> Option<()> {
         let mut f = Some(f);
         self.add_impl(None, id, label.into(), target, &mut |it| f.take().unwrap()(it))
@@ -186,7 +203,10 @@ impl Assists {
         f: impl FnOnce(&mut SourceChangeBuilder),
     ) -> Option<()> {
         let mut f = Some(f);
-        self.add_impl(Some(group), id, label.into(), target, &mut |it| f.take().unwrap()(it))
+        self.add_impl(Some(group), id, label.into(), target, &mut |it| {
+            f.take().unwrap()(it);
+            Some(())
+        })
     }
 
     fn add_impl(
@@ -195,7 +215,7 @@ impl Assists {
         id: AssistId,
         label: String,
         target: TextRange,
-        f: &mut dyn FnMut(&mut SourceChangeBuilder),
+        f: &mut dyn FnMut(&mut SourceChangeBuilder) -> Option<()>,
     ) -> Option<()> {
         if !self.is_allowed(&id) {
             return None;
@@ -204,9 +224,10 @@ impl Assists {
         let mut trigger_signature_help = false;
         let source_change = if self.resolve.should_resolve(&id) {
             let mut builder = SourceChangeBuilder::new(self.file);
-            f(&mut builder);
-            trigger_signature_help = builder.trigger_signature_help;
-            Some(builder.finish())
+            f(&mut builder).map(|()| {
+                trigger_signature_help = builder.trigger_signature_help;
+                builder.finish()
+            })
         } else {
             None
         };
@@ -671,7 +671,13 @@ pub(crate) fn inline_simple(
                 cov_mark::hit!(inline_call_inline_direct_field);
                 field.replace_expr(replacement.clone_for_update());
             } else {
-                ted::replace(usage.syntax(), &replacement.syntax().clone_for_update());
+                let replacement = replacement.clone_for_update();
+                ted::replace(usage.syntax(), replacement.syntax());
+                // arguments are inlined even when they are not atomic, like `x - 1` into `y * x`
+                if replacement.syntax().parent().is_some_and(|it| replacement.needs_parens_in(it)) {
+                    let paren = make::expr_paren(replacement.clone()).clone_for_update();
+                    ted::replace(replacement.syntax(), paren.syntax());
+                }
             }
         };
 
@@ -1,12 +1,19 @@
 #[allow(dead_code)]
 pub(crate) mod apply_induction;
+pub(crate) mod assert_by_prover;
 pub(crate) mod convert_imply_to_if;
 #[allow(dead_code)]
 pub(crate) mod decompose_failing_assert;
+pub(crate) mod extract_lemma;
+pub(crate) mod inline_lemma;
 pub(crate) mod insert_assert_by_block;
+pub(crate) mod insert_failing_invariant;
 pub(crate) mod insert_failing_postcondition;
 pub(crate) mod insert_failing_precondition;
+pub(crate) mod instantiate_forall;
 pub(crate) mod intro_assume_false;
+pub(crate) mod intro_exists_witness;
+pub(crate) mod intro_ext_equality;
 pub(crate) mod intro_forall;
 pub(crate) mod intro_forall_implies;
 #[allow(dead_code)]
@@ -18,4 +25,5 @@ pub(crate) mod reveal_opaque_in_by_block;
 pub(crate) mod seq_index_inbound;
 pub(crate) mod split_imply_ensures;
 pub(crate) mod split_smaller_or_equal_to;
+pub(crate) mod unfold_spec_call;
 pub(crate) mod weakest_pre_step;
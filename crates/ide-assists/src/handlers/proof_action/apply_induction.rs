@@ -1,4 +1,4 @@
-use crate::{AssistContext, Assists};
+use crate::{proof_plumber_api::vst_diff::apply_vst_edit, AssistContext, Assists};
 use ide_db::assists::{AssistId, AssistKind};
 use itertools::Itertools;
 use syntax::{
@@ -9,12 +9,45 @@ use syntax::{
 pub(crate) fn apply_induction(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let func: ast::Fn = ctx.find_node_at_offset::<ast::Fn>()?;
     let body: ast::BlockExpr = func.body()?;
-    let func: Fn = Fn::try_from(func).ok()?;
+    let v_func: Fn = Fn::try_from(func.clone()).ok()?;
+
+    // get the selected argument
+    let index = v_func.param_list.as_ref()?.params.iter().position(|p| {
+        p.cst.as_ref().unwrap().syntax().text_range().contains_range(ctx.selection_trimmed())
+    })?;
+    let result = vst_rewriter_apply_induction(ctx, &func, index)?;
+    let mut new_fn = v_func;
+    new_fn.body = Some(Box::new(result.clone()));
+    let result = ctx.vst_edit(&body, result)?;
+
+    acc.add_verifying(
+        AssistId("apply_induction", AssistKind::RefactorRewrite),
+        "Apply Induction",
+        body.syntax().text_range(),
+        |edit| {
+            // only apply when the proof now goes through, and make sure it is fast
+            let verif_result = ctx.try_verus(&new_fn)?;
+            if !verif_result.is_success || verif_result.time > 10 {
+                return None;
+            }
+            apply_vst_edit(edit, result);
+            Some(())
+        },
+    )
+}
+
+/// The body of `func` as a proof by induction on its `index`th parameter, which is a `nat` or an
+/// enum. `func` must be part of the analysed crate, as enums are recognized by their type.
+pub(crate) fn vst_rewriter_apply_induction(
+    ctx: &AssistContext<'_>,
+    func: &ast::Fn,
+    index: usize,
+) -> Option<BlockExpr> {
+    let func: Fn = Fn::try_from(func.clone()).ok()?;
     if func.signature_decreases.is_none() {
         return None;
     }
 
-    let mut new_fn = func.clone();
     let param_list = &(*func.param_list?).params;
     let param_names: Option<Vec<String>> = param_list
         .iter()
@@ -29,39 +62,15 @@ pub(crate) fn apply_induction(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
         .collect();
     let param_names = param_names?;
 
-    // get the selected argument
-    let index = param_list.iter().position(|p| {
-        p.cst.as_ref().unwrap().syntax().text_range().contains_range(ctx.selection_trimmed())
-    })?;
-
-    let pty = param_list[index].ty.as_ref()?;
-    let mut result = BlockExpr::new(StmtList::new());
-
+    let pty = param_list.get(index)?.ty.as_ref()?;
     if pty.to_string().trim() == "nat" {
-        result = apply_induction_on_nat(ctx, func.name.to_string(), param_names, index)?;
+        apply_induction_on_nat(ctx, func.name.to_string(), param_names, index)
     } else {
         let p = param_list[index].pat.as_ref()?.as_ref();
-        if let Some(en) = ctx.type_of_pat_enum(p) {
-            let bty = format!("Box<{}>", param_list[index].ty.as_ref()?.to_string().trim());
-            result =
-                apply_induction_on_enum(ctx, func.name.to_string(), param_names, index, &en, bty)?;
-        }
+        let en = ctx.type_of_pat_enum(p)?;
+        let bty = format!("Box<{}>", pty.to_string().trim());
+        apply_induction_on_enum(ctx, func.name.to_string(), param_names, index, &en, bty)
     }
-
-    // now check if proof now goes through, and make sure it is fast
-    new_fn.body = Some(Box::new(result.clone()));
-    let verif_result = ctx.try_verus(&new_fn)?;
-    if !verif_result.is_success || verif_result.time > 10 {
-        return None;
-    }
-
-    let result = ctx.fmt(body.clone(), result.to_string())?;
-    return acc.add(
-        AssistId("apply_induction", AssistKind::RefactorRewrite),
-        "Apply Induction",
-        body.syntax().text_range(),
-        |edit| edit.replace(body.syntax().text_range(), result),
-    );
 }
 
 fn apply_induction_on_nat(
@@ -161,14 +170,36 @@ fn apply_induction_on_enum(
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_unresolved_with_verifier, check_assist_with_verifier},
+    };
 
     use super::*;
 
+    #[test]
+    fn discovery_does_not_verify() {
+        check_assist_unresolved_with_verifier(
+            apply_induction,
+            MockVerifier::new().panicking(),
+            r#"
+use vstd::prelude::*;
+
+proof fn count_down($0n: nat)
+    ensures n >= 0,
+    decreases n,
+{}
+
+fn main() {}
+"#,
+        );
+    }
+
     #[test]
     fn apply_induction_on_nat1() {
-        check_assist(
+        check_assist_with_verifier(
             apply_induction,
+            MockVerifier::new(),
             r#"
 use vstd::prelude::*;
 
@@ -222,7 +253,6 @@ proof fn sum_equal(n: nat, m: nat) by(nonlinear_arith)
     };
 }
 
-
 fn main() {}
 "#,
         );
@@ -231,8 +261,9 @@ fn main() {}
     #[test]
     // from https://github.com/verus-lang/verus/blob/0088380265ed6e10c5d8034e89ce807a728f98e3/source/rust_verify/example/summer_school/chapter-1-22.rs
     fn apply_induction_on_enum1() {
-        check_assist(
+        check_assist_with_verifier(
             apply_induction,
+            MockVerifier::new(),
             // before
             r#"
 use vstd::prelude::*;
@@ -354,7 +385,6 @@ proof fn sorted_tree_means_sorted_sequence(tree: Tree)
     };
 }
 
-
 fn main() {}
 "#,
         );
@@ -0,0 +1,290 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::{
+        context_facts::{facts_about, Fact},
+        vst_diff::apply_vst_edit,
+        vst_ext::vst_walk_expr,
+        vst_subst::Binding,
+    },
+    AssistId, AssistKind, GroupLabel,
+};
+use syntax::{
+    ast::{self, vst::*, ArithOp, AstNode},
+    T,
+};
+
+/// Types the `bit_vector` prover supports
+const BIT_VECTOR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "bool",
+];
+
+// Prove an arithmetic assertion with `by (nonlinear_arith)` or `by (bit_vector)`.
+// These provers do not see the context, so the facts known about the variables of the assertion
+// are passed in a `requires`.
+pub(crate) fn assert_by_prover(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    ctx.at_this_token(T![assert])?;
+    let assert_expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let func: ast::Fn = assert_expr.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let assert = AssertExpr::try_from(assert_expr.clone()).ok()?;
+    // the assertion already has a proof
+    if assert.by_token || assert.block_expr.is_some() {
+        return None;
+    }
+
+    // the assertion has to be a statement (or the tail) of a block
+    let stmt = match ast::ExprStmt::cast(assert_expr.syntax().parent()?) {
+        Some(expr_stmt) => expr_stmt.syntax().clone(),
+        None => assert_expr.syntax().clone(),
+    };
+    let stmt_list = ast::StmtList::cast(stmt.parent()?)?;
+    let position = match stmt_list.tail_expr() {
+        Some(tail) if tail.syntax() == &stmt => None,
+        _ => Some(stmt_list.statements().position(|it| it.syntax() == &stmt)?),
+    };
+    let replace = |assert: AssertExpr| -> Option<_> {
+        let mut v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+        match position {
+            Some(idx) => v_stmt_list.statements[idx] = ExprStmt::new(assert).into(),
+            // without a semicolon, the `requires` is closed by a block
+            None => v_stmt_list.tail_expr = Some(Box::new(assert.into())),
+        }
+        ctx.vst_edit(&stmt_list, v_stmt_list)
+    };
+
+    let vars = ctx.vst_free_vars(&assert.expr);
+    let nonlinear = has_op(&assert.expr, &[ArithOp::Mul, ArithOp::Div, ArithOp::Rem]);
+    let bit_vector = is_bit_vector_expr(&assert.expr) && bit_vector_vars(ctx, &vars);
+    if !nonlinear && !bit_vector {
+        return None;
+    }
+
+    // only a failing assertion needs a prover
+    if !ctx.is_failing_assert(&assert_expr) {
+        return None;
+    }
+
+    let candidates = ctx.facts_at(&func, assert_expr.syntax());
+    let group = GroupLabel("Prove assertion with an arithmetic prover".to_owned());
+
+    if nonlinear {
+        let candidates =
+            candidates.iter().filter(|it| !has_quantifier(&it.expr)).cloned().collect();
+        let (facts, _) = facts_about(candidates, vars.clone());
+        let result = vst_rewriter_assert_by_prover(
+            assert.clone(),
+            "nonlinear_arith",
+            facts,
+            position.is_none(),
+        );
+        // a failed edit only drops this prover, the other one may still apply
+        if let Some(result) = replace(result) {
+            acc.add_group(
+                &group,
+                AssistId("assert_by_prover", AssistKind::RefactorRewrite),
+                "Prove with `nonlinear_arith`",
+                assert_expr.syntax().text_range(),
+                |edit| apply_vst_edit(edit, result),
+            );
+        }
+    }
+
+    if bit_vector {
+        let candidates: Vec<Fact> = candidates
+            .into_iter()
+            .filter(|it| is_bit_vector_expr(&it.expr) && bit_vector_vars(ctx, &it.vars))
+            .collect();
+        let (facts, _) = facts_about(candidates, vars);
+        let result = vst_rewriter_assert_by_prover(assert, "bit_vector", facts, position.is_none());
+        if let Some(result) = replace(result) {
+            acc.add_group(
+                &group,
+                AssistId("assert_by_prover", AssistKind::RefactorRewrite),
+                "Prove with `bit_vector`",
+                assert_expr.syntax().text_range(),
+                |edit| apply_vst_edit(edit, result),
+            );
+        }
+    }
+    Some(())
+}
+
+/// `assert(e)` becomes `assert(e) by (prover) requires facts`
+pub(crate) fn vst_rewriter_assert_by_prover(
+    mut assert: AssertExpr,
+    prover: &str,
+    facts: Vec<Expr>,
+    with_block: bool,
+) -> AssertExpr {
+    let mut name = Name::new();
+    name.ident_token = Some(prover.to_owned());
+    assert.by_token = true;
+    assert.name = Some(Box::new(name));
+    if !facts.is_empty() {
+        let mut requires = RequiresClause::new();
+        requires.exprs = facts;
+        assert.requires_clause = Some(Box::new(requires));
+    }
+    if with_block {
+        assert.block_expr = Some(Box::new(BlockExpr::new(StmtList::new())));
+    }
+    assert
+}
+
+/// Whether `expr` uses one of the arithmetic operators `ops`
+fn has_op(expr: &Expr, ops: &[ArithOp]) -> bool {
+    let mut found = false;
+    vst_walk_expr(expr, &mut |e| {
+        if let Expr::BinExpr(bin) = e {
+            found |= matches!(bin.op, BinaryOp::ArithOp(op) if ops.contains(&op));
+        }
+    });
+    found
+}
+
+fn has_quantifier(expr: &Expr) -> bool {
+    let mut found = false;
+    vst_walk_expr(expr, &mut |e| found |= matches!(e, Expr::ClosureExpr(_)));
+    found
+}
+
+/// Whether `expr` is made of variables, literals, casts and operators only,
+/// as `bit_vector` does not know about functions or quantifiers
+fn is_bit_vector_expr(expr: &Expr) -> bool {
+    let mut supported = true;
+    vst_walk_expr(expr, &mut |e| {
+        supported &= matches!(
+            e,
+            Expr::Literal(_)
+                | Expr::PathExpr(_)
+                | Expr::ParenExpr(_)
+                | Expr::BinExpr(_)
+                | Expr::PrefixExpr(_)
+                | Expr::CastExpr(_)
+        );
+    });
+    supported
+}
+
+/// Whether all of `vars` have a type `bit_vector` supports
+fn bit_vector_vars(ctx: &AssistContext<'_>, vars: &[Binding]) -> bool {
+    vars.iter().all(|var| {
+        let ty = var.local.and_then(|it| ctx.type_of_local(it));
+        ty.is_some_and(|it| BIT_VECTOR_TYPES.contains(&it.to_string().trim()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verus_error::mk_assert_failure,
+        tests::{
+            check_assist_by_label_with_verus_error, check_assist_not_applicable_with_verus_error,
+        },
+    };
+
+    #[test]
+    fn assert_by_prover_nonlinear_arith() {
+        check_assist_by_label_with_verus_error(
+            assert_by_prover,
+            // `assert(x * z <= y * z)` is at offset (126, 148)
+            vec![mk_assert_failure(126, 148)],
+            r#"
+proof fn mul(x: int, y: int, z: int, w: int)
+    requires
+        0 <= x,
+        x <= y,
+        0 < z,
+        w > 5,
+{
+    ass$0ert(x * z <= y * z);
+}
+"#,
+            r#"
+proof fn mul(x: int, y: int, z: int, w: int)
+    requires
+        0 <= x,
+        x <= y,
+        0 < z,
+        w > 5,
+{
+    assert(x * z <= y * z) by (nonlinear_arith)
+        requires
+            0 <= x,
+            x <= y,
+            0 < z,
+    ;
+}
+"#,
+            "Prove with `nonlinear_arith`",
+        );
+    }
+
+    #[test]
+    fn assert_by_prover_bit_vector() {
+        check_assist_by_label_with_verus_error(
+            assert_by_prover,
+            // `assert(z <= 0xff)` is at offset (115, 132)
+            vec![mk_assert_failure(115, 132)],
+            r#"
+fn mask(x: u32, y: u32, v: Vec<u32>)
+    requires
+        y == 0xff,
+        v.len() > 0,
+{
+    let z = x & y;
+    ass$0ert(z <= 0xff);
+}
+"#,
+            r#"
+fn mask(x: u32, y: u32, v: Vec<u32>)
+    requires
+        y == 0xff,
+        v.len() > 0,
+{
+    let z = x & y;
+    assert(z <= 0xff) by (bit_vector)
+        requires
+            y == 0xff,
+            z == x & y,
+    ;
+}
+"#,
+            "Prove with `bit_vector`",
+        );
+    }
+
+    #[test]
+    fn assert_by_prover_not_when_verified() {
+        check_assist_not_applicable_with_verus_error(
+            assert_by_prover,
+            vec![],
+            r#"
+proof fn sq(x: int)
+    requires
+        x >= 0,
+{
+    ass$0ert(x * x >= 0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn assert_by_prover_not_for_linear_int() {
+        check_assist_not_applicable_with_verus_error(
+            assert_by_prover,
+            // `assert(x & 1 <= y + 1)` is at offset (64, 86)
+            vec![mk_assert_failure(64, 86)],
+            r#"
+proof fn add(x: int, y: int)
+    requires
+        x <= y,
+{
+    ass$0ert(x & 1 <= y + 1);
+}
+"#,
+        );
+    }
+}
@@ -63,15 +63,17 @@ pub(crate) fn localize_error(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
 
     let assertion = ctx.find_node_at_offset::<ast::AssertExpr>()?;
     let v_assertion = AssertExpr::try_from(assertion.clone()).ok()?;
-    let result = vst_rewriter_localize_error_minimized(ctx, v_assertion.clone())?;
-    let result = ctx.fmt(assertion.clone(), result.to_string())?;
+    split_expr(ctx, &v_assertion.expr)?;
 
-    acc.add(
+    acc.add_verifying(
         AssistId("localize_error", AssistKind::RefactorRewrite),
         "Decompose Failing Assertion",
         assertion.syntax().text_range(),
         |edit| {
+            let result = vst_rewriter_localize_error_minimized(ctx, v_assertion)?;
+            let result = ctx.fmt(assertion.clone(), result.to_string())?;
             edit.replace(assertion.syntax().text_range(), result);
+            Some(())
         },
     )
 }
@@ -145,6 +147,14 @@ pub(crate) fn vst_rewriter_localize_error_minimized(
     assertion: AssertExpr,
 ) -> Option<BlockExpr> {
     let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    // the split assertions take the place of `assertion`, and thus its position among the asserts
+    let assert_index = this_fn
+        .cst
+        .as_ref()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::AssertExpr::cast)
+        .position(|it| Some(&it) == assertion.cst.as_ref())?;
     let exp = &assertion.expr;
     let split_exprs = split_expr(ctx, exp)?;
     // for e in &split_exprs {
@@ -157,10 +167,7 @@ pub(crate) fn vst_rewriter_localize_error_minimized(
         let modified_fn =
             ctx.replace_statement(&this_fn, assertion.clone(), split_assert.clone())?;
         let verif_result = ctx.try_verus(&modified_fn)?;
-        if verif_result.is_failing(&split_assert) {
-            //dbg!(verif_result);
-            // this is not enough -- need to retrieve failing assertions
-            // and check if this split assertion is failing
+        if verif_result.is_failing_at(assert_index, &split_assert) {
             stmts.statements.push(split_assert.into());
         }
     }
@@ -172,13 +179,72 @@ pub(crate) fn vst_rewriter_localize_error_minimized(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_unresolved_with_verifier, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn decompose_attributes_failures_by_position() {
+        // The first split, `a > 10`, also appears as the first assertion of the function. Only
+        // that one fails, so the split must not be kept.
+        let verifier = MockVerifier::new()
+            .fails_asserts("fn foo() { let a: u32 = 1; assert(a > 10); assert(a > 10); }", &[0])
+            .fails_asserts("fn foo() { let a: u32 = 1; assert(a > 10); assert(a < 100); }", &[1]);
+        check_assist_with_verifier(
+            localize_error,
+            verifier,
+            r#"
+use vstd::prelude::*;
+fn foo()
+{
+    let a:u32 = 1;
+    assert(a > 10);
+    ass$0ert(a > 10 && a < 100);
+}
+fn main() {}
+"#,
+            r#"
+use vstd::prelude::*;
+fn foo()
+{
+    let a:u32 = 1;
+    assert(a > 10);
+    {
+        assert(a < 100);
+        assert(a > 10 && a < 100);
+    };
+}
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn discovery_does_not_verify() {
+        check_assist_unresolved_with_verifier(
+            localize_error,
+            MockVerifier::new().panicking(),
+            r#"
+use vstd::prelude::*;
+fn foo()
+{
+    let a:u32 = 1;
+    ass$0ert(a > 10 && a < 100);
+}
+fn main() {}
+"#,
+        );
+    }
 
     // TEST: && (1)
     #[test]
     fn decompose_conjunct_failure() {
-        check_assist(
+        let verifier =
+            MockVerifier::new().fails_asserts("fn foo() { let a: u32 = 1; assert(a > 10); }", &[0]);
+        check_assist_with_verifier(
             localize_error,
+            verifier,
             // before
             r#"
 use vstd::prelude::*;
@@ -208,8 +274,13 @@ fn main() {}
     // TEST: && (2)
     #[test]
     fn decompose_conjunct_failure2() {
-        check_assist(
+        let verifier = MockVerifier::new().fails_asserts(
+            "proof fn lemma_mul_strict_upper_bound(x: int, xbound: int, y: int, ybound: int) requires x < xbound && y < ybound && 0 <= x && 0 <= y ensures x * y <= (xbound - 1) * (ybound - 1) { { assert(y > 0); lemma_mul_inequality(x, xbound - 1, y); }; lemma_mul_inequality(y, ybound-1, xbound-1); }",
+            &[0],
+        );
+        check_assist_with_verifier(
             localize_error,
+            verifier,
             // before
             r#"
 use vstd::prelude::*;
@@ -263,8 +334,13 @@ fn main() {}
     // TEST: inline
     #[test]
     fn decompose_function_inline() {
-        check_assist(
+        let verifier = MockVerifier::new().fails_asserts(
+            "proof fn test() { let mut ss: Seq<int> = Seq::empty(); ss = ss.push(0); ss = ss.push(1); assert(long_seq(ss)); }",
+            &[0],
+        );
+        check_assist_with_verifier(
             localize_error,
+            verifier,
             r#"
 use vstd::prelude::*;
 use vstd::seq::*;
@@ -0,0 +1,562 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::{
+        context_facts::facts_about,
+        vst_diff::apply_vst_edit,
+        vst_subst::{vst_path_expr, Binding},
+    },
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, edit::IndentLevel, make, vst::*, AstNode, HasName},
+    SyntaxNode, T,
+};
+
+// Move an assertion (and its proof block) into a new lemma, and call that lemma instead
+pub(crate) fn extract_lemma(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    ctx.at_this_token(T![assert])?;
+    let assert_expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let func: ast::Fn = assert_expr.syntax().ancestors().find_map(ast::Fn::cast)?;
+
+    // the assertion has to be a statement (or the tail) of a block
+    let stmt = match ast::ExprStmt::cast(assert_expr.syntax().parent()?) {
+        Some(expr_stmt) => expr_stmt.syntax().clone(),
+        None => assert_expr.syntax().clone(),
+    };
+    let stmt_list = ast::StmtList::cast(stmt.parent()?)?;
+    let position = match stmt_list.tail_expr() {
+        Some(tail) if tail.syntax() == &stmt => None,
+        _ => Some(stmt_list.statements().position(|it| it.syntax() == &stmt)?),
+    };
+
+    let assert = AssertExpr::try_from(assert_expr.clone()).ok()?;
+    let free_vars = ctx.vst_free_vars(&assert.clone().into());
+    // a fact can relate the variables of the assertion to other variables, which then become
+    // lemma parameters as well
+    let (mut facts, vars) = facts_about(ctx.facts_at(&func, assert_expr.syntax()), free_vars);
+    let mut params = vec![];
+    let mut args = ArgList::new();
+    let mut renames = vec![];
+    for var in &vars {
+        let ty = ctx.type_of_local(var.local?)?.cst()?;
+        // a free function cannot take `self`, the lemma takes it as an ordinary parameter
+        let param_name = match var.name.as_str() {
+            "self" => fresh_param_name(&vars, "self_"),
+            name => name.to_owned(),
+        };
+        if param_name != var.name {
+            renames.push((var.clone(), vst_path_expr(&param_name)));
+        }
+        let pat = make::ident_pat(false, false, make::name(&param_name));
+        params.push(make::param(pat.into(), ty));
+        args.args.push(vst_path_expr(&var.name));
+    }
+    let param_list = ParamList::try_from(make::param_list(None, params)).ok()?;
+    let mut assert = assert;
+    if !renames.is_empty() {
+        let Expr::AssertExpr(renamed) = ctx.vst_subst(assert.into(), &renames) else {
+            return None;
+        };
+        assert = *renamed;
+        facts = facts.into_iter().map(|it| ctx.vst_subst(it, &renames)).collect();
+    }
+    let name = fresh_lemma_name(ctx, &func);
+
+    // edit TOST nodes
+    let (lemma, call) = vst_rewriter_extract_lemma(ctx, assert, &name, param_list, args, facts)?;
+    let call_stmt: Stmt = if in_ghost_code(&func, stmt_list.syntax()) {
+        call.into()
+    } else {
+        // exec code calls lemmas from a proof block
+        let mut stmts = StmtList::new();
+        stmts.statements.push(call.into());
+        let mut proof_block = BlockExpr::new(stmts);
+        let mut mode = FnMode::new();
+        mode.proof_token = true;
+        proof_block.fn_mode = Some(Box::new(mode));
+        let mut stmt = ExprStmt::new(proof_block);
+        stmt.semicolon_token = false;
+        stmt.into()
+    };
+    let mut v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+    match position {
+        Some(idx) => v_stmt_list.statements[idx] = call_stmt,
+        None => {
+            v_stmt_list.tail_expr = None;
+            v_stmt_list.statements.push(call_stmt);
+        }
+    }
+
+    // pretty-print
+    let call_edit = ctx.vst_edit(&stmt_list, v_stmt_list)?;
+    let lemma = ctx.run_fmt_replacing(&func, func.clone(), lemma.to_string())?;
+
+    // the lemma goes right after the function, or after the impl holding it
+    let anchor = func
+        .syntax()
+        .ancestors()
+        .filter(|it| ast::Impl::can_cast(it.kind()) || ast::Trait::can_cast(it.kind()))
+        .last()
+        .unwrap_or_else(|| func.syntax().clone());
+    let indent = IndentLevel::from_node(&anchor);
+    let lemma = lemma
+        .lines()
+        .map(|line| if line.is_empty() { String::new() } else { format!("{indent}{line}") })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    acc.add(
+        AssistId("extract_lemma", AssistKind::RefactorExtract),
+        format!("Extract assertion into `{name}`"),
+        assert_expr.syntax().text_range(),
+        |edit| {
+            apply_vst_edit(edit, call_edit);
+            edit.insert(anchor.text_range().end(), format!("\n\n{lemma}"));
+        },
+    )
+}
+
+/// Build `proof fn name(params) requires facts ensures assertion { proof }` and the call `name(args)`
+pub(crate) fn vst_rewriter_extract_lemma(
+    ctx: &AssistContext<'_>,
+    assert: AssertExpr,
+    name: &str,
+    param_list: ParamList,
+    args: ArgList,
+    facts: Vec<Expr>,
+) -> Option<(Fn, CallExpr)> {
+    // a prover or a `requires` of the assertion itself does not carry over to a lemma body
+    if assert.name.is_some() || assert.requires_clause.is_some() {
+        return None;
+    }
+
+    let call = ctx.vst_call_expr_from_text(name, args)?;
+
+    let mut lemma_name = Name::new();
+    lemma_name.ident_token = Some(name.to_owned());
+    let mut lemma = Fn::new(lemma_name);
+    let mut mode = FnMode::new();
+    mode.proof_token = true;
+    lemma.fn_mode = Some(Box::new(mode));
+    lemma.param_list = Some(Box::new(param_list));
+    if !facts.is_empty() {
+        let mut requires = RequiresClause::new();
+        requires.exprs = facts;
+        lemma.requires_clause = Some(Box::new(requires));
+    }
+    let mut ensures = EnsuresClause::new();
+    ensures.exprs.push(*assert.expr);
+    lemma.ensures_clause = Some(Box::new(ensures));
+    let body = match assert.block_expr {
+        Some(proof) => *proof,
+        None => BlockExpr::new(StmtList::new()),
+    };
+    lemma.body = Some(Box::new(body));
+    Some((lemma, call))
+}
+
+/// `lemma_<fn name>`, numbered when the file already has a function with that name
+fn fresh_lemma_name(ctx: &AssistContext<'_>, func: &ast::Fn) -> String {
+    let fn_name = func.name().map(|it| it.to_string()).unwrap_or_default();
+    // a lemma extracted from `lemma_foo` is `lemma_foo_2`, not `lemma_lemma_foo`
+    let base = if fn_name.starts_with("lemma_") { fn_name } else { format!("lemma_{fn_name}") };
+    let taken: Vec<String> = ctx
+        .source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .filter_map(|it| it.name())
+        .map(|it| it.to_string())
+        .collect();
+    let mut name = base.clone();
+    let mut idx = 1;
+    while taken.contains(&name) {
+        idx += 1;
+        name = format!("{base}_{idx}");
+    }
+    name
+}
+
+/// `base`, or `base` numbered when one of `vars` already has that name
+fn fresh_param_name(vars: &[Binding], base: &str) -> String {
+    let mut name = base.to_owned();
+    let mut idx = 1;
+    while vars.iter().any(|it| it.name == name) {
+        idx += 1;
+        name = format!("{base}{idx}");
+    }
+    name
+}
+
+/// Whether a lemma can be called directly at `node`, without a `proof` block
+fn in_ghost_code(func: &ast::Fn, node: &SyntaxNode) -> bool {
+    let ghost_fn = func.fn_mode().is_some_and(|it| {
+        it.proof_token().is_some() || it.spec_token().is_some() || it.axiom_token().is_some()
+    });
+    ghost_fn
+        || node.ancestors().take_while(|it| it != func.syntax()).any(|it| {
+            let proof_block = ast::BlockExpr::cast(it.clone())
+                .and_then(|it| it.fn_mode())
+                .is_some_and(|it| it.proof_token().is_some());
+            proof_block
+                || ast::AssertExpr::can_cast(it.kind())
+                || ast::AssertForallExpr::can_cast(it.kind())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn extract_lemma_assert_by() {
+        check_assist(
+            extract_lemma,
+            r#"
+proof fn lemma_sum(x: int, y: int)
+    requires
+        x < 100,
+        y < 100,
+{
+    // the bound on the sum
+    ass$0ert(x + y < 200) by {
+        assert(x + y < 100 + 100);
+    }
+    assert(x + y < 300);
+}
+"#,
+            r#"
+proof fn lemma_sum(x: int, y: int)
+    requires
+        x < 100,
+        y < 100,
+{
+    // the bound on the sum
+    lemma_sum_2(x, y);
+    assert(x + y < 300);
+}
+
+proof fn lemma_sum_2(x: int, y: int)
+    requires
+        x < 100,
+        y < 100,
+    ensures
+        x + y < 200,
+{
+    assert(x + y < 100 + 100);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_lemma_exec_fn() {
+        check_assist(
+            extract_lemma,
+            r#"
+fn count(n: u64, k: u64)
+    requires
+        n < 1000,
+{
+    let mut i: u64 = 0;
+    let m = n + 1;
+    assert(k > 0);
+    while i < n
+        invariant
+            i <= n,
+    {
+        ass$0ert(i <= m);
+        i = i + 1;
+    }
+}
+"#,
+            r#"
+fn count(n: u64, k: u64)
+    requires
+        n < 1000,
+{
+    let mut i: u64 = 0;
+    let m = n + 1;
+    assert(k > 0);
+    while i < n
+        invariant
+            i <= n,
+    {
+        proof {
+            lemma_count(i, m, n);
+        }
+        i = i + 1;
+    }
+}
+
+proof fn lemma_count(i: u64, m: u64, n: u64)
+    requires
+        n < 1000,
+        m == n + 1,
+        i <= n,
+        i < n,
+    ensures
+        i <= m,
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_lemma_path_conditions() {
+        check_assist(
+            extract_lemma,
+            r#"
+enum Shape {
+    Circle(u64),
+    Empty,
+}
+
+proof fn size(x: u64, s: Shape) {
+    if x > 0 {
+    } else {
+        match s {
+            Shape::Circle(r) if r < x => {
+                ass$0ert(r <= x);
+            }
+            _ => {}
+        }
+    }
+}
+"#,
+            r#"
+enum Shape {
+    Circle(u64),
+    Empty,
+}
+
+proof fn size(x: u64, s: Shape) {
+    if x > 0 {
+    } else {
+        match s {
+            Shape::Circle(r) if r < x => {
+                lemma_size(r, x, s);
+            }
+            _ => {}
+        }
+    }
+}
+
+proof fn lemma_size(r: u64, x: u64, s: Shape)
+    requires
+        !(x > 0),
+        s == Shape::Circle(r),
+        r < x,
+    ensures
+        r <= x,
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_lemma_self() {
+        check_assist(
+            extract_lemma,
+            r#"
+struct S {
+    x: int,
+}
+
+impl S {
+    proof fn check(&self)
+        requires
+            self.x > 0,
+    {
+        ass$0ert(self.x >= 0);
+    }
+}
+"#,
+            r#"
+struct S {
+    x: int,
+}
+
+impl S {
+    proof fn check(&self)
+        requires
+            self.x > 0,
+    {
+        lemma_check(self);
+    }
+}
+
+proof fn lemma_check(self_: &S)
+    requires
+        self_.x > 0,
+    ensures
+        self_.x >= 0,
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_lemma_only_pure_lets() {
+        check_assist(
+            extract_lemma,
+            r#"
+struct V {
+    n: u64,
+}
+
+impl V {
+    fn pop(&mut self) -> u64 {
+        0
+    }
+}
+
+fn next(x: u64) -> u64 {
+    x
+}
+
+fn f(v: V, a: u64)
+    requires
+        a < 10,
+{
+    let mut v = v;
+    let b = a + 1;
+    let c = next(a);
+    let d = v.pop();
+    ass$0ert(b + c + d > a);
+}
+"#,
+            r#"
+struct V {
+    n: u64,
+}
+
+impl V {
+    fn pop(&mut self) -> u64 {
+        0
+    }
+}
+
+fn next(x: u64) -> u64 {
+    x
+}
+
+fn f(v: V, a: u64)
+    requires
+        a < 10,
+{
+    let mut v = v;
+    let b = a + 1;
+    let c = next(a);
+    let d = v.pop();
+    proof {
+        lemma_f(b, c, d, a);
+    }
+}
+
+proof fn lemma_f(b: u64, c: u64, d: u64, a: u64)
+    requires
+        a < 10,
+        b == a + 1,
+    ensures
+        b + c + d > a,
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_lemma_drops_facts_about_changed_vars() {
+        check_assist(
+            extract_lemma,
+            r#"
+struct S {
+    x: u64,
+}
+
+impl S {
+    fn clear(&mut self) {}
+}
+
+fn take(s: &mut S) {}
+
+fn g(mut s: S, mut t: S, mut u: S, mut a: [u64; 2], k: u64)
+    requires
+        s.x > 0,
+        t.x > 0,
+        u.x > 0,
+        a[0] > 0,
+        k > 0,
+{
+    s.x = 0;
+    take(&mut t);
+    u.clear();
+    a[0] = 0;
+    ass$0ert(s.x + t.x + u.x + a[0] + k > 0);
+}
+"#,
+            r#"
+struct S {
+    x: u64,
+}
+
+impl S {
+    fn clear(&mut self) {}
+}
+
+fn take(s: &mut S) {}
+
+fn g(mut s: S, mut t: S, mut u: S, mut a: [u64; 2], k: u64)
+    requires
+        s.x > 0,
+        t.x > 0,
+        u.x > 0,
+        a[0] > 0,
+        k > 0,
+{
+    s.x = 0;
+    take(&mut t);
+    u.clear();
+    a[0] = 0;
+    proof {
+        lemma_g(s, t, u, a, k);
+    }
+}
+
+proof fn lemma_g(s: S, t: S, u: S, a: [u64; 2], k: u64)
+    requires
+        k > 0,
+    ensures
+        s.x + t.x + u.x + a[0] + k > 0,
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_lemma_not_for_prover() {
+        check_assist_not_applicable(
+            extract_lemma,
+            r#"
+proof fn mul(x: int, y: int)
+    requires
+        0 <= x,
+        0 <= y,
+{
+    ass$0ert(0 <= x * y) by (nonlinear_arith)
+        requires
+            0 <= x,
+            0 <= y,
+    ;
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_diff::apply_vst_edit,
+    AssistId, AssistKind,
+};
+use syntax::ast::{self, vst::*, AstNode};
+
+// Replace a lemma call with its `requires` as assertions,
+// and its `ensures` proven by the body of the lemma
+pub(crate) fn inline_lemma(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on the name of the lemma at the callsite
+    let name_ref: ast::NameRef = ctx.find_node_at_offset()?;
+    let call: ast::CallExpr = name_ref.syntax().ancestors().find_map(ast::CallExpr::cast)?;
+    let ast::Expr::PathExpr(callee) = call.expr()? else { return None };
+    if callee.path()?.segment()?.name_ref()? != name_ref {
+        return None;
+    }
+
+    // the call has to be a statement
+    let stmt = ast::ExprStmt::cast(call.syntax().parent()?)?;
+    let stmt_list = ast::StmtList::cast(stmt.syntax().parent()?)?;
+    let position = stmt_list.statements().position(|it| it.syntax() == stmt.syntax())?;
+
+    // lift CST into TOST nodes
+    let v_call = CallExpr::try_from(call.clone()).ok()?;
+    let v_name_ref = NameRef::try_from(name_ref).ok()?;
+    let lemma = ctx.vst_find_fn(&v_call)?;
+
+    // edit TOST nodes
+    let inlined = vst_rewriter_inline_lemma(ctx, v_name_ref, &lemma)?;
+    let mut v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+    v_stmt_list.statements.splice(position..=position, inlined);
+    let result = ctx.vst_edit(&stmt_list, v_stmt_list)?;
+
+    acc.add(
+        AssistId("inline_lemma", AssistKind::RefactorInline),
+        format!("Inline `{}` into an assert-by block", lemma.name.to_string().trim()),
+        call.syntax().text_range(),
+        |edit| apply_vst_edit(edit, result),
+    )
+}
+
+/// `lemma(args)` becomes `assert(requires); assert(ensures) by { body }`,
+/// with the parameters of `lemma` replaced by the arguments at `name_ref`
+pub(crate) fn vst_rewriter_inline_lemma(
+    ctx: &AssistContext<'_>,
+    name_ref: NameRef,
+    lemma: &Fn,
+) -> Option<Vec<Stmt>> {
+    // only lemmas: proof functions without return value
+    if !lemma.fn_mode.as_ref().is_some_and(|it| it.proof_token) || lemma.ret_type.is_some() {
+        return None;
+    }
+    let inline = |e: Expr| ctx.vst_inline_call(name_ref.clone(), e);
+    let requires = match &lemma.requires_clause {
+        Some(requires) => requires.exprs.iter().cloned().map(inline).collect::<Option<Vec<_>>>()?,
+        None => vec![],
+    };
+    let ensures = lemma.ensures_clause.as_ref()?.exprs.iter().cloned().map(inline);
+    let ensures = ctx.reduce_exprs(ensures.collect::<Option<Vec<_>>>()?)?;
+    let body = match inline(lemma.body.as_deref()?.clone().into())? {
+        Expr::BlockExpr(body) => unwrap_block(*body),
+        body => {
+            let mut stmts = StmtList::new();
+            stmts.tail_expr = Some(Box::new(body));
+            BlockExpr::new(stmts)
+        }
+    };
+
+    let mut stmts: Vec<Stmt> =
+        requires.iter().cloned().map(|e| AssertExpr::new(e).into()).collect();
+    let mut assert = AssertExpr::new(ensures);
+    assert.by_token = true;
+    if let Some(prover) = &lemma.prover {
+        // provers like `nonlinear_arith` do not see the context, pass the requires along
+        assert.name = Some(prover.name.clone());
+        if !requires.is_empty() {
+            let mut clause = RequiresClause::new();
+            clause.exprs = requires;
+            assert.requires_clause = Some(Box::new(clause));
+        }
+    }
+    assert.block_expr = Some(Box::new(body));
+    stmts.push(assert.into());
+    Some(stmts)
+}
+
+/// Inlining wraps the body in another block, `{ { .. } }` gives `{ .. }`
+fn unwrap_block(block: BlockExpr) -> BlockExpr {
+    match &block.stmt_list.tail_expr {
+        Some(tail) if block.stmt_list.statements.is_empty() => match tail.as_ref() {
+            Expr::BlockExpr(inner) => unwrap_block(*inner.clone()),
+            _ => block,
+        },
+        _ => block,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn inline_lemma_with_body() {
+        check_assist(
+            inline_lemma,
+            r#"
+proof fn lemma_double(x: int, y: int)
+    requires
+        0 <= x,
+        x <= y,
+    ensures
+        x + x <= y + y,
+{
+    assert(x + x <= x + y);
+}
+
+proof fn use_it(a: int, b: int)
+    requires
+        0 <= a < b,
+{
+    lemma_do$0uble(a, b - 1);
+    assert(a + a <= 2 * b);
+}
+"#,
+            r#"
+proof fn lemma_double(x: int, y: int)
+    requires
+        0 <= x,
+        x <= y,
+    ensures
+        x + x <= y + y,
+{
+    assert(x + x <= x + y);
+}
+
+proof fn use_it(a: int, b: int)
+    requires
+        0 <= a < b,
+{
+    assert(0 <= a);
+    assert(a <= b - 1);
+    assert(a + a <= b - 1 + (b - 1)) by {
+        assert(a + a <= a + (b - 1));
+    };
+    assert(a + a <= 2 * b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_lemma_nonlinear() {
+        check_assist(
+            inline_lemma,
+            r#"
+proof fn lemma_mul_inequality(x: int, y: int, z: int) by (nonlinear_arith)
+    requires
+        x <= y,
+        z > 0,
+    ensures
+        x * z <= y * z,
+{
+}
+
+proof fn use_it(a: int, b: int) {
+    assume(a <= b);
+    lemma_mul_$0inequality(a, b, 3);
+}
+"#,
+            r#"
+proof fn lemma_mul_inequality(x: int, y: int, z: int) by (nonlinear_arith)
+    requires
+        x <= y,
+        z > 0,
+    ensures
+        x * z <= y * z,
+{
+}
+
+proof fn use_it(a: int, b: int) {
+    assume(a <= b);
+    assert(a <= b);
+    assert(3 > 0);
+    assert(a * 3 <= b * 3) by (nonlinear_arith)
+        requires
+            a <= b,
+            3 > 0,
+    {};
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_lemma_not_for_spec_fn() {
+        check_assist_not_applicable(
+            inline_lemma,
+            r#"
+spec fn double(x: int) -> int {
+    x + x
+}
+
+proof fn use_it(a: int) {
+    dou$0ble(a);
+}
+"#,
+        );
+    }
+}
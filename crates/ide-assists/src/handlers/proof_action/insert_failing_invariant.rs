@@ -0,0 +1,399 @@
+use crate::proof_plumber_api::verus_error::*;
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+use crate::proof_plumber_api::vst_diff::apply_vst_edit;
+use syntax::{
+    ast::{self, vst::*},
+    AstNode,
+};
+
+pub(crate) fn intro_failing_invariant(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "invariant" of a loop
+    let invariant: ast::InvariantClause = ctx.find_node_at_offset::<ast::InvariantClause>()?;
+    let invariant_keyword = invariant.invariant_token()?;
+    if !invariant_keyword.text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    let the_loop = invariant.syntax().ancestors().find_map(ast::Expr::cast)?;
+    if !matches!(the_loop, ast::Expr::WhileExpr(_) | ast::Expr::LoopExpr(_) | ast::Expr::ForExpr(_))
+    {
+        return None;
+    }
+
+    // failing invariants of this loop, in the order they are written
+    let mut inv_fails: Vec<InvFailure> = ctx
+        .inv_failures()
+        .into_iter()
+        .filter(|inv| invariant.syntax().text_range().contains_range(inv.failing_inv))
+        .collect();
+    inv_fails.sort_by_key(|inv| inv.failing_inv.start());
+    let asserts_at = |at: InvFailureAt| -> Option<Vec<Stmt>> {
+        inv_fails
+            .iter()
+            .filter(|inv| inv.at == at)
+            .map(|inv| Some(AssertExpr::new(ctx.expr_from_inv_failure(inv.clone())?).into()))
+            .collect()
+    };
+    let before_loop = asserts_at(InvFailureAt::BeforeLoop)?;
+    let end_of_body = asserts_at(InvFailureAt::EndOfBody)?;
+    if before_loop.is_empty() && end_of_body.is_empty() {
+        return None;
+    }
+
+    // the loop is either a statement or the tail of its surrounding statement list
+    let loop_stmt = the_loop
+        .syntax()
+        .ancestors()
+        .find(|it| it.parent().and_then(ast::StmtList::cast).is_some())?;
+    let stmt_list = ast::StmtList::cast(loop_stmt.parent()?)?;
+    let position = match stmt_list.tail_expr() {
+        Some(tail) if tail.syntax() == &loop_stmt => None,
+        _ => Some(stmt_list.statements().position(|it| it.syntax() == &loop_stmt)?),
+    };
+
+    let v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+    let result =
+        vst_rewriter_intro_failing_invariant(v_stmt_list, position, before_loop, end_of_body)?;
+    let result = ctx.vst_edit(&stmt_list, result)?;
+
+    acc.add(
+        AssistId("intro_failing_invariant", AssistKind::RefactorRewrite),
+        "Insert failing loop invariants as assertions",
+        the_loop.syntax().text_range(),
+        |edit| apply_vst_edit(edit, result),
+    )
+}
+
+/// Asserts `before_loop` right before the loop and `end_of_body` on every path back to the loop
+/// head. The loop is the statement at `position` of `stmt_list`, or its tail when `None`.
+pub(crate) fn vst_rewriter_intro_failing_invariant(
+    mut stmt_list: StmtList,
+    position: Option<usize>,
+    before_loop: Vec<Stmt>,
+    end_of_body: Vec<Stmt>,
+) -> Option<StmtList> {
+    let the_loop = match position {
+        Some(idx) => match stmt_list.statements.get_mut(idx)? {
+            Stmt::ExprStmt(stmt) => stmt.expr.as_mut(),
+            _ => return None,
+        },
+        None => stmt_list.tail_expr.as_deref_mut()?,
+    };
+    let (label, body) = match the_loop {
+        Expr::WhileExpr(it) => (&it.label, &mut it.loop_body),
+        Expr::LoopExpr(it) => (&it.label, &mut it.loop_body),
+        Expr::ForExpr(it) => (&it.label, &mut it.loop_body),
+        _ => return None,
+    };
+
+    if !end_of_body.is_empty() {
+        let mut continue_sites = ContinueSites {
+            label: label.as_ref().map(|it| it.lifetime.to_string()),
+            asserts: &end_of_body,
+            depth: 0,
+        };
+        let mut body_stmts = continue_sites.fold_stmt_list(*body.stmt_list.clone());
+        if let Some(tail) = body_stmts.tail_expr.take() {
+            if continue_sites.targets(&tail) {
+                // already asserted before this `continue`
+                body_stmts.tail_expr = Some(tail);
+            } else {
+                let mut tail_stmt = ExprStmt::new(*tail);
+                tail_stmt.semicolon_token = !is_block_like(&tail_stmt.expr);
+                body_stmts.statements.push(tail_stmt.into());
+                body_stmts.statements.extend(end_of_body.iter().cloned());
+            }
+        } else if !body_stmts.statements.last().is_some_and(diverges) {
+            body_stmts.statements.extend(end_of_body.iter().cloned());
+        }
+        *body.stmt_list = body_stmts;
+    }
+
+    let at = position.unwrap_or(stmt_list.statements.len());
+    stmt_list.statements.splice(at..at, before_loop);
+    Some(stmt_list)
+}
+
+/// Inserts `asserts` before every `continue` that jumps to the head of the loop being rewritten
+struct ContinueSites<'a> {
+    label: Option<String>,
+    asserts: &'a [Stmt],
+    /// number of nested loops around the current node
+    depth: usize,
+}
+
+impl ContinueSites<'_> {
+    fn targets(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::ContinueExpr(it) => match &it.lifetime {
+                Some(lifetime) => self.label.as_deref() == Some(&*lifetime.to_string()),
+                None => self.depth == 0,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl VstFold for ContinueSites<'_> {
+    fn fold_stmt_list(&mut self, mut node: StmtList) -> StmtList {
+        let mut statements = vec![];
+        for stmt in std::mem::take(&mut node.statements) {
+            match &stmt {
+                Stmt::ExprStmt(it) if self.targets(&it.expr) => {
+                    statements.extend(self.asserts.iter().cloned());
+                    statements.push(stmt);
+                }
+                _ => statements.push(self.fold_stmt(stmt)),
+            }
+        }
+        node.tail_expr = match node.tail_expr.take() {
+            Some(tail) if self.targets(&tail) => {
+                statements.extend(self.asserts.iter().cloned());
+                Some(tail)
+            }
+            Some(tail) => Some(Box::new(self.fold_expr(*tail))),
+            None => None,
+        };
+        node.statements = statements;
+        node
+    }
+
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        if self.targets(&node) {
+            // `continue` in expression position, e.g. a match arm
+            let mut stmt_list = StmtList::new();
+            stmt_list.statements = self.asserts.to_vec();
+            stmt_list.tail_expr = Some(Box::new(node));
+            return BlockExpr::new(stmt_list).into();
+        }
+        walk_fold_expr(self, node)
+    }
+
+    fn fold_while_expr(&mut self, node: WhileExpr) -> WhileExpr {
+        self.depth += 1;
+        let node = walk_fold_while_expr(self, node);
+        self.depth -= 1;
+        node
+    }
+
+    fn fold_loop_expr(&mut self, node: LoopExpr) -> LoopExpr {
+        self.depth += 1;
+        let node = walk_fold_loop_expr(self, node);
+        self.depth -= 1;
+        node
+    }
+
+    fn fold_for_expr(&mut self, node: ForExpr) -> ForExpr {
+        self.depth += 1;
+        let node = walk_fold_for_expr(self, node);
+        self.depth -= 1;
+        node
+    }
+
+    fn fold_closure_expr(&mut self, node: ClosureExpr) -> ClosureExpr {
+        // a `continue` cannot leave a closure
+        node
+    }
+}
+
+fn is_block_like(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::IfExpr(_)
+            | Expr::MatchExpr(_)
+            | Expr::BlockExpr(_)
+            | Expr::WhileExpr(_)
+            | Expr::LoopExpr(_)
+            | Expr::ForExpr(_)
+    )
+}
+
+/// The end of the loop body is unreachable after this statement
+fn diverges(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::ExprStmt(it) => {
+            matches!(*it.expr, Expr::ContinueExpr(_) | Expr::BreakExpr(_) | Expr::ReturnExpr(_))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist_not_applicable, check_assist_with_verus_error};
+
+    use super::*;
+
+    #[test]
+    fn intro_failing_invariant_before_loop() {
+        check_assist_with_verus_error(
+            intro_failing_invariant,
+            vec![
+                mk_inv_failure(111, 117, InvFailureAt::BeforeLoop),
+                mk_inv_failure(131, 143, InvFailureAt::EndOfBody),
+            ],
+            r#"
+fn sum(n: u32) {
+    let mut i: u32 = 0;
+    let mut s: u32 = 0;
+    while i < n
+        inv$0ariant
+            i <= n,
+            s <= i * 100,
+    {
+        s = s + 1;
+        i = i + 1;
+    }
+}
+"#,
+            r#"
+fn sum(n: u32) {
+    let mut i: u32 = 0;
+    let mut s: u32 = 0;
+    assert(i <= n);
+    while i < n
+        invariant
+            i <= n,
+            s <= i * 100,
+    {
+        s = s + 1;
+        i = i + 1;
+        assert(s <= i * 100);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn intro_failing_invariant_continue() {
+        check_assist_with_verus_error(
+            intro_failing_invariant,
+            vec![mk_inv_failure(162, 168, InvFailureAt::EndOfBody)],
+            r#"
+fn count_nonzero(v: Vec<u64>) {
+    let mut i: usize = 0;
+    let mut k: usize = 0;
+    while i < v.len()
+        inv$0ariant
+            i <= v.len(),
+            k <= i,
+    {
+        i = i + 1;
+        if v[i - 1] == 0 {
+            continue;
+        }
+        k = k + 1;
+    }
+}
+"#,
+            r#"
+fn count_nonzero(v: Vec<u64>) {
+    let mut i: usize = 0;
+    let mut k: usize = 0;
+    while i < v.len()
+        invariant
+            i <= v.len(),
+            k <= i,
+    {
+        i = i + 1;
+        if v[i - 1] == 0 {
+            assert(k <= i);
+            continue;
+        }
+        k = k + 1;
+        assert(k <= i);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn intro_failing_invariant_nested_loop() {
+        check_assist_with_verus_error(
+            intro_failing_invariant,
+            vec![mk_inv_failure(81, 87, InvFailureAt::EndOfBody)],
+            r#"
+fn walk(n: u64) {
+    let mut i: u64 = 0;
+    loop
+        inv$0ariant
+            i <= n,
+    {
+        if i >= n {
+            break;
+        }
+        let mut j: u64 = 0;
+        while j < i {
+            j = j + 1;
+            continue;
+        }
+        match i % 3 {
+            0 => {
+                i = i + 1;
+                continue
+            }
+            1 => continue,
+            _ => {}
+        }
+        i = i + 1
+    }
+}
+"#,
+            r#"
+fn walk(n: u64) {
+    let mut i: u64 = 0;
+    loop
+        invariant
+            i <= n,
+    {
+        if i >= n {
+            break;
+        }
+        let mut j: u64 = 0;
+        while j < i {
+            j = j + 1;
+            continue;
+        }
+        match i % 3 {
+            0 => {
+                i = i + 1;
+                assert(i <= n);
+                continue
+            },
+            1 => {
+                assert(i <= n);
+                continue
+            },
+            _ => {},
+        }
+        i = i + 1;
+        assert(i <= n);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn intro_failing_invariant_not_failing() {
+        check_assist_not_applicable(
+            intro_failing_invariant,
+            r#"
+fn sum(n: u32) {
+    let mut i: u32 = 0;
+    let mut s: u32 = 0;
+    while i < n
+        inv$0ariant
+            i <= n,
+            s <= i * 100,
+    {
+        s = s + 1;
+        i = i + 1;
+    }
+}
+"#,
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::proof_plumber_api::verus_error::*;
 use crate::{AssistContext, AssistId, AssistKind, Assists};
 
-use crate::proof_plumber_api::vst_ext;
+use crate::proof_plumber_api::{vst_diff::apply_vst_edit, vst_ext};
 use syntax::{
     ast::{self, vst::*},
     AstNode,
@@ -23,30 +23,36 @@ pub(crate) fn intro_failing_ensures(acc: &mut Assists, ctx: &AssistContext<'_>)
 
     let v_body = BlockExpr::try_from(body.clone()).ok()?;
     let result = vst_rewriter_intro_failing_ensures(ctx, v_body.clone())?;
-    let result = ctx.fmt(body.clone(), result.to_string())?;
+    let result = ctx.vst_edit(&body, result)?;
 
     acc.add(
         AssistId("intro_failing_ensures", AssistKind::RefactorRewrite),
         "Insert failing ensures clauses to the end",
         body.syntax().text_range(),
-        |edit| {
-            edit.replace(body.syntax().text_range(), result);
-        },
+        |edit| apply_vst_edit(edit, result),
     )
 }
 
 pub(crate) fn vst_rewriter_intro_failing_ensures(
     ctx: &AssistContext<'_>,
-    mut blk: BlockExpr,
+    blk: BlockExpr,
 ) -> Option<BlockExpr> {
     let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
     let post_fails = filter_post_failures(&ctx.verus_errors_inside_fn(&this_fn)?);
     let failed_exprs: Option<Vec<Expr>> =
         post_fails.into_iter().map(|p| ctx.expr_from_post_failure(p)).collect();
+    vst_rewriter_assert_at_end(&this_fn, blk, failed_exprs?)
+}
+
+/// `blk`, the body of `vst_node`, asserting `exprs` where it returns
+pub(crate) fn vst_rewriter_assert_at_end(
+    vst_node: &Fn,
+    mut blk: BlockExpr,
+    exprs: Vec<Expr>,
+) -> Option<BlockExpr> {
     let asserts_failed_exprs =
-        failed_exprs?.into_iter().map(|e| AssertExpr::new(e).into()).collect::<Vec<Stmt>>();
+        exprs.into_iter().map(|e| AssertExpr::new(e).into()).collect::<Vec<Stmt>>();
 
-    let vst_node = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
     if vst_node.ret_type.is_some() {
         // need to map in-place for each tail expression
         // when the function has a returning expression `e`
@@ -56,8 +62,8 @@ pub(crate) fn vst_rewriter_intro_failing_ensures(
         // assert(failing_stuff);
         // ret
         // ```
-        let pat = vst_node.ret_type?.pat?.clone();
-        let tail = vst_node.body?.stmt_list.tail_expr?;
+        let pat = vst_node.ret_type.clone()?.pat?;
+        let tail = blk.stmt_list.tail_expr.clone()?;
         let cb = &mut |e: &mut Expr| {
             let mut new_binding = LetExpr::new(e.clone());
             new_binding.pat = Some(pat.clone());
@@ -117,7 +123,6 @@ proof fn my_proof_fun(x: int, y: int)
     assert(x + y < 600);
     assert(x + y < 100);
 }
-
 "#,
         );
     }
@@ -160,7 +165,6 @@ proof fn my_proof_fun(x: int, y: int) -> (sum: int)
         sum
     }
 }
-
 "#,
         );
     }
@@ -207,7 +211,6 @@ proof fn my_proof_fun(x: int, y: int) -> (sum: int)
         sum
     }
 }
-
 "#,
         );
     }
@@ -255,7 +258,6 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
     };
     assert(fibo(i) <= fibo(j));
 }
-
 "#,
         );
     }
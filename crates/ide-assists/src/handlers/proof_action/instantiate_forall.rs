@@ -0,0 +1,432 @@
+use std::cmp::Reverse;
+
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::{
+        vst_diff::apply_vst_edit, vst_ext::vst_quantifier_body, vst_subst::Binding,
+    },
+    AssistId, AssistKind, GroupLabel,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode, HasAttrs, LogicOp},
+    NodeOrToken, SyntaxNode, T,
+};
+
+/// Terms of the assertion that the bound variables of a quantifier are matched with
+type Instantiation = Vec<(String, ast::Expr)>;
+
+// Assert an instance of a `forall` fact known at an assertion, for each way the triggers of the
+// quantifier match terms of the assertion. Instances matching more triggers come first.
+pub(crate) fn instantiate_forall(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    ctx.at_this_token(T![assert])?;
+    let assert_expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let goal = assert_expr.expr()?;
+    let func: ast::Fn = assert_expr.syntax().ancestors().find_map(ast::Fn::cast)?;
+
+    // the assertion has to be a statement (or the tail) of a block
+    let stmt = match ast::ExprStmt::cast(assert_expr.syntax().parent()?) {
+        Some(expr_stmt) => expr_stmt.syntax().clone(),
+        None => assert_expr.syntax().clone(),
+    };
+    let stmt_list = ast::StmtList::cast(stmt.parent()?)?;
+    let position = match stmt_list.tail_expr() {
+        Some(tail) if tail.syntax() == &stmt => stmt_list.statements().count(),
+        _ => stmt_list.statements().position(|it| it.syntax() == &stmt)?,
+    };
+
+    // terms bound inside the assertion are out of scope before it
+    let terms: Vec<ast::Expr> = outside_closures(goal.syntax()).collect();
+    let mut instances: Vec<(usize, String, Expr)> = vec![];
+    for fact in ctx.facts_at(&func, assert_expr.syntax()) {
+        for forall in foralls(fact.expr) {
+            for (score, inst) in instantiations(&forall, &terms) {
+                let Some(instance) = vst_rewriter_instantiate_forall(ctx, &forall, &inst) else {
+                    continue;
+                };
+                if instances.iter().any(|(_, _, it)| it.to_string() == instance.to_string()) {
+                    continue;
+                }
+                let label = inst
+                    .iter()
+                    .map(|(name, term)| format!("{name} = {term}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                instances.push((score, label, instance));
+            }
+        }
+    }
+    // a stable sort keeps the order of the facts among instances of the same rank
+    instances.sort_by_key(|(score, _, _)| Reverse(*score));
+
+    let group = GroupLabel("Instantiate a `forall` fact".to_owned());
+    for (_, label, instance) in instances {
+        let mut v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+        v_stmt_list.statements.insert(position, AssertExpr::new(instance).into());
+        let result = ctx.vst_edit(&stmt_list, v_stmt_list)?;
+        acc.add_group(
+            &group,
+            AssistId("instantiate_forall", AssistKind::RefactorRewrite),
+            format!("Instantiate `forall` with `{label}`"),
+            assert_expr.syntax().text_range(),
+            |edit| apply_vst_edit(edit, result),
+        );
+    }
+    Some(())
+}
+
+/// The body of `forall`, with its bound variables replaced by the terms of `inst`
+pub(crate) fn vst_rewriter_instantiate_forall(
+    ctx: &AssistContext<'_>,
+    forall: &ast::ClosureExpr,
+    inst: &Instantiation,
+) -> Option<Expr> {
+    let body = vst_quantifier_body(forall)?;
+    let mut subst: Vec<(Binding, Expr)> = vec![];
+    for binding in bound_vars(ctx, forall)? {
+        let (_, term) = inst.iter().find(|(name, _)| *name == binding.name)?;
+        subst.push((binding, Expr::try_from(term.clone()).ok()?));
+    }
+    Some(ctx.vst_subst(body, &subst))
+}
+
+/// The `forall` quantifiers among the conjuncts of `fact`
+fn foralls(fact: Expr) -> Vec<ast::ClosureExpr> {
+    match fact {
+        Expr::BinExpr(e) if e.op == BinaryOp::LogicOp(LogicOp::And) => {
+            let mut res = foralls(*e.lhs);
+            res.extend(foralls(*e.rhs));
+            res
+        }
+        Expr::ParenExpr(e) => foralls(*e.expr),
+        Expr::ClosureExpr(e) if e.forall_token => e.cst.into_iter().collect(),
+        _ => vec![],
+    }
+}
+
+fn bound_vars(ctx: &AssistContext<'_>, forall: &ast::ClosureExpr) -> Option<Vec<Binding>> {
+    let mut vars = vec![];
+    for param in forall.param_list()?.params() {
+        let pat = Pat::try_from(param.pat()?).ok()?;
+        vars.extend(ctx.vst_pat_bindings(&pat));
+    }
+    Some(vars)
+}
+
+/// Instantiations of `forall` whose triggers all occur among `terms`, with the number of trigger
+/// terms each of them makes occur in `terms`
+fn instantiations(forall: &ast::ClosureExpr, terms: &[ast::Expr]) -> Vec<(usize, Instantiation)> {
+    let Some(bound) = forall.param_list().map(|params| {
+        params
+            .params()
+            .filter_map(|it| it.pat())
+            .flat_map(|it| {
+                it.syntax().descendants().filter_map(ast::Name::cast).collect::<Vec<_>>()
+            })
+            .map(|it| it.text().to_string())
+            .collect::<Vec<_>>()
+    }) else {
+        return vec![];
+    };
+    let groups = triggers(forall, &bound);
+
+    let mut found: Vec<Instantiation> = vec![];
+    for group in &groups {
+        let mut matches = vec![];
+        match_all(group, terms, &bound, vec![], &mut matches);
+        for inst in matches {
+            let complete = bound.iter().all(|var| inst.iter().any(|(name, _)| name == var));
+            if complete && !found.iter().any(|it| same_instantiation(it, &inst)) {
+                found.push(inst);
+            }
+        }
+    }
+
+    let mut all_triggers: Vec<&ast::Expr> = groups.iter().flatten().collect();
+    all_triggers.dedup_by(|a, b| same_text(a.syntax(), b.syntax()));
+    found
+        .into_iter()
+        .map(|inst| {
+            let score = all_triggers
+                .iter()
+                .filter(|pat| {
+                    terms.iter().any(|term| {
+                        let mut inst = inst.clone();
+                        match_term(pat.syntax(), term.syntax(), &bound, &mut inst)
+                    })
+                })
+                .count();
+            (score, inst)
+        })
+        .collect()
+}
+
+/// Each trigger is a list of terms that have to occur together. Like Verus, without a
+/// `#![trigger ..]` or `#[trigger]` annotation, calls and indexing mentioning every bound
+/// variable are used as triggers.
+fn triggers(forall: &ast::ClosureExpr, bound: &[String]) -> Vec<Vec<ast::Expr>> {
+    let explicit: Vec<Vec<ast::Expr>> = forall
+        .attrs()
+        .filter_map(|it| it.trigger_attribute())
+        .map(|it| it.exprs().collect::<Vec<_>>())
+        .filter(|it| !it.is_empty())
+        .collect();
+    if !explicit.is_empty() {
+        return explicit;
+    }
+    let Some(body) = forall.body() else { return vec![] };
+    let marked: Vec<ast::Expr> = outside_closures(body.syntax())
+        .filter(|it| {
+            it.attrs().any(|attr| {
+                attr.trigger_attribute().is_some_and(|trigger| trigger.exprs().next().is_none())
+            })
+        })
+        .collect();
+    if !marked.is_empty() {
+        return vec![marked];
+    }
+    outside_closures(body.syntax())
+        .filter(|it| {
+            matches!(
+                it,
+                ast::Expr::CallExpr(_) | ast::Expr::MethodCallExpr(_) | ast::Expr::IndexExpr(_)
+            )
+        })
+        .filter(|it| {
+            let mentioned: Vec<String> =
+                it.syntax().descendants().filter_map(|it| bound_var(&it, bound)).collect();
+            bound.iter().all(|var| mentioned.contains(var))
+        })
+        .map(|it| vec![it])
+        .collect()
+}
+
+/// Expressions inside `root` that are not under a closure or quantifier below it
+fn outside_closures(root: &SyntaxNode) -> impl Iterator<Item = ast::Expr> + 'static {
+    let root = root.clone();
+    root.descendants().filter_map(ast::Expr::cast).filter(move |it| {
+        it.syntax()
+            .ancestors()
+            .take_while(|it| it != &root)
+            .all(|it| !ast::ClosureExpr::can_cast(it.kind()))
+    })
+}
+
+/// Match every pattern of `group` with one of `terms`, extending `inst` consistently
+fn match_all(
+    group: &[ast::Expr],
+    terms: &[ast::Expr],
+    bound: &[String],
+    inst: Instantiation,
+    res: &mut Vec<Instantiation>,
+) {
+    let Some((pat, rest)) = group.split_first() else {
+        res.push(inst);
+        return;
+    };
+    for term in terms {
+        let mut inst = inst.clone();
+        if match_term(pat.syntax(), term.syntax(), bound, &mut inst) {
+            match_all(rest, terms, bound, inst, res);
+        }
+    }
+}
+
+/// Syntactic matching of a trigger term against a term, where the `bound` variables of the
+/// trigger match any expression
+fn match_term(
+    pat: &SyntaxNode,
+    term: &SyntaxNode,
+    bound: &[String],
+    inst: &mut Instantiation,
+) -> bool {
+    if let Some(name) = bound_var(pat, bound) {
+        let Some(term) = ast::Expr::cast(term.clone()) else { return false };
+        return match inst.iter().find(|(it, _)| *it == name) {
+            Some((_, prev)) => same_text(prev.syntax(), term.syntax()),
+            None => {
+                inst.push((name, term));
+                true
+            }
+        };
+    }
+    if pat.kind() != term.kind() {
+        return false;
+    }
+    let (pats, terms) = (significant_children(pat), significant_children(term));
+    pats.len() == terms.len()
+        && pats.into_iter().zip(terms).all(|it| match it {
+            (NodeOrToken::Node(pat), NodeOrToken::Node(term)) => {
+                match_term(&pat, &term, bound, inst)
+            }
+            (NodeOrToken::Token(pat), NodeOrToken::Token(term)) => {
+                pat.kind() == term.kind() && pat.text() == term.text()
+            }
+            _ => false,
+        })
+}
+
+/// Children without whitespace, comments and attributes
+fn significant_children(node: &SyntaxNode) -> Vec<syntax::SyntaxElement> {
+    node.children_with_tokens()
+        .filter(|it| match it {
+            NodeOrToken::Node(it) => !ast::Attr::can_cast(it.kind()),
+            NodeOrToken::Token(it) => !it.kind().is_trivia(),
+        })
+        .collect()
+}
+
+/// The name of the variable at `node`, when it is one of `bound`
+fn bound_var(node: &SyntaxNode, bound: &[String]) -> Option<String> {
+    let path = ast::PathExpr::cast(node.clone())?.path()?;
+    if path.qualifier().is_some() {
+        return None;
+    }
+    let name = path.segment()?.name_ref()?.text().to_string();
+    bound.contains(&name).then_some(name)
+}
+
+fn same_text(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    let tokens = |node: &SyntaxNode| {
+        node.descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|it| !it.kind().is_trivia())
+            .map(|it| it.text().to_owned())
+            .collect::<Vec<_>>()
+    };
+    tokens(a) == tokens(b)
+}
+
+fn same_instantiation(a: &Instantiation, b: &Instantiation) -> bool {
+    a.len() == b.len()
+        && a.iter().all(|(name, term)| {
+            b.iter().any(|(it, other)| it == name && same_text(term.syntax(), other.syntax()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
+
+    #[test]
+    fn instantiate_forall_requires() {
+        check_assist(
+            instantiate_forall,
+            r#"
+proof fn positive(s: Seq<int>)
+    requires
+        s.len() > 5,
+        forall|i: int| 0 <= i < s.len() ==> s[i] > 0,
+{
+    ass$0ert(s[3] > 0);
+}
+"#,
+            r#"
+proof fn positive(s: Seq<int>)
+    requires
+        s.len() > 5,
+        forall|i: int| 0 <= i < s.len() ==> s[i] > 0,
+{
+    assert(0 <= 3 < s.len() ==> s[3] > 0);
+    assert(s[3] > 0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn instantiate_forall_ranked_by_triggers() {
+        // `i = a` matches both `f(a)` and `g(a)`, and comes before `i = b`
+        check_assist_by_label(
+            instantiate_forall,
+            r#"
+spec fn f(x: int) -> int;
+
+spec fn g(x: int) -> int;
+
+proof fn bounded(a: int, b: int) {
+    assume(forall|i: int| #![trigger f(i)] #![trigger g(i)] f(i) <= g(i));
+    ass$0ert(f(a) <= g(b) + g(a) - f(b));
+}
+"#,
+            r#"
+spec fn f(x: int) -> int;
+
+spec fn g(x: int) -> int;
+
+proof fn bounded(a: int, b: int) {
+    assume(forall|i: int| #![trigger f(i)] #![trigger g(i)] f(i) <= g(i));
+    assert(f(a) <= g(a));
+    assert(f(a) <= g(b) + g(a) - f(b));
+}
+"#,
+            "Instantiate `forall` with `i = a`",
+        );
+    }
+
+    #[test]
+    fn instantiate_forall_invariant_with_trigger_annotation() {
+        check_assist(
+            instantiate_forall,
+            r#"
+spec fn even(x: int) -> bool;
+
+fn count(v: Vec<u64>, n: usize)
+    requires
+        n == v.len(),
+{
+    let mut i: usize = 0;
+    while i < n
+        invariant
+            n == v.len(),
+            forall|j: int| 0 <= j < i ==> #[trigger] even(v[j] as int),
+    {
+        if i > 0 {
+            ass$0ert(even(v[0] as int));
+        }
+        i = i + 1;
+    }
+}
+"#,
+            r#"
+spec fn even(x: int) -> bool;
+
+fn count(v: Vec<u64>, n: usize)
+    requires
+        n == v.len(),
+{
+    let mut i: usize = 0;
+    while i < n
+        invariant
+            n == v.len(),
+            forall|j: int| 0 <= j < i ==> #[trigger] even(v[j] as int),
+    {
+        if i > 0 {
+            assert(0 <= 0 < i ==> even(v[0] as int));
+            assert(even(v[0] as int));
+        }
+        i = i + 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn instantiate_forall_no_matching_trigger() {
+        check_assist_not_applicable(
+            instantiate_forall,
+            r#"
+spec fn f(x: int) -> int;
+
+proof fn nothing(s: Seq<int>)
+    requires
+        forall|i: int| 0 <= i < s.len() ==> f(s[i]) > 0,
+{
+    ass$0ert(f(3) > 0);
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,541 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::{
+        vst_diff::apply_vst_edit,
+        vst_ext::vst_quantifier_body,
+        vst_subst::{vst_path_expr, Binding},
+    },
+    AssistId, AssistKind, GroupLabel,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode, LogicOp},
+    SyntaxKind, SyntaxNode, T,
+};
+
+/// Most witness assertions offered for one goal
+const MAX_WITNESSES: usize = 16;
+
+const INTEGER_TYPES: &[&str] = &[
+    "int", "nat", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+    "isize",
+];
+
+/// A candidate witness, with its type when known
+struct Term {
+    expr: Expr,
+    ty: Option<String>,
+    is_int_literal: bool,
+}
+
+// Assert the body of an `exists` goal for candidate witnesses: the variables in scope, and the
+// terms of the goal and of the known facts, that have the type of the bound variable.
+// Directly nested `exists` are witnessed together.
+pub(crate) fn intro_exists_witness(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "exists"
+    ctx.at_this_token(T![exists])?;
+    let exists_keyword = ctx.find_token_syntax_at_offset(T![exists])?;
+    let exists = ast::ClosureExpr::cast(exists_keyword.parent()?)?;
+    let func: ast::Fn = exists.syntax().ancestors().find_map(ast::Fn::cast)?;
+
+    // the goal is an assertion, or a postcondition proven wherever the function returns
+    let (goal, stmt_list, sites) = match exists.syntax().ancestors().find_map(ast::AssertExpr::cast)
+    {
+        Some(assert_expr) => {
+            let stmt = match ast::ExprStmt::cast(assert_expr.syntax().parent()?) {
+                Some(expr_stmt) => expr_stmt.syntax().clone(),
+                None => assert_expr.syntax().clone(),
+            };
+            let stmt_list = ast::StmtList::cast(stmt.parent()?)?;
+            let position = match stmt_list.tail_expr() {
+                Some(tail) if tail.syntax() == &stmt => stmt_list.statements().count(),
+                _ => stmt_list.statements().position(|it| it.syntax() == &stmt)?,
+            };
+            let site = Site { stmt_list: stmt_list.clone(), kind: SiteKind::At(position) };
+            (assert_expr.expr()?, stmt_list, vec![site])
+        }
+        None => {
+            let ensures = exists.syntax().ancestors().find_map(ast::EnsuresClause::cast)?;
+            if ensures.syntax().parent()? != *func.syntax() {
+                return None;
+            }
+            let goal = ensures
+                .exprs()
+                .find(|it| it.syntax().text_range().contains_range(exists.syntax().text_range()))?;
+            let stmt_list = func.body()?.stmt_list()?;
+            (goal, stmt_list, return_sites(&func)?)
+        }
+    };
+    // a witness only proves an `exists` that has to hold on its own
+    let conjunct = exists
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .take_while(|it| goal.syntax().text_range().contains_range(it.text_range()))
+        .all(|it| match ast::Expr::cast(it) {
+            Some(ast::Expr::ParenExpr(_)) => true,
+            Some(ast::Expr::BinExpr(bin)) => {
+                bin.op_kind() == Some(ast::BinaryOp::LogicOp(LogicOp::And))
+            }
+            _ => false,
+        });
+    if !conjunct {
+        return None;
+    }
+
+    // `exists|x| exists|y| ..` is witnessed for `x` and `y` at once
+    let mut quantifiers = vec![exists.clone()];
+    while let Some(ast::Expr::ClosureExpr(inner)) = quantifiers.last()?.body() {
+        if inner.exists_token().is_none() {
+            break;
+        }
+        quantifiers.push(inner);
+    }
+    let mut bound: Vec<(Binding, String)> = vec![];
+    for quantifier in &quantifiers {
+        for param in quantifier.param_list()?.params() {
+            let ty = param.ty()?.syntax().text().to_string();
+            let pat = Pat::try_from(param.pat()?).ok()?;
+            for binding in ctx.vst_pat_bindings(&pat) {
+                bound.push((binding, ty.clone()));
+            }
+        }
+    }
+
+    // a witness has to be in scope at every site
+    let mut terms = candidate_terms(ctx, &func, &goal, &sites[0])?;
+    for site in &sites[1..] {
+        let texts: Vec<String> = candidate_terms(ctx, &func, &goal, site)?
+            .iter()
+            .map(|it| it.expr.to_string())
+            .collect();
+        terms.retain(|it| texts.contains(&it.expr.to_string()));
+    }
+    let mut witnesses: Vec<Vec<&Term>> = vec![vec![]];
+    for (_, ty) in &bound {
+        let fitting: Vec<&Term> = terms.iter().filter(|it| fits(ty, it)).collect();
+        witnesses = witnesses
+            .into_iter()
+            .flat_map(|prefix| {
+                fitting.iter().map(move |term| {
+                    let mut witness = prefix.clone();
+                    witness.push(*term);
+                    witness
+                })
+            })
+            .take(MAX_WITNESSES)
+            .collect();
+    }
+
+    let body = vst_quantifier_body(quantifiers.last()?)?;
+    let group = GroupLabel("Supply a witness for `exists`".to_owned());
+    for witness in witnesses {
+        let subst: Vec<(Binding, Expr)> = bound
+            .iter()
+            .zip(&witness)
+            .map(|((binding, _), term)| (binding.clone(), term.expr.clone()))
+            .collect();
+        let instance = ctx.vst_subst(body.clone(), &subst);
+        // a postcondition about the return value is asserted with the value bound to its name
+        let ret_pat = func.ret_type().and_then(|it| it.pat()).and_then(|it| Pat::try_from(it).ok());
+        let bind = match ret_pat {
+            Some(pat) => {
+                let ret_names: Vec<String> =
+                    ctx.vst_pat_bindings(&pat).into_iter().map(|it| it.name).collect();
+                let mentions_ret =
+                    ctx.vst_free_vars(&instance).iter().any(|it| ret_names.contains(&it.name));
+                let ret_expr = match &pat {
+                    Pat::IdentPat(ident) if ident.pat.is_none() => {
+                        vst_path_expr(ident.name.ident_token.as_deref()?)
+                    }
+                    _ => ctx.vst_expr_from_text(pat.to_string().as_str())?,
+                };
+                mentions_ret.then_some((pat, ret_expr))
+            }
+            None => None,
+        };
+        let mut assert_at_sites =
+            AssertAtSites { sites: &sites, assert: AssertExpr::new(instance).into(), bind };
+        let v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+        let v_stmt_list = assert_at_sites.fold_stmt_list(v_stmt_list);
+        let result = ctx.vst_edit(&stmt_list, v_stmt_list)?;
+        let label = subst
+            .iter()
+            .map(|(binding, term)| {
+                let text = match term.cst() {
+                    Some(it) => it.syntax().text().to_string(),
+                    None => term.to_string().trim().to_owned(),
+                };
+                format!("{} = {text}", binding.name)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        acc.add_group(
+            &group,
+            AssistId("intro_exists_witness", AssistKind::RefactorRewrite),
+            format!("Assert witness `{label}`"),
+            exists.syntax().text_range(),
+            |edit| apply_vst_edit(edit, result),
+        );
+    }
+    Some(())
+}
+
+/// Where the witness assertion goes
+struct Site {
+    stmt_list: ast::StmtList,
+    kind: SiteKind,
+}
+
+enum SiteKind {
+    /// Before the statement at this index (or before the tail, past the last statement),
+    /// which may be a `return`
+    At(usize),
+    /// At the tail expression, which is returned
+    Tail,
+    /// After the last statement of a function returning nothing
+    End,
+}
+
+/// The points where `func` returns: its `return`s and the end of its body.
+/// `None` when a `return` is nested inside an expression.
+fn return_sites(func: &ast::Fn) -> Option<Vec<Site>> {
+    let body = func.body()?.stmt_list()?;
+    let mut sites = vec![];
+    let returns = body.syntax().descendants().filter_map(ast::ReturnExpr::cast).filter(|it| {
+        // closures and nested functions return on their own
+        !it.syntax()
+            .ancestors()
+            .take_while(|it| it != body.syntax())
+            .any(|it| ast::ClosureExpr::can_cast(it.kind()) || ast::Fn::can_cast(it.kind()))
+    });
+    for ret in returns {
+        let site = match ast::ExprStmt::cast(ret.syntax().parent()?) {
+            Some(stmt) => {
+                let stmt_list = ast::StmtList::cast(stmt.syntax().parent()?)?;
+                let position =
+                    stmt_list.statements().position(|it| it.syntax() == stmt.syntax())?;
+                Site { stmt_list, kind: SiteKind::At(position) }
+            }
+            None => Site {
+                stmt_list: ast::StmtList::cast(ret.syntax().parent()?)?,
+                kind: SiteKind::Tail,
+            },
+        };
+        sites.push(site);
+    }
+    let returns_value = func.ret_type().is_some();
+    let end = match body.tail_expr() {
+        Some(ast::Expr::ReturnExpr(_)) => None,
+        Some(_) if returns_value => Some(SiteKind::Tail),
+        _ => Some(SiteKind::End),
+    };
+    if let Some(kind) = end {
+        sites.push(Site { stmt_list: body, kind });
+    }
+    Some(sites)
+}
+
+/// Insert `assert` at each of `sites`. With `bind`, a returned value is first bound to the
+/// pattern of the return value, which the assertion talks about: `return e` becomes
+/// `let r = e; assert(..); return r`.
+struct AssertAtSites<'a> {
+    sites: &'a [Site],
+    assert: Stmt,
+    bind: Option<(Pat, Expr)>,
+}
+
+impl AssertAtSites<'_> {
+    /// `let <return pattern> = value;`
+    fn let_ret(&self, value: Expr) -> Option<Stmt> {
+        let (pat, _) = self.bind.as_ref()?;
+        let mut let_stmt = LetStmt::new(value);
+        let_stmt.pat = Some(Box::new(pat.clone()));
+        Some(let_stmt.into())
+    }
+
+    fn ret_expr(&self) -> Option<Expr> {
+        self.bind.as_ref().map(|(_, ret)| ret.clone())
+    }
+}
+
+impl VstFold for AssertAtSites<'_> {
+    fn fold_stmt_list(&mut self, node: StmtList) -> StmtList {
+        let mut node = walk_fold_stmt_list(self, node);
+        let Some(cst) = node.cst.clone() else { return node };
+        let mut kinds: Vec<&SiteKind> =
+            self.sites.iter().filter(|it| it.stmt_list == cst).map(|it| &it.kind).collect();
+        // later statements first, so that the indices stay valid
+        kinds.sort_by_key(|it| {
+            std::cmp::Reverse(match it {
+                SiteKind::At(idx) => *idx,
+                SiteKind::Tail | SiteKind::End => usize::MAX,
+            })
+        });
+        for kind in kinds {
+            match kind {
+                SiteKind::At(idx) => {
+                    let ret = match node.statements.get_mut(*idx) {
+                        Some(Stmt::ExprStmt(stmt)) => match &mut *stmt.expr {
+                            Expr::ReturnExpr(ret) => Some(ret),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    let let_ret = ret.and_then(|ret| {
+                        let let_ret = self.let_ret(*ret.expr.clone()?)?;
+                        ret.expr = self.ret_expr().map(Box::new);
+                        Some(let_ret)
+                    });
+                    node.statements.insert(*idx, self.assert.clone());
+                    if let Some(let_ret) = let_ret {
+                        node.statements.insert(*idx, let_ret);
+                    }
+                }
+                SiteKind::Tail => {
+                    let Some(tail) = node.tail_expr.take() else { continue };
+                    let (ret, value) = match *tail {
+                        Expr::ReturnExpr(mut ret) => {
+                            let value = ret.expr.take();
+                            (Some(ret), value)
+                        }
+                        value => (None, Some(Box::new(value))),
+                    };
+                    let value = match value.as_ref().and_then(|it| self.let_ret((**it).clone())) {
+                        Some(let_ret) => {
+                            node.statements.push(let_ret);
+                            self.ret_expr().map(Box::new)
+                        }
+                        None => value,
+                    };
+                    node.statements.push(self.assert.clone());
+                    node.tail_expr = match ret {
+                        Some(mut ret) => {
+                            ret.expr = value;
+                            Some(Box::new(Expr::ReturnExpr(ret)))
+                        }
+                        None => value,
+                    };
+                }
+                SiteKind::End => {
+                    // the tail of a function returning nothing runs before the end
+                    if let Some(tail) = node.tail_expr.take() {
+                        node.statements.push(ExprStmt::new(*tail).into());
+                    }
+                    node.statements.push(self.assert.clone());
+                }
+            }
+        }
+        node
+    }
+}
+
+/// The variables in scope at `site`, then the terms of `goal` and of the facts known there.
+/// Terms using a variable bound inside them are left out.
+fn candidate_terms(
+    ctx: &AssistContext<'_>,
+    func: &ast::Fn,
+    goal: &ast::Expr,
+    site: &Site,
+) -> Option<Vec<Term>> {
+    let stmt_list = &site.stmt_list;
+    let position = match site.kind {
+        SiteKind::At(idx) => idx,
+        SiteKind::Tail | SiteKind::End => stmt_list.statements().count(),
+    };
+    let anchor: SyntaxNode = match stmt_list.statements().nth(position) {
+        Some(stmt) => stmt.syntax().clone(),
+        None => match stmt_list.tail_expr() {
+            Some(tail) => tail.syntax().clone(),
+            None => stmt_list.syntax().clone(),
+        },
+    };
+    let scope = if anchor == *stmt_list.syntax() {
+        let end = stmt_list.r_curly_token()?.text_range().start();
+        ctx.sema.scope_at_offset(stmt_list.syntax(), end)?
+    } else {
+        ctx.sema.scope(&anchor)?
+    };
+    let mut locals = vec![];
+    scope.process_all_names(&mut |name, def| {
+        if let (Some(name), hir::ScopeDef::Local(local)) = (name.as_text(), def) {
+            locals.push((name, local));
+        }
+    });
+
+    let mut terms: Vec<Term> = vec![];
+    let mut push = |term: Term| {
+        let text = term.expr.to_string();
+        if !terms.iter().any(|it| it.expr.to_string() == text) {
+            terms.push(term);
+        }
+    };
+    for (name, local) in locals {
+        let ty = ctx.type_of_local(local).map(|it| it.to_string().trim().to_owned());
+        push(Term { expr: vst_path_expr(&name), ty, is_int_literal: false });
+    }
+    let facts = ctx.facts_at(func, &anchor).into_iter().filter_map(|it| it.expr.cst());
+    for root in std::iter::once(goal.clone()).chain(facts) {
+        let binders: Vec<String> = root
+            .syntax()
+            .descendants()
+            .filter_map(ast::ClosureExpr::cast)
+            .filter_map(|it| it.param_list())
+            .flat_map(|it| it.syntax().descendants().filter_map(ast::Name::cast))
+            .map(|it| it.text().to_string())
+            .collect();
+        for e in root.syntax().descendants().filter_map(ast::Expr::cast) {
+            let uses_binder = e
+                .syntax()
+                .descendants()
+                .filter_map(ast::PathExpr::cast)
+                .filter_map(|it| it.path()?.as_single_name_ref())
+                .any(|it| binders.contains(&it.text().to_string()));
+            if uses_binder || matches!(e, ast::Expr::ParenExpr(_) | ast::Expr::PathExpr(_)) {
+                continue;
+            }
+            let is_int_literal = matches!(&e, ast::Expr::Literal(lit)
+                if lit.token().kind() == SyntaxKind::INT_NUMBER);
+            let Ok(expr) = Expr::try_from(e) else { continue };
+            let ty = ctx.type_of_expr_source(&expr).map(|it| it.to_string().trim().to_owned());
+            if ty.is_some() || is_int_literal {
+                push(Term { expr, ty, is_int_literal });
+            }
+        }
+    }
+    Some(terms)
+}
+
+/// Whether `term` can be a witness for a variable of type `ty`.
+/// Spec integers take any integer, since Verus converts them.
+fn fits(ty: &str, term: &Term) -> bool {
+    let is_integer = |ty: &str| INTEGER_TYPES.contains(&ty);
+    match &term.ty {
+        Some(term_ty) if term_ty == ty => true,
+        Some(term_ty) => match ty {
+            "int" => is_integer(term_ty),
+            "nat" => is_integer(term_ty) && (term_ty == "nat" || term_ty.starts_with('u')),
+            _ => false,
+        },
+        None => term.is_int_literal && is_integer(ty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist_by_label, check_assist_not_applicable};
+
+    #[test]
+    fn intro_exists_witness_from_requires() {
+        check_assist_by_label(
+            intro_exists_witness,
+            r#"
+spec fn f(n: int) -> int;
+
+proof fn witness(a: int, b: nat)
+    requires
+        f(a + 1) > 0,
+{
+    assert(ex$0ists|k: int| f(k) > 0);
+}
+"#,
+            r#"
+spec fn f(n: int) -> int;
+
+proof fn witness(a: int, b: nat)
+    requires
+        f(a + 1) > 0,
+{
+    assert(f(a + 1) > 0);
+    assert(exists|k: int| f(k) > 0);
+}
+"#,
+            "Assert witness `k = a + 1`",
+        );
+    }
+
+    #[test]
+    fn intro_exists_witness_nested_in_ensures() {
+        check_assist_by_label(
+            intro_exists_witness,
+            r#"
+spec fn p(x: int, y: int) -> bool;
+
+proof fn pair(a: int, b: int)
+    requires
+        p(a, b),
+    ensures
+        ex$0ists|x: int| exists|y: int| #[trigger] p(x, y),
+{
+}
+"#,
+            r#"
+spec fn p(x: int, y: int) -> bool;
+
+proof fn pair(a: int, b: int)
+    requires
+        p(a, b),
+    ensures
+        exists|x: int| exists|y: int| #[trigger] p(x, y),
+{
+    assert(p(a, b));
+}
+"#,
+            "Assert witness `x = a, y = b`",
+        );
+    }
+
+    #[test]
+    fn intro_exists_witness_at_returns() {
+        check_assist_by_label(
+            intro_exists_witness,
+            r#"
+spec fn p(x: int, y: int) -> bool;
+
+proof fn pick(a: int) -> (r: int)
+    requires
+        p(a, a),
+    ensures
+        ex$0ists|k: int| p(k, r),
+{
+    if a > 0 {
+        return a;
+    }
+    a
+}
+"#,
+            r#"
+spec fn p(x: int, y: int) -> bool;
+
+proof fn pick(a: int) -> (r: int)
+    requires
+        p(a, a),
+    ensures
+        exists|k: int| p(k, r),
+{
+    if a > 0 {
+        let r = a;
+        assert(p(a, r));
+        return r;
+    }
+    let r = a;
+    assert(p(a, r));
+    r
+}
+"#,
+            "Assert witness `k = a`",
+        );
+    }
+
+    #[test]
+    fn intro_exists_witness_not_under_implication() {
+        check_assist_not_applicable(
+            intro_exists_witness,
+            r#"
+proof fn implied(a: int) {
+    assert(a > 0 ==> ex$0ists|k: int| k > 0);
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::{
+        vst_diff::apply_vst_edit,
+        vst_subst::{vst_path_expr, vst_postfix_operand},
+    },
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, make, vst::*, AstNode, CmpOp},
+    T,
+};
+
+/// vstd collections compared by their contents with `=~=`
+const COLLECTIONS: &[&str] = &["Seq", "Set", "Map", "Multiset"];
+
+// For `assert(a == b)` on vstd collections, assert the extensional equality `a =~= b` first
+// (`=~~=` for collections of collections). For sequences, also offer to prove it index by index.
+pub(crate) fn intro_ext_equality(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    ctx.at_this_token(T![assert])?;
+    let assert_expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let ast::Expr::BinExpr(goal) = assert_expr.expr()? else { return None };
+    if goal.op_kind()? != ast::BinaryOp::CmpOp(CmpOp::Eq { negated: false }) {
+        return None;
+    }
+
+    // the assertion has to be a statement (or the tail) of a block
+    let stmt = match ast::ExprStmt::cast(assert_expr.syntax().parent()?) {
+        Some(expr_stmt) => expr_stmt.syntax().clone(),
+        None => assert_expr.syntax().clone(),
+    };
+    let stmt_list = ast::StmtList::cast(stmt.parent()?)?;
+    let position = match stmt_list.tail_expr() {
+        Some(tail) if tail.syntax() == &stmt => stmt_list.statements().count(),
+        _ => stmt_list.statements().position(|it| it.syntax() == &stmt)?,
+    };
+
+    // the type of one side is enough, e.g. for `s == Seq::empty()`
+    let lhs = Expr::try_from(goal.lhs()?).ok()?;
+    let rhs = Expr::try_from(goal.rhs()?).ok()?;
+    let ty = [&lhs, &rhs]
+        .into_iter()
+        .filter_map(|it| ctx.type_of_expr_source(it)?.cst())
+        .find(|it| collection_name(it).is_some())?;
+    let deep = collection_args(&ty).iter().any(|it| collection_name(it).is_some());
+
+    // only a failing equality needs help
+    if !ctx.is_failing_assert(&assert_expr) {
+        return None;
+    }
+
+    let insert = |stmts: Vec<Stmt>| -> Option<_> {
+        let mut v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+        v_stmt_list.statements.splice(position..position, stmts);
+        ctx.vst_edit(&stmt_list, v_stmt_list)
+    };
+    let ext_eq = vst_rewriter_ext_equality(lhs.clone(), rhs.clone(), deep);
+    let result = insert(vec![AssertExpr::new(ext_eq.clone()).into()])?;
+    let op = if deep { "=~~=" } else { "=~=" };
+    acc.add(
+        AssistId("intro_ext_equality", AssistKind::RefactorRewrite),
+        format!("Assert extensional equality `{op}`"),
+        assert_expr.syntax().text_range(),
+        |edit| apply_vst_edit(edit, result),
+    );
+
+    if collection_name(&ty)? == "Seq" {
+        let used: Vec<String> = [&lhs, &rhs]
+            .into_iter()
+            .flat_map(|it| ctx.vst_free_vars(it))
+            .map(|it| it.name)
+            .collect();
+        let index = ["i", "j", "k"]
+            .into_iter()
+            .map(str::to_owned)
+            .chain((1..).map(|idx| format!("i_{idx}")))
+            .find(|it| !used.contains(it))?;
+        let mut stmts = vst_rewriter_seq_pointwise_equality(lhs, rhs, &index)?;
+        stmts.push(AssertExpr::new(ext_eq).into());
+        let result = insert(stmts)?;
+        acc.add(
+            AssistId("intro_ext_equality", AssistKind::RefactorRewrite),
+            format!("Assert extensional equality `{op}` index by index"),
+            assert_expr.syntax().text_range(),
+            |edit| apply_vst_edit(edit, result),
+        );
+    }
+    Some(())
+}
+
+/// `lhs =~= rhs`, or `lhs =~~= rhs` when `deep`
+pub(crate) fn vst_rewriter_ext_equality(lhs: Expr, rhs: Expr, deep: bool) -> Expr {
+    BinExpr::new(lhs, BinaryOp::CmpOp(CmpOp::ExtEq { deep }), rhs).into()
+}
+
+/// `assert(lhs.len() == rhs.len());` and
+/// `assert forall|index: int| 0 <= index < lhs.len() implies lhs[index] == rhs[index] by {}`
+pub(crate) fn vst_rewriter_seq_pointwise_equality(
+    lhs: Expr,
+    rhs: Expr,
+    index: &str,
+) -> Option<Vec<Stmt>> {
+    let (lhs, rhs) = (vst_postfix_operand(lhs), vst_postfix_operand(rhs));
+    let len = |seq: &Expr| -> Expr {
+        let mut name = NameRef::new();
+        name.ident_token = Some("len".to_owned());
+        MethodCallExpr::new(seq.clone(), name, ArgList::new()).into()
+    };
+    let eq = |l: Expr, r: Expr| BinExpr::new(l, BinaryOp::CmpOp(CmpOp::Eq { negated: false }), r);
+    let same_len = eq(len(&lhs), len(&rhs));
+
+    let param =
+        make::param(make::ident_pat(false, false, make::name(index)).into(), make::ty("int"));
+    let mut closure = ClosureExpr::new(BinExpr::new(
+        BinExpr::new(
+            Literal::new("0".to_owned()),
+            BinaryOp::CmpOp(CmpOp::Ord { ordering: ast::Ordering::Less, strict: false }),
+            vst_path_expr(index),
+        ),
+        BinaryOp::CmpOp(CmpOp::Ord { ordering: ast::Ordering::Less, strict: true }),
+        len(&lhs),
+    ));
+    closure.forall_token = true;
+    let mut params = ParamList::try_from(make::param_list(None, [param])).ok()?;
+    (params.l_paren_token, params.r_paren_token, params.pipe_token) = (false, false, true);
+    closure.param_list = Some(Box::new(params));
+    let mut pointwise = AssertForallExpr::new(closure, BlockExpr::new(StmtList::new()));
+    pointwise.implies_token = true;
+    pointwise.expr = Some(Box::new(
+        eq(
+            IndexExpr::new(lhs, vst_path_expr(index)).into(),
+            IndexExpr::new(rhs, vst_path_expr(index)).into(),
+        )
+        .into(),
+    ));
+    Some(vec![AssertExpr::new(same_len).into(), ExprStmt::new(pointwise).into()])
+}
+
+/// `Seq`, `Set`, `Map` or `Multiset`, when `ty` is one of them
+fn collection_name(ty: &ast::Type) -> Option<String> {
+    let ast::Type::PathType(path) = ty else { return None };
+    let name = path.path()?.segment()?.name_ref()?.text().to_string();
+    COLLECTIONS.contains(&name.as_str()).then_some(name)
+}
+
+/// The type arguments of a collection type, e.g. `int` for `Seq<int>`
+fn collection_args(ty: &ast::Type) -> Vec<ast::Type> {
+    let ast::Type::PathType(path) = ty else { return vec![] };
+    let Some(args) = path.path().and_then(|it| it.segment()?.generic_arg_list()) else {
+        return vec![];
+    };
+    args.generic_args()
+        .filter_map(|it| match it {
+            ast::GenericArg::TypeArg(arg) => arg.ty(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verus_error::mk_assert_failure,
+        tests::{
+            check_assist_by_label_with_verus_error, check_assist_not_applicable_with_verus_error,
+        },
+    };
+
+    #[test]
+    fn intro_ext_equality_set() {
+        check_assist_by_label_with_verus_error(
+            intro_ext_equality,
+            // `assert(a.union(b) == b)` is at offset (84, 107)
+            vec![mk_assert_failure(84, 107)],
+            r#"
+proof fn union(a: Set<int>, b: Set<int>)
+    requires
+        a.subset_of(b),
+{
+    ass$0ert(a.union(b) == b);
+}
+"#,
+            r#"
+proof fn union(a: Set<int>, b: Set<int>)
+    requires
+        a.subset_of(b),
+{
+    assert(a.union(b) =~= b);
+    assert(a.union(b) == b);
+}
+"#,
+            "Assert extensional equality `=~=`",
+        );
+    }
+
+    #[test]
+    fn intro_ext_equality_nested() {
+        check_assist_by_label_with_verus_error(
+            intro_ext_equality,
+            // `assert(m == n)` is at offset (97, 111)
+            vec![mk_assert_failure(97, 111)],
+            r#"
+proof fn rows(m: Seq<Seq<int>>, n: Seq<Seq<int>>)
+    requires
+        m.len() == n.len(),
+{
+    ass$0ert(m == n);
+}
+"#,
+            r#"
+proof fn rows(m: Seq<Seq<int>>, n: Seq<Seq<int>>)
+    requires
+        m.len() == n.len(),
+{
+    assert(m =~~= n);
+    assert(m == n);
+}
+"#,
+            "Assert extensional equality `=~~=`",
+        );
+    }
+
+    #[test]
+    fn intro_ext_equality_seq_index_by_index() {
+        check_assist_by_label_with_verus_error(
+            intro_ext_equality,
+            // `assert(s.push(i).drop_last() == s)` is at offset (41, 75)
+            vec![mk_assert_failure(41, 75)],
+            r#"
+proof fn push(s: Seq<int>, i: int) {
+    ass$0ert(s.push(i).drop_last() == s);
+}
+"#,
+            r#"
+proof fn push(s: Seq<int>, i: int) {
+    assert(s.push(i).drop_last().len() == s.len());
+    assert forall|j: int| 0 <= j < s.push(i).drop_last().len() implies s.push(i).drop_last()[j]
+        == s[j] by {};
+    assert(s.push(i).drop_last() =~= s);
+    assert(s.push(i).drop_last() == s);
+}
+"#,
+            "Assert extensional equality `=~=` index by index",
+        );
+    }
+
+    #[test]
+    fn intro_ext_equality_not_when_verified() {
+        check_assist_not_applicable_with_verus_error(
+            intro_ext_equality,
+            vec![],
+            r#"
+proof fn empty(s: Seq<int>)
+    requires
+        s.len() == 0,
+{
+    ass$0ert(s == Seq::<int>::empty());
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn intro_ext_equality_not_for_integers() {
+        check_assist_not_applicable_with_verus_error(
+            intro_ext_equality,
+            // `assert(a == b)` is at offset (65, 79)
+            vec![mk_assert_failure(65, 79)],
+            r#"
+proof fn ints(a: int, b: int)
+    requires
+        a == b,
+{
+    ass$0ert(a == b);
+}
+"#,
+        );
+    }
+}
@@ -19,24 +19,24 @@ pub(crate) fn intro_match(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
     }
 
     let assert: AssertExpr = AssertExpr::try_from(assert_expr.clone()).ok()?;
-    let result = vst_rewriter_intro_match(ctx, assert.clone())?;
-    let result = ctx.fmt(assert_expr.clone(), result.to_string())?;
+    enum_expr_in(ctx, &assert)?;
 
     // register code change to `acc`
-    acc.add(
+    acc.add_verifying(
         AssistId("intro_match", AssistKind::RefactorRewrite),
         "Add match pattern for failed assert on enum ",
         assert_expr.syntax().text_range(),
         |edit| {
+            let result = vst_rewriter_intro_match(ctx, assert)?;
+            let result = ctx.fmt(assert_expr.clone(), result.to_string())?;
             edit.replace(assert_expr.syntax().text_range(), result);
+            Some(())
         },
     )
 }
 
-pub(crate) fn vst_rewriter_intro_match(
-    ctx: &AssistContext<'_>,
-    assert: AssertExpr,
-) -> Option<MatchExpr> {
+/// The first expression of `enum` type in `assert`, and its enum
+fn enum_expr_in(ctx: &AssistContext<'_>, assert: &AssertExpr) -> Option<(Expr, Enum)> {
     let mut v = vec![];
     let cb = &mut |e: Expr| {
         if let Some(_) = ctx.type_of_expr_enum(&e) {
@@ -46,11 +46,16 @@ pub(crate) fn vst_rewriter_intro_match(
     let exp_assert = Expr::AssertExpr(Box::new(assert.clone()));
     // walk over the assertion's predicate, to get expressions of `enum` type.
     vst_walk_expr(&exp_assert, cb);
-    if v.len() == 0 {
-        return None;
-    }
-    let enum_expr_inside_assertion = &v[0]; // select first
-    let en = ctx.type_of_expr_enum(enum_expr_inside_assertion)?;
+    let enum_expr = v.into_iter().next()?; // select first
+    let en = ctx.type_of_expr_enum(&enum_expr)?;
+    Some((enum_expr, en))
+}
+
+pub(crate) fn vst_rewriter_intro_match(
+    ctx: &AssistContext<'_>,
+    assert: AssertExpr,
+) -> Option<MatchExpr> {
+    let (enum_expr_inside_assertion, en) = enum_expr_in(ctx, &assert)?;
     let mut match_arms: Vec<MatchArm> = vec![];
     for variant in &en.variant_list.variants {
         let vst_pat = Literal::new(format!("{}::{}(..)", en.name, variant.name));
@@ -61,11 +66,19 @@ pub(crate) fn vst_rewriter_intro_match(
 
     // now run verifier and only present failing variants
     // Try each variant --- for the rest(`_`), use "assume false"
+    let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    // the match takes the place of `assert`, and the arm keeps its position among the asserts
+    let assert_index = this_fn
+        .cst
+        .as_ref()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::AssertExpr::cast)
+        .position(|it| Some(&it) == assert.cst.as_ref())?;
     let mut is_filtered = false;
     let match_arms: Option<Vec<MatchArm>> = match_arms
         .into_iter()
         .map(|arm| {
-            let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
             let wild_card = Literal::new(format!("_"));
             let wild_pat = LiteralPat::new(wild_card);
             let assume_false = ctx.vst_expr_from_text("assume(false)")?;
@@ -78,7 +91,7 @@ pub(crate) fn vst_rewriter_intro_match(
             let modified_fn =
                 ctx.replace_statement(&this_fn, assert.clone(), simple_match_stmt.clone())?;
             let verif_result = ctx.try_verus(&modified_fn)?;
-            if verif_result.is_failing(&assert) {
+            if verif_result.is_failing_at(assert_index, &assert) {
                 Some(arm.clone())
             } else {
                 is_filtered = true;
@@ -106,15 +119,120 @@ pub(crate) fn vst_rewriter_intro_match(
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_unresolved_with_verifier, check_assist_with_verifier},
+    };
 
     use super::*;
 
+    #[test]
+    fn intro_match_attributes_failures_by_position() {
+        // The assertion also appears earlier in the function, where it fails for `Up`. The arm
+        // for `Up` verifies nonetheless, so it must not be kept.
+        let verifier = MockVerifier::new()
+            .fails_asserts(
+                "proof fn good_move(m: Movement) { assert(is_good_move(m)); match m { Movement::Up(..) => assert(is_good_move(m)), _ => assume(false), }; }",
+                &[0],
+            )
+            .fails_asserts(
+                "proof fn good_move(m: Movement) { assert(is_good_move(m)); match m { Movement::Down(..) => assert(is_good_move(m)), _ => assume(false), }; }",
+                &[1],
+            );
+        check_assist_with_verifier(
+            intro_match,
+            verifier,
+            r#"
+use vstd::prelude::*;
+enum Movement {
+    Up(u32),
+    Down(u32),
+}
+
+spec fn is_good_move(m: Movement) -> bool {
+    match m {
+        Movement::Up(v) => v > 100,
+        Movement::Down(v) => v > 100,
+    }
+}
+
+proof fn good_move(m: Movement)
+{
+    assert(is_good_move(m));
+    ass$0ert(is_good_move(m));
+}
+fn main() {}
+"#,
+            r#"
+use vstd::prelude::*;
+enum Movement {
+    Up(u32),
+    Down(u32),
+}
+
+spec fn is_good_move(m: Movement) -> bool {
+    match m {
+        Movement::Up(v) => v > 100,
+        Movement::Down(v) => v > 100,
+    }
+}
+
+proof fn good_move(m: Movement)
+{
+    assert(is_good_move(m));
+    match m {
+        Movement::Down(..) => assert(is_good_move(m)),
+        _ => {},
+    };
+}
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn discovery_does_not_verify() {
+        check_assist_unresolved_with_verifier(
+            intro_match,
+            MockVerifier::new().panicking(),
+            r#"
+use vstd::prelude::*;
+enum Movement {
+    Up(u32),
+    Down(u32),
+}
+
+spec fn is_good_move(m: Movement) -> bool {
+    match m {
+        Movement::Up(v) => v > 100,
+        Movement::Down(v) => v > 100,
+    }
+}
+
+proof fn good_move(m: Movement)
+{
+    ass$0ert(is_good_move(m));
+}
+fn main() {}
+"#,
+        );
+    }
+
     // TEST1
     #[test]
     fn intro_match1() {
-        check_assist(
+        let verifier = MockVerifier::new()
+            .fails_asserts(
+                "proof fn good_move(m: Movement) { match m { Movement::Up(..) => assert(is_good_move(m)), _ => assume(false), }; }",
+                &[0],
+            )
+            .fails_asserts(
+                "proof fn good_move(m: Movement) { match m { Movement::Down(..) => assert(is_good_move(m)), _ => assume(false), }; }",
+                &[0],
+            );
+        check_assist_with_verifier(
             intro_match,
+            verifier,
             // before
             r#"
 use vstd::prelude::*;
@@ -166,8 +284,18 @@ fn main() {}
     // TEST2
     #[test]
     fn intro_match2() {
-        check_assist(
+        let verifier = MockVerifier::new()
+            .fails_asserts(
+                "proof fn good_move(m: Movement) { match m { Movement::Up(..) => assert(is_good_move(m, 100)), _ => assume(false), }; }",
+                &[0],
+            )
+            .fails_asserts(
+                "proof fn good_move(m: Movement) { match m { Movement::Down(..) => assert(is_good_move(m, 100)), _ => assume(false), }; }",
+                &[0],
+            );
+        check_assist_with_verifier(
             intro_match,
+            verifier,
             // Before
             r#"
 use vstd::prelude::*;
@@ -218,8 +346,13 @@ fn main() {}
 
     #[test]
     fn intro_match3() {
-        check_assist(
+        let verifier = MockVerifier::new().fails_asserts(
+            "fn update_msg(msg: Message) requires message_well_formed(msg) { let new_msg = match msg { Message::Quit(b) => Message::Quit(b), Message::Move{x, y} => Message::Move{x: x+1, y: y-1}, Message::Write(b) => Message::Write(b), }; match new_msg { Message::Move(..) => assert(message_well_formed(new_msg)), _ => assume(false), }; }",
+            &[0],
+        );
+        check_assist_with_verifier(
             intro_match,
+            verifier,
             r#"
 use vstd::prelude::*;
 #[derive(PartialEq, Eq, Clone)] 
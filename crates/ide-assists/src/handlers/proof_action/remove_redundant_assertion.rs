@@ -1,5 +1,5 @@
 // use ide_db::syntax_helpers::node_ext::is_pattern_cond;
-use crate::proof_plumber_api::vst_ext::vst_map_expr_visitor;
+use crate::proof_plumber_api::{vst_diff::apply_vst_edit, vst_ext::vst_map_expr_visitor};
 use crate::{
     assist_context::{AssistContext, Assists},
     proof_plumber_api::run_verus::VerifResult,
@@ -19,7 +19,7 @@ use syntax::{
 /// It iterates the proof and collects assertions that are redundant
 /// This proof action invokes Verus potentially a large number of times.
 /// Therefore, it delays invoking Verus to the point where an user explicitly invokes this proof action.
-/// However, it initially invokes Verus once, to check if the proof succeeds
+/// It then first invokes Verus once, to check if the proof succeeds
 ///
 /// As proof actions usually automatically adds a bunch of "redundant" assertions
 /// to dignose proof failures, this assertion supports the "clean up" process after debugging.
@@ -33,26 +33,20 @@ pub(crate) fn remove_dead_assertions(acc: &mut Assists, ctx: &AssistContext<'_>)
     // lift CST into TOST node
     let v_func = vst::Fn::try_from(func.clone()).ok()?;
 
-    // Check if this is succeeding proof. Run verus only one time (verus is fast so it is fine to run once)
-    let initial_verif_result = ctx.try_verus(&v_func)?;
-
-    // only rewrite succeeding proof
-    if !initial_verif_result.is_success {
-        return None;
-    }
-
-    acc.add(
+    acc.add_verifying(
         AssistId("remove_dead_assertion", AssistKind::RefactorRewrite),
         "Remove Redundant Assertions",
         func.syntax().text_range(),
         |edit| {
-            // Run `vst_rewriter_remove_dead_assertions` only when the user explicitly wants it,
-            // since it runs Verus multiple times.
-            // if we run before user permission, it slows down the interaction significantly
-            let result = vst_rewriter_remove_dead_assertions(ctx, v_func, initial_verif_result)
-                .expect("vst_rewriter_remove_dead_assertions");
-            let result = ctx.fmt(func.clone(), result.to_string()).expect("fmt"); // pretty-print
-            edit.replace(func.syntax().text_range(), result);
+            // Check if this is succeeding proof, and only rewrite succeeding proofs
+            let initial_verif_result = ctx.try_verus(&v_func)?;
+            if !initial_verif_result.is_success {
+                return None;
+            }
+            let result = vst_rewriter_remove_dead_assertions(ctx, v_func, initial_verif_result)?;
+            let result = ctx.vst_edit(&func, result)?; // keeps comments and layout
+            apply_vst_edit(edit, result);
+            Some(())
         },
     )
 }
@@ -140,12 +134,126 @@ fn rewriter_rm_assertions(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{
+            check_assist_no_edit_with_verifier, check_assist_unresolved_with_verifier,
+            check_assist_with_verifier,
+        },
+    };
+
+    #[test]
+    fn mock_keeps_needed_assertion() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .verifies(
+                "proof fn foo(x: nat) ensures x * x >= 0 { assert(x >= 0); assert(x * x >= 0) by (nonlinear_arith); }",
+            )
+            .verifies("proof fn foo(x: nat) ensures x * x >= 0 { assert(x * x >= 0) by (nonlinear_arith); }");
+        check_assist_with_verifier(
+            remove_dead_assertions,
+            verifier,
+            "
+use vstd::prelude::*;
+pr$0oof fn foo(x: nat)
+    ensures
+        x * x >= 0,
+{
+    assert(x >= 0);
+    assert(x * x >= 0) by (nonlinear_arith);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat)
+    ensures
+        x * x >= 0,
+{
+    assert(x * x >= 0) by (nonlinear_arith);
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn mock_keeps_comments() {
+        check_assist_with_verifier(
+            remove_dead_assertions,
+            MockVerifier::new(),
+            "
+use vstd::prelude::*;
+pr$0oof fn foo(x: nat)
+    ensures x >= 0, // from the spec
+{
+    // x is a nat
+    assert(x >= 0);
+    let y = x; /* copy */
+    assert(y >= 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat)
+    ensures x >= 0, // from the spec
+{
+    // x is a nat
+    let y = x; /* copy */
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn discovery_does_not_verify() {
+        check_assist_unresolved_with_verifier(
+            remove_dead_assertions,
+            MockVerifier::new().panicking(),
+            "
+use vstd::prelude::*;
+pr$0oof fn foo(x: nat)
+    ensures
+        x >= 0,
+{
+    assert(x >= 0);
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn mock_failing_proof_is_left_alone() {
+        check_assist_no_edit_with_verifier(
+            remove_dead_assertions,
+            MockVerifier::new().failing_by_default(),
+            "
+use vstd::prelude::*;
+pr$0oof fn foo(x: int)
+    ensures
+        x * x > 0,
+{
+    assert(x >= 0);
+}
+
+fn main() {}
+",
+        )
+    }
 
     #[test]
     fn remove_one() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::new(),
             "
 use vstd::prelude::*;
 pr$0oof fn foo(x: nat)
@@ -165,7 +273,6 @@ proof fn foo(x: nat)
 {
 }
 
-
 fn main() {}
 ",
         )
@@ -173,8 +280,9 @@ fn main() {}
 
     #[test]
     fn remove_multiple() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::new(),
             "
 use vstd::prelude::*;
 pr$0oof fn foo(x: nat)
@@ -197,7 +305,6 @@ proof fn foo(x: nat)
 {
 }
 
-
 fn main() {}
 ",
         )
@@ -205,14 +312,24 @@ fn main() {}
 
     #[test]
     fn preserve_necessary() {
-        check_assist(
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .verifies(
+                "proof fn foo(x: u32, y: u32) ensures x & y == y & x { assert(x == x); assert(x & y == y & x) by (bit_vector); }",
+            )
+            .verifies(
+                "proof fn foo(x: u32, y: u32) ensures x & y == y & x { assert(x & y == y & x) by (bit_vector); }",
+            );
+        check_assist_with_verifier(
             remove_dead_assertions,
+            verifier,
             "
 use vstd::prelude::*;
 proo$0f fn foo(x: u32, y: u32)
     ensures
         x & y == y & x,
 {
+    assert(x == x);
     assert(x & y == y & x) by (bit_vector);
 }
 
@@ -227,7 +344,6 @@ proof fn foo(x: u32, y: u32)
     assert(x & y == y & x) by (bit_vector);
 }
 
-
 fn main() {}
 ",
         )
@@ -235,8 +351,9 @@ fn main() {}
 
     #[test]
     fn assert_comment_success() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::new(),
             r#"
 use vstd::prelude::*;
 fn main() {}
@@ -263,15 +380,15 @@ proof fn proof_index(a: u16, offset: u16)
         offset < 16,
 {
 }
-
 "#,
         );
     }
 
     #[test]
     fn remove_autogen_asserts_fibo() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::new(),
             "
 use vstd::prelude::*;
 pub open spec fn fibo(n: nat) -> nat
@@ -316,10 +433,8 @@ pub open spec fn fibo(n: nat) -> nat
 
 
 proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
-    requires
-        i <= j,
-    ensures
-        fibo(i) <= fibo(j),
+    requires i <= j,
+    ensures fibo(i) <= fibo(j),
     decreases j - i,
 {
     if i < 2 && j < 2 {
@@ -332,7 +447,6 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
     };
 }
 
-
 fn main() {}
 ",
         )
@@ -340,8 +454,9 @@ fn main() {}
 
     #[test]
     fn remove_autogen_asserts_fibo2() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::new(),
             "
 use vstd::prelude::*;
 pub open spec fn fibo(n: nat) -> nat
@@ -384,10 +499,8 @@ pub open spec fn fibo(n: nat) -> nat
 
 
 proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
-    requires
-        i <= j,
-    ensures
-        fibo(i) <= fibo(j),
+    requires i <= j,
+    ensures fibo(i) <= fibo(j),
     decreases j - i,
 {
     if i < 2 && j < 2 {
@@ -400,7 +513,6 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
     };
 }
 
-
 fn main() {}
 ",
         )
@@ -408,8 +520,9 @@ fn main() {}
 
     #[test]
     fn remove_autogen_asserts_fibo3() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::new(),
             "
 use vstd::prelude::*;
 pub open spec fn fibo(n: nat) -> nat
@@ -479,7 +592,6 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
     };
 }
 
-
 fn main() {}
 ",
         )
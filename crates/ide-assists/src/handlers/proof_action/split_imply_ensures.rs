@@ -1,5 +1,6 @@
 use crate::{
     assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_diff::apply_vst_edit,
     AssistId, AssistKind,
 };
 use syntax::ast::{self, vst::*, AstNode, LogicOp};
@@ -19,19 +20,17 @@ pub(crate) fn split_imply_ensures(acc: &mut Assists, ctx: &AssistContext<'_>) ->
 
     let v_func = Fn::try_from(func.clone()).ok()?;
     let result = vst_rewriter_split_imply_ensures(v_func)?;
-    let result = ctx.fmt(func.clone(), result.to_string())?;
+    let result = ctx.vst_edit(&func, result)?;
 
     acc.add(
         AssistId("split_imply_ensures", AssistKind::RefactorRewrite),
         "Split implication in ensures into requires and ensures",
         func.syntax().text_range(),
-        |edit: &mut ide_db::source_change::SourceChangeBuilder| {
-            edit.replace(func.syntax().text_range(), result);
-        },
+        |edit: &mut ide_db::source_change::SourceChangeBuilder| apply_vst_edit(edit, result),
     )
 }
 
-pub(crate) fn vst_rewriter_split_imply_ensures(mut func: Fn) -> Option<String> {
+pub(crate) fn vst_rewriter_split_imply_ensures(mut func: Fn) -> Option<Fn> {
     let ensures = *func.ensures_clause.clone()?;
     // check if the number of ensures clause if 1
     if ensures.exprs.len() != 1 {
@@ -58,7 +57,7 @@ pub(crate) fn vst_rewriter_split_imply_ensures(mut func: Fn) -> Option<String> {
     let mut req = RequiresClause::new();
     req.exprs.push(new_req);
     func.requires_clause = Some(Box::new(req));
-    Some(func.to_string())
+    Some(func)
 }
 
 #[cfg(test)]
@@ -96,7 +95,6 @@ fn test_split_imply_ensures(b: bool) -> (ret: u32)
     }
     ret
 }
-  
 ",
         )
     }
@@ -114,14 +112,13 @@ proof fn lemma_mul_inequality(x: int, y: int, z: int)
 ",
             "
 proof fn lemma_mul_inequality(x: int, y: int, z: int)
-    by (nonlinear_arith)
+    by(nonlinear_arith)
     requires
         x <= y && z > 0,
     ensures
         x * z <= y * z,
 {
 }
-
 ",
         )
     }
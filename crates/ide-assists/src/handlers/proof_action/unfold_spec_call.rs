@@ -0,0 +1,422 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::{
+        vst_diff::apply_vst_edit,
+        vst_subst::{vst_replace_expr, Binding},
+    },
+    AssistId, AssistKind,
+};
+use hir::{ImportPathConfig, ModuleDef, PathResolution};
+use ide_db::helpers::mod_path_to_ast;
+use syntax::ast::{self, vst::*, AstNode};
+
+// Unfold a call to a spec function inside an assertion or a postcondition one level, into the
+// body of the function with the arguments substituted for the parameters.
+// The body of an opaque function is revealed first. The paths of a body from another module are
+// qualified for the call site.
+pub(crate) fn unfold_spec_call(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on the name of the function at the callsite
+    let name_ref: ast::NameRef = ctx.find_node_at_offset()?;
+    let call: ast::CallExpr = name_ref.syntax().ancestors().find_map(ast::CallExpr::cast)?;
+    let ast::Expr::PathExpr(callee) = call.expr()? else { return None };
+    if callee.path()?.segment()?.name_ref()? != name_ref {
+        return None;
+    }
+    let func: ast::Fn = call.syntax().ancestors().find_map(ast::Fn::cast)?;
+
+    let v_call = CallExpr::try_from(call.clone()).ok()?;
+    let spec_fn = ctx.vst_find_fn(&v_call)?;
+    let unfolded = vst_rewriter_unfold_spec_call(ctx, &v_call, &spec_fn)?;
+    let target = ast::Expr::CallExpr(call.clone());
+    let reveal = reveal_stmt(ctx, &v_call, &spec_fn);
+
+    // the call is in an assertion (not in its proof), or in the `ensures` of the function
+    let contains_call = |it: Option<ast::Expr>| {
+        it.is_some_and(|it| it.syntax().text_range().contains_range(call.syntax().text_range()))
+    };
+    let result = match call.syntax().ancestors().find_map(ast::AssertExpr::cast) {
+        Some(assert_expr) if contains_call(assert_expr.expr()) => {
+            let stmt = match ast::ExprStmt::cast(assert_expr.syntax().parent()?) {
+                Some(expr_stmt) => expr_stmt.syntax().clone(),
+                None => assert_expr.syntax().clone(),
+            };
+            let stmt_list = ast::StmtList::cast(stmt.parent()?)?;
+            let position = match stmt_list.tail_expr() {
+                Some(tail) if tail.syntax() == &stmt => stmt_list.statements().count(),
+                _ => stmt_list.statements().position(|it| it.syntax() == &stmt)?,
+            };
+            let v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
+            let mut v_stmt_list = vst_replace_expr(v_stmt_list, &target, unfolded);
+            if let Some(reveal) = reveal {
+                v_stmt_list.statements.insert(position, reveal);
+            }
+            ctx.vst_edit(&stmt_list, v_stmt_list)?
+        }
+        Some(_) => return None,
+        None => {
+            let ensures = call.syntax().ancestors().find_map(ast::EnsuresClause::cast)?;
+            if ensures.syntax().parent()? != *func.syntax() {
+                return None;
+            }
+            let v_func = Fn::try_from(func.clone()).ok()?;
+            let mut v_func = vst_replace_expr(v_func, &target, unfolded);
+            if let Some(reveal) = reveal {
+                v_func.body.as_mut()?.stmt_list.statements.insert(0, reveal);
+            }
+            ctx.vst_edit(&func, v_func)?
+        }
+    };
+
+    acc.add(
+        AssistId("unfold_spec_call", AssistKind::RefactorInline),
+        format!("Unfold `{}` one level", spec_fn.name.to_string().trim()),
+        call.syntax().text_range(),
+        |edit| apply_vst_edit(edit, result),
+    )
+}
+
+/// The body of `spec_fn`, with the arguments of `call` substituted for its parameters
+pub(crate) fn vst_rewriter_unfold_spec_call(
+    ctx: &AssistContext<'_>,
+    call: &CallExpr,
+    spec_fn: &Fn,
+) -> Option<Expr> {
+    // only spec functions with a body
+    if !spec_fn.fn_mode.as_ref().is_some_and(|it| it.spec_token) {
+        return None;
+    }
+    let body = spec_fn.body.as_deref()?;
+
+    let params = &spec_fn.param_list.as_ref()?.params;
+    if params.len() != call.arg_list.args.len() {
+        return None;
+    }
+    let mut subst: Vec<(Binding, Expr)> = vec![];
+    for (param, arg) in params.iter().zip(&call.arg_list.args) {
+        let pat @ Pat::IdentPat(_) = param.pat.as_deref()? else { return None };
+        let binding = ctx.vst_pat_bindings(pat).into_iter().next()?;
+        subst.push((binding, arg.clone()));
+    }
+
+    // `{ e }` unfolds to `e`
+    let body: Expr = match &body.stmt_list.tail_expr {
+        Some(tail) if body.stmt_list.statements.is_empty() => *tail.clone(),
+        _ => body.clone().into(),
+    };
+    let paths = qualified_paths(ctx, call.cst.as_ref()?, &body)?;
+    let body = paths.into_iter().fold(body, |body, (path, qualified)| {
+        vst_replace_expr(body, &ast::Expr::PathExpr(path), qualified)
+    });
+    Some(ctx.vst_subst(body, &subst))
+}
+
+/// The paths of `body` that name items differently at `call`, with the path to use there.
+/// `None` if an item of `body` cannot be named at `call`.
+fn qualified_paths(
+    ctx: &AssistContext<'_>,
+    call: &ast::CallExpr,
+    body: &Expr,
+) -> Option<Vec<(ast::PathExpr, Expr)>> {
+    let body = body.cst()?;
+    let module = ctx.sema.scope(call.syntax())?.module();
+    if ctx.sema.scope(body.syntax())?.module() == module {
+        return Some(vec![]);
+    }
+    let cfg = ImportPathConfig {
+        prefer_no_std: ctx.config.prefer_no_std,
+        prefer_prelude: ctx.config.prefer_prelude,
+    };
+    let mut res = vec![];
+    // qualifying the first segment of a path qualifies the whole path
+    for first in body.syntax().descendants().filter_map(ast::Path::cast) {
+        if first.qualifier().is_some() {
+            continue;
+        }
+        let def = match ctx.sema.resolve_path(&first) {
+            Some(PathResolution::Def(ModuleDef::BuiltinType(_))) => continue,
+            Some(PathResolution::Def(def)) => def,
+            Some(PathResolution::SelfType(_)) => return None,
+            _ => continue,
+        };
+        let qualified = mod_path_to_ast(&module.find_path(ctx.db(), def, cfg)?);
+        let name = first.segment()?.name_ref()?;
+        if qualified.to_string() == name.to_string() {
+            continue;
+        }
+        // only paths of expressions can be replaced, keeping what follows the name
+        let path_expr = first.top_path().syntax().parent().and_then(ast::PathExpr::cast)?;
+        let text = path_expr.syntax().to_string();
+        let name_end = name.syntax().text_range().end() - path_expr.syntax().text_range().start();
+        let rest = &text[usize::from(name_end)..];
+        res.push((path_expr, ctx.vst_expr_from_text(&format!("{qualified}{rest}"))?));
+    }
+    Some(res)
+}
+
+/// `reveal(f)` when the body of `spec_fn` is hidden from the solver.
+/// A recursive function is revealed with `reveal_with_fuel(f, 2)`, so that the solver also sees
+/// the body of the recursive calls in the unfolded body.
+fn reveal_stmt(ctx: &AssistContext<'_>, call: &CallExpr, spec_fn: &Fn) -> Option<Stmt> {
+    if !ctx.is_opaque(spec_fn) {
+        return None;
+    }
+    let mut arglist = ArgList::new();
+    arglist.args.push(*call.expr.clone());
+    if spec_fn.signature_decreases.is_none() {
+        return Some(ctx.vst_call_expr_from_text("reveal", arglist)?.into());
+    }
+    arglist.args.push(Literal::new("2".to_owned()).into());
+    Some(ctx.vst_call_expr_from_text("reveal_with_fuel", arglist)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn unfold_spec_call_open() {
+        check_assist(
+            unfold_spec_call,
+            r#"
+spec fn double(x: int) -> int {
+    x + x
+}
+
+proof fn test(a: int) {
+    assert(dou$0ble(a - 1) * 2 == 4 * a - 4);
+}
+"#,
+            r#"
+spec fn double(x: int) -> int {
+    x + x
+}
+
+proof fn test(a: int) {
+    assert((a - 1 + (a - 1)) * 2 == 4 * a - 4);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_avoids_capture() {
+        check_assist(
+            unfold_spec_call,
+            r#"
+spec fn all_pos(s: Seq<int>, n: int) -> bool {
+    forall|i: int| 0 <= i < n ==> s[i] > 0
+}
+
+proof fn test(t: Seq<int>, i: int)
+    requires
+        all_pos(t, i),
+{
+    assert(all_p$0os(t, i));
+}
+"#,
+            r#"
+spec fn all_pos(s: Seq<int>, n: int) -> bool {
+    forall|i: int| 0 <= i < n ==> s[i] > 0
+}
+
+proof fn test(t: Seq<int>, i: int)
+    requires
+        all_pos(t, i),
+{
+    assert(forall|i_1: int| 0 <= i_1 < i ==> t[i_1] > 0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_in_module() {
+        check_assist(
+            unfold_spec_call,
+            r#"
+mod arith {
+    pub open spec fn double(x: int) -> int {
+        x + x
+    }
+}
+
+spec fn double(x: int) -> int {
+    2 * x
+}
+
+proof fn test(a: int) {
+    assert(arith::dou$0ble(a) == 2 * a);
+}
+"#,
+            r#"
+mod arith {
+    pub open spec fn double(x: int) -> int {
+        x + x
+    }
+}
+
+spec fn double(x: int) -> int {
+    2 * x
+}
+
+proof fn test(a: int) {
+    assert(a + a == 2 * a);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_qualifies_paths() {
+        check_assist(
+            unfold_spec_call,
+            r#"
+mod arith {
+    pub open spec fn half(x: int) -> int {
+        x / 2
+    }
+
+    pub open spec fn quarter(x: int) -> int {
+        half(half(x))
+    }
+}
+
+proof fn test(a: int) {
+    assert(arith::quar$0ter(a) == a / 4);
+}
+"#,
+            r#"
+mod arith {
+    pub open spec fn half(x: int) -> int {
+        x / 2
+    }
+
+    pub open spec fn quarter(x: int) -> int {
+        half(half(x))
+    }
+}
+
+proof fn test(a: int) {
+    assert(arith::half(arith::half(a)) == a / 4);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_not_with_private_items() {
+        check_assist_not_applicable(
+            unfold_spec_call,
+            r#"
+mod arith {
+    spec fn half(x: int) -> int {
+        x / 2
+    }
+
+    pub open spec fn quarter(x: int) -> int {
+        half(half(x))
+    }
+}
+
+proof fn test(a: int) {
+    assert(arith::quar$0ter(a) == a / 4);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_closed() {
+        check_assist(
+            unfold_spec_call,
+            r#"
+pub closed spec fn is_even(n: int) -> bool {
+    n % 2 == 0
+}
+
+proof fn test(k: int) {
+    assume(k == 4);
+    assert(is_e$0ven(k));
+}
+"#,
+            r#"
+pub closed spec fn is_even(n: int) -> bool {
+    n % 2 == 0
+}
+
+proof fn test(k: int) {
+    assume(k == 4);
+    assert(k % 2 == 0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_opaque_recursive_in_ensures() {
+        check_assist(
+            unfold_spec_call,
+            r#"
+#[verifier::opaque]
+spec fn sum(n: nat) -> nat
+    decreases n,
+{
+    if n == 0 {
+        0
+    } else {
+        n + sum((n - 1) as nat)
+    }
+}
+
+proof fn test(m: nat)
+    ensures
+        s$0um(m + 1) == m + 1 + sum(m),
+{
+}
+"#,
+            r#"
+#[verifier::opaque]
+spec fn sum(n: nat) -> nat
+    decreases n,
+{
+    if n == 0 {
+        0
+    } else {
+        n + sum((n - 1) as nat)
+    }
+}
+
+proof fn test(m: nat)
+    ensures
+        (if m + 1 == 0 {
+            0
+        } else {
+            m + 1 + sum((m + 1 - 1) as nat)
+        }) == m + 1 + sum(m),
+{
+    reveal_with_fuel(sum, 2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unfold_spec_call_not_for_proof_fn() {
+        check_assist_not_applicable(
+            unfold_spec_call,
+            r#"
+proof fn lemma(x: int) -> (r: int)
+    ensures
+        r == x,
+{
+    x
+}
+
+proof fn test(a: int) {
+    assert(lem$0ma(a) == a);
+}
+"#,
+        );
+    }
+}
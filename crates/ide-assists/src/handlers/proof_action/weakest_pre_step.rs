@@ -1,4 +1,4 @@
-use crate::proof_plumber_api::vst_ext::*;
+use crate::proof_plumber_api::{vst_diff::apply_vst_edit, vst_ext::*, vst_subst::vst_path_expr};
 use crate::{AssistContext, Assists};
 use ide_db::assists::{AssistId, AssistKind};
 use syntax::{
@@ -59,16 +59,15 @@ pub(crate) fn wp_move_assertion(acc: &mut Assists, ctx: &AssistContext<'_>) -> O
     // in this case, let's modify statement_list that contains the assertion
     let stmt_list = ctx.find_node_at_offset::<ast::StmtList>()?;
     let v_stmt_list = StmtList::try_from(stmt_list.clone()).ok()?;
-    let result = vst_rewriter_wp_move_assertion(ctx, v_stmt_list.clone())?;
-    let result = ctx.fmt(stmt_list.clone(), result.to_string())?;
+    let assertion = ctx.vst_find_node_at_offset::<AssertExpr, ast::AssertExpr>()?;
+    let result = vst_rewriter_wp_move_assertion(ctx, v_stmt_list.clone(), assertion)?;
+    let result = ctx.vst_edit(&stmt_list, result)?;
 
     acc.add(
         AssistId("move_up_assertion", AssistKind::RefactorRewrite),
         "Move up assertion through statements ",
         stmt_list.syntax().text_range(),
-        |edit| {
-            edit.replace(stmt_list.syntax().text_range(), result);
-        },
+        |edit| apply_vst_edit(edit, result),
     )
 }
 
@@ -76,10 +75,8 @@ pub(crate) fn wp_move_assertion(acc: &mut Assists, ctx: &AssistContext<'_>) -> O
 pub(crate) fn vst_rewriter_wp_move_assertion(
     ctx: &AssistContext<'_>,
     stmt_list: StmtList,
+    assertion: AssertExpr,
 ) -> Option<StmtList> {
-    // TODO: return VST Node instead of string, to make it easier to use in other places
-    // find the assertion of interest
-    let assertion = ctx.vst_find_node_at_offset::<AssertExpr, ast::AssertExpr>()?;
     // find the index of the assertion in the statement list
     let index = stmt_list.statements.iter().position(|s| match s {
         Stmt::ExprStmt(e) => match e.expr.as_ref() {
@@ -107,38 +104,31 @@ pub(crate) fn vst_rewriter_wp_move_assertion(
                 Expr::CallExpr(call) => {
                     let func = ctx.vst_find_fn(call)?;
                     let func_ret_type = func.ret_type?.clone();
-                    let first_formal_param = func.param_list.as_ref()?.params.first().clone()?;
-                    let first_formal_arg = first_formal_param.pat.as_ref()?;
-                    let first_arg = call.arg_list.args.first()?;
                     // TODO: make sure spec function call is directly replaced -- i.e. make sure spec function's ensures is Some; see `?` below
                     if func.ensures_clause.as_ref()?.ensures_token {
                         let mut new_assertion = assertion.clone();
                         let ret_var = func_ret_type.pat?;
-                        let ensures: Result<Vec<Expr>, String> = func
+                        // the return value is the let-bound pattern, and parameters are the arguments
+                        let ret_expr = match pat {
+                            Pat::IdentPat(ident) if ident.pat.is_none() => {
+                                vst_path_expr(ident.name.ident_token.as_deref()?)
+                            }
+                            _ => ctx.vst_expr_from_text(pat.to_string().as_str())?,
+                        };
+                        let mut subst = ctx.vst_destructure_let(&ret_var, &ret_expr)?;
+                        let params = &func.param_list.as_ref()?.params;
+                        for (param, arg) in params.iter().zip(&call.arg_list.args) {
+                            subst.extend(ctx.vst_destructure_let(param.pat.as_ref()?, arg)?);
+                        }
+                        let ensures: Vec<Expr> = func
                             .ensures_clause?
                             .exprs
-                            .clone()
                             .iter()
-                            .map(|e1| {
-                                vst_map_expr_visitor(e1.clone(), &mut |e2| {
-                                    if e2.to_string().trim() == ret_var.to_string().trim() {
-                                        // assume one arg for now
-                                        ctx.vst_expr_from_text(pat.to_string().as_str())
-                                            .map_or(Err(String::new()), |e| Ok(e))
-                                    } else if e2.to_string().trim()
-                                        == first_formal_arg.to_string().trim()
-                                    {
-                                        ctx.vst_expr_from_text(first_arg.to_string().as_str())
-                                            .map_or(Err(String::new()), |e| Ok(e))
-                                    } else {
-                                        Ok(e2.clone())
-                                    }
-                                })
-                            })
+                            .map(|e| ctx.vst_subst(e.clone(), &subst))
                             .collect();
 
                         // reduce ensures clauses into one &&-ed expr
-                        let ensures_anded: Expr = ctx.reduce_exprs(ensures.ok()?)?;
+                        let ensures_anded: Expr = ctx.reduce_exprs(ensures)?;
 
                         // ensures ==> original predicate
                         let bin_expr: Expr = BinExpr::new(
@@ -174,16 +164,8 @@ pub(crate) fn vst_rewriter_wp_move_assertion(
                 Some(new_stmt) => (new_stmt, true),
                 None => {
                     // when `prev` is let-binding, do substitution (replace `pat` with `init`)
-                    let new_assert = vst_map_expr_visitor(assertion.clone(), &mut |e| {
-                        // TODO: do proper usage check in semantic level instead of string match
-                        // TODO: variable name shadowing
-                        if e.to_string().trim() == pat.to_string().trim() {
-                            Ok(init_expr.clone())
-                        } else {
-                            Ok(e.clone())
-                        }
-                    })
-                    .ok()?;
+                    let subst = ctx.vst_destructure_let(pat, init_expr)?;
+                    let new_assert = ctx.vst_subst(assertion.clone().into(), &subst);
                     (new_assert.into(), true)
                 }
             }
@@ -317,7 +299,33 @@ fn foo()
     let a: u32 = 1;
     assert(a > 10 && a < 100);
 }
+"#,
+        );
+    }
 
+    // TEST: comments around the statements are kept
+    #[test]
+    fn wp_keeps_comments() {
+        check_assist(
+            wp_move_assertion,
+            r#"
+fn foo(b: u32)
+{
+    // the input
+    let a: u32 = b; // copy
+    /* check */
+    ass$0ert(a > 10);
+}
+"#,
+            r#"
+fn foo(b: u32)
+{
+    assert(b > 10);
+    // the input
+    let a: u32 = b; // copy
+    /* check */
+    assert(a > 10);
+}
 "#,
         );
     }
@@ -341,7 +349,6 @@ fn foo(b: u32)
     let a: u32 = b;
     assert(a > 10 && a < 100);
 }
-
 "#,
         );
     }
@@ -365,10 +372,101 @@ fn foo(b: u32, c: u32)
     let a: u32 = b + c;
     assert(a > 10 && a < 100);
 }
+"#,
+        );
+    }
+    // TEST: let-binding, the quantifier binder is renamed instead of capturing `b`
+    #[test]
+    fn wp_let_bind_capture() {
+        check_assist(
+            wp_move_assertion,
+            r#"
+proof fn foo(b: int)
+{
+    let a: int = b + 1;
+    ass$0ert(forall|b: int| b > 0 ==> b * a > 0);
+}
+"#,
+            r#"
+proof fn foo(b: int)
+{
+    assert(forall|b_1: int| b_1 > 0 ==> b_1 * (b + 1) > 0);
+    let a: int = b + 1;
+    assert(forall|b: int| b > 0 ==> b * a > 0);
+}
+"#,
+        );
+    }
+
+    // TEST: let-binding, a quantifier binder shadowing the variable
+    #[test]
+    fn wp_let_bind_shadowed() {
+        check_assist(
+            wp_move_assertion,
+            r#"
+proof fn foo(b: int)
+{
+    let a: int = b;
+    ass$0ert(a > 0 && forall|a: int| a > 0);
+}
+"#,
+            r#"
+proof fn foo(b: int)
+{
+    assert(b > 0 && forall|a: int| a > 0);
+    let a: int = b;
+    assert(a > 0 && forall|a: int| a > 0);
+}
+"#,
+        );
+    }
 
+    // TEST: let-binding with a tuple pattern
+    #[test]
+    fn wp_let_bind_tuple() {
+        check_assist(
+            wp_move_assertion,
+            r#"
+proof fn foo(b: int)
+{
+    let (x, y) = (b, b + 1);
+    ass$0ert(x < y);
+}
+"#,
+            r#"
+proof fn foo(b: int)
+{
+    assert(b < b + 1);
+    let (x, y) = (b, b + 1);
+    assert(x < y);
+}
 "#,
         );
     }
+
+    // TEST: let-binding with a tuple pattern and a tuple-typed variable
+    #[test]
+    fn wp_let_bind_tuple_var() {
+        check_assist(
+            wp_move_assertion,
+            r#"
+proof fn foo(p: (int, int))
+{
+    let (x, y) = p;
+    ass$0ert(x < y);
+}
+"#,
+            r#"
+proof fn foo(p: (int, int))
+{
+    assert(p.0 < p.1);
+    let (x, y) = p;
+    assert(x < y);
+}
+"#,
+        );
+    }
+
     // TEST: assert
     #[test]
     fn wp_assertion_step() {
@@ -390,7 +488,6 @@ fn foo()
     assert(true);
     assert(a > 10 && a < 100);
 }
-
 "#,
         );
     }
@@ -420,7 +517,6 @@ fn foo()
     };
     assert(a > 10 && a < 100);
 }
-
 "#,
         );
     }
@@ -460,7 +556,6 @@ fn foo()
     };
     assert(a > 10 && a < 100);
 }
-
 "#,
         );
     }
@@ -500,7 +595,6 @@ fn foo()
     commutative(v1, v2);
     assert(false);
 }
-
 "#,
         )
     }
@@ -551,7 +645,6 @@ fn use_octuple() {
     let num = octuple(two);
     assert(num == 32);
 }
-
 "#,
         )
     }
@@ -600,7 +693,6 @@ proof fn good_move(m: Movement)
     };
     assert(true);
 }
-
 "#,
         );
     }
@@ -661,7 +753,6 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
     };
     assert(fibo(i) <= fibo(j));
 }
-
 "#,
         );
     }
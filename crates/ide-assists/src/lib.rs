@@ -68,8 +68,11 @@ macro_rules! eprintln {
 
 pub(crate) mod assist_config;
 pub mod assist_context;
+mod minimize_proof;
+mod proof_api;
 // #[cfg(test)]
 pub mod proof_plumber_api;
+mod proof_search;
 pub(crate) mod tests;
 pub mod utils;
 
@@ -84,6 +87,11 @@ pub use assist_config::AssistConfig;
 pub use ide_db::assists::{
     Assist, AssistId, AssistKind, AssistResolveStrategy, GroupLabel, SingleResolve,
 };
+pub use minimize_proof::{minimize_proof, MinimizeCache, MinimizeScope};
+pub use proof_api::{
+    function_context, verify_candidate, CandidateResult, Clause, FunctionContext, FunctionContract,
+};
+pub use proof_search::{search_proof, ProofCandidate, SearchBudget};
 
 /// Return all the assists applicable at the given position.
 ///
@@ -120,6 +128,12 @@ pub fn assists_with_verus_error(
     acc.finish()
 }
 
+/// The ids of the assists that run Verus, possibly many times, to compute their edit.
+///
+/// Resolving one of these can take minutes, so clients should resolve them in the background.
+pub const VERIFYING_ASSISTS: &[&str] =
+    &["apply_induction", "intro_match", "localize_error", "remove_dead_assertion"];
+
 pub(crate) mod handlers {
     use crate::{AssistContext, Assists};
 
@@ -246,7 +260,7 @@ pub(crate) mod handlers {
     mod wrap_return_type_in_result;
     mod wrap_unwrap_cfg_attr;
     // verus
-    mod proof_action;
+    pub(crate) mod proof_action;
 
     pub(crate) fn all() -> &'static [Handler] {
         &[
@@ -411,6 +425,22 @@ pub(crate) mod handlers {
             proof_action::insert_failing_postcondition::intro_failing_ensures,
             #[cfg(feature="proof-action")]
             proof_action::insert_failing_precondition::intro_failing_requires,
+            #[cfg(feature="proof-action")]
+            proof_action::insert_failing_invariant::intro_failing_invariant,
+            #[cfg(feature="proof-action")]
+            proof_action::extract_lemma::extract_lemma,
+            #[cfg(feature="proof-action")]
+            proof_action::inline_lemma::inline_lemma,
+            #[cfg(feature="proof-action")]
+            proof_action::instantiate_forall::instantiate_forall,
+            #[cfg(feature="proof-action")]
+            proof_action::intro_exists_witness::intro_exists_witness,
+            #[cfg(feature="proof-action")]
+            proof_action::intro_ext_equality::intro_ext_equality,
+            #[cfg(feature="proof-action")]
+            proof_action::assert_by_prover::assert_by_prover,
+            #[cfg(feature="proof-action")]
+            proof_action::unfold_spec_call::unfold_spec_call,
             //#[cfg(feature="proof-action")]
             //proof_action::intro_matching_assertions::intro_match,
             #[cfg(feature="proof-action")]
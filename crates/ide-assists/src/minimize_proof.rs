@@ -0,0 +1,452 @@
+//! Proof minimization: removing the proof steps a proof does not need.
+//!
+//! After a debugging session, functions tend to be littered with assertions, `reveal`s and lemma
+//! calls that were added along the way. [`minimize_proof`] tries removing each of them, keeps the
+//! removals with which the function still verifies, and returns a single edit for the whole scope.
+//!
+//! Removals are batched with delta debugging: all candidates of a function are removed at once,
+//! and a batch that breaks the proof is split in halves until the culprits are found. Most proof
+//! steps left over from debugging are redundant, so this takes far fewer Verus runs than trying
+//! the candidates one at a time, like `remove_dead_assertions` does.
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use hir::{FnMode, HirFileIdExt, ModuleDef, PathResolution, Semantics};
+use ide_db::{
+    base_db::{FileId, FilePosition, FileRange, SourceDatabaseExt},
+    source_change::SourceChange,
+    FxHashMap, FxHashSet, RootDatabase,
+};
+use syntax::{
+    algo::find_node_at_offset,
+    ast::{self, vst, HasName},
+    AstNode, Edition, NodeOrToken, SyntaxKind, SyntaxNode, TextRange, TextSize,
+};
+use text_edit::TextEdit;
+
+use crate::{proof_plumber_api::verification_cache::verification_key, AssistConfig, AssistContext};
+
+/// The functions to minimize
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinimizeScope {
+    /// The function at the cursor
+    Function,
+    /// The functions of the file at the cursor
+    Module,
+    /// The functions of every file of the crate the cursor is in
+    Crate,
+}
+
+/// The removals found for each function, by [`verification_key`].
+///
+/// Functions are skipped as long as neither they nor the spec items they depend on (e.g. the
+/// lemmas they call) changed since they were last minimized.
+#[derive(Debug, Default)]
+pub struct MinimizeCache {
+    removals: Mutex<FxHashMap<u64, Vec<Removal>>>,
+}
+
+/// A proof step that can be removed, relative to the start of its function
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Removal {
+    range: TextRange,
+    replace_with: &'static str,
+}
+
+/// Remove the assertions, `assert ... by` proofs, `reveal`s and lemma calls that the functions in
+/// `scope` still verify without. Functions that do not verify are left alone.
+///
+/// Returns None when there is nothing to remove.
+pub fn minimize_proof(
+    db: &RootDatabase,
+    config: &AssistConfig,
+    position: FilePosition,
+    scope: MinimizeScope,
+    cache: &MinimizeCache,
+) -> Option<SourceChange> {
+    let sema = Semantics::new(db);
+    let files = match scope {
+        MinimizeScope::Function | MinimizeScope::Module => vec![position.file_id],
+        MinimizeScope::Crate => crate_files(&sema, position.file_id)?,
+    };
+    let mut source_change = SourceChange::default();
+    for file_id in files {
+        let source_file = sema.parse(file_id);
+        let fns: Vec<ast::Fn> = match scope {
+            MinimizeScope::Function => {
+                find_node_at_offset(source_file.syntax(), position.offset).into_iter().collect()
+            }
+            MinimizeScope::Module | MinimizeScope::Crate => source_file
+                .syntax()
+                .descendants()
+                .filter_map(ast::Fn::cast)
+                // nested functions are minimized along with the function around them
+                .filter(|func| {
+                    !func.syntax().ancestors().skip(1).any(|it| ast::Fn::can_cast(it.kind()))
+                })
+                .collect(),
+        };
+        let mut edit = TextEdit::builder();
+        for func in fns {
+            let fn_start = func.syntax().text_range().start();
+            for removal in minimize_fn(db, config, file_id, &func, cache) {
+                edit.replace(removal.range + fn_start, removal.replace_with.to_owned());
+            }
+        }
+        let edit = edit.finish();
+        if !edit.is_empty() {
+            source_change.insert_source_edit(file_id, edit);
+        }
+    }
+    (!source_change.source_file_edits.is_empty()).then_some(source_change)
+}
+
+/// The removals with which `func` still verifies
+fn minimize_fn(
+    db: &RootDatabase,
+    config: &AssistConfig,
+    file_id: FileId,
+    func: &ast::Fn,
+    cache: &MinimizeCache,
+) -> Vec<Removal> {
+    // `try_verus` replaces the function at the cursor
+    let Some(name) = func.name() else { return Vec::new() };
+    let frange = FileRange { file_id, range: TextRange::empty(name.syntax().text_range().start()) };
+    let ctx = AssistContext::new(Semantics::new(db), config, frange, vec![]);
+    let Some(func) = ctx.find_node_at_offset::<ast::Fn>() else { return Vec::new() };
+    let text = func.syntax().to_string();
+    let fingerprint = ctx.verifier_fingerprint();
+    let key_of = |text: &str| {
+        let parsed = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+        let rewrite = parsed.syntax().descendants().find_map(ast::Fn::cast)?;
+        let rewrite = vst::Fn::try_from(rewrite).ok()?;
+        Some(verification_key(&ctx.sema, &func, &rewrite, fingerprint))
+    };
+    let key = key_of(&text);
+    if let Some(removals) = key.and_then(|key| cache.removals.lock().unwrap().get(&key).cloned()) {
+        return removals;
+    }
+
+    let candidates = candidates(&ctx.sema, &func);
+    // whether some Verus run gave no verdict, e.g. because it was cancelled
+    let mut inconclusive = false;
+    let removals = if candidates.is_empty() {
+        Vec::new()
+    } else {
+        let mut verified = FxHashMap::default();
+        let mut verifies = |removed: &[usize]| {
+            let variant = apply(&text, &effective(&candidates, removed));
+            let result =
+                *verified.entry(variant).or_insert_with_key(|variant| verify_text(&ctx, variant));
+            inconclusive |= result.is_none();
+            result == Some(true)
+        };
+        // only a proof that goes through can be minimized
+        if !verifies(&[]) {
+            return Vec::new();
+        }
+        let removed = delta_debug(candidates.len(), verifies);
+        effective(&candidates, &removed).into_iter().cloned().collect()
+    };
+    let Some(key) = key.filter(|_| !inconclusive) else { return removals };
+
+    let mut cache = cache.removals.lock().unwrap();
+    if !removals.is_empty() {
+        // the minimized function has nothing left to remove
+        let minimized = apply(&text, &removals.iter().collect::<Vec<_>>());
+        if let Some(minimized) = key_of(&minimized) {
+            cache.insert(minimized, Vec::new());
+        }
+    }
+    cache.insert(key, removals.clone());
+    removals
+}
+
+/// Find a large set of candidates whose removal keeps the proof working.
+///
+/// Removing everything is tried first. A batch that breaks the proof is split in halves, which
+/// are tried separately, on top of the removals accepted so far.
+fn delta_debug(len: usize, mut verifies: impl FnMut(&[usize]) -> bool) -> Vec<usize> {
+    let mut removed = Vec::new();
+    let mut batches = VecDeque::from([(0..len).collect::<Vec<_>>()]);
+    while let Some(batch) = batches.pop_front() {
+        let mut trial = removed.clone();
+        trial.extend(batch.iter().copied());
+        trial.sort_unstable();
+        if verifies(&trial) {
+            removed = trial;
+        } else if batch.len() > 1 {
+            let (left, right) = batch.split_at(batch.len() / 2);
+            batches.push_back(left.to_vec());
+            batches.push_back(right.to_vec());
+        }
+    }
+    removed
+}
+
+/// The proof steps of `func` that may be unnecessary, in source order
+fn candidates(sema: &Semantics<'_, RootDatabase>, func: &ast::Fn) -> Vec<Removal> {
+    let Some(body) = func.body() else { return Vec::new() };
+    let fn_start = func.syntax().text_range().start();
+    let mut res = Vec::new();
+    for stmt in body.syntax().descendants().filter_map(ast::ExprStmt::cast) {
+        let Some(expr) = stmt.expr() else { continue };
+        let is_proof_step = match &expr {
+            ast::Expr::AssertExpr(_) | ast::Expr::AssertForallExpr(_) => true,
+            ast::Expr::CallExpr(call) => is_reveal(call) || is_lemma_call(sema, call),
+            ast::Expr::MethodCallExpr(call) => {
+                sema.resolve_method_call(call).is_some_and(|f| f.mode(sema.db) == FnMode::Proof)
+            }
+            _ => false,
+        };
+        if is_proof_step {
+            res.push(Removal { range: line_range(stmt.syntax()) - fn_start, replace_with: "" });
+        }
+        // keep the assertion, but drop its proof
+        match &expr {
+            ast::Expr::AssertExpr(assert) if assert.by_token().is_some() => {
+                let (Some(r_paren), Some(block)) = (assert.r_paren_token(), assert.block_expr())
+                else {
+                    continue;
+                };
+                if !is_empty_block(&block) {
+                    let range = TextRange::new(
+                        r_paren.text_range().end(),
+                        block.syntax().text_range().end(),
+                    );
+                    // `assert(..) by { .. }` needs no semicolon, but `assert(..)` does
+                    let replace_with = if stmt.semicolon_token().is_some() { "" } else { ";" };
+                    res.push(Removal { range: range - fn_start, replace_with });
+                }
+            }
+            ast::Expr::AssertForallExpr(assert) => {
+                let Some(block) = assert.block_expr() else { continue };
+                if !is_empty_block(&block) {
+                    let range = block.syntax().text_range() - fn_start;
+                    res.push(Removal { range, replace_with: "{}" });
+                }
+            }
+            _ => (),
+        }
+    }
+    res
+}
+
+fn is_reveal(call: &ast::CallExpr) -> bool {
+    let Some(ast::Expr::PathExpr(path)) = call.expr() else { return false };
+    path.path()
+        .and_then(|it| it.segment())
+        .and_then(|it| it.name_ref())
+        .is_some_and(|name| matches!(name.text().as_str(), "reveal" | "reveal_with_fuel"))
+}
+
+fn is_lemma_call(sema: &Semantics<'_, RootDatabase>, call: &ast::CallExpr) -> bool {
+    let Some(ast::Expr::PathExpr(path)) = call.expr() else { return false };
+    match path.path().and_then(|path| sema.resolve_path(&path)) {
+        Some(PathResolution::Def(ModuleDef::Function(f))) => f.mode(sema.db) == FnMode::Proof,
+        _ => false,
+    }
+}
+
+fn is_empty_block(block: &ast::BlockExpr) -> bool {
+    block
+        .stmt_list()
+        .map_or(true, |it| it.statements().next().is_none() && it.tail_expr().is_none())
+}
+
+/// The range of `node`, extended to the start of its line if nothing else is on it
+fn line_range(node: &SyntaxNode) -> TextRange {
+    let range = node.text_range();
+    match node.prev_sibling_or_token() {
+        Some(NodeOrToken::Token(ws)) if ws.kind() == SyntaxKind::WHITESPACE => {
+            match ws.text().rfind('\n') {
+                Some(newline) => TextRange::new(
+                    ws.text_range().start() + TextSize::from(newline as u32),
+                    range.end(),
+                ),
+                None => range,
+            }
+        }
+        _ => range,
+    }
+}
+
+/// The candidates at `removed` that are not already part of a larger removal
+fn effective<'a>(candidates: &'a [Removal], removed: &[usize]) -> Vec<&'a Removal> {
+    let removed: FxHashSet<usize> = removed.iter().copied().collect();
+    let mut res: Vec<&Removal> = Vec::new();
+    for (idx, candidate) in candidates.iter().enumerate() {
+        if !removed.contains(&idx) {
+            continue;
+        }
+        if res.iter().any(|it| it.range.contains_range(candidate.range)) {
+            continue;
+        }
+        // a larger removal later in source order replaces the smaller ones it contains
+        res.retain(|it| !candidate.range.contains_range(it.range));
+        res.push(candidate);
+    }
+    res.sort_by_key(|it| it.range.start());
+    res
+}
+
+fn apply(text: &str, removals: &[&Removal]) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut last = 0;
+    for removal in removals {
+        res.push_str(&text[last..usize::from(removal.range.start())]);
+        res.push_str(removal.replace_with);
+        last = usize::from(removal.range.end());
+    }
+    res.push_str(&text[last..]);
+    res
+}
+
+/// Whether the function `text` verifies, or None if Verus gave no verdict
+fn verify_text(ctx: &AssistContext<'_>, text: &str) -> Option<bool> {
+    let parsed = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+    let Some(func) = parsed.syntax().descendants().find_map(ast::Fn::cast) else {
+        return Some(false);
+    };
+    let Ok(func) = vst::Fn::try_from(func) else { return Some(false) };
+    ctx.try_verus(&func).map(|result| result.is_success)
+}
+
+fn crate_files(sema: &Semantics<'_, RootDatabase>, file_id: FileId) -> Option<Vec<FileId>> {
+    let db = sema.db;
+    let krate = sema.file_to_module_def(file_id)?.krate();
+    let mut files = Vec::new();
+    for module in krate.modules(db) {
+        let file_id = module.definition_source_file_id(db).original_file(db);
+        if !db.source_root(db.file_source_root(file_id)).is_library && !files.contains(&file_id) {
+            files.push(file_id);
+        }
+    }
+    Some(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ide_db::base_db::SourceDatabaseExt;
+    use test_fixture::WithFixture;
+
+    use super::*;
+    use crate::{
+        proof_plumber_api::{
+            run_verus::VerifResult,
+            verifier::{MockVerifier, VerificationRequest, Verifier},
+        },
+        tests::TEST_CONFIG,
+    };
+
+    const LEMMA: &str = "proof fn lemma_a() ensures true {}\n";
+
+    fn minimize(
+        verifier: impl Verifier + 'static,
+        scope: MinimizeScope,
+        cache: &MinimizeCache,
+        fixture: &str,
+    ) -> Option<String> {
+        let (db, position) = RootDatabase::with_position(fixture);
+        let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+        let change = minimize_proof(&db, &config, position, scope, cache)?;
+        let (edit, _) = change.get_source_and_snippet_edit(position.file_id)?;
+        let mut text = db.file_text(position.file_id).to_string();
+        edit.apply(&mut text);
+        Some(text)
+    }
+
+    #[test]
+    fn delta_debugging_keeps_what_is_needed() {
+        let mut runs = 0;
+        let removed = delta_debug(8, |removed| {
+            runs += 1;
+            !removed.contains(&3) && !removed.contains(&5)
+        });
+        assert_eq!(removed, vec![0, 1, 2, 4, 6, 7]);
+        assert!(runs < 16, "{runs} runs");
+    }
+
+    #[test]
+    fn minimize_function() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .verifies(
+                "proof fn foo(x: nat) ensures x + 0 == x {
+                    assert(x >= 0);
+                    lemma_a();
+                    assert(x + 0 == x) by { lemma_a(); }
+                }",
+            )
+            .verifies(
+                "proof fn foo(x: nat) ensures x + 0 == x { assert(x + 0 == x) by { lemma_a(); } }",
+            )
+            .verifies("proof fn foo(x: nat) ensures x + 0 == x { assert(x + 0 == x); }");
+        let fixture = format!(
+            "{LEMMA}proof fn f$0oo(x: nat) ensures x + 0 == x {{
+    assert(x >= 0);
+    lemma_a();
+    assert(x + 0 == x) by {{ lemma_a(); }}
+}}
+"
+        );
+        let minimized = minimize(verifier, MinimizeScope::Function, &Default::default(), &fixture);
+        assert_eq!(
+            minimized.unwrap(),
+            format!(
+                "{LEMMA}proof fn foo(x: nat) ensures x + 0 == x {{\n    assert(x + 0 == x);\n}}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn failing_proofs_are_left_alone() {
+        let fixture = format!("{LEMMA}proof fn f$0oo() {{\n    lemma_a();\n}}\n");
+        let verifier = MockVerifier::new().failing_by_default();
+        assert_eq!(minimize(verifier, MinimizeScope::Module, &Default::default(), &fixture), None);
+    }
+
+    #[test]
+    fn unchanged_functions_come_from_the_cache() {
+        let fixture = format!(
+            "{LEMMA}proof fn f$0oo() {{\n    lemma_a();\n}}\nproof fn bar() {{\n    reveal(foo);\n}}\n"
+        );
+        let expected = format!("{LEMMA}proof fn foo() {{\n}}\nproof fn bar() {{\n}}\n");
+        let cache = MinimizeCache::default();
+        let minimized = minimize(MockVerifier::new(), MinimizeScope::Module, &cache, &fixture);
+        assert_eq!(minimized.as_deref(), Some(&*expected));
+
+        // nothing is verified again, otherwise the failing verifier would keep everything
+        let verifier = MockVerifier::new().failing_by_default();
+        let minimized = minimize(verifier, MinimizeScope::Module, &cache, &fixture);
+        assert_eq!(minimized.as_deref(), Some(&*expected));
+        // and the minimized functions have nothing left to remove
+        let minimized_fixture = expected.replacen("fn foo", "fn f$0oo", 1);
+        let verifier = MockVerifier::new().failing_by_default();
+        assert_eq!(minimize(verifier, MinimizeScope::Module, &cache, &minimized_fixture), None);
+    }
+
+    /// Verifies the function as written, and gives no verdict afterwards, as if cancelled
+    #[derive(Debug, Default)]
+    struct CancelledAfterFirstRun(std::sync::atomic::AtomicBool);
+
+    impl Verifier for CancelledAfterFirstRun {
+        fn verify(&self, _: &VerificationRequest<'_>) -> Option<VerifResult> {
+            let ran = self.0.swap(true, std::sync::atomic::Ordering::SeqCst);
+            (!ran).then(|| VerifResult::mk_success(0))
+        }
+    }
+
+    #[test]
+    fn inconclusive_runs_are_not_cached() {
+        let fixture = format!("{LEMMA}proof fn f$0oo() {{\n    lemma_a();\n}}\n");
+        let cache = MinimizeCache::default();
+        let verifier = CancelledAfterFirstRun::default();
+        assert_eq!(minimize(verifier, MinimizeScope::Function, &cache, &fixture), None);
+
+        let minimized = minimize(MockVerifier::new(), MinimizeScope::Function, &cache, &fixture);
+        assert_eq!(minimized.unwrap(), format!("{LEMMA}proof fn foo() {{\n}}\n"));
+    }
+}
@@ -0,0 +1,330 @@
+//! Entry points for proof tools outside of verus-analyzer, e.g. scripts proposing proof edits.
+//!
+//! [`verify_candidate`] verifies an edit to a function speculatively, like proof actions do, so
+//! the edit never has to be written to disk. [`function_context`] describes a function and the
+//! contracts of the functions it calls, for tools to build their candidates on.
+
+use std::time::{Duration, Instant};
+
+use hir::{FnMode, Function, HasSource, ModuleDef, PathResolution, Semantics};
+use ide_db::{
+    base_db::{FileId, FilePosition, FileRange},
+    FxHashSet, RootDatabase,
+};
+use syntax::{
+    algo::find_node_at_offset,
+    ast::{self, vst, HasAttrs, HasModuleItem, HasName},
+    AstNode, Edition, SyntaxNode, TextRange, TextSize,
+};
+use text_edit::TextEdit;
+
+use crate::{
+    proof_plumber_api::run_verus::{fn_path, VerifFailure},
+    AssistConfig, AssistContext,
+};
+
+/// The outcome of verifying a candidate edit
+#[derive(Debug, Clone)]
+pub struct CandidateResult {
+    pub verified: bool,
+    /// The errors Verus reported, with spans in the file as edited by the candidate
+    pub failures: Vec<VerifFailure>,
+    /// Verus' error output, as a human would read it
+    pub stderr: String,
+    pub time: Duration,
+}
+
+/// Verify the function at `position` as `edit` changes it, without changing the file.
+///
+/// The edit must stay within the function, and leave it a single function of the same name.
+pub fn verify_candidate(
+    db: &RootDatabase,
+    config: &AssistConfig,
+    position: FilePosition,
+    edit: &TextEdit,
+) -> Result<CandidateResult, String> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let func = find_node_at_offset::<ast::Fn>(source_file.syntax(), position.offset)
+        .ok_or("there is no function at the given position")?;
+    let name = func.name().ok_or("the function has no name")?;
+    // `try_verus` replaces the function at the cursor
+    let frange = FileRange {
+        file_id: position.file_id,
+        range: TextRange::empty(name.syntax().text_range().start()),
+    };
+    let ctx = AssistContext::new(sema, config, frange, vec![]);
+    let func = ctx.find_node_at_offset::<ast::Fn>().ok_or("the function is not in the file")?;
+    let fn_range = func.syntax().text_range();
+
+    let mut fn_edit = TextEdit::builder();
+    for indel in edit.iter() {
+        if !fn_range.contains_range(indel.delete) {
+            return Err(format!("the edit at {:?} is outside of the function", indel.delete));
+        }
+        fn_edit.replace(indel.delete - fn_range.start(), indel.insert.clone());
+    }
+    let mut fn_text = func.syntax().to_string();
+    fn_edit.finish().apply(&mut fn_text);
+
+    let parse = ast::SourceFile::parse(&fn_text, Edition::CURRENT);
+    if let Some(error) = parse.errors().first() {
+        return Err(format!("the edited function does not parse: {error}"));
+    }
+    let mut items = parse.tree().items();
+    let (Some(ast::Item::Fn(candidate)), None) = (items.next(), items.next()) else {
+        return Err("the edit must leave a single function".to_owned());
+    };
+    if candidate.name().map(|it| it.text().to_string()) != Some(name.text().to_string()) {
+        return Err("the edit must not rename the function".to_owned());
+    }
+    let vst_fn = vst::Fn::try_from(candidate)?;
+
+    if config.verifier.is_none() {
+        return Err("no verifier is configured".to_owned());
+    }
+    let started = Instant::now();
+    let result = ctx
+        .try_verus_text(&vst_fn, &fn_text)
+        .ok_or("Verus did not run, e.g. because the edited function does not compile")?;
+    let time = started.elapsed();
+
+    // the edited function starts where the original one does, everything after it moves
+    let delta = fn_text.len() as i64 - i64::from(u32::from(fn_range.len()));
+    let to_candidate = |range: TextRange| -> Option<TextRange> {
+        let file = result.file.as_ref()?;
+        let spliced = file.fn_range();
+        if spliced.contains_range(range) {
+            return Some(range - spliced.start() + fn_range.start());
+        }
+        let range = file.to_original(range)?;
+        if range.end() <= fn_range.start() {
+            Some(range)
+        } else if range.start() >= fn_range.end() {
+            let start = i64::from(u32::from(range.start())) + delta;
+            Some(TextRange::at(TextSize::from(start as u32), range.len()))
+        } else {
+            None
+        }
+    };
+    let failures = result
+        .failures
+        .iter()
+        .filter_map(|failure| {
+            Some(VerifFailure {
+                kind: failure.kind,
+                message: failure.message.clone(),
+                range: to_candidate(failure.range)?,
+                related: failure.related.iter().filter_map(|&it| to_candidate(it)).collect(),
+            })
+        })
+        .collect();
+    Ok(CandidateResult { verified: result.is_success, failures, stderr: result.stderr, time })
+}
+
+/// A function, as seen by a proof tool
+#[derive(Debug, Clone)]
+pub struct FunctionContext {
+    pub contract: FunctionContract,
+    /// The function printed from its VST, as proof actions see it. None when the function does
+    /// not convert to VST.
+    pub vst: Option<String>,
+    /// The functions it calls, in order of first call
+    pub callees: Vec<FunctionContract>,
+}
+
+/// The signature and specification of a function
+#[derive(Debug, Clone)]
+pub struct FunctionContract {
+    pub name: String,
+    /// The argument to `--verify-function`, e.g. `foo` or `MyStruct::foo`
+    pub path: String,
+    pub mode: FnMode,
+    pub file_id: FileId,
+    pub range: TextRange,
+    /// Whether its body is hidden unless revealed
+    pub opaque: bool,
+    /// The parameters, e.g. `x: int`
+    pub params: Vec<String>,
+    /// The return type, e.g. `(r: int)`
+    pub ret: Option<String>,
+    pub requires: Vec<Clause>,
+    pub ensures: Vec<Clause>,
+    pub decreases: Vec<Clause>,
+}
+
+/// One expression of a `requires`, `ensures` or `decreases` clause
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    pub text: String,
+    /// In the file of the function
+    pub range: TextRange,
+}
+
+/// Describe the function at `position`, and the contracts of the functions it calls
+pub fn function_context(db: &RootDatabase, position: FilePosition) -> Option<FunctionContext> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let func = find_node_at_offset::<ast::Fn>(source_file.syntax(), position.offset)?;
+    let function = sema.to_def(&func)?;
+    let contract = fn_contract(&sema, function, position.file_id, &func)?;
+    let vst = vst::Fn::try_from(func.clone()).ok().map(|it| it.to_string());
+
+    let mut seen = FxHashSet::default();
+    let mut callees = Vec::new();
+    for node in func.body()?.syntax().descendants() {
+        let callee = resolve_call(&sema, node);
+        let Some(callee) = callee.filter(|it| seen.insert(*it)) else { continue };
+        let Some(source) = callee.source(db) else { continue };
+        // functions defined by macros have no text of their own
+        let Some(file_id) = source.file_id.file_id() else { continue };
+        if let Some(contract) = fn_contract(&sema, callee, file_id, &source.value) {
+            callees.push(contract);
+        }
+    }
+    Some(FunctionContext { contract, vst, callees })
+}
+
+/// The function `node` calls, if it is a call
+fn resolve_call(sema: &Semantics<'_, RootDatabase>, node: SyntaxNode) -> Option<Function> {
+    if let Some(call) = ast::CallExpr::cast(node.clone()) {
+        let ast::Expr::PathExpr(path) = call.expr()? else { return None };
+        match sema.resolve_path(&path.path()?)? {
+            PathResolution::Def(ModuleDef::Function(it)) => Some(it),
+            _ => None,
+        }
+    } else if let Some(call) = ast::MethodCallExpr::cast(node) {
+        sema.resolve_method_call(&call)
+    } else {
+        None
+    }
+}
+
+fn fn_contract(
+    sema: &Semantics<'_, RootDatabase>,
+    function: Function,
+    file_id: FileId,
+    func: &ast::Fn,
+) -> Option<FunctionContract> {
+    let clauses = |exprs: Option<ast::AstChildren<ast::Expr>>| -> Vec<Clause> {
+        exprs
+            .into_iter()
+            .flatten()
+            .map(|it| Clause { text: it.to_string(), range: it.syntax().text_range() })
+            .collect()
+    };
+    let ret = func.ret_type().map(|it| {
+        let arrow = it.thin_arrow_token().map_or(0, |it| it.text().len());
+        it.to_string()[arrow..].trim().to_owned()
+    });
+    Some(FunctionContract {
+        name: func.name()?.text().to_string(),
+        path: fn_path(func)?,
+        mode: function.mode(sema.db),
+        file_id,
+        range: func.syntax().text_range(),
+        opaque: func.attrs().any(|it| it.to_string().contains("opaque")),
+        params: func
+            .param_list()
+            .into_iter()
+            .flat_map(|it| {
+                let self_param = it.self_param().map(|it| it.to_string());
+                self_param.into_iter().chain(it.params().map(|it| it.to_string()))
+            })
+            .collect(),
+        ret,
+        requires: clauses(func.requires_clause().map(|it| it.exprs())),
+        ensures: clauses(func.ensures_clause().map(|it| it.exprs())),
+        decreases: clauses(
+            func.signature_decreases().and_then(|it| it.decreases_clause()).map(|it| it.exprs()),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ide_db::base_db::SourceDatabaseExt;
+    use test_fixture::WithFixture;
+
+    use super::*;
+    use crate::{
+        proof_plumber_api::{run_verus::VerifFailureKind, verifier::MockVerifier},
+        tests::TEST_CONFIG,
+    };
+
+    /// Replace the first occurrence of `from` in the file at `position` by `to`
+    fn edit(db: &RootDatabase, position: FilePosition, from: &str, to: &str) -> TextEdit {
+        let text = db.file_text(position.file_id);
+        let start = TextSize::from(text.find(from).unwrap() as u32);
+        TextEdit::replace(TextRange::at(start, TextSize::of(from)), to.to_owned())
+    }
+
+    #[test]
+    fn failures_are_reported_in_the_edited_file() {
+        let (db, position) = RootDatabase::with_position(
+            "proof fn f$0oo(x: int) {\n    assert(x > 0);\n}\nproof fn bar() {}\n",
+        );
+        let verifier = MockVerifier::new()
+            .fails_asserts("proof fn foo(x: int) { assert(x >= 0); assert(x > 0); }", &[1]);
+        let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+        let edit = edit(&db, position, "assert(x > 0);", "assert(x >= 0);\n    assert(x > 0);");
+        let result = verify_candidate(&db, &config, position, &edit).unwrap();
+
+        let mut text = db.file_text(position.file_id).to_string();
+        edit.apply(&mut text);
+        assert!(!result.verified);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].kind, VerifFailureKind::Assertion);
+        assert_eq!(&text[result.failures[0].range], "assert(x > 0)");
+        // the file itself is left alone
+        assert!(!db.file_text(position.file_id).contains("x >= 0"));
+    }
+
+    #[test]
+    fn edits_must_stay_in_the_function() {
+        let (db, position) =
+            RootDatabase::with_position("proof fn f$0oo() {}\nproof fn bar() {}\n");
+        let config = AssistConfig { verifier: Some(Arc::new(MockVerifier::new())), ..TEST_CONFIG };
+        let outside = edit(&db, position, "bar", "baz");
+        assert!(verify_candidate(&db, &config, position, &outside).is_err());
+        let renamed = edit(&db, position, "foo", "qux");
+        assert!(verify_candidate(&db, &config, position, &renamed).is_err());
+        let broken = edit(&db, position, "{}", "{");
+        assert!(verify_candidate(&db, &config, position, &broken).is_err());
+        let fine = edit(&db, position, "{}", "{ assert(true); }");
+        assert!(verify_candidate(&db, &config, position, &fine).unwrap().verified);
+    }
+
+    #[test]
+    fn context_includes_callee_contracts() {
+        let (db, position) = RootDatabase::with_position(
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn lemma_pos(x: int) requires x == 1 ensures pos(x) {}
+proof fn f$0oo(x: int) -> (r: int) requires x == 1, x < 2 ensures pos(x) {
+    lemma_pos(x);
+    lemma_pos(x);
+    x
+}
+"#,
+        );
+        let context = function_context(&db, position).unwrap();
+        let texts = |clauses: &[Clause]| -> Vec<String> {
+            clauses.iter().map(|it| it.text.clone()).collect()
+        };
+        assert_eq!(context.contract.name, "foo");
+        assert_eq!(context.contract.mode, FnMode::Proof);
+        assert_eq!(context.contract.params, vec!["x: int"]);
+        assert_eq!(context.contract.ret.as_deref(), Some("(r: int)"));
+        assert_eq!(texts(&context.contract.requires), vec!["x == 1", "x < 2"]);
+        assert_eq!(texts(&context.contract.ensures), vec!["pos(x)"]);
+        assert!(context.vst.is_some());
+
+        let callees: Vec<_> = context.callees.iter().map(|it| it.name.as_str()).collect();
+        assert_eq!(callees, vec!["lemma_pos"]);
+        assert_eq!(texts(&context.callees[0].ensures), vec!["pos(x)"]);
+    }
+}
@@ -6,7 +6,7 @@
 //! for a developer to write proof actions
 //!
 //! See [`crate::AssistContext`] for APIs that utilize context information
-//! For the rest, see [`verus_error`] and [`vst_ext`].
+//! For the rest, see [`verus_error`], [`vst_ext`], [`vst_subst`] and [`vst_diff`].
 //!
 //! Aside from above APIs, there are several traits already implemented for TOST nodes
 //!
@@ -33,11 +33,16 @@
 //! hand-written pars are at `syntax/src/vst.rs`
 //!
 //!
+pub mod context_facts;
 pub mod inline_function_api;
 pub mod proof_action_context;
 pub mod run_fmt;
 pub mod run_verus;
 pub mod semantic_info;
+pub mod verification_cache;
+pub mod verifier;
 pub mod verus_error;
+pub mod vst_diff;
 pub mod vst_ext;
 pub mod vst_from_text;
+pub mod vst_subst;
@@ -0,0 +1,354 @@
+//! ProofPlumber API for the facts known at a program point
+//!
+//! Facts come from the `requires` of the function, the invariants of the surrounding loops,
+//! the conditions of the surrounding `if`s, `while`s and match arms, and the earlier `let`s,
+//! assertions and assumptions of the surrounding blocks.
+//!
+
+use crate::{
+    proof_plumber_api::vst_subst::{vst_not, vst_path_expr, Binding},
+    AssistContext,
+};
+use hir::{ModuleDef, PathResolution};
+use syntax::{
+    ast::{self, vst, AstNode, HasLoopBody},
+    match_ast, SyntaxNode, TextRange, TextSize,
+};
+
+/// A fact known at a program point, with the variables it uses
+#[derive(Debug, Clone)]
+pub struct Fact {
+    pub expr: vst::Expr,
+    pub vars: Vec<Binding>,
+}
+
+/// The facts among `candidates` about `vars`, and about the variables these facts bring in, until no
+/// more variables come in. Returns the facts and all their variables.
+pub fn facts_about(
+    candidates: Vec<Fact>,
+    mut vars: Vec<Binding>,
+) -> (Vec<vst::Expr>, Vec<Binding>) {
+    let mut taken = vec![false; candidates.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (idx, fact) in candidates.iter().enumerate() {
+            if taken[idx] || !fact.vars.iter().any(|it| vars.contains(it)) {
+                continue;
+            }
+            taken[idx] = true;
+            changed = true;
+            for var in &fact.vars {
+                if !vars.contains(var) {
+                    vars.push(var.clone());
+                }
+            }
+        }
+    }
+    let mut facts: Vec<vst::Expr> = vec![];
+    for (fact, taken) in candidates.into_iter().zip(taken) {
+        if taken && !facts.contains(&fact.expr) {
+            facts.push(fact.expr);
+        }
+    }
+    (facts, vars)
+}
+
+impl<'a> AssistContext<'a> {
+    /// Facts known right before `node` inside `func`, in the order they are established.
+    /// Facts about a variable assigned after them are dropped.
+    pub fn facts_at(&self, func: &ast::Fn, node: &SyntaxNode) -> Vec<Fact> {
+        let body_start = func.body().map_or(0.into(), |it| it.syntax().text_range().start());
+        // (fact, variables it uses, where it is established)
+        let mut candidates: Vec<(vst::Expr, Vec<Binding>, TextSize)> = vec![];
+        // the semantic model does not cover `requires`, look up its variables among the parameters
+        let mut fn_params: Vec<Binding> = func
+            .param_list()
+            .into_iter()
+            .flat_map(|it| it.params())
+            .filter_map(|it| vst::Pat::try_from(it.pat()?).ok())
+            .flat_map(|it| self.vst_pat_bindings(&it))
+            .collect();
+        if let Some(self_param) = func.param_list().and_then(|it| it.self_param()) {
+            let local = self.sema.to_def(&self_param);
+            fn_params.push(Binding { name: "self".to_owned(), local });
+        }
+        for e in func.requires_clause().into_iter().flat_map(|it| it.exprs()) {
+            let Ok(e) = vst::Expr::try_from(e) else { continue };
+            let used = self
+                .vst_free_vars(&e)
+                .into_iter()
+                .map(|var| match var.local {
+                    Some(_) => var,
+                    None => fn_params.iter().find(|it| it.name == var.name).cloned().unwrap_or(var),
+                })
+                .collect();
+            candidates.push((e, used, body_start));
+        }
+        let mut add_expr = |e: ast::Expr, at: TextSize, negated: bool| {
+            if let Ok(e) = vst::Expr::try_from(e) {
+                let used = self.vst_free_vars(&e);
+                candidates.push((if negated { vst_not(e) } else { e }, used, at));
+            }
+        };
+
+        let before = node.text_range().start();
+        let mut let_facts = vec![];
+        let mut arm_facts = vec![];
+        let mut child = node.clone();
+        for ancestor in node.ancestors().take_while(|it| it != func.syntax()) {
+            let from = std::mem::replace(&mut child, ancestor.clone());
+            if let Some(the_loop) = ast::AnyHasLoopBody::cast(ancestor.clone()) {
+                // invariants hold at the start of each iteration
+                let Some(loop_body) = the_loop.loop_body() else { continue };
+                if loop_body.syntax() != &from {
+                    continue;
+                }
+                let at = loop_body.syntax().text_range().start();
+                for inv in the_loop.syntax().children().filter_map(ast::InvariantClause::cast) {
+                    inv.exprs().for_each(|e| add_expr(e, at, false));
+                }
+                let condition =
+                    ast::WhileExpr::cast(ancestor.clone()).and_then(|it| it.condition());
+                if let Some(condition) = condition.filter(|it| !has_let(it)) {
+                    add_expr(condition, at, false);
+                }
+            }
+            if let Some(if_expr) = ast::IfExpr::cast(ancestor.clone()) {
+                // the condition holds in the then-branch and fails in the else-branch
+                let Some(condition) = if_expr.condition().filter(|it| !has_let(it)) else {
+                    continue;
+                };
+                let at = from.text_range().start();
+                match if_expr.then_branch() {
+                    Some(then_branch) if then_branch.syntax() == &from => {
+                        add_expr(condition, at, false)
+                    }
+                    _ if if_expr.else_branch().is_some_and(|it| else_syntax(&it) == from) => {
+                        add_expr(condition, at, true)
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(arm) = ast::MatchArm::cast(ancestor.clone()) {
+                if arm.expr().is_some_and(|it| it.syntax() == &from) {
+                    let at = from.text_range().start();
+                    if let Some(fact) = self.arm_fact(&arm) {
+                        arm_facts.push((fact.expr, fact.vars, at));
+                    }
+                    let guard = arm.guard().and_then(|it| it.condition());
+                    let guard = guard.filter(|it| !has_let(it)).map(vst::Expr::try_from);
+                    if let Some(Ok(guard)) = guard {
+                        let used = self.vst_free_vars(&guard);
+                        arm_facts.push((guard, used, at));
+                    }
+                }
+            }
+            let Some(stmt_list) = ast::StmtList::cast(ancestor.clone()) else { continue };
+            for stmt in stmt_list.statements().filter(|it| it.syntax().text_range().end() <= before)
+            {
+                match stmt {
+                    ast::Stmt::ExprStmt(it) => match it.expr() {
+                        Some(ast::Expr::AssertExpr(fact)) => {
+                            let at = it.syntax().text_range().end();
+                            fact.expr().into_iter().for_each(|e| add_expr(e, at, false))
+                        }
+                        Some(ast::Expr::AssumeExpr(fact)) => {
+                            let at = it.syntax().text_range().end();
+                            fact.expr().into_iter().for_each(|e| add_expr(e, at, false))
+                        }
+                        _ => (),
+                    },
+                    ast::Stmt::LetStmt(it) => let_facts.push(it),
+                    _ => (),
+                }
+            }
+        }
+        candidates.extend(arm_facts);
+        // `let x = e` gives `x == e`, unless evaluating `e` has an effect
+        for let_stmt in let_facts {
+            let (Some(pat), Some(init)) = (let_stmt.pat(), let_stmt.initializer()) else {
+                continue;
+            };
+            if !self.is_pure(&init) {
+                continue;
+            }
+            let (Ok(pat), Ok(init)) = (vst::Pat::try_from(pat), vst::Expr::try_from(init)) else {
+                continue;
+            };
+            let bound = self.vst_pat_bindings(&pat);
+            let [var] = &bound[..] else { continue };
+            if !matches!(pat, vst::Pat::IdentPat(_)) {
+                continue;
+            }
+            let mut used = self.vst_free_vars(&init);
+            used.push(var.clone());
+            let fact = vst::BinExpr::new(
+                vst_path_expr(&var.name),
+                vst::BinaryOp::CmpOp(ast::CmpOp::Eq { negated: false }),
+                init,
+            );
+            candidates.push((fact.into(), used, let_stmt.syntax().text_range().end()));
+        }
+        candidates.sort_by_key(|(_, _, at)| *at);
+
+        candidates
+            .into_iter()
+            .filter(|(_, used, established)| {
+                if used.iter().any(|it| it.local.is_none()) {
+                    return false;
+                }
+                // a loop around `node` may assign the variable after `node`
+                let until = node
+                    .ancestors()
+                    .take_while(|it| it.text_range().start() > *established)
+                    .filter(|it| ast::AnyHasLoopBody::can_cast(it.kind()))
+                    .last()
+                    .map_or(before, |it| it.text_range().end());
+                if until < *established {
+                    return false;
+                }
+                let locals: Vec<hir::Local> = used.iter().filter_map(|it| it.local).collect();
+                !self.assigned_in(func, &locals, TextRange::new(*established, until))
+            })
+            .map(|(expr, vars, _)| Fact { expr, vars })
+            .collect()
+    }
+
+    /// What entering `arm` tells about the scrutinee: `e == P` when the pattern `P` also reads as an
+    /// expression (so its bindings are tied to `e`), `e matches P` when it binds nothing
+    fn arm_fact(&self, arm: &ast::MatchArm) -> Option<Fact> {
+        let match_expr = arm.syntax().ancestors().find_map(ast::MatchExpr::cast)?;
+        let scrutinee = vst::Expr::try_from(match_expr.expr()?).ok()?;
+        let pat = vst::Pat::try_from(arm.pat()?).ok()?;
+        let mut vars = self.vst_free_vars(&scrutinee);
+        // unit variants like `None` are paths, not bindings
+        let bound: Vec<Binding> =
+            self.vst_pat_bindings(&pat).into_iter().filter(|it| it.local.is_some()).collect();
+        if bound.is_empty() {
+            let mut matches = vst::MatchesExpr::new(scrutinee);
+            matches.pat = Some(Box::new(pat));
+            return Some(Fact { expr: matches.into(), vars });
+        }
+        let reads_as_expr =
+            arm.pat()?.syntax().descendants().filter_map(ast::Pat::cast).all(|it| match it {
+                ast::Pat::IdentPat(it) => {
+                    it.ref_token().is_none() && it.mut_token().is_none() && it.pat().is_none()
+                }
+                ast::Pat::TupleStructPat(_)
+                | ast::Pat::TuplePat(_)
+                | ast::Pat::PathPat(_)
+                | ast::Pat::LiteralPat(_)
+                | ast::Pat::RecordPat(_) => true,
+                _ => false,
+            });
+        if !reads_as_expr {
+            return None;
+        }
+        let as_expr = self.vst_expr_from_text(&pat.to_string())?;
+        for var in bound {
+            if !vars.contains(&var) {
+                vars.push(var);
+            }
+        }
+        let fact = vst::BinExpr::new(
+            scrutinee,
+            vst::BinaryOp::CmpOp(ast::CmpOp::Eq { negated: false }),
+            as_expr,
+        );
+        Some(Fact { expr: fact.into(), vars })
+    }
+
+    /// Whether evaluating `expr` has no effect: it calls no exec function, borrows nothing
+    /// mutably and assigns nothing
+    fn is_pure(&self, expr: &ast::Expr) -> bool {
+        let not_exec = |f: hir::Function| f.mode(self.db()) != hir::FnMode::Exec;
+        expr.syntax().descendants().filter_map(ast::Expr::cast).all(|it| match it {
+            ast::Expr::CallExpr(call) => {
+                let Some(ast::Expr::PathExpr(callee)) = call.expr() else { return false };
+                match callee.path().and_then(|it| self.sema.resolve_path(&it)) {
+                    Some(PathResolution::Def(ModuleDef::Function(f))) => not_exec(f),
+                    // tuple struct and enum variant constructors
+                    Some(PathResolution::Def(ModuleDef::Adt(_) | ModuleDef::Variant(_))) => true,
+                    _ => false,
+                }
+            }
+            ast::Expr::MethodCallExpr(call) => {
+                self.sema.resolve_method_call(&call).is_some_and(not_exec)
+            }
+            ast::Expr::RefExpr(it) => it.mut_token().is_none(),
+            ast::Expr::BinExpr(it) => {
+                !matches!(it.op_kind(), Some(ast::BinaryOp::Assignment { .. }))
+            }
+            ast::Expr::MacroExpr(_)
+            | ast::Expr::ClosureExpr(_)
+            | ast::Expr::LoopExpr(_)
+            | ast::Expr::WhileExpr(_)
+            | ast::Expr::ForExpr(_) => false,
+            _ => true,
+        })
+    }
+
+    /// Whether one of `locals` is changed inside `range` of `func`: assigned to (also through a
+    /// field or an index, like `x.f = e` and `x[i] = e`), borrowed with `&mut`, or the receiver
+    /// of a `&mut self` method like `x.push(e)`
+    fn assigned_in(&self, func: &ast::Fn, locals: &[hir::Local], range: TextRange) -> bool {
+        let changed_place = |node: SyntaxNode| -> Option<ast::Expr> {
+            match_ast! {
+                match node {
+                    ast::BinExpr(it) => match it.op_kind()? {
+                        ast::BinaryOp::Assignment { .. } => it.lhs(),
+                        _ => None,
+                    },
+                    ast::RefExpr(it) => it.mut_token().and(it.expr()),
+                    ast::MethodCallExpr(it) => {
+                        let self_param = self.sema.resolve_method_call(&it)?.self_param(self.db())?;
+                        match self_param.access(self.db()) {
+                            hir::Access::Exclusive => it.receiver(),
+                            _ => None,
+                        }
+                    },
+                    _ => None,
+                }
+            }
+        };
+        func.syntax()
+            .descendants()
+            .filter(|it| range.contains_range(it.text_range()))
+            .filter_map(changed_place)
+            .filter_map(|place| self.place_local(place))
+            .any(|local| locals.contains(&local))
+    }
+
+    /// The variable whose value `place` is part of, like `x` for `x.f[i]`
+    fn place_local(&self, mut place: ast::Expr) -> Option<hir::Local> {
+        loop {
+            place = match place {
+                ast::Expr::FieldExpr(it) => it.expr()?,
+                ast::Expr::IndexExpr(it) => it.base()?,
+                ast::Expr::ParenExpr(it) => it.expr()?,
+                ast::Expr::PrefixExpr(it) if it.op_kind() == Some(ast::UnaryOp::Deref) => {
+                    it.expr()?
+                }
+                ast::Expr::PathExpr(it) => match self.sema.resolve_path(&it.path()?)? {
+                    PathResolution::Local(local) => return Some(local),
+                    _ => return None,
+                },
+                _ => return None,
+            };
+        }
+    }
+}
+
+/// Whether `condition` binds variables with `let`, like `if let Some(x) = e`
+fn has_let(condition: &ast::Expr) -> bool {
+    condition.syntax().descendants().any(|it| ast::LetExpr::can_cast(it.kind()))
+}
+
+/// The block or `if` of an `else` branch
+fn else_syntax(else_branch: &ast::ElseBranch) -> SyntaxNode {
+    match else_branch {
+        ast::ElseBranch::Block(it) => it.syntax().clone(),
+        ast::ElseBranch::IfExpr(it) => it.syntax().clone(),
+    }
+}
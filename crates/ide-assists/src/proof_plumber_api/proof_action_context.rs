@@ -5,7 +5,7 @@
 
 use crate::{proof_plumber_api::verus_error::*, AssistContext};
 use syntax::{
-    ast::{self, vst},
+    ast::{self, vst, vst::VstFold},
     AstNode, SyntaxKind,
 };
 
@@ -40,6 +40,7 @@ impl<'a> AssistContext<'a> {
                 VerusError::Pre(pre) => surrounding_range.contains_range(pre.callsite),
                 VerusError::Post(post) => surrounding_range.contains_range(post.failing_post),
                 VerusError::Assert(assert) => surrounding_range.contains_range(assert.range),
+                VerusError::Inv(inv) => surrounding_range.contains_range(inv.failing_inv),
             })
             .collect();
         Some(filtered_verus_errs)
@@ -70,6 +71,28 @@ impl<'a> AssistContext<'a> {
         filter_post_failures(&self.verus_errors)
     }
 
+    /// Gather every assertion failure
+    pub fn assert_failures(&self) -> Vec<AssertFailure> {
+        filter_assert_failures(&self.verus_errors)
+    }
+
+    /// Whether Verus reported `assertion` as failing.
+    /// The reported span covers the asserted predicate, and lies within the assertion
+    /// (and thus not in an assertion of its `by` block).
+    pub fn is_failing_assert(&self, assertion: &ast::AssertExpr) -> bool {
+        let Some(expr) = assertion.expr() else { return false };
+        let expr_range = expr.syntax().text_range();
+        let assert_range = assertion.syntax().text_range();
+        self.assert_failures()
+            .iter()
+            .any(|it| it.range.contains_range(expr_range) && assert_range.contains_range(it.range))
+    }
+
+    /// Gather every loop invariant failure
+    pub fn inv_failures(&self) -> Vec<InvFailure> {
+        filter_inv_failures(&self.verus_errors)
+    }
+
     /// From a Precondition Failure, retrieve the TOST expression of the failing predicate
     pub fn expr_from_pre_failure(&self, pre: PreFailure) -> Option<vst::Expr> {
         self.find_node_at_given_range::<syntax::ast::Expr>(pre.failing_pre)?.try_into().ok()
@@ -80,6 +103,11 @@ impl<'a> AssistContext<'a> {
         self.find_node_at_given_range::<syntax::ast::Expr>(post.failing_post)?.try_into().ok()
     }
 
+    /// From a Loop Invariant Failure, retrieve the TOST expression of the failing invariant
+    pub fn expr_from_inv_failure(&self, inv: InvFailure) -> Option<vst::Expr> {
+        self.find_node_at_given_range::<syntax::ast::Expr>(inv.failing_inv)?.try_into().ok()
+    }
+
     /// Specify the syntax token to invoke a proof action
     pub fn at_this_token(&self, token: SyntaxKind) -> Option<()> {
         let the_keyword_of_interest = self.find_token_syntax_at_offset(token)?;
@@ -99,47 +127,12 @@ impl<'a> AssistContext<'a> {
     {
         let old: vst::Stmt = old.into();
         let new: vst::Stmt = new.into();
-        let stmts = func.body.as_ref()?.stmt_list.statements.clone();
         let mut func = func.clone();
-        let replaced_stmts = self.replace_statement_rec(&stmts, old, new);
-        func.body.as_mut()?.stmt_list.statements = replaced_stmts;
+        let body = *func.body.take()?;
+        func.body = Some(Box::new(ReplaceStmt { old: &old, new: &new }.fold_block_expr(body)));
         Some(func)
     }
 
-    fn replace_statement_rec(
-        &self,
-        stmts: &Vec<vst::Stmt>,
-        old: vst::Stmt,
-        new: vst::Stmt,
-    ) -> Vec<vst::Stmt> {
-        let replaced_stmts: Vec<vst::Stmt> = stmts
-            .into_iter()
-            .map(|s| {
-                if s.to_string().trim() == old.to_string().trim() {
-                    new.clone()
-                } else {
-                    if let vst::Stmt::ExprStmt(exprstmt) = s {
-                        if let vst::Expr::BlockExpr(be) = &*exprstmt.expr {
-                            let inner_stmts = &be.stmt_list.statements;
-                            let changed_inner =
-                                self.replace_statement_rec(&inner_stmts, old.clone(), new.clone());
-                            let mut changed_stmts = vst::StmtList::new();
-                            changed_stmts.statements = changed_inner;
-                            let changed_exprstmt: vst::Stmt =
-                                vst::BlockExpr::new(changed_stmts).into();
-                            changed_exprstmt
-                        } else {
-                            s.clone()
-                        }
-                    } else {
-                        s.clone()
-                    }
-                }
-            })
-            .collect();
-        replaced_stmts
-    }
-
     /// helper routine to reduce a list of predicate into &&-ed predicate
     pub fn reduce_exprs(&self, es: Vec<vst::Expr>) -> Option<vst::Expr> {
         es.into_iter().reduce(|acc, e| {
@@ -151,3 +144,32 @@ impl<'a> AssistContext<'a> {
         })
     }
 }
+
+struct ReplaceStmt<'a> {
+    old: &'a vst::Stmt,
+    new: &'a vst::Stmt,
+}
+
+impl VstFold for ReplaceStmt<'_> {
+    fn fold_stmt(&mut self, node: vst::Stmt) -> vst::Stmt {
+        if is_same_stmt(&node, self.old) {
+            self.new.clone()
+        } else {
+            vst::walk_fold_stmt(self, node)
+        }
+    }
+}
+
+/// Statements are the same when they come from the same CST node.
+/// Statements built by a proof action (no CST) are compared by text.
+fn is_same_stmt(a: &vst::Stmt, b: &vst::Stmt) -> bool {
+    let syntax = |stmt: &vst::Stmt| match stmt {
+        // `Stmt::from(expr)` does not have a CST node, but `expr` does
+        vst::Stmt::ExprStmt(it) => it.expr.cst().map(|it| it.syntax().clone()),
+        _ => stmt.cst().map(|it| it.syntax().clone()),
+    };
+    match (syntax(a), syntax(b)) {
+        (Some(a), Some(b)) if a.ancestors().last() == b.ancestors().last() => a == b,
+        _ => a.to_string().trim() == b.to_string().trim(),
+    }
+}
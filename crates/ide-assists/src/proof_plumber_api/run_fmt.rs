@@ -11,7 +11,7 @@
 
 use crate::AssistContext;
 use core::ops::Range;
-use syntax::{ast, AstNode};
+use syntax::{ast, AstNode, TextRange};
 
 /*
 verus! {
@@ -42,14 +42,26 @@ impl<'a> AssistContext<'a> {
         self.run_fmt_replacing(&func, sth_to_remove, text_to_replace)
     }
 
-    fn run_fmt_replacing<N: AstNode>(
+    /// Format `text_to_replace`, put in place of `sth_to_remove` in `func`. Unlike [`Self::fmt`],
+    /// `func` does not have to be the function at the cursor, or even part of the file.
+    pub(crate) fn run_fmt_replacing<N: AstNode>(
         &self,
         func: &ast::Fn,          // original
         sth_to_remove: N,        // old
         text_to_replace: String, // new
+    ) -> Option<String> {
+        self.run_fmt_replacing_range(func, sth_to_remove.syntax().text_range(), text_to_replace)
+    }
+
+    /// Like [`Self::run_fmt_replacing`], with the text to replace given by its range in the file
+    /// of `func`
+    pub(crate) fn run_fmt_replacing_range(
+        &self,
+        func: &ast::Fn,
+        expr_range: TextRange,
+        text_to_replace: String,
     ) -> Option<String> {
         let fn_range = func.syntax().text_range();
-        let expr_range = sth_to_remove.syntax().text_range();
         let expr_range_in_fn = expr_range.checked_sub(fn_range.start())?;
         let range: core::ops::Range<usize> = expr_range_in_fn.into();
         let string_result = self.try_fmt(func.to_string(), range, text_to_replace)?;
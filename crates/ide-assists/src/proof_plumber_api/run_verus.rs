@@ -1,161 +1,397 @@
 //! Run Verus and return the verification result
 
-use crate::AssistContext;
-use std::{fs::File, io::Write, process::Command, time::Instant};
-use syntax::ast::{self, vst, HasModuleItem, HasName};
+use crate::{
+    proof_plumber_api::{
+        verification_cache::verification_key,
+        verifier::{SpeculativeCrate, SpeculativeFile, VerificationRequest},
+    },
+    AssistContext,
+};
+use ide_db::{
+    base_db::{salsa::Database, Cancelled, FileId, SourceDatabaseExt},
+    FxHasher,
+};
+use itertools::Itertools;
+use paths::AbsPathBuf;
+use serde::{Deserialize, Serialize};
+use std::{
+    hash::{Hash, Hasher},
+    panic::AssertUnwindSafe,
+    time::Instant,
+};
+use syntax::{
+    ast::{self, vst, HasName},
+    AstNode, Edition, TextRange,
+};
 
 impl<'a> AssistContext<'a> {
-    // for now, assume one file only
-    // 1) copy the file to a temporary file
-    // 2) replace out the function with this VST Fn
-    // 3) run verus on the temporary file
-    // run Verus on the `vst::Fn` node
-    // assume running verus inside vs-code
-    // TODO: pass the whole project to verus, instead of this single file
-    // TODO: projects with multiple file/module -- `verify-module` flag --verify-function flag
-    // output: None -> compile error
-    /// We only replace the function in the input
-    /// we use the remaining codebase when invoking Verus
-    /// Output None when Verus fails to start (e.g., compile error on the modified function)
+    /// Verify `vst_fn` in place of the function of the same name in the current file
+    ///
+    /// 1) splice the function into the text of the current file (see `verifier::SpeculativeFile`)
+    /// 2) hand it to the configured verifier, together with the crate the file belongs to,
+    ///    restricted to this function
+    ///
+    /// Output None when Verus fails to start (e.g., compile error on the modified function),
+    /// or when no verifier is configured
+    ///
+    /// Results are looked up in, and added to, the configured verification cache.
+    ///
+    /// Verus is stopped as soon as the analysis is cancelled (e.g., the file was edited), and the
+    /// cancellation is then propagated like any other salsa cancellation.
     pub(crate) fn try_verus(
         &self,
         vst_fn: &vst::Fn, // only replace this function and run
     ) -> Option<VerifResult> {
-        let source_file = &self.source_file;
-        let verus_exec_path = std::env::var("VERUS_BINARY_PATH")
-            .expect("please set VERUS_BINARY_PATH environment variable");
-        let mut text_string = String::new();
-        // in VST, we should also be able to "print" and verify
-        // display for VST should be correct modulo whitespace
-        for it in source_file.items() {
-            match it {
-                ast::Item::Fn(f) => {
-                    text_string += "\nverus!{\n";
-                    if f.name()?.to_string().trim() == vst_fn.name.to_string().trim() {
-                        text_string += &vst_fn.to_string();
-                    } else {
-                        // review: f.cst.to_string?
-                        text_string += &f.to_string();
-                    }
-                    text_string += "\n}\n";
-                }
-                ast::Item::Enum(e) => {
-                    text_string += "\nverus!{\n";
-                    text_string += &e.to_string();
-                    text_string += "\n}\n";
-                }
-                ast::Item::Struct(e) => {
-                    text_string += "\nverus!{\n";
-                    // review: it.cst.to_string?  for now, No -- see is_failing
-                    text_string += &e.to_string();
-                    text_string += "\n}\n";
-                }
-                ast::Item::Impl(e) => {
-                    text_string += "\nverus!{\n";
-                    text_string += &e.to_string();
-                    text_string += "\n}\n";
-                }
-                _ => {
-                    text_string += &it.to_string();
-                    text_string += "\n";
-                }
+        self.try_verus_text(vst_fn, &vst_fn.to_string())
+    }
+
+    /// Like [`Self::try_verus`], but Verus is also stopped at `deadline`, giving None
+    pub(crate) fn try_verus_until(
+        &self,
+        vst_fn: &vst::Fn,
+        deadline: Instant,
+    ) -> Option<VerifResult> {
+        self.verify(vst_fn, &vst_fn.to_string(), Some(deadline))
+    }
+
+    /// Like [`Self::try_verus`], but splices `fn_text`, the text `vst_fn` was parsed from, into
+    /// the file instead of printing `vst_fn`. The spans of the result then refer to `fn_text`.
+    pub(crate) fn try_verus_text(&self, vst_fn: &vst::Fn, fn_text: &str) -> Option<VerifResult> {
+        self.verify(vst_fn, fn_text, None)
+    }
+
+    fn verify(
+        &self,
+        vst_fn: &vst::Fn,
+        fn_text: &str,
+        deadline: Option<Instant>,
+    ) -> Option<VerifResult> {
+        let verifier = self.config.verifier.as_ref()?;
+        let func = self.fn_to_replace(vst_fn)?;
+        let original = self.source_file.syntax().to_string();
+        // the parser drops `verus!{` and `}` from the tree, but keeps their tokens
+        let has_verus_macro = self
+            .source_file
+            .syntax()
+            .children_with_tokens()
+            .any(|it| it.as_token().is_some_and(|t| t.text() == "verus"));
+        let file =
+            SpeculativeFile::new(&original, func.syntax().text_range(), fn_text, !has_verus_macro);
+        let cache = self.config.verification_cache.as_ref().map(|cache| {
+            let mut key = verification_key(&self.sema, &func, vst_fn, self.verifier_fingerprint());
+            // spans are cached relative to the function, so they depend on how it is printed
+            if fn_text != vst_fn.to_string() {
+                let mut hasher = FxHasher::default();
+                (key, fn_text).hash(&mut hasher);
+                key = hasher.finish();
             }
+            (cache, key)
+        });
+        if let Some(mut result) = cache.and_then(|(cache, key)| cache.get(key, &file)) {
+            result.file = Some(file);
+            return Some(result);
         }
-        //dbg!(&text_string);
-
-        // let verify_func_flag = "--verify-function";
-        // let verify_root_flag = "--verify-root"; // TODO: figure out the surrounding module of `token`
-        // let func_name = vst_fn.name.to_string();
-
-        // REIVEW: instead of writing to a file in the tmp directory, consider using `memfd_create` for an anonymous file
-        // refer to `man memfd_create` or `dev/shm`
-        // REVIEW: Is this true? In linux, set env TMPDIR to set the tmp directory. Otherwise, it fails
-        let tmp_dir = tempfile::TempDir::new().ok()?;
-        let file_path = tmp_dir.path().join("verus_proof_action_scratch_file.rs");
-        //dbg!(&file_path);
-        let display = file_path.display();
-
-        // Open a file in write-only mode, returns `io::Result<File>`
-        let mut file = match File::create(&file_path) {
-            Err(why) => {
-                dbg!("couldn't create {}: {}", display, why);
-                return None;
-            }
-            Ok(file) => file,
+        let request = VerificationRequest {
+            function: vst_fn,
+            function_path: fn_path(&func)?,
+            file: &file,
+            krate: self.speculative_crate(),
+            is_cancelled: &|| {
+                deadline.is_some_and(|it| Instant::now() >= it)
+                    || Cancelled::catch(AssertUnwindSafe(|| self.db().unwind_if_cancelled()))
+                        .is_err()
+            },
         };
+        let result = verifier.verify(&request);
+        self.db().unwind_if_cancelled();
+        let mut result = result?;
+        if let Some((cache, key)) = cache {
+            cache.insert(key, &file, &result);
+        }
+        result.file = Some(file);
+        Some(result)
+    }
 
-        // Write the modified verus program to `file`, returns `io::Result<()>`
-        match file.write_all(text_string.as_bytes()) {
-            Err(why) => {
-                dbg!("couldn't write to {}: {}", display, why);
-                return None;
-            }
-            Ok(_) => (),//dbg!("successfully wrote to {}", display),
-        };
+    /// The function `vst_fn` is a rewrite of: the one at the cursor, or else the first one of the same name
+    fn fn_to_replace(&self, vst_fn: &vst::Fn) -> Option<ast::Fn> {
+        let name = vst_fn.name.to_string();
+        let same_name = |f: &ast::Fn| f.name().is_some_and(|n| n.text().trim() == name.trim());
+        self.find_node_at_offset::<ast::Fn>().filter(same_name).or_else(|| {
+            self.source_file.syntax().descendants().filter_map(ast::Fn::cast).find(same_name)
+        })
+    }
 
-        let now = Instant::now();
-        let output = Command::new(verus_exec_path)
-            .arg(file_path)
-            .arg("--multiple-errors")
-            .arg("10") // we want many errors as proof-action reads this. By default, Verus gives a couple of errors as a human reads those.
-            .output();
-        let elapsed = now.elapsed().as_secs();
-
-        let output = output.ok()?;
-        // dbg!(&output);
-        if output.status.success() {
-            return Some(VerifResult::mk_success(elapsed));
+    /// The fingerprint of the configured verifier for the crate of the current file, see
+    /// [`crate::proof_plumber_api::verifier::Verifier::fingerprint`]
+    pub(crate) fn verifier_fingerprint(&self) -> u64 {
+        let Some(verifier) = &self.config.verifier else { return 0 };
+        let root = self
+            .sema
+            .file_to_module_def(self.file_id())
+            .and_then(|module| self.path_on_disk(module.krate().root_file(self.db())));
+        verifier.fingerprint(root.as_deref())
+    }
+
+    fn path_on_disk(&self, file_id: FileId) -> Option<AbsPathBuf> {
+        let db = self.db();
+        let source_root = db.source_root(db.file_source_root(file_id));
+        let path = source_root.path_for_file(&file_id)?.as_path()?.to_path_buf();
+        // files that only exist in memory, e.g. in tests, cannot be overlaid
+        std::fs::metadata(&path).is_ok().then_some(path)
+    }
+
+    fn speculative_crate(&self) -> Option<SpeculativeCrate> {
+        let db = self.db();
+        let module = self.sema.file_to_module_def(self.file_id())?;
+        let root = self.path_on_disk(module.krate().root_file(db))?;
+        let file = self.path_on_disk(self.file_id())?;
+        // the files of the module tree, which `#[path]` attributes may put anywhere
+        let modules = module
+            .krate()
+            .modules(db)
+            .into_iter()
+            .filter_map(|it| it.as_source_file_id(db))
+            .unique()
+            .filter(|&file_id| file_id != self.file_id())
+            .filter_map(|file_id| {
+                let source_root = db.source_root(db.file_source_root(file_id));
+                let path = source_root.path_for_file(&file_id)?.as_path()?.to_path_buf();
+                let text = db.file_text(file_id);
+                let saved = std::fs::read_to_string(&path).ok();
+                let unsaved = (saved.as_deref() != Some(&*text)).then(|| text.to_string());
+                Some((path, unsaved))
+            })
+            .collect();
+        let module_path = module
+            .path_to_root(db)
+            .into_iter()
+            .rev()
+            .filter_map(|it| it.name(db))
+            .map(|name| name.display(db).to_string())
+            .join("::");
+        let module = (!module_path.is_empty()).then_some(module_path);
+        Some(SpeculativeCrate { root, file, module, modules })
+    }
+}
+
+/// `foo` for free functions, `MyStruct::foo` for methods, also of generic types
+pub(crate) fn fn_path(func: &ast::Fn) -> Option<String> {
+    let name = func.name()?.text().to_string();
+    let impl_ty = func.syntax().ancestors().find_map(ast::Impl::cast).and_then(|it| it.self_ty());
+    Some(match impl_ty {
+        Some(ast::Type::PathType(ty)) => {
+            let path = ty.path()?;
+            let ty = path.segments().filter_map(|it| it.name_ref()).join("::");
+            format!("{ty}::{name}")
+        }
+        Some(ty) => format!("{ty}::{name}"),
+        None => name,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifResult {
+    pub is_success: bool,
+    pub stdout: String,
+    /// Verus' error output, as a human would read it
+    pub stderr: String,
+    /// Verification time, in seconds
+    pub time: u64,
+    /// The errors Verus reported, parsed from its JSON diagnostics
+    pub failures: Vec<VerifFailure>,
+    /// The file that was verified, set by `AssistContext::try_verus`
+    pub file: Option<SpeculativeFile>,
+}
+
+/// What kind of proof obligation failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerifFailureKind {
+    Assertion,
+    Precondition,
+    Postcondition,
+    Other,
+}
+
+impl VerifFailureKind {
+    /// Classify a Verus error by its message
+    pub fn from_message(message: &str) -> VerifFailureKind {
+        if message.contains("assertion failed") {
+            VerifFailureKind::Assertion
+        } else if message.contains("precondition not satisfied") {
+            VerifFailureKind::Precondition
+        } else if message.contains("postcondition not satisfied") {
+            VerifFailureKind::Postcondition
         } else {
-            // disambiguate verification failure     VS    compile error etc
-            match std::str::from_utf8(&output.stdout) {
-                Ok(out) => {
-                    //dbg!(out);
-                    if out.contains("verification results:: verified: 0 errors: 0") {
-                        // failure from other errors. (e.g. compile error)
-                        return None;
-                    } else {
-                        // verification failure
-                        match std::str::from_utf8(&output.stderr) {
-                            Ok(err_msg) => {
-                                return Some(VerifResult::mk_failure(
-                                    out.into(),
-                                    err_msg.into(),
-                                    elapsed,
-                                ));
-                            }
-                            Err(_) => return None,
-                        }
-                    }
-                }
-                Err(_) => return None,
-            }
+            VerifFailureKind::Other
         }
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct VerifResult {
-    pub(crate) is_success: bool,
-    // FIXME: properly parse json using serde and store the list of assertion/ensures/requires
-    #[allow(dead_code)]
-    pub(crate) stdout: String,
-    pub(crate) stderr: String,
-    pub(crate) time: u64,
+/// A single error reported by Verus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifFailure {
+    pub kind: VerifFailureKind,
+    pub message: String,
+    /// The primary span, in the verified file
+    pub range: TextRange,
+    /// The secondary spans, e.g. the failing `requires` clause of a precondition failure
+    pub related: Vec<TextRange>,
+}
+
+/// An assertion of the verified function that failed
+#[derive(Debug, Clone)]
+pub struct FailingAssert {
+    /// The position of the assertion among the assertions of the function, in source order
+    pub index: usize,
+    pub assert: vst::AssertExpr,
 }
 
 impl VerifResult {
-    pub(crate) fn mk_success(time: u64) -> Self {
-        VerifResult { is_success: true, stdout: String::new(), stderr: String::new(), time }
+    pub fn mk_success(time: u64) -> Self {
+        VerifResult {
+            is_success: true,
+            stdout: String::new(),
+            stderr: String::new(),
+            time,
+            failures: Vec::new(),
+            file: None,
+        }
+    }
+
+    pub fn mk_failure(stdout: String, stderr: String, time: u64) -> Self {
+        VerifResult { is_success: false, stdout, stderr, time, failures: Vec::new(), file: None }
+    }
+
+    pub fn with_failures(mut self, failures: Vec<VerifFailure>) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    /// The failures whose primary span lies in the verified function, with that span relative
+    /// to the start of the function
+    pub fn failures_in_function(&self) -> impl Iterator<Item = (&VerifFailure, TextRange)> {
+        let fn_range = self.file.as_ref().map(|file| file.fn_range());
+        self.failures.iter().filter_map(move |failure| {
+            let fn_range = fn_range?;
+            fn_range
+                .contains_range(failure.range)
+                .then(|| (failure, failure.range - fn_range.start()))
+        })
+    }
+
+    /// The failures outside of the verified function, in offsets of the original file.
+    /// Failures in other files are not included.
+    pub fn failures_elsewhere(&self) -> impl Iterator<Item = (&VerifFailure, TextRange)> {
+        self.failures.iter().filter_map(move |failure| {
+            let range = self.file.as_ref()?.to_original(failure.range)?;
+            Some((failure, range))
+        })
     }
 
-    pub(crate) fn mk_failure(stdout: String, stderr: String, time: u64) -> Self {
-        VerifResult { is_success: false, stdout, stderr, time }
+    /// The assertions of the verified function that failed
+    ///
+    /// The function is reparsed from the verified text, so the spans Verus reports are matched
+    /// against the exact assertion they point at, even if the function contains identical ones.
+    pub fn failing_asserts(&self) -> Vec<FailingAssert> {
+        let Some(file) = &self.file else { return Vec::new() };
+        let fn_text = &file.text[file.fn_range()];
+        let parsed = ast::SourceFile::parse(fn_text, Edition::CURRENT).tree();
+        let asserts: Vec<ast::AssertExpr> =
+            parsed.syntax().descendants().filter_map(ast::AssertExpr::cast).collect();
+        let mut failing: Vec<FailingAssert> = Vec::new();
+        for (failure, range) in self.failures_in_function() {
+            if failure.kind != VerifFailureKind::Assertion {
+                continue;
+            }
+            // the innermost assertion covering the span
+            let found = asserts
+                .iter()
+                .enumerate()
+                .filter(|(_, it)| it.syntax().text_range().contains_range(range))
+                .last();
+            let Some((index, assert)) = found else { continue };
+            if failing.iter().any(|it| it.index == index) {
+                continue;
+            }
+            if let Ok(assert) = vst::AssertExpr::try_from(assert.clone()) {
+                failing.push(FailingAssert { index, assert });
+            }
+        }
+        failing
     }
 
+    /// The positions of the failing postconditions among the `ensures` clauses of the verified
+    /// function
+    pub fn failing_ensures(&self) -> Vec<usize> {
+        let Some(file) = &self.file else { return Vec::new() };
+        let fn_text = &file.text[file.fn_range()];
+        let parsed = ast::SourceFile::parse(fn_text, Edition::CURRENT).tree();
+        let Some(ensures) = parsed
+            .syntax()
+            .descendants()
+            .find_map(ast::Fn::cast)
+            .and_then(|func| func.ensures_clause())
+        else {
+            return Vec::new();
+        };
+        let exprs: Vec<ast::Expr> = ensures.exprs().collect();
+        let mut failing = Vec::new();
+        for (failure, range) in self.failures_in_function() {
+            if failure.kind != VerifFailureKind::Postcondition {
+                continue;
+            }
+            let found = exprs.iter().position(|it| it.syntax().text_range().contains_range(range));
+            if let Some(index) = found.filter(|it| !failing.contains(it)) {
+                failing.push(index);
+            }
+        }
+        failing
+    }
+
+    /// Whether the `index`th assertion of the verified function failed
+    pub fn is_assert_failing(&self, index: usize) -> bool {
+        self.failing_asserts().iter().any(|it| it.index == index)
+    }
+
+    /// Whether `assertion`, the `index`th assertion of the verified function, failed
+    pub(crate) fn is_failing_at(&self, index: usize, assertion: &vst::AssertExpr) -> bool {
+        if self.failures.is_empty() {
+            return self.is_failing(assertion);
+        }
+        self.is_assert_failing(index)
+    }
+
+    /// Whether an assertion like `assertion` failed
+    ///
+    /// Prefer [`VerifResult::is_assert_failing`] when the function may contain the same assertion
+    /// more than once.
     pub(crate) fn is_failing(&self, assertion: &vst::AssertExpr) -> bool {
         if self.is_success {
             return false;
         }
-        self.stderr.contains(&assertion.to_string())
+        if self.failures.is_empty() {
+            // no parsed diagnostics, e.g. from a scripted verifier
+            return self.stderr.contains(&assertion.to_string());
+        }
+        let text = assertion.to_string();
+        self.failing_asserts().iter().any(|it| it.assert.to_string() == text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_path_of_methods() {
+        let text = "fn a() {}\nimpl<T> S<T> { fn b() {} }\nimpl m::R { fn c() {} }\n";
+        let parsed = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+        let paths: Vec<_> = parsed
+            .syntax()
+            .descendants()
+            .filter_map(ast::Fn::cast)
+            .filter_map(|f| fn_path(&f))
+            .collect();
+        assert_eq!(paths, ["a", "S::b", "m::R::c"]);
     }
 }
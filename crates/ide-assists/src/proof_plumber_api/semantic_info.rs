@@ -4,8 +4,8 @@
 //!
 #![allow(dead_code)]
 use crate::AssistContext;
-use hir::Semantics;
-use syntax::ast::{self, vst, HasModuleItem};
+use hir::{HirDisplay, PathResolution, Semantics};
+use syntax::ast::{self, make, vst, AstNode, HasModuleItem, HasName};
 
 impl<'a> AssistContext<'a> {
     /// From an VST Expr, get the definition VST Adt of that type
@@ -96,6 +96,70 @@ impl<'a> AssistContext<'a> {
         None
     }
 
+    /// The type of a local variable, as written in source.
+    /// Verus types such as `int` and `nat` are not inferred, their declared type is used instead.
+    pub fn type_of_local(&self, local: hir::Local) -> Option<vst::Type> {
+        let ty = local.ty(self.db());
+        if !ty.is_unknown() {
+            let module = local.module(self.db());
+            if let Ok(ty) = ty.display_source_code(self.db(), module.into(), true) {
+                return make::ty(&ty).try_into().ok();
+            }
+        }
+        let pat = local.primary_source(self.db()).into_ident_pat()?;
+        let parent = pat.syntax().parent()?;
+        let ty = match ast::Param::cast(parent.clone()) {
+            Some(param) => param.ty(),
+            None => ast::LetStmt::cast(parent)?.ty(),
+        };
+        ty?.try_into().ok()
+    }
+
+    /// The type of an expression, as written in source.
+    /// Falls back to the declared type of a variable and the return type of a function of this
+    /// file for Verus types. Literals have no type here, as their type depends on the context.
+    pub fn type_of_expr_source(&self, expr: &vst::Expr) -> Option<vst::Type> {
+        let cst = expr.cst()?;
+        match &cst {
+            ast::Expr::Literal(_) => return None,
+            ast::Expr::PathExpr(path) => {
+                return match self.sema.resolve_path(&path.path()?) {
+                    Some(PathResolution::Local(local)) => self.type_of_local(local),
+                    Some(_) => None,
+                    // the semantic model does not cover `requires` and `ensures`
+                    None => self.type_of_param(path),
+                };
+            }
+            _ => (),
+        }
+        let known = self.sema.type_of_expr(&cst).filter(|it| !it.original.is_unknown());
+        if let (Some(ty), Some(scope)) = (known, self.sema.scope(cst.syntax())) {
+            let ty =
+                ty.original.display_source_code(self.db(), scope.module().into(), true).ok()?;
+            return make::ty(&ty).try_into().ok();
+        }
+        match expr {
+            vst::Expr::CallExpr(call) => Some(*self.vst_find_fn(call)?.ret_type?.ty?),
+            vst::Expr::ParenExpr(paren) => self.type_of_expr_source(&paren.expr),
+            // arithmetic keeps the type of its operands
+            vst::Expr::BinExpr(bin) if matches!(bin.op, ast::BinaryOp::ArithOp(_)) => {
+                self.type_of_expr_source(&bin.lhs).or_else(|| self.type_of_expr_source(&bin.rhs))
+            }
+            _ => None,
+        }
+    }
+
+    /// The declared type of the parameter of the enclosing function that `path` names
+    fn type_of_param(&self, path: &ast::PathExpr) -> Option<vst::Type> {
+        let name = path.path()?.as_single_name_ref()?;
+        let func = path.syntax().ancestors().find_map(ast::Fn::cast)?;
+        let param = func.param_list()?.params().find(|it| match it.pat() {
+            Some(ast::Pat::IdentPat(pat)) => pat.name().is_some_and(|it| it.text() == name.text()),
+            _ => false,
+        })?;
+        param.ty()?.try_into().ok()
+    }
+
     /// Get the NameRef at the callsite
     pub fn name_ref_from_call_expr(&self, call: &vst::CallExpr) -> Option<vst::NameRef> {
         let path = match &*call.expr {
@@ -110,25 +174,30 @@ impl<'a> AssistContext<'a> {
     }
 
     /// Get function definition from the callsite
+    ///
+    /// The callee is resolved with the semantic model. A call that is not part of this file, such
+    /// as one in a rewritten proof, is looked up by name among the functions of this file.
     pub fn vst_find_fn(&self, call: &vst::CallExpr) -> Option<vst::Fn> {
-        for item in self.source_file.items() {
-            let v_item: ast::generated::vst_nodes::Item = match item.try_into() {
-                Ok(ii) => ii,
-                Err(err_msg) => {
-                    dbg!("into_vst failed: {}", err_msg);
-                    continue;
-                }
-            };
-            match v_item {
-                ast::generated::vst_nodes::Item::Fn(f) => {
-                    if call.expr.to_string().trim() == f.name.to_string().trim() {
-                        return Some(*f);
-                    }
-                }
-                _ => {}
+        let callee = match call.cst.as_ref().and_then(|it| it.expr()) {
+            Some(callee) if self.is_in_this_file(callee.syntax()) => callee,
+            _ => return self.vst_find_fn_by_name(call),
+        };
+        let ast::Expr::PathExpr(path) = callee else { return None };
+        match self.sema.resolve_path(&path.path()?)? {
+            PathResolution::Def(hir::ModuleDef::Function(func)) => {
+                self.sema.source(func)?.value.try_into().ok()
             }
+            _ => None,
         }
-        return None;
+    }
+
+    /// The function of this file named like the callee of `call`
+    fn vst_find_fn_by_name(&self, call: &vst::CallExpr) -> Option<vst::Fn> {
+        let name = call.expr.to_string();
+        self.source_file.items().find_map(|item| match item {
+            ast::Item::Fn(func) if func.name()?.text() == name.trim() => func.try_into().ok(),
+            _ => None,
+        })
     }
 
     /// Query if this function is opaque (non-visible to the solver)
@@ -0,0 +1,549 @@
+//! A persistent cache of verification results
+//!
+//! Verus takes seconds to minutes per function, while proof actions and flycheck keep asking about
+//! functions that were verified before. Results are looked up by [`verification_key`]: a hash of
+//! the function's VST and of the spec items Verus may use while verifying it, namely the
+//! signatures of the functions it calls (transitively), the bodies of the spec functions among
+//! them, and the broadcast groups in use. Editing any of these changes the key, so entries are
+//! never invalidated, only left behind.
+//!
+//! The cache is a JSON-lines file under the target directory. Results are appended to it, and it is
+//! compacted when loaded if superseded entries make up most of it or it grew too large.
+
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use hir::{FnMode, Function, HirFileIdExt, ModuleDef, PathResolution, Semantics};
+use ide_db::{
+    base_db::{FileId, SourceDatabaseExt},
+    FxHashMap, FxHashSet, FxHasher, RootDatabase,
+};
+use serde::{Deserialize, Serialize};
+use syntax::{
+    ast::{self, vst, HasName},
+    AstNode, Edition, SyntaxNode, TextRange, TextSize,
+};
+
+use crate::proof_plumber_api::{
+    run_verus::{VerifFailure, VerifFailureKind, VerifResult},
+    verifier::SpeculativeFile,
+};
+
+/// How many functions a key covers at most. Dependencies beyond that are not followed.
+const MAX_DEPENDENCIES: usize = 256;
+
+/// How many entries compaction keeps, the most recently written ones
+const MAX_ENTRIES: usize = 50_000;
+
+/// Verification results, by [`verification_key`]
+#[derive(Debug, Default)]
+pub struct VerificationCache {
+    /// None for a cache that only lives in memory
+    path: Option<PathBuf>,
+    /// Loaded from `path` on first use
+    entries: Mutex<Option<FxHashMap<u64, Entry>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    key: u64,
+    verified: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    stderr: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<CachedFailure>,
+}
+
+/// A [`VerifFailure`], with its spans relative to the start of the verified function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFailure {
+    kind: VerifFailureKind,
+    message: String,
+    range: (u32, u32),
+    related: Vec<(u32, u32)>,
+}
+
+impl VerificationCache {
+    /// A cache persisted in `path`, e.g. `target/verus-analyzer/verification-cache.jsonl`
+    pub fn new(path: PathBuf) -> Self {
+        VerificationCache { path: Some(path), entries: Mutex::default() }
+    }
+
+    /// A cache that is not persisted
+    pub fn in_memory() -> Self {
+        VerificationCache::default()
+    }
+
+    /// The result of verifying the function of `file`, if it is known
+    pub fn get(&self, key: u64, file: &SpeculativeFile) -> Option<VerifResult> {
+        let entry = self.with_entries(|entries| entries.get(&key).cloned())?;
+        if entry.verified {
+            return Some(VerifResult::mk_success(0));
+        }
+        let start = file.fn_range().start();
+        let range = |(from, to): (u32, u32)| TextRange::new(from.into(), to.into()) + start;
+        let failures = entry
+            .failures
+            .into_iter()
+            .map(|failure| VerifFailure {
+                kind: failure.kind,
+                message: failure.message,
+                range: range(failure.range),
+                related: failure.related.into_iter().map(range).collect(),
+            })
+            .collect();
+        Some(VerifResult::mk_failure(String::new(), entry.stderr, 0).with_failures(failures))
+    }
+
+    /// Whether the function with this key is known to verify
+    pub fn is_verified(&self, key: u64) -> bool {
+        self.with_entries(|entries| entries.get(&key).is_some_and(|it| it.verified))
+    }
+
+    /// Remember the result of verifying the function of `file`
+    ///
+    /// The key does not cover the rest of the file, so spans outside of the function cannot be
+    /// cached: failures located elsewhere are left out, as are related spans elsewhere (e.g. the
+    /// `requires` clause of a failing precondition). A failure located elsewhere that relates to
+    /// the function cannot be placed, and the result is not cached at all.
+    pub fn insert(&self, key: u64, file: &SpeculativeFile, result: &VerifResult) {
+        let fn_range = file.fn_range();
+        let relative = |range: TextRange| {
+            fn_range.contains_range(range).then(|| {
+                let range = range - fn_range.start();
+                (range.start().into(), range.end().into())
+            })
+        };
+        let mut failures = Vec::new();
+        for failure in &result.failures {
+            let related: Vec<_> = failure.related.iter().filter_map(|&it| relative(it)).collect();
+            let Some(range) = relative(failure.range) else {
+                if related.is_empty() {
+                    continue;
+                }
+                return;
+            };
+            failures.push(CachedFailure {
+                kind: failure.kind,
+                message: failure.message.clone(),
+                range,
+                related,
+            });
+        }
+        let stderr = if result.is_success { String::new() } else { result.stderr.clone() };
+        self.add(Entry { key, verified: result.is_success, stderr, failures });
+    }
+
+    /// Remember that the function with this key verifies, e.g. because flycheck reported no
+    /// error in it
+    pub fn record_verified(&self, key: u64) {
+        if !self.is_verified(key) {
+            self.add(Entry { key, verified: true, stderr: String::new(), failures: Vec::new() });
+        }
+    }
+
+    fn add(&self, entry: Entry) {
+        if let Some(path) = &self.path {
+            if let Err(e) = append(path, &entry) {
+                tracing::warn!("failed to write the verification cache {}: {e}", path.display());
+            }
+        }
+        self.with_entries(|entries| entries.insert(entry.key, entry));
+    }
+
+    fn with_entries<T>(&self, f: impl FnOnce(&mut FxHashMap<u64, Entry>) -> T) -> T {
+        let mut entries = self.entries.lock().unwrap();
+        f(entries.get_or_insert_with(|| self.path.as_deref().map(load).unwrap_or_default()))
+    }
+}
+
+fn load(path: &Path) -> FxHashMap<u64, Entry> {
+    let Ok(text) = fs::read_to_string(path) else { return FxHashMap::default() };
+    // later lines supersede earlier ones; unreadable lines (e.g. a write cut short) are skipped
+    let mut entries = FxHashMap::default();
+    let mut lines = 0;
+    for (line, entry) in text.lines().enumerate() {
+        lines += 1;
+        if let Ok(entry) = serde_json::from_str::<Entry>(entry) {
+            entries.insert(entry.key, (line, entry));
+        }
+    }
+    if lines >= 2 * entries.len().max(1) || entries.len() > MAX_ENTRIES {
+        let mut kept: Vec<_> = entries.values().collect();
+        kept.sort_by_key(|&&(line, _)| line);
+        let kept = &kept[kept.len().saturating_sub(MAX_ENTRIES)..];
+        if let Err(e) = rewrite(path, kept.iter().map(|(_, entry)| entry)) {
+            tracing::warn!("failed to compact the verification cache {}: {e}", path.display());
+        }
+        let kept: FxHashSet<u64> = kept.iter().map(|(_, entry)| entry.key).collect();
+        entries.retain(|key, _| kept.contains(key));
+    }
+    entries.into_iter().map(|(key, (_, entry))| (key, entry)).collect()
+}
+
+/// Replace the file at `path` by `entries`, at once so that readers never see half of it
+fn rewrite<'a>(path: &Path, entries: impl Iterator<Item = &'a Entry>) -> io::Result<()> {
+    let mut text = String::new();
+    for entry in entries {
+        text.push_str(&serde_json::to_string(entry)?);
+        text.push('\n');
+    }
+    let tmp = path.with_extension("jsonl.tmp");
+    fs::write(&tmp, text)?;
+    fs::rename(tmp, path)
+}
+
+fn append(path: &Path, entry: &Entry) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    fs::OpenOptions::new().create(true).append(true).open(path)?.write_all(line.as_bytes())
+}
+
+/// The cache key of `function`, a rewrite of `original`
+///
+/// Paths in `function` are resolved in the scope of `original`, so the rewrite may call functions
+/// `original` does not. Method calls cannot be resolved without type information, and are looked
+/// up by name among the method calls of `original`.
+///
+/// `verifier` tells apart the results of different verifiers, see
+/// [`crate::proof_plumber_api::verifier::Verifier::fingerprint`].
+pub fn verification_key(
+    sema: &Semantics<'_, RootDatabase>,
+    original: &ast::Fn,
+    function: &vst::Fn,
+    verifier: u64,
+) -> u64 {
+    let text = function.to_string();
+    let mut dependencies = Dependencies::new(sema);
+
+    let rewritten = ast::SourceFile::parse(&text, Edition::CURRENT).tree();
+    let scope_node =
+        original.body().map_or_else(|| original.syntax().clone(), |it| it.syntax().clone());
+    if let Some(scope) = sema.scope(&scope_node) {
+        for path in rewritten.syntax().descendants().filter_map(ast::Path::cast) {
+            if let Some(PathResolution::Def(ModuleDef::Function(it))) =
+                scope.speculative_resolve(&path)
+            {
+                dependencies.push(it);
+            }
+        }
+    }
+    let methods: FxHashMap<String, Function> = original
+        .syntax()
+        .descendants()
+        .filter_map(ast::MethodCallExpr::cast)
+        .filter_map(|call| {
+            Some((call.name_ref()?.text().to_string(), sema.resolve_method_call(&call)?))
+        })
+        .collect();
+    for call in rewritten.syntax().descendants().filter_map(ast::MethodCallExpr::cast) {
+        if let Some(&it) = call.name_ref().and_then(|name| methods.get(name.text().as_str())) {
+            dependencies.push(it);
+        }
+    }
+    dependencies.broadcast_uses(original.syntax());
+
+    let mut hasher = FxHasher::default();
+    verifier.hash(&mut hasher);
+    text.hash(&mut hasher);
+    dependencies.texts().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The cache keys of the functions of a file
+#[derive(Debug, Clone)]
+pub struct FileVerificationKeys {
+    /// The functions, by range. Functions nested in other functions are part of those.
+    pub functions: Vec<(TextRange, u64)>,
+    /// The key of everything but the functions, e.g. type definitions
+    pub rest: u64,
+}
+
+/// The cache keys of the functions of `file_id`, as they are now
+pub fn file_verification_keys(
+    db: &RootDatabase,
+    file_id: FileId,
+    verifier: u64,
+) -> FileVerificationKeys {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(file_id);
+    let mut functions = Vec::new();
+    let mut rest = String::new();
+    let mut last = TextSize::from(0);
+    let text = source_file.syntax().to_string();
+    let outermost =
+        source_file.syntax().descendants().filter_map(ast::Fn::cast).filter(|func| {
+            !func.syntax().ancestors().skip(1).any(|it| ast::Fn::can_cast(it.kind()))
+        });
+    for func in outermost {
+        let range = func.syntax().text_range();
+        rest.push_str(&text[TextRange::new(last, range.start())]);
+        last = range.end();
+        if let Ok(function) = vst::Fn::try_from(func.clone()) {
+            functions.push((range, verification_key(&sema, &func, &function, verifier)));
+        }
+    }
+    rest.push_str(&text[usize::from(last)..]);
+
+    let mut hasher = FxHasher::default();
+    verifier.hash(&mut hasher);
+    rest.hash(&mut hasher);
+    FileVerificationKeys { functions, rest: hasher.finish() }
+}
+
+/// The functions and broadcast groups a function depends on, found transitively
+struct Dependencies<'a, 'db> {
+    sema: &'a Semantics<'db, RootDatabase>,
+    seen: FxHashSet<Function>,
+    queue: Vec<Function>,
+    /// The text of the broadcast groups in use
+    broadcast: Vec<String>,
+}
+
+impl<'a, 'db> Dependencies<'a, 'db> {
+    fn new(sema: &'a Semantics<'db, RootDatabase>) -> Self {
+        Dependencies { sema, seen: FxHashSet::default(), queue: Vec::new(), broadcast: Vec::new() }
+    }
+
+    /// Follow `func`, unless it is library code: that only changes along with the toolchain
+    fn push(&mut self, func: Function) {
+        let db = self.sema.db;
+        if self.seen.len() >= MAX_DEPENDENCIES || self.seen.contains(&func) {
+            return;
+        }
+        let Some(source) = self.sema.source(func) else { return };
+        let file_id = source.file_id.original_file(db);
+        if db.source_root(db.file_source_root(file_id)).is_library {
+            return;
+        }
+        self.seen.insert(func);
+        self.queue.push(func);
+    }
+
+    /// Follow the functions called in `nodes`
+    fn resolve_calls(&mut self, nodes: impl Iterator<Item = SyntaxNode>) {
+        for node in nodes {
+            let func = if let Some(path) = ast::Path::cast(node.clone()) {
+                match self.sema.resolve_path(&path) {
+                    Some(PathResolution::Def(ModuleDef::Function(it))) => Some(it),
+                    _ => None,
+                }
+            } else if let Some(call) = ast::MethodCallExpr::cast(node) {
+                self.sema.resolve_method_call(&call)
+            } else {
+                None
+            };
+            if let Some(func) = func {
+                self.push(func);
+            }
+        }
+    }
+
+    /// Record the `broadcast use` items of the file of `node` and of the crate root, and the
+    /// groups they name when those are defined in the same files
+    fn broadcast_uses(&mut self, node: &SyntaxNode) {
+        let db = self.sema.db;
+        let mut files = vec![node.ancestors().last().unwrap_or_else(|| node.clone())];
+        let file_id = self.sema.hir_file_for(node).original_file(db);
+        if let Some(module) = self.sema.file_to_module_def(file_id) {
+            let root = module.krate().root_file(db);
+            if root != file_id {
+                files.push(self.sema.parse(root).syntax().clone());
+            }
+        }
+        let groups: Vec<ast::BroadcastGroup> = files
+            .iter()
+            .flat_map(|it| it.descendants().filter_map(ast::BroadcastGroup::cast))
+            .collect();
+        // the parser makes the whole `broadcast use` item a `BroadcastUseList`
+        let uses: Vec<ast::BroadcastUseList> = files
+            .iter()
+            .flat_map(|it| it.descendants().filter_map(ast::BroadcastUseList::cast))
+            .collect();
+        for broadcast_use in uses {
+            self.broadcast.push(broadcast_use.syntax().to_string());
+            for path in broadcast_use.paths() {
+                let Some(name) = path.segment().and_then(|it| it.name_ref()) else { continue };
+                let group = groups.iter().find(|group| {
+                    group
+                        .broadcast_group_identifier()
+                        .and_then(|it| it.ident_token())
+                        .is_some_and(|it| it.text() == name.text())
+                });
+                let Some(group) = group else { continue };
+                self.broadcast.push(group.syntax().to_string());
+                // as are the members of a group, which are bare paths
+                let members = group.broadcast_group_list().into_iter().flat_map(|it| {
+                    it.syntax().children().filter(|it| ast::Path::can_cast(it.kind()))
+                });
+                self.resolve_calls(members);
+            }
+        }
+    }
+
+    /// The texts the key covers, in a stable order: the signatures of the functions (the whole
+    /// function for spec functions, whose bodies Verus may unfold), and the broadcast groups
+    fn texts(mut self) -> Vec<String> {
+        let db = self.sema.db;
+        let mut texts = std::mem::take(&mut self.broadcast);
+        while let Some(func) = self.queue.pop() {
+            let Some(source) = self.sema.source(func) else { continue };
+            let func_syntax = source.value.syntax().clone();
+            let body = source.value.body().filter(|_| func.mode(db) != FnMode::Spec);
+            let visible = |range: TextRange| {
+                body.as_ref().map_or(true, |it| !it.syntax().text_range().contains_range(range))
+            };
+            let text = match &body {
+                Some(body) => {
+                    let len = body.syntax().text_range().start() - func_syntax.text_range().start();
+                    func_syntax.text().slice(..len).to_string()
+                }
+                None => func_syntax.to_string(),
+            };
+            let name = source.value.name().map(|it| it.to_string()).unwrap_or_default();
+            texts.push(format!("{name}: {text}"));
+            self.resolve_calls(func_syntax.descendants().filter(|it| visible(it.text_range())));
+        }
+        texts.sort();
+        texts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ide_db::base_db::SourceDatabaseExt;
+    use test_fixture::WithFixture;
+
+    use super::*;
+
+    fn key_of_foo(text: &str) -> u64 {
+        let (db, file_id) = RootDatabase::with_single_file(text);
+        let keys = file_verification_keys(&db, file_id, 0);
+        let text = db.file_text(file_id);
+        let foo = text.find("fn foo").unwrap() as u32;
+        keys.functions
+            .iter()
+            .find(|(range, _)| range.contains(foo.into()))
+            .map(|&(_, key)| key)
+            .unwrap()
+    }
+
+    #[test]
+    fn key_covers_spec_dependencies() {
+        let base = key_of_foo(
+            "
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int) ensures double(x) == 2 * x { assert(x + x == 2 * x); }
+proof fn foo(x: int) ensures double(x) == 2 * x { lemma_double(x); }
+",
+        );
+        let same_proof = key_of_foo(
+            "
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int) ensures double(x) == 2 * x { }
+proof fn foo(x: int) ensures double(x) == 2 * x { lemma_double(x); }
+",
+        );
+        let other_ensures = key_of_foo(
+            "
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int) ensures double(x) == x + x { assert(x + x == 2 * x); }
+proof fn foo(x: int) ensures double(x) == 2 * x { lemma_double(x); }
+",
+        );
+        let other_spec = key_of_foo(
+            "
+spec fn double(x: int) -> int { 2 * x }
+proof fn lemma_double(x: int) ensures double(x) == 2 * x { assert(x + x == 2 * x); }
+proof fn foo(x: int) ensures double(x) == 2 * x { lemma_double(x); }
+",
+        );
+        let other_group = key_of_foo(
+            "
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int) ensures double(x) == 2 * x { assert(x + x == 2 * x); }
+broadcast group group_double { lemma_double }
+broadcast use group_double;
+proof fn foo(x: int) ensures double(x) == 2 * x { lemma_double(x); }
+",
+        );
+        assert_eq!(base, same_proof);
+        assert_ne!(base, other_ensures);
+        assert_ne!(base, other_spec);
+        assert_ne!(base, other_group);
+    }
+
+    #[test]
+    fn results_are_persisted() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("target").join("verification-cache.jsonl");
+        let original = "fn a() {}\nfn b() { assert(false); }\n";
+        let b = TextRange::new(
+            TextSize::of("fn a() {}\n"),
+            TextSize::of(original) - TextSize::of("\n"),
+        );
+        let file = SpeculativeFile::new(original, b, "fn b() { assert(false); }", false);
+        let assert_range =
+            TextRange::at(TextSize::of("fn a() {}\nfn b() { "), TextSize::of("assert(false)"));
+        let failure = |range, related| VerifFailure {
+            kind: VerifFailureKind::Assertion,
+            message: "assertion failed".to_owned(),
+            range,
+            related,
+        };
+
+        let cache = VerificationCache::new(path.clone());
+        cache.record_verified(1);
+        let failing = VerifResult::mk_failure(String::new(), "error".to_owned(), 3);
+        cache.insert(2, &file, &failing.clone().with_failures(vec![failure(assert_range, vec![])]));
+        // a span in `fn a` cannot be cached, as key 3 does not cover `fn a`
+        let outside = TextRange::at(0.into(), TextSize::of("fn a() {}"));
+        cache.insert(
+            3,
+            &file,
+            &failing.clone().with_failures(vec![
+                failure(assert_range, vec![outside]),
+                failure(outside, vec![]),
+            ]),
+        );
+        cache.insert(4, &file, &failing.with_failures(vec![failure(outside, vec![assert_range])]));
+
+        let reloaded = VerificationCache::new(path);
+        assert!(reloaded.is_verified(1));
+        assert!(!reloaded.is_verified(2));
+        let result = reloaded.get(2, &file).unwrap();
+        assert!(!result.is_success);
+        assert_eq!(result.stderr, "error");
+        assert_eq!(result.failures, vec![failure(assert_range, vec![])]);
+        assert_eq!(reloaded.get(3, &file).unwrap().failures, vec![failure(assert_range, vec![])]);
+        assert!(reloaded.get(4, &file).is_none());
+    }
+
+    #[test]
+    fn superseded_entries_are_compacted() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("verification-cache.jsonl");
+        let cache = VerificationCache::new(path.clone());
+        let file =
+            SpeculativeFile::new("fn a() {}", TextRange::up_to(9.into()), "fn a() {}", false);
+        let failing = VerifResult::mk_failure(String::new(), "error".to_owned(), 3);
+        cache.insert(1, &file, &failing);
+        cache.insert(2, &file, &failing);
+        cache.record_verified(1);
+        cache.record_verified(2);
+        let lines = || fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines(), 4);
+
+        let reloaded = VerificationCache::new(path.clone());
+        assert!(reloaded.is_verified(1) && reloaded.is_verified(2));
+        assert_eq!(lines(), 2);
+    }
+}
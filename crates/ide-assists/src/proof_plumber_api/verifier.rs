@@ -0,0 +1,644 @@
+//! The verifier behind [`crate::AssistContext::try_verus`]
+//!
+//! Proof actions never run Verus themselves. They hand the rewritten function to the
+//! [`Verifier`] in [`crate::AssistConfig`], which is [`VerusBinary`] in the IDE
+//! and usually a [`MockVerifier`] in tests, so that proof actions can be tested without Verus.
+//!
+//! Verification is speculative: the rewritten function is spliced into the text of its file
+//! ([`SpeculativeFile`]), and that file is overlaid onto a mirror of the crate's module tree
+//! ([`SpeculativeCrate`]), so that other modules, `mod` declarations and crate attributes stay as
+//! they are.
+//!
+
+use std::{
+    fmt, fs,
+    hash::{Hash, Hasher},
+    io::Read,
+    panic::RefUnwindSafe,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ide_db::FxHasher;
+use paths::{AbsPath, AbsPathBuf, Utf8PathBuf};
+use serde::Deserialize;
+use syntax::{
+    ast::{self, vst},
+    AstNode, Edition, SyntaxNode, TextRange, TextSize,
+};
+
+use crate::proof_plumber_api::run_verus::{VerifFailure, VerifFailureKind, VerifResult};
+
+/// Something that can verify a Verus program
+pub trait Verifier: fmt::Debug + Send + Sync + RefUnwindSafe {
+    /// Verify `request.function`, as it appears in `request.file`.
+    ///
+    /// Output None when verification did not happen (e.g., compile error on the modified function)
+    fn verify(&self, request: &VerificationRequest<'_>) -> Option<VerifResult>;
+
+    /// Identifies the verifier in cache keys, so that the results of one verifier (e.g. a Verus
+    /// build) are never mistaken for those of another. `crate_root` is the root of the crate
+    /// being verified, as the arguments of a verifier may depend on the crate.
+    fn fingerprint(&self, _crate_root: Option<&AbsPath>) -> u64 {
+        0
+    }
+}
+
+/// A rewritten function to verify, and where it lives
+pub struct VerificationRequest<'a> {
+    /// The function, as rewritten by a proof action
+    pub function: &'a vst::Fn,
+    /// The argument to `--verify-function`, e.g. `foo` or `MyStruct::foo`
+    pub function_path: String,
+    /// The text of the function's file, with the function replaced
+    pub file: &'a SpeculativeFile,
+    /// The crate the file belongs to, if it exists on disk
+    pub krate: Option<SpeculativeCrate>,
+    /// Whether the result is no longer wanted. Verifiers that take long should poll this and
+    /// give up (returning None) once it turns true.
+    pub is_cancelled: &'a dyn Fn() -> bool,
+}
+
+impl fmt::Debug for VerificationRequest<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerificationRequest")
+            .field("function_path", &self.function_path)
+            .field("file", &self.file)
+            .field("krate", &self.krate)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A crate on disk, with one of its files replaced by a [`SpeculativeFile`]
+#[derive(Debug, Clone)]
+pub struct SpeculativeCrate {
+    /// The crate root, e.g. `src/lib.rs`
+    pub root: AbsPathBuf,
+    /// The file to replace
+    pub file: AbsPathBuf,
+    /// The module `file` defines as a `--verify-module` argument, None for the crate root
+    pub module: Option<String>,
+    /// The files of the other modules of the crate, with their text in the editor if it differs
+    /// from the disk
+    pub modules: Vec<(AbsPathBuf, Option<String>)>,
+}
+
+/// The text of a file after replacing one function, and how offsets in it relate to the original
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeculativeFile {
+    pub text: String,
+    /// Where the replaced function was in the original file
+    original_fn_range: TextRange,
+    /// Where the new function is in `text`
+    fn_range: TextRange,
+    /// Length of the text prepended to the original file
+    prefix_len: TextSize,
+}
+
+impl SpeculativeFile {
+    /// Replace `fn_range` of `original` by `new_fn`.
+    ///
+    /// Files that do not wrap their items in `verus!{}` themselves (e.g. test fixtures) are wrapped
+    /// into one when `wrap_in_verus_macro` is set.
+    pub fn new(
+        original: &str,
+        fn_range: TextRange,
+        new_fn: &str,
+        wrap_in_verus_macro: bool,
+    ) -> SpeculativeFile {
+        let (prefix, suffix) = if wrap_in_verus_macro { ("verus!{\n", "\n}\n") } else { ("", "") };
+        let before = &original[..usize::from(fn_range.start())];
+        let after = &original[usize::from(fn_range.end())..];
+        let text = format!("{prefix}{before}{new_fn}{after}{suffix}");
+        let prefix_len = TextSize::of(prefix);
+        let fn_start = prefix_len + fn_range.start();
+        SpeculativeFile {
+            text,
+            original_fn_range: fn_range,
+            fn_range: TextRange::at(fn_start, TextSize::of(new_fn)),
+            prefix_len,
+        }
+    }
+
+    /// Where the rewritten function is in [`SpeculativeFile::text`]
+    pub fn fn_range(&self) -> TextRange {
+        self.fn_range
+    }
+
+    /// Map a range of [`SpeculativeFile::text`] back to the original file.
+    ///
+    /// Ranges inside the rewritten function have no counterpart in the original file and map to
+    /// None; use [`SpeculativeFile::fn_range`] to locate them in the rewritten function instead.
+    pub fn to_original(&self, range: TextRange) -> Option<TextRange> {
+        if range.end() <= self.fn_range.start() {
+            range.checked_sub(self.prefix_len)
+        } else if range.start() >= self.fn_range.end() {
+            let shifted = range.checked_sub(self.fn_range.end())?;
+            let original = shifted + self.original_fn_range.end();
+            Some(original)
+        } else {
+            None
+        }
+    }
+}
+
+/// Runs the `verus` binary on a mirror of the crate, or on a temporary file if there is no crate
+#[derive(Debug)]
+pub struct VerusBinary {
+    path: Utf8PathBuf,
+    /// The roots of the crates that are binaries; everything else is verified as a library
+    bin_roots: Vec<AbsPathBuf>,
+    /// Extra arguments by crate root, e.g. the `extra_args` and `rlimit` of the crate's package
+    crate_args: Vec<(AbsPathBuf, Vec<String>)>,
+}
+
+impl VerusBinary {
+    pub fn new(path: Utf8PathBuf) -> Self {
+        VerusBinary { path, bin_roots: Vec::new(), crate_args: Vec::new() }
+    }
+
+    pub fn with_bin_roots(mut self, bin_roots: Vec<AbsPathBuf>) -> Self {
+        self.bin_roots = bin_roots;
+        self
+    }
+
+    pub fn with_crate_args(mut self, crate_args: Vec<(AbsPathBuf, Vec<String>)>) -> Self {
+        self.crate_args = crate_args;
+        self
+    }
+
+    fn args_for(&self, crate_root: Option<&AbsPath>) -> &[String] {
+        let args = self.crate_args.iter().find(|(root, _)| Some(&**root) == crate_root);
+        args.map_or(&[], |(_, args)| args)
+    }
+}
+
+impl Verifier for VerusBinary {
+    fn verify(&self, request: &VerificationRequest<'_>) -> Option<VerifResult> {
+        // REIVEW: instead of writing to a file in the tmp directory, consider using `memfd_create` for an anonymous file
+        // refer to `man memfd_create` or `dev/shm`
+        // REVIEW: Is this true? In linux, set env TMPDIR to set the tmp directory. Otherwise, it fails
+        let tmp_dir = tempfile::TempDir::new().ok()?;
+        let (root, file, module, is_lib) = match &request.krate {
+            Some(krate) => {
+                let (root, file) = overlay_crate(krate, &request.file.text, tmp_dir.path())?;
+                (root, file, krate.module.clone(), !self.bin_roots.contains(&krate.root))
+            }
+            None => {
+                let file_path = tmp_dir.path().join("verus_proof_action_scratch_file.rs");
+                fs::write(&file_path, &request.file.text).ok()?;
+                (file_path.clone(), file_path, None, true)
+            }
+        };
+
+        let mut cmd = Command::new(&self.path);
+        cmd.current_dir(tmp_dir.path());
+        cmd.arg(&root);
+        if is_lib {
+            cmd.args(["--crate-type", "lib"]);
+        }
+        match &module {
+            Some(module) => cmd.args(["--verify-module", module]),
+            None => cmd.arg("--verify-root"),
+        };
+        cmd.args(["--verify-function", &request.function_path]);
+        // we want many errors as proof-action reads this. By default, Verus gives a couple of errors as a human reads those.
+        cmd.args(["--multiple-errors", "10"]);
+        cmd.args(self.args_for(request.krate.as_ref().map(|it| &*it.root)));
+        cmd.args(toolchain::VERUS_JSON_ARGS);
+
+        let now = Instant::now();
+        let output = run_cancellable(cmd, request.is_cancelled)?;
+        let elapsed = now.elapsed().as_secs();
+
+        if output.status.success() {
+            return Some(VerifResult::mk_success(elapsed));
+        }
+        // disambiguate verification failure     VS    compile error etc
+        let out = std::str::from_utf8(&output.stdout).ok()?;
+        if out.contains("verification results:: verified: 0 errors: 0") {
+            // failure from other errors. (e.g. compile error)
+            return None;
+        }
+        // verification failure
+        let err_msg = std::str::from_utf8(&output.stderr).ok()?;
+        let file = file.strip_prefix(tmp_dir.path()).ok()?;
+        let (failures, rendered) = parse_diagnostics(err_msg, file);
+        Some(VerifResult::mk_failure(out.into(), rendered, elapsed).with_failures(failures))
+    }
+
+    /// The binary's path and modification time, which changes when Verus is rebuilt in place, and
+    /// the extra arguments of the crate
+    fn fingerprint(&self, crate_root: Option<&AbsPath>) -> u64 {
+        let mut hasher = FxHasher::default();
+        self.path.hash(&mut hasher);
+        fs::metadata(&self.path).and_then(|it| it.modified()).ok().hash(&mut hasher);
+        self.args_for(crate_root).hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Run `cmd` to completion, killing it as soon as `is_cancelled` turns true
+fn run_cancellable(mut cmd: Command, is_cancelled: &dyn Fn() -> bool) -> Option<Output> {
+    let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().ok()?;
+    // drain the pipes while waiting, so that Verus never blocks on a full pipe
+    let stdout = child.stdout.take().map(read_to_end);
+    let stderr = child.stderr.take().map(read_to_end);
+    let status = loop {
+        if is_cancelled() {
+            let _ = child.kill();
+            let _ = child.wait();
+            return None;
+        }
+        match child.try_wait().ok()? {
+            Some(status) => break status,
+            None => thread::sleep(Duration::from_millis(50)),
+        }
+    };
+    let join = |reader: Option<thread::JoinHandle<Vec<u8>>>| {
+        reader.and_then(|it| it.join().ok()).unwrap_or_default()
+    };
+    Some(Output { status, stdout: join(stdout), stderr: join(stderr) })
+}
+
+fn read_to_end(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+/// The subset of rustc's JSON diagnostics we read
+#[derive(Deserialize)]
+struct JsonDiagnostic {
+    message: String,
+    level: String,
+    spans: Vec<JsonSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JsonSpan {
+    file_name: String,
+    byte_start: u32,
+    byte_end: u32,
+    is_primary: bool,
+}
+
+/// Parse the JSON diagnostics Verus printed to `stderr`, keeping the errors in `file`.
+/// Also returns the human-readable rendering of all diagnostics.
+fn parse_diagnostics(stderr: &str, file: &Path) -> (Vec<VerifFailure>, String) {
+    let mut failures = Vec::new();
+    let mut rendered = String::new();
+    for line in stderr.lines() {
+        let Ok(diagnostic) = serde_json::from_str::<JsonDiagnostic>(line) else {
+            // not every line is a diagnostic, e.g. Verus' own summary
+            rendered.push_str(line);
+            rendered.push('\n');
+            continue;
+        };
+        rendered.push_str(diagnostic.rendered.as_deref().unwrap_or(&diagnostic.message));
+        if diagnostic.level != "error" {
+            continue;
+        }
+        let range_of = |span: &JsonSpan| {
+            TextRange::new(TextSize::from(span.byte_start), TextSize::from(span.byte_end))
+        };
+        let in_file = |span: &&JsonSpan| Path::new(&span.file_name).ends_with(file);
+        let Some(primary) = diagnostic.spans.iter().filter(in_file).find(|it| it.is_primary) else {
+            continue;
+        };
+        failures.push(VerifFailure {
+            kind: VerifFailureKind::from_message(&diagnostic.message),
+            message: diagnostic.message.clone(),
+            range: range_of(primary),
+            related: diagnostic
+                .spans
+                .iter()
+                .filter(in_file)
+                .filter(|it| !it.is_primary)
+                .map(range_of)
+                .collect(),
+        });
+    }
+    (failures, rendered)
+}
+
+/// Mirror the module files of the crate in `dest`: `krate.file` is written with `text`, unsaved
+/// files with their text in the editor, and the other files are linked to the originals.
+/// Returns the paths of the crate root and of the replaced file in `dest`.
+fn overlay_crate(krate: &SpeculativeCrate, text: &str, dest: &Path) -> Option<(PathBuf, PathBuf)> {
+    // `#[path]` attributes may put modules outside the directory of the crate root
+    let contains_all = |dir: &AbsPath| {
+        krate.file.starts_with(dir) && krate.modules.iter().all(|(path, _)| path.starts_with(dir))
+    };
+    let mut base = krate.root.parent()?;
+    while !contains_all(base) {
+        base = base.parent()?;
+    }
+    let in_dest = |path: &AbsPath| Some(dest.join(path.strip_prefix(base)?.as_str()));
+    for (path, unsaved) in &krate.modules {
+        let mirrored = in_dest(path)?;
+        fs::create_dir_all(mirrored.parent()?).ok()?;
+        match unsaved {
+            Some(unsaved) => fs::write(&mirrored, unsaved).ok()?,
+            None => link_file(path.as_ref(), &mirrored).ok()?,
+        }
+    }
+    let file = in_dest(&krate.file)?;
+    fs::create_dir_all(file.parent()?).ok()?;
+    fs::write(&file, text).ok()?;
+    Some((in_dest(&krate.root)?, file))
+}
+
+fn link_file(src: &Path, dest: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    return std::os::unix::fs::symlink(src, dest);
+    #[cfg(not(unix))]
+    return fs::copy(src, dest).map(drop);
+}
+
+/// A scripted verifier for tests
+///
+/// Results are looked up by the text of the function under verification, ignoring whitespace.
+/// Functions without a scripted result verify successfully, unless
+/// [`MockVerifier::failing_by_default`] was used.
+#[derive(Debug, Default)]
+pub struct MockVerifier {
+    results: Vec<(String, MockResult)>,
+    fail_by_default: bool,
+    panicking: bool,
+}
+
+#[derive(Debug)]
+enum MockResult {
+    Result(VerifResult),
+    /// The assertions at these positions fail, as if Verus reported a span for each of them
+    FailingAsserts(Vec<usize>),
+    /// The `ensures` clauses at these positions fail
+    FailingEnsures(Vec<usize>),
+}
+
+impl MockVerifier {
+    pub fn new() -> Self {
+        MockVerifier::default()
+    }
+
+    /// Functions without a scripted result fail to verify, instead of succeeding
+    pub fn failing_by_default(mut self) -> Self {
+        self.fail_by_default = true;
+        self
+    }
+
+    /// Panics when asked to verify anything, to check that Verus is not run at all
+    pub fn panicking(mut self) -> Self {
+        self.panicking = true;
+        self
+    }
+
+    /// `function` verifies
+    pub fn verifies(self, function: &str) -> Self {
+        self.with_result(function, VerifResult::mk_success(0))
+    }
+
+    /// `function` fails to verify, with `stderr` as Verus' error output
+    pub fn fails(self, function: &str, stderr: &str) -> Self {
+        self.with_result(function, VerifResult::mk_failure(String::new(), stderr.to_owned(), 0))
+    }
+
+    /// The assertions of `function` at the given positions (in source order) fail
+    pub fn fails_asserts(mut self, function: &str, indices: &[usize]) -> Self {
+        self.results
+            .push((strip_whitespace(function), MockResult::FailingAsserts(indices.to_vec())));
+        self
+    }
+
+    /// The `ensures` clauses of `function` at the given positions fail
+    pub fn fails_ensures(mut self, function: &str, indices: &[usize]) -> Self {
+        self.results
+            .push((strip_whitespace(function), MockResult::FailingEnsures(indices.to_vec())));
+        self
+    }
+
+    /// `function` verifies with the given `result`
+    pub fn with_result(mut self, function: &str, result: VerifResult) -> Self {
+        self.results.push((strip_whitespace(function), MockResult::Result(result)));
+        self
+    }
+}
+
+impl Verifier for MockVerifier {
+    fn verify(&self, request: &VerificationRequest<'_>) -> Option<VerifResult> {
+        assert!(!self.panicking, "unexpected verification of `{}`", request.function);
+        let function = strip_whitespace(&request.function.to_string());
+        match self.results.iter().find(|(text, _)| *text == function) {
+            Some((_, MockResult::Result(result))) => Some(result.clone()),
+            Some((_, MockResult::FailingAsserts(indices))) => {
+                let failures = mock_failures(request.file, indices, VerifFailureKind::Assertion);
+                let result = VerifResult::mk_failure(String::new(), String::new(), 0);
+                Some(result.with_failures(failures))
+            }
+            Some((_, MockResult::FailingEnsures(indices))) => {
+                let failures =
+                    mock_failures(request.file, indices, VerifFailureKind::Postcondition);
+                let result = VerifResult::mk_failure(String::new(), String::new(), 0);
+                Some(result.with_failures(failures))
+            }
+            None if self.fail_by_default => {
+                Some(VerifResult::mk_failure(String::new(), String::new(), 0))
+            }
+            None => Some(VerifResult::mk_success(0)),
+        }
+    }
+}
+
+/// Failures spanning the assertions or `ensures` clauses at `indices` of the function of `file`
+fn mock_failures(
+    file: &SpeculativeFile,
+    indices: &[usize],
+    kind: VerifFailureKind,
+) -> Vec<VerifFailure> {
+    let fn_range = file.fn_range();
+    let parsed = ast::SourceFile::parse(&file.text[fn_range], Edition::CURRENT).tree();
+    let nodes: Vec<SyntaxNode> = match kind {
+        VerifFailureKind::Postcondition => parsed
+            .syntax()
+            .descendants()
+            .find_map(ast::EnsuresClause::cast)
+            .map(|it| it.exprs().map(|e| e.syntax().clone()).collect())
+            .unwrap_or_default(),
+        _ => parsed
+            .syntax()
+            .descendants()
+            .filter_map(ast::AssertExpr::cast)
+            .map(|it| it.syntax().clone())
+            .collect(),
+    };
+    let message = match kind {
+        VerifFailureKind::Postcondition => "postcondition not satisfied",
+        _ => "assertion failed",
+    };
+    indices
+        .iter()
+        .filter_map(|&index| nodes.get(index))
+        .map(|node| VerifFailure {
+            kind,
+            message: message.to_owned(),
+            range: node.text_range() + fn_range.start(),
+            related: Vec::new(),
+        })
+        .collect()
+}
+
+fn strip_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speculative_offsets_map_back() {
+        let original = "verus!{\nfn a() {}\nfn b() { assert(true); }\nfn c() {}\n}\n";
+        let b = TextRange::at(
+            TextSize::of("verus!{\nfn a() {}\n"),
+            TextSize::of("fn b() { assert(true); }"),
+        );
+        let file = SpeculativeFile::new(original, b, "fn b() {}", false);
+        assert_eq!(file.text, "verus!{\nfn a() {}\nfn b() {}\nfn c() {}\n}\n");
+
+        let range_of = |text: &str, needle: &str| {
+            TextRange::at(TextSize::from(text.find(needle).unwrap() as u32), TextSize::of(needle))
+        };
+        assert_eq!(
+            file.to_original(range_of(&file.text, "fn a")),
+            Some(range_of(original, "fn a"))
+        );
+        assert_eq!(
+            file.to_original(range_of(&file.text, "fn c")),
+            Some(range_of(original, "fn c"))
+        );
+        assert_eq!(file.to_original(file.fn_range()), None);
+
+        let wrapped = SpeculativeFile::new(original, b, "fn b() {}", true);
+        assert!(wrapped.text.starts_with("verus!{\nverus!{"));
+        assert_eq!(
+            wrapped.to_original(range_of(&wrapped.text, "fn c")),
+            Some(range_of(original, "fn c"))
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn cancelled_runs_are_killed() {
+        let mut echo = Command::new("echo");
+        echo.arg("verified");
+        let output = run_cancellable(echo, &|| false).unwrap();
+        assert_eq!(output.stdout, b"verified\n");
+
+        let mut sleep = Command::new("sleep");
+        sleep.arg("60");
+        let start = Instant::now();
+        let polls = std::cell::Cell::new(0);
+        let cancel_soon = || {
+            polls.set(polls.get() + 1);
+            polls.get() > 2
+        };
+        assert!(run_cancellable(sleep, &cancel_soon).is_none());
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn overlay_writes_unsaved_files() {
+        let src = tempfile::TempDir::new().unwrap();
+        let dest = tempfile::TempDir::new().unwrap();
+        let abs =
+            |path: &str| AbsPathBuf::try_from(src.path().join(path).to_str().unwrap()).unwrap();
+        fs::create_dir_all(src.path().join("src/m")).unwrap();
+        fs::write(src.path().join("src/lib.rs"), "mod m;").unwrap();
+        fs::write(src.path().join("src/m/mod.rs"), "fn saved() {}").unwrap();
+        let krate = SpeculativeCrate {
+            root: abs("src/lib.rs"),
+            file: abs("src/lib.rs"),
+            module: None,
+            modules: vec![(abs("src/m/mod.rs"), Some("fn unsaved() {}".to_owned()))],
+        };
+        let (root, file) = overlay_crate(&krate, "mod m; fn f() {}", dest.path()).unwrap();
+        assert_eq!(root, file);
+        assert_eq!(fs::read_to_string(file).unwrap(), "mod m; fn f() {}");
+        assert_eq!(fs::read_to_string(dest.path().join("m/mod.rs")).unwrap(), "fn unsaved() {}");
+    }
+
+    #[test]
+    fn overlay_mirrors_only_the_module_tree() {
+        let src = tempfile::TempDir::new().unwrap();
+        let dest = tempfile::TempDir::new().unwrap();
+        let abs =
+            |path: &str| AbsPathBuf::try_from(src.path().join(path).to_str().unwrap()).unwrap();
+        fs::create_dir_all(src.path().join("src")).unwrap();
+        fs::create_dir_all(src.path().join("shared")).unwrap();
+        fs::create_dir_all(src.path().join("target")).unwrap();
+        fs::write(src.path().join("src/lib.rs"), "mod a; #[path = \"../shared/b.rs\"] mod b;")
+            .unwrap();
+        fs::write(src.path().join("src/a.rs"), "fn a() {}").unwrap();
+        fs::write(src.path().join("shared/b.rs"), "fn b() {}").unwrap();
+        fs::write(src.path().join("target/big"), "").unwrap();
+        let krate = SpeculativeCrate {
+            root: abs("src/lib.rs"),
+            file: abs("src/a.rs"),
+            module: Some("a".to_owned()),
+            modules: vec![(abs("src/lib.rs"), None), (abs("shared/b.rs"), None)],
+        };
+        let (root, file) = overlay_crate(&krate, "fn a() { }", dest.path()).unwrap();
+        assert_eq!(root, dest.path().join("src/lib.rs"));
+        assert_eq!(file, dest.path().join("src/a.rs"));
+        assert_eq!(fs::read_to_string(file).unwrap(), "fn a() { }");
+        assert_eq!(fs::read_to_string(dest.path().join("shared/b.rs")).unwrap(), "fn b() {}");
+        assert_eq!(fs::symlink_metadata(root).unwrap().file_type().is_symlink(), cfg!(unix));
+        assert!(!dest.path().join("target").exists());
+    }
+
+    #[test]
+    fn fingerprint_covers_crate_args() {
+        let root = |path: &str| AbsPathBuf::assert_utf8(std::env::temp_dir().join(path));
+        let verus = VerusBinary::new("verus".into())
+            .with_crate_args(vec![(root("a/src/lib.rs"), vec!["--rlimit".into(), "20".into()])]);
+        let a = root("a/src/lib.rs");
+        let b = root("b/src/lib.rs");
+        assert_ne!(verus.fingerprint(Some(&a)), verus.fingerprint(Some(&b)));
+        assert_eq!(verus.fingerprint(Some(&b)), verus.fingerprint(None));
+    }
+
+    #[test]
+    fn parse_verus_json() {
+        let stderr = concat!(
+            r#"{"$message_type":"diagnostic","message":"precondition not satisfied","code":null,"level":"error","spans":["#,
+            r#"{"file_name":"/tmp/x/src/lib.rs","byte_start":40,"byte_end":46,"is_primary":true,"label":"failed precondition"},"#,
+            r#"{"file_name":"/tmp/x/src/lib.rs","byte_start":10,"byte_end":15,"is_primary":false,"label":null},"#,
+            r#"{"file_name":"/tmp/x/src/other.rs","byte_start":1,"byte_end":2,"is_primary":false,"label":null}"#,
+            r#"],"children":[],"rendered":"error: precondition not satisfied\n"}"#,
+            "\n",
+            r#"{"$message_type":"diagnostic","message":"assertion failed","code":null,"level":"error","spans":["#,
+            r#"{"file_name":"/tmp/x/src/other.rs","byte_start":3,"byte_end":9,"is_primary":true,"label":null}"#,
+            r#"],"children":[],"rendered":"error: assertion failed\n"}"#,
+            "\n",
+            "verification results:: 1 verified, 2 errors\n",
+        );
+        let (failures, rendered) = parse_diagnostics(stderr, Path::new("src/lib.rs"));
+        assert_eq!(
+            failures,
+            vec![VerifFailure {
+                kind: VerifFailureKind::Precondition,
+                message: "precondition not satisfied".to_owned(),
+                range: TextRange::new(40.into(), 46.into()),
+                related: vec![TextRange::new(10.into(), 15.into())],
+            }]
+        );
+        assert!(rendered.contains("error: assertion failed"));
+        assert!(rendered.contains("verification results::"));
+    }
+}
@@ -1,19 +1,21 @@
 //! Basic enum/struct/fn for Verus Errors
 //!
 //! These are used to represent various errors from the verifier
-//! There are three kinds: precondition Failure, postcondition failure, assertion failure
+//! There are four kinds: precondition Failure, postcondition failure, assertion failure,
+//! and loop invariant failure
 //!
 //! For further reference, see `crates/rust-analyzer/verus_interaction`
 //!
 
 use text_edit::TextRange;
 
-/// Verus Errors with three kinds: pre/post/assert
+/// Verus Errors with four kinds: pre/post/assert/invariant
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum VerusError {
     Pre(PreFailure),
     Post(PostFailure),
     Assert(AssertFailure),
+    Inv(InvFailure),
 }
 
 /// Precondition Failure contains
@@ -41,6 +43,24 @@ pub struct AssertFailure {
     pub range: TextRange,
 }
 
+/// Loop invariant failure contains
+/// (1) the exact invariant that is failing
+/// (2) whether it fails on entry to the loop or at the end of its body
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct InvFailure {
+    pub failing_inv: TextRange,
+    pub at: InvFailureAt,
+}
+
+/// The point where Verus could not re-establish a loop invariant
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum InvFailureAt {
+    /// "invariant not satisfied before loop"
+    BeforeLoop,
+    /// "invariant not satisfied at end of loop body"
+    EndOfBody,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct VerusQuantifier {
     pub exprs: Vec<TextRange>,
@@ -70,6 +90,30 @@ pub fn filter_post_failures(verus_errors: &Vec<VerusError>) -> Vec<PostFailure>
     post_errs
 }
 
+/// From a vector of VerusErrors,
+/// filter only assertion failures
+pub fn filter_assert_failures(verus_errors: &Vec<VerusError>) -> Vec<AssertFailure> {
+    let mut assert_errs = vec![];
+    for verr in verus_errors {
+        if let VerusError::Assert(a) = verr {
+            assert_errs.push(a.clone());
+        }
+    }
+    assert_errs
+}
+
+/// From a vector of VerusErrors,
+/// filter only loop invariant failures
+pub fn filter_inv_failures(verus_errors: &Vec<VerusError>) -> Vec<InvFailure> {
+    let mut inv_errs = vec![];
+    for verr in verus_errors {
+        if let VerusError::Inv(i) = verr {
+            inv_errs.push(i.clone());
+        }
+    }
+    inv_errs
+}
+
 /// just for writing testcases
 #[cfg(test)]
 pub fn mk_pre_failure(pre_start: u32, pre_end: u32, call_start: u32, call_end: u32) -> VerusError {
@@ -91,3 +135,16 @@ pub fn mk_post_failure(
         func_name: TextRange::new(name_start.into(), name_end.into()),
     })
 }
+/// just for writing testcases
+#[cfg(test)]
+pub fn mk_assert_failure(start: u32, end: u32) -> VerusError {
+    VerusError::Assert(AssertFailure { range: TextRange::new(start.into(), end.into()) })
+}
+/// just for writing testcases
+#[cfg(test)]
+pub fn mk_inv_failure(inv_start: u32, inv_end: u32, at: InvFailureAt) -> VerusError {
+    VerusError::Inv(InvFailure {
+        failing_inv: TextRange::new(inv_start.into(), inv_end.into()),
+        at,
+    })
+}
@@ -0,0 +1,492 @@
+//! ProofPlumber API for layout-preserving edits
+//!
+//! TOST nodes (VST nodes) abstract away whitespace and comments, so printing a rewritten node
+//! loses the user's comments and layout. Instead of replacing the whole node with the printed
+//! text, [`AssistContext::vst_edit`] computes the edit that only touches what the rewrite changed.
+//!
+//! 1) Subtrees of the rewritten node that are identical to their original CST (through the `cst`
+//!    field) and contain comments are printed as placeholders, and restored verbatim after
+//!    formatting.
+//! 2) The original and the new text are diffed token by token, ignoring whitespace and comments.
+//!    Only the tokens that changed are edited, so the comments and layout around them are kept.
+//!    Unchanged tokens only pick up the new indentation, e.g. when wrapped into a new block.
+//!
+
+use crate::AssistContext;
+use ide_db::source_change::SourceChangeBuilder;
+use std::ops::Range;
+use syntax::{
+    ast::{self, edit::IndentLevel, vst, vst::VstFold},
+    AstNode, Edition, SyntaxKind, TextRange, TextSize,
+};
+use text_edit::{TextEdit, TextEditBuilder};
+
+/// Largest token diff (in table cells) before falling back to a single replacement
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// TOST nodes that can be turned into an edit of their original CST
+pub trait VstNode: std::fmt::Display + Sized {
+    fn fold_with<F: VstFold>(self, folder: &mut F) -> Self;
+}
+
+macro_rules! impl_vst_node {
+    ($($ty:ident => $fold:ident,)*) => {$(
+        impl VstNode for vst::$ty {
+            fn fold_with<F: VstFold>(self, folder: &mut F) -> Self {
+                folder.$fold(self)
+            }
+        }
+    )*};
+}
+
+impl_vst_node! {
+    Fn => fold_fn,
+    BlockExpr => fold_block_expr,
+    StmtList => fold_stmt_list,
+    Stmt => fold_stmt,
+    Expr => fold_expr,
+}
+
+impl<'a> AssistContext<'a> {
+    /// Compute the edit replacing `old` (a node of this file) with `new`.
+    ///
+    /// `new` is usually the result of rewriting the VST of `old`. Unchanged statements and
+    /// expressions, and the comments between them, are kept verbatim.
+    pub fn vst_edit<N: AstNode + Clone, V: VstNode>(&self, old: &N, new: V) -> Option<TextEdit> {
+        let file_text = self.source_file.syntax().text().to_string();
+        let mut keep = Keep { ctx: self, kept: vec![] };
+        let text = new.fold_with(&mut keep).to_string();
+
+        // format within the surrounding function, when there is one
+        let func = old.syntax().ancestors().find_map(ast::Fn::cast);
+        let text = match func {
+            Some(func) => match self.run_fmt_replacing(&func, old.clone(), text.clone()) {
+                // the formatter sees the function at the top level, like a method out of its impl
+                Some(formatted) => indent_lines(&formatted, IndentLevel::from_node(func.syntax())),
+                None => text,
+            },
+            None => text,
+        };
+        let text = restore_kept(&text, &keep.kept, &file_text);
+        Some(diff_tokens(old.syntax(), &file_text, &text))
+    }
+}
+
+/// Indent all lines of `text` but the first by `indent`
+fn indent_lines(text: &str, indent: IndentLevel) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            if idx == 0 || line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Apply an edit computed by [`AssistContext::vst_edit`]
+pub fn apply_vst_edit(builder: &mut SourceChangeBuilder, edit: TextEdit) {
+    for indel in edit {
+        builder.replace(indel.delete, indel.insert);
+    }
+}
+
+/// Replace unchanged statements and expressions containing comments with placeholders
+struct Keep<'a, 'b> {
+    ctx: &'b AssistContext<'a>,
+    /// original range of each placeholder
+    kept: Vec<TextRange>,
+}
+
+impl Keep<'_, '_> {
+    /// The original range of `node`, when it is a node of this file that was not modified
+    fn unchanged<C, V>(&self, node: &V, cst: Option<C>) -> Option<TextRange>
+    where
+        C: AstNode,
+        V: TryFrom<C> + PartialEq,
+    {
+        let cst = cst?;
+        let syntax = cst.syntax().clone();
+        if syntax.ancestors().last().as_ref() != Some(self.ctx.source_file.syntax()) {
+            return None;
+        }
+        // without comments, the diff already keeps the layout of the unchanged tokens. A
+        // placeholder would throw off the line width seen by the formatter, and keep the original
+        // line breaks of a node moved elsewhere, like a block turned into a `let` initializer
+        let has_comment = syntax
+            .descendants_with_tokens()
+            .any(|it| it.as_token().is_some_and(|it| it.kind() == SyntaxKind::COMMENT));
+        if !has_comment {
+            return None;
+        }
+        let original = V::try_from(cst).ok()?;
+        (original == *node).then(|| syntax.text_range())
+    }
+
+    fn placeholder(&mut self, kind: &str, range: TextRange) -> vst::Literal {
+        self.kept.push(range);
+        vst::Literal::new(format!("__vst_{kind}_{}__", self.kept.len() - 1))
+    }
+}
+
+impl VstFold for Keep<'_, '_> {
+    fn fold_stmt(&mut self, node: vst::Stmt) -> vst::Stmt {
+        match self.unchanged(&node, node.cst()) {
+            Some(range) => vst::ExprStmt::new(self.placeholder("stmt", range)).into(),
+            None => vst::walk_fold_stmt(self, node),
+        }
+    }
+
+    fn fold_expr(&mut self, node: vst::Expr) -> vst::Expr {
+        match self.unchanged(&node, node.cst()) {
+            Some(range) => self.placeholder("expr", range).into(),
+            None => vst::walk_fold_expr(self, node),
+        }
+    }
+}
+
+/// Put the original text back in place of the placeholders, reindented to their new position
+fn restore_kept(text: &str, kept: &[TextRange], file_text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("__vst_") {
+        res.push_str(&rest[..start]);
+        let after = &rest[start + "__vst_".len()..];
+        let parsed = after.split_once('_').and_then(|(kind, after)| {
+            let (idx, after) = after.split_once("__")?;
+            let range = *kept.get(idx.parse::<usize>().ok()?)?;
+            // statement placeholders are printed with a `;` that belongs to the original
+            let after = match kind {
+                "stmt" => after.trim_start_matches(' ').strip_prefix(';').unwrap_or(after),
+                _ => after,
+            };
+            Some((range, after))
+        });
+        match parsed {
+            Some((range, after)) => {
+                let indent = line_indent(&res, res.len());
+                let original_indent = line_indent(file_text, range.start().into());
+                let kept = file_text[range]
+                    .replace(&format!("\n{original_indent}"), &format!("\n{indent}"));
+                res.push_str(&kept);
+                rest = after;
+            }
+            None => {
+                res.push_str("__vst_");
+                rest = after;
+            }
+        }
+    }
+    res.push_str(rest);
+    res
+}
+
+/// The indentation of the line containing `offset`
+fn line_indent(text: &str, offset: usize) -> &str {
+    let line_start = text[..offset].rfind('\n').map_or(0, |it| it + 1);
+    let line = &text[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+fn is_trivia(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT)
+}
+
+/// Edit `old` into `new_text`, only touching the tokens that differ.
+/// Whitespace and comments of `old` are kept unless they are inside a changed region.
+fn diff_tokens(old: &syntax::SyntaxNode, file_text: &str, new_text: &str) -> TextEdit {
+    let old_tokens: Vec<(String, TextRange)> = old
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| !is_trivia(it.kind()))
+        .map(|it| (it.text().to_owned(), it.text_range()))
+        .collect();
+    let parsed = ast::SourceFile::parse(new_text, Edition::CURRENT).tree();
+    let new_tokens: Vec<(String, TextRange)> = parsed
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| !is_trivia(it.kind()))
+        .map(|it| (it.text().to_owned(), it.text_range()))
+        .collect();
+
+    let mut builder = TextEdit::builder();
+    let hunks = merge_close_hunks(diff_hunks(&old_tokens, &new_tokens), &old_tokens, file_text);
+    let mut matched = (0, 0);
+    let end = Hunk::end(&old_tokens, &new_tokens);
+    for &Hunk { old: (i, j), new: (k, l), .. } in hunks.iter().chain([&end]) {
+        // unchanged tokens only follow the new indentation, except where an insertion could
+        // equally have been placed
+        for t in 1..i - matched.0 {
+            let (old_gap, new_gap) =
+                (gap(&old_tokens, matched.0 + t), gap(&new_tokens, matched.1 + t));
+            if hunks.iter().any(|it| it.slack.contains(&(matched.0 + t))) {
+                relayout_gap(&mut builder, file_text, old_gap, &new_text[new_gap]);
+            } else {
+                reindent_gap(&mut builder, file_text, old_gap, &new_text[new_gap]);
+            }
+        }
+        matched = (j, l);
+
+        if i == j && k == l {
+            // the end of the tokens
+        } else if i < j && k < l {
+            // take the new layout around the replacement, unless there are comments
+            let (mut start, mut new_start) = (old_tokens[i].1.start(), new_tokens[k].1.start());
+            if i > 0 && is_blank(&file_text[gap(&old_tokens, i)]) {
+                (start, new_start) = (old_tokens[i - 1].1.end(), new_tokens[k - 1].1.end());
+            }
+            let (mut end, mut new_end) = (old_tokens[j - 1].1.end(), new_tokens[l - 1].1.end());
+            if j < old_tokens.len() && is_blank(&file_text[gap(&old_tokens, j)]) {
+                (end, new_end) = (old_tokens[j].1.start(), new_tokens[l].1.start());
+            }
+            let new_range = TextRange::new(new_start, new_end);
+            builder.replace(TextRange::new(start, end), new_text[new_range].to_owned());
+        } else if i < j {
+            let range = TextRange::new(old_tokens[i].1.start(), old_tokens[j - 1].1.end());
+            builder.delete(whole_lines(file_text, range));
+        } else if i > 0 && j < old_tokens.len() && is_blank(&file_text[gap(&old_tokens, i)]) {
+            // only whitespace around the insertion, take the new layout of the whole gap
+            let new_range = TextRange::new(new_tokens[k - 1].1.end(), new_tokens[l].1.start());
+            builder.replace(gap(&old_tokens, i), new_text[new_range].to_owned());
+        } else if i > 0 {
+            // insert after the previous token, and after the comment trailing it
+            let prev_end = old_tokens[i - 1].1.end();
+            let at = end_of_trivia_line(file_text, prev_end);
+            let new_start = new_tokens[k - 1].1.end();
+            let new_range = TextRange::new(new_start, new_tokens[l - 1].1.end());
+            builder.insert(at, new_text[new_range].to_owned());
+        } else {
+            // insert before the next token
+            let at = old_tokens.get(j).map_or(old.text_range().end(), |it| it.1.start());
+            let new_end = new_tokens.get(l).map_or(TextSize::of(new_text), |it| it.1.start());
+            let new_range = TextRange::new(new_tokens[k].1.start(), new_end);
+            builder.insert(at, new_text[new_range].to_owned());
+        }
+    }
+    match trailing_whitespace(file_text, old.text_range().end()) {
+        Some(range) if !builder.invalidates_offset(range.start()) => builder.delete(range),
+        _ => (),
+    }
+    builder.finish()
+}
+
+/// The text between token `i - 1` and token `i`
+fn gap(tokens: &[(String, TextRange)], i: usize) -> TextRange {
+    TextRange::new(tokens[i - 1].1.end(), tokens[i].1.start())
+}
+
+/// Replace a blank gap with the new one, when it differs
+fn relayout_gap(builder: &mut TextEditBuilder, file_text: &str, gap: TextRange, new_gap: &str) {
+    let old_gap = &file_text[gap];
+    if is_blank(old_gap) && is_blank(new_gap) && old_gap != new_gap {
+        builder.replace(gap, new_gap.to_owned());
+    }
+}
+
+/// Replace the indentation of a blank multi-line gap, when the new one differs, and drop the
+/// trailing whitespace of its first line
+fn reindent_gap(builder: &mut TextEditBuilder, file_text: &str, gap: TextRange, new_gap: &str) {
+    let old_gap = &file_text[gap];
+    if !is_blank(old_gap) || !is_blank(new_gap) {
+        return;
+    }
+    if let Some(range) = trailing_whitespace(file_text, gap.start()) {
+        builder.delete(range);
+    }
+    let (Some(old_nl), Some(new_nl)) = (old_gap.rfind('\n'), new_gap.rfind('\n')) else {
+        return;
+    };
+    if old_gap[old_nl..] != new_gap[new_nl..] {
+        let indent_start = gap.start() + TextSize::from(old_nl as u32 + 1);
+        builder.replace(TextRange::new(indent_start, gap.end()), new_gap[new_nl + 1..].to_owned());
+    }
+}
+
+/// The whitespace from `offset` to the end of its line, unless there is something else
+fn trailing_whitespace(text: &str, offset: TextSize) -> Option<TextRange> {
+    let rest = &text[usize::from(offset)..];
+    let len = rest.find('\n')?;
+    (len > 0 && is_blank(&rest[..len])).then(|| TextRange::at(offset, TextSize::from(len as u32)))
+}
+
+fn is_blank(text: &str) -> bool {
+    text.chars().all(char::is_whitespace)
+}
+
+/// `range` extended to its whole line(s), when nothing else is on them
+fn whole_lines(text: &str, range: TextRange) -> TextRange {
+    let (start, end): (usize, usize) = (range.start().into(), range.end().into());
+    let line_start = text[..start].rfind('\n');
+    let line_end = text[end..].find('\n').map(|it| end + it);
+    match (line_start, line_end) {
+        (Some(line_start), Some(line_end))
+            if text[line_start..start].trim().is_empty()
+                && text[end..line_end].trim().is_empty() =>
+        {
+            TextRange::new((line_start as u32).into(), (line_end as u32).into())
+        }
+        _ => range,
+    }
+}
+
+/// The end of the line at `offset`, when the rest of the line is whitespace and comments
+fn end_of_trivia_line(text: &str, offset: TextSize) -> TextSize {
+    let start: usize = offset.into();
+    let Some(len) = text[start..].find('\n') else { return offset };
+    let rest = text[start..start + len].trim();
+    let is_trivia = rest.is_empty()
+        || rest.starts_with("//")
+        || (rest.starts_with("/*") && rest.ends_with("*/"));
+    if is_trivia {
+        TextSize::from((start + len) as u32)
+    } else {
+        offset
+    }
+}
+
+/// Tokens `old.0..old.1` are replaced with tokens `new.0..new.1`
+struct Hunk {
+    old: (usize, usize),
+    new: (usize, usize),
+    /// for an insertion, the gaps before these old tokens, where it could equally have been placed
+    slack: Range<usize>,
+}
+
+impl Hunk {
+    /// An empty hunk after the last tokens
+    fn end(old: &[(String, TextRange)], new: &[(String, TextRange)]) -> Hunk {
+        Hunk { old: (old.len(), old.len()), new: (new.len(), new.len()), slack: 0..0 }
+    }
+}
+
+/// The differing hunks between the token texts.
+/// Insertions and deletions are moved to statement boundaries when the alignment is ambiguous,
+/// e.g. deleting `assert(x); assert(x + 1);` from `assert(x); assert(x + 1); assert(x + 2);`
+fn diff_hunks(old: &[(String, TextRange)], new: &[(String, TextRange)]) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = vec![];
+    let mut prev = (0, 0);
+    for (j, l) in lcs_matches(old, new).into_iter().chain([(old.len(), new.len())]) {
+        if prev != (j, l) {
+            hunks.push(Hunk { old: (prev.0, j), new: (prev.1, l), slack: 0..0 });
+        }
+        prev = (j + 1, l + 1);
+    }
+
+    for idx in 0..hunks.len() {
+        let lower = if idx == 0 { (0, 0) } else { (hunks[idx - 1].old.1, hunks[idx - 1].new.1) };
+        let upper = match hunks.get(idx + 1) {
+            Some(next) => (next.old.0, next.new.0),
+            None => (old.len(), new.len()),
+        };
+        let has_next = idx + 1 < hunks.len();
+        let hunk = &mut hunks[idx];
+        let (tokens, range, bound) = match (hunk.old, hunk.new) {
+            ((i, j), (k, l)) if i == j => (new, (k, l), (lower.1, upper.1)),
+            ((i, j), (k, l)) if k == l => (old, (i, j), (lower.0, upper.0)),
+            _ => continue,
+        };
+        // every position the hunk can slide to, keeping the same result
+        let (mut start, mut end) = range;
+        while start > bound.0 + usize::from(idx > 0) && tokens[start - 1].0 == tokens[end - 1].0 {
+            start -= 1;
+            end -= 1;
+        }
+        let lowest = start;
+        let mut best = (start, slide_score(tokens, start, end));
+        while end + usize::from(has_next) < bound.1 && tokens[start].0 == tokens[end].0 {
+            start += 1;
+            end += 1;
+            let score = slide_score(tokens, start, end);
+            if score > best.1 {
+                best = (start, score);
+            }
+        }
+        if hunk.old.0 == hunk.old.1 {
+            let at = |it: usize| hunk.old.0 + it - range.0;
+            hunk.slack = at(lowest)..at(start) + 1;
+        }
+        let shift = best.0 as isize - range.0 as isize;
+        let slide =
+            |(a, b): (usize, usize)| ((a as isize + shift) as usize, (b as isize + shift) as usize);
+        hunk.old = slide(hunk.old);
+        hunk.new = slide(hunk.new);
+    }
+    hunks
+}
+
+/// Merge hunks separated by a few unchanged tokens, which are usually coincidental matches
+/// like the `b` in `ensures b ==> c` rewritten into `requires b ensures c`
+fn merge_close_hunks(hunks: Vec<Hunk>, old: &[(String, TextRange)], file_text: &str) -> Vec<Hunk> {
+    const CLOSE: usize = 3;
+    let mut merged: Vec<Hunk> = vec![];
+    for hunk in hunks {
+        if let Some(last) = merged.last_mut() {
+            let (run_start, run_end) = (last.old.1, hunk.old.0);
+            let is_close = run_end - run_start <= CLOSE
+                && (run_start..=run_end)
+                    .filter(|&it| it > 0 && it < old.len())
+                    .all(|it| is_blank(&file_text[gap(old, it)]));
+            if is_close {
+                last.old.1 = hunk.old.1;
+                last.new.1 = hunk.new.1;
+                continue;
+            }
+        }
+        merged.push(hunk);
+    }
+    merged
+}
+
+/// How well `tokens[start..end]` lines up with statements
+fn slide_score(tokens: &[(String, TextRange)], start: usize, end: usize) -> u32 {
+    let is_end = |i: usize| matches!(tokens[i].0.as_str(), ";" | "}" | ",");
+    let is_start = |i: usize| i == 0 || matches!(tokens[i - 1].0.as_str(), ";" | "{" | "}" | ",");
+    u32::from(is_end(end - 1)) * 2 + u32::from(is_start(start))
+}
+
+/// Longest common subsequence of the token texts, as pairs of matching token indices
+fn lcs_matches(old: &[(String, TextRange)], new: &[(String, TextRange)]) -> Vec<(usize, usize)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a.0 == b.0).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a.0 == b.0)
+        .count();
+    let (old_mid, new_mid) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+    let (n, m) = (old_mid.len(), new_mid.len());
+
+    let mut matches: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+    if n * m <= MAX_DIFF_CELLS {
+        // lcs[i][j] is the LCS length of old_mid[i..] and new_mid[j..]
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        let at = |i: usize, j: usize| i * (m + 1) + j;
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[at(i, j)] = if old_mid[i].0 == new_mid[j].0 {
+                    lcs[at(i + 1, j + 1)] + 1
+                } else {
+                    lcs[at(i + 1, j)].max(lcs[at(i, j + 1)])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if old_mid[i].0 == new_mid[j].0 {
+                matches.push((prefix + i, prefix + j));
+                i += 1;
+                j += 1;
+            } else if lcs[at(i + 1, j)] >= lcs[at(i, j + 1)] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+    }
+    matches.extend((0..suffix).map(|it| (prefix + n + it, prefix + m + it)));
+    matches
+}
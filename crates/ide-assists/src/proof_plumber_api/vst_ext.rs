@@ -3,7 +3,14 @@
 //! Visitor patterns available to map an expression recursively
 //!
 //! Referenced syntax_helpers::node_ext
-use syntax::ast::vst;
+use syntax::{
+    ast::{
+        self,
+        vst::{self, VstFold, VstVisitor},
+        AstNode,
+    },
+    ted,
+};
 
 /// Preorder walk all the expression's child expressions.
 pub fn vst_walk_expr(expr: &vst::Expr, cb: &mut dyn FnMut(vst::Expr)) {
@@ -14,138 +21,127 @@ pub fn vst_walk_expr(expr: &vst::Expr, cb: &mut dyn FnMut(vst::Expr)) {
 }
 
 /// Map a VST Node recursively
+///
+/// `cb` is applied to literals, paths, assertions and blocks. Assertions and blocks are mapped
+/// before their children. Only the following children are visited: the operands of binary
+/// expressions, the condition and branches of `if`, the expression of assertions and the
+/// expression statements of blocks. Everything else, e.g. calls and `let` initializers, is left
+/// as it is.
 pub fn vst_map_expr_visitor<EE, FF>(exp: EE, cb: &mut FF) -> Result<vst::Expr, String>
 where
     EE: Into<vst::Expr>,
     FF: FnMut(&mut vst::Expr) -> Result<vst::Expr, String>,
 {
-    let exp: vst::Expr = exp.into();
-    let res = match exp {
-        vst::Expr::Literal(_) | vst::Expr::PathExpr(_) => cb(&mut exp.clone())?,
-        vst::Expr::BinExpr(mut e) => {
-            let new_lhs = vst_map_expr_visitor(*e.lhs.clone(), cb)?;
-            let new_rhs = vst_map_expr_visitor(*e.rhs.clone(), cb)?;
-            e.lhs = Box::new(new_lhs);
-            e.rhs = Box::new(new_rhs);
-            vst::Expr::BinExpr(e)
-        }
+    let mut folder = MapExpr { cb, err: None };
+    let res = folder.fold_expr(exp.into());
+    match folder.err {
+        Some(err) => Err(err),
+        None => Ok(res),
+    }
+}
 
-        // note that vst_map_expr_visitor cannot map ifexpr to another Expr variant
-        vst::Expr::IfExpr(mut e) => {
-            let new_cond = vst_map_expr_visitor(*e.condition.clone(), cb)?;
-            let new_then =
-                vst_map_expr_visitor(vst::Expr::BlockExpr(Box::new(*e.then_branch.clone())), cb)?;
-            let new_else = match e.else_branch.clone() {
-                Some(el) => match *el {
-                    vst::ElseBranch::Block(blk) => Some(Box::new(vst_map_expr_visitor(
-                        vst::Expr::BlockExpr(Box::new(*blk)),
-                        cb,
-                    )?)),
-                    vst::ElseBranch::IfExpr(ife) => {
-                        Some(Box::new(vst_map_expr_visitor(vst::Expr::IfExpr(Box::new(*ife)), cb)?))
-                    }
-                },
-                None => None,
-            };
-            e.condition = Box::new(new_cond);
-            if let vst::Expr::BlockExpr(bb) = new_then {
-                e.then_branch = bb;
-            } else {
-                return Err("if then branch is not a block".to_string());
-            }
+struct MapExpr<'a, FF> {
+    cb: &'a mut FF,
+    err: Option<String>,
+}
 
-            match new_else {
-                Some(vv) => {
-                    if let vst::Expr::BlockExpr(bb) = *vv {
-                        e.else_branch = Some(Box::new(vst::ElseBranch::Block(bb)));
-                    } else if let vst::Expr::IfExpr(ife) = *vv {
-                        e.else_branch = Some(Box::new(vst::ElseBranch::IfExpr(ife)));
-                    } else {
-                        return Err("if else branch is not a block".to_string());
-                    }
-                }
-                None => (),
+impl<FF> MapExpr<'_, FF>
+where
+    FF: FnMut(&mut vst::Expr) -> Result<vst::Expr, String>,
+{
+    fn apply(&mut self, exp: vst::Expr) -> Option<vst::Expr> {
+        if self.err.is_some() {
+            return None;
+        }
+        match (self.cb)(&mut exp.clone()) {
+            Ok(it) => Some(it),
+            Err(err) => {
+                self.err = Some(err);
+                None
             }
-            vst::Expr::IfExpr(e)
         }
-        vst::Expr::AssertExpr(_) => {
-            let assert_exp = cb(&mut exp.clone())?;
-            match assert_exp {
-                vst::Expr::AssertExpr(mut e) => {
-                    let new_exp = vst_map_expr_visitor(*e.expr.clone(), cb)?;
-                    e.expr = Box::new(new_exp);
+    }
+}
+
+impl<FF> VstFold for MapExpr<'_, FF>
+where
+    FF: FnMut(&mut vst::Expr) -> Result<vst::Expr, String>,
+{
+    fn fold_expr(&mut self, exp: vst::Expr) -> vst::Expr {
+        match exp {
+            vst::Expr::Literal(_) | vst::Expr::PathExpr(_) => {
+                self.apply(exp.clone()).unwrap_or(exp)
+            }
+            vst::Expr::BinExpr(mut e) => {
+                e.lhs = Box::new(self.fold_expr(*e.lhs));
+                e.rhs = Box::new(self.fold_expr(*e.rhs));
+                vst::Expr::BinExpr(e)
+            }
+            vst::Expr::IfExpr(mut e) => {
+                e.condition = Box::new(self.fold_expr(*e.condition));
+                e.then_branch = Box::new(self.fold_block_expr(*e.then_branch));
+                e.else_branch = e.else_branch.map(|it| {
+                    Box::new(match *it {
+                        vst::ElseBranch::Block(blk) => {
+                            vst::ElseBranch::Block(Box::new(self.fold_block_expr(*blk)))
+                        }
+                        vst::ElseBranch::IfExpr(ife) => {
+                            match self.fold_expr(vst::Expr::IfExpr(ife.clone())) {
+                                vst::Expr::IfExpr(ife) => vst::ElseBranch::IfExpr(ife),
+                                _ => vst::ElseBranch::IfExpr(ife),
+                            }
+                        }
+                    })
+                });
+                vst::Expr::IfExpr(e)
+            }
+            vst::Expr::AssertExpr(_) => match self.apply(exp.clone()) {
+                Some(vst::Expr::AssertExpr(mut e)) => {
+                    e.expr = Box::new(self.fold_expr(*e.expr));
                     vst::Expr::AssertExpr(e)
                 }
-                _ => panic!(),
-            }
+                Some(_) => {
+                    self.err = Some("assertion is not mapped to an assertion".to_string());
+                    exp
+                }
+                None => exp,
+            },
+            vst::Expr::BlockExpr(blk) => vst::Expr::BlockExpr(Box::new(self.fold_block_expr(*blk))),
+            _ => exp,
         }
-        vst::Expr::BlockExpr(_) => {
-            let newbe = cb(&mut exp.clone())?;
-            match newbe {
-                vst::Expr::BlockExpr(mut e) => {
-                    let new_stmts: Result<Vec<vst::Stmt>, String> = e
-                        .clone()
-                        .stmt_list
-                        .statements
-                        .into_iter()
-                        .map(|s| match s {
-                            vst::Stmt::ExprStmt(exprstmt) => {
-                                let new_exp = vst_map_expr_visitor(*exprstmt.expr.clone(), cb);
-                                match new_exp {
-                                    Ok(ne) => {
-                                        Ok(vst::Stmt::ExprStmt(Box::new(vst::ExprStmt::new(ne))))
-                                    }
-                                    Err(ee) => Err(ee),
-                                }
-                            }
-                            _ => Ok(s),
-                        })
-                        .collect();
-                    e.stmt_list.statements = new_stmts?;
-                    vst::Expr::BlockExpr(e)
+    }
+
+    fn fold_block_expr(&mut self, blk: vst::BlockExpr) -> vst::BlockExpr {
+        match self.apply(vst::Expr::BlockExpr(Box::new(blk.clone()))) {
+            Some(vst::Expr::BlockExpr(mut e)) => {
+                for stmt in &mut e.stmt_list.statements {
+                    if let vst::Stmt::ExprStmt(expr_stmt) = stmt {
+                        *expr_stmt.expr = self.fold_expr(*expr_stmt.expr.clone());
+                    }
                 }
-                _ => panic!(),
+                *e
             }
+            Some(_) => {
+                self.err = Some("block is not mapped to a block".to_string());
+                blk
+            }
+            None => blk,
         }
-        _ => {
-            //dgb!("note(warning): base case map expr");
-            //dgb!(&exp.to_string());
-            exp.clone()
-        }
-    };
-    Ok(res)
+    }
 }
 
-/// Preorder walk all the expression's child expressions
+/// Preorder walk all the expression's child expressions.
+/// Children of an expression are skipped when `cb` returns `true` for it.
 pub fn vst_preorder_expr(exp: &vst::Expr, cb: &mut dyn FnMut(vst::Expr) -> bool) {
-    cb(exp.clone());
-    match exp {
-        vst::Expr::ArrayExpr(e) => {
-            for expr in &e.exprs {
-                vst_preorder_expr(&expr, cb);
-            }
-            match &e.expr {
-                Some(expr) => vst_preorder_expr(&expr, cb),
-                None => (),
+    struct Preorder<'a>(&'a mut dyn FnMut(vst::Expr) -> bool);
+    impl VstVisitor for Preorder<'_> {
+        fn visit_expr(&mut self, exp: &vst::Expr) {
+            if !(self.0)(exp.clone()) {
+                vst::walk_expr(self, exp);
             }
         }
-        vst::Expr::BinExpr(e) => {
-            vst_preorder_expr(&e.lhs, cb);
-            vst_preorder_expr(&e.rhs, cb);
-        }
-        vst::Expr::CallExpr(e) => {
-            for arg in &e.arg_list.args {
-                vst_preorder_expr(&arg, cb);
-            }
-        }
-        vst::Expr::AssertExpr(e) => {
-            vst_preorder_expr(&e.expr, cb);
-        }
-        _ => {
-            //dgb!("note(warning): base case vst_preorder_expr");
-            //dgb!(&exp.to_string());
-        }
     }
+    Preorder(cb).visit_expr(exp);
 }
 
 /// Map each tail expressions
@@ -234,3 +230,59 @@ pub fn for_each_tail_expr(expr: &vst::Expr, cb: &mut dyn FnMut(&vst::Expr)) {
         _ => cb(expr),
     }
 }
+
+/// The body of a quantifier, without its `#[trigger]` annotations, which are only allowed inside
+/// a quantifier. Annotations of nested quantifiers stay.
+pub fn vst_quantifier_body(quantifier: &ast::ClosureExpr) -> Option<vst::Expr> {
+    let body = quantifier.body()?.clone_for_update();
+    let annotations: Vec<ast::Attr> = body
+        .syntax()
+        .descendants()
+        .filter_map(ast::Attr::cast)
+        .filter(|it| it.trigger_attribute().is_some())
+        .filter(|it| {
+            it.syntax()
+                .ancestors()
+                .take_while(|it| it != body.syntax())
+                .all(|it| !ast::ClosureExpr::can_cast(it.kind()))
+        })
+        .collect();
+    for attr in annotations {
+        ted::remove(attr.syntax());
+    }
+    body.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use syntax::{ast, AstNode, Edition};
+
+    use super::*;
+
+    #[test]
+    fn map_expr_visitor_skips_calls_and_lets() {
+        let text = "fn foo(x: int) { f(x); let y = { x }; if x > 0 { assert(x > 0); }; x }";
+        let parsed = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+        let body = parsed.syntax().descendants().find_map(ast::Fn::cast).unwrap().body().unwrap();
+        let body = vst::BlockExpr::try_from(body).unwrap();
+        let mut visited = Vec::new();
+        vst_map_expr_visitor(body, &mut |exp: &mut vst::Expr| {
+            visited.push(exp.to_string().split_whitespace().collect::<String>());
+            Ok(exp.clone())
+        })
+        .unwrap();
+        // neither the call, the block bound to `y`, nor the tail expression
+        assert_eq!(
+            visited,
+            [
+                "{f(x);lety={x};ifx>0{assert(x>0);};x}",
+                "x",
+                "0",
+                "{assert(x>0);}",
+                "assert(x>0)",
+                "x",
+                "0",
+            ]
+        );
+    }
+}
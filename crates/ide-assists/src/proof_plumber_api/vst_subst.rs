@@ -0,0 +1,745 @@
+//! ProofPlumber API for variables of TOST Nodes (VST Nodes)
+//!
+//! Free-variable analysis and capture-avoiding substitution.
+//!
+//! Identifiers are resolved through `hir::Semantics` when the VST node still points into the
+//! current file. For nodes built by a proof action (no `cst`), identifiers are matched by name,
+//! taking the binders inside the expression into account.
+//!
+
+use crate::{proof_plumber_api::vst_diff::VstNode, AssistContext};
+use hir::PathResolution;
+use ide_db::FxHashSet;
+use syntax::{
+    ast::{self, vst, vst::VstFold, vst::VstVisitor},
+    AstNode,
+};
+
+/// A variable, identified by its name and (when known) its definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    pub name: String,
+    pub local: Option<hir::Local>,
+}
+
+impl<'a> AssistContext<'a> {
+    /// Resolve a variable usage to the local it refers to
+    pub fn vst_resolve_local(&self, path: &vst::PathExpr) -> Option<hir::Local> {
+        match self.vst_resolve_path(path)? {
+            PathResolution::Local(local) => Some(local),
+            _ => None,
+        }
+    }
+
+    /// Variables bound by a pattern, in order of appearance
+    pub fn vst_pat_bindings(&self, pat: &vst::Pat) -> Vec<Binding> {
+        let mut names = PatNames(vec![]);
+        names.visit_pat(pat);
+        names
+            .0
+            .into_iter()
+            .map(|ident| {
+                let local = ident
+                    .cst
+                    .as_ref()
+                    .filter(|it| self.is_in_this_file(it.syntax()))
+                    .and_then(|it| self.sema.to_def(it));
+                Binding { name: ident_name(&ident).unwrap_or_default(), local }
+            })
+            .collect()
+    }
+
+    /// Local variables used but not bound inside `expr`, in order of first use.
+    /// Paths resolving to functions, constants, etc. are not variables.
+    pub fn vst_free_vars(&self, expr: &vst::Expr) -> Vec<Binding> {
+        let mut fv = FreeVars { ctx: self, bound: vec![], free: vec![] };
+        fv.visit_expr(expr);
+        fv.free
+    }
+
+    /// Substitute each binding in `subst` with its expression.
+    ///
+    /// Binders inside `expr` (quantifiers, closures, `let`, match arms, ...) that would capture a
+    /// free variable of a replacement are renamed. Binders that shadow a substituted variable stop
+    /// the substitution. Replacements are parenthesized when precedence requires it.
+    pub fn vst_subst(&self, expr: vst::Expr, subst: &[(Binding, vst::Expr)]) -> vst::Expr {
+        let mut used = FxHashSet::default();
+        let mut names = AllNames(&mut used);
+        names.visit_expr(&expr);
+        for (binding, e) in subst {
+            names.0.insert(binding.name.clone());
+            names.visit_expr(e);
+        }
+        let vars = subst
+            .iter()
+            .map(|(binding, e)| {
+                let fv = self.vst_free_vars(e).into_iter().map(|it| it.name).collect();
+                Var { binding: binding.clone(), to: e.clone(), fv }
+            })
+            .collect();
+        let mut subst = Subst { ctx: self, vars, used, parent: Parent::Free };
+        subst.fold_expr(expr)
+    }
+
+    /// From `let pat = init`, compute the expression each variable of `pat` stands for.
+    /// For example, `let (a, S { f: b, .. }) = e` gives `a` => `e.0` and `b` => `e.1.f`.
+    pub fn vst_destructure_let(
+        &self,
+        pat: &vst::Pat,
+        init: &vst::Expr,
+    ) -> Option<Vec<(Binding, vst::Expr)>> {
+        let mut res = vec![];
+        self.destructure(pat, init.clone(), &mut res)?;
+        Some(res)
+    }
+
+    fn destructure(
+        &self,
+        pat: &vst::Pat,
+        init: vst::Expr,
+        res: &mut Vec<(Binding, vst::Expr)>,
+    ) -> Option<()> {
+        match pat {
+            vst::Pat::IdentPat(ident) => {
+                if let Some(sub) = &ident.pat {
+                    self.destructure(sub, init.clone(), res)?;
+                }
+                let binding = self.vst_pat_bindings(pat).into_iter().next()?;
+                res.push((binding, init));
+            }
+            vst::Pat::WildcardPat(_) => (),
+            vst::Pat::ParenPat(p) => self.destructure(p.pat.as_ref()?, init, res)?,
+            vst::Pat::TuplePat(p) => {
+                // `..` would shift the indices of the following fields
+                if p.fields.iter().any(|it| matches!(it, vst::Pat::RestPat(_))) {
+                    return None;
+                }
+                for (idx, field) in p.fields.iter().enumerate() {
+                    self.destructure(field, vst_project(&init, &idx.to_string()), res)?;
+                }
+            }
+            vst::Pat::RecordPat(p) => {
+                for field in &p.record_pat_field_list.fields {
+                    let sub = field.pat.as_ref()?;
+                    let name = match &field.name_ref {
+                        Some(name_ref) => name_ref.ident_token.clone()?,
+                        // shorthand `S { f }`
+                        None => self.vst_pat_bindings(sub).into_iter().next()?.name,
+                    };
+                    self.destructure(sub, vst_project(&init, &name), res)?;
+                }
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    fn vst_resolve_path(&self, path: &vst::PathExpr) -> Option<PathResolution> {
+        let path = path.path.cst.as_ref()?;
+        if !self.is_in_this_file(path.syntax()) {
+            return None;
+        }
+        match self.sema.resolve_path(path)? {
+            // outside of a body (like in `requires`), a lone `self` is taken for the module
+            PathResolution::Def(hir::ModuleDef::Module(_))
+                if path.qualifier().is_none()
+                    && path.segment().is_some_and(|it| it.self_token().is_some()) =>
+            {
+                None
+            }
+            it => Some(it),
+        }
+    }
+
+    /// Whether a CST node belongs to the file of this context (and can be given to `sema`)
+    pub(crate) fn is_in_this_file(&self, node: &syntax::SyntaxNode) -> bool {
+        node.ancestors().last().as_ref() == Some(self.source_file.syntax())
+    }
+}
+
+/// Generate `name` as a path expression
+pub fn vst_path_expr(name: &str) -> vst::Expr {
+    let mut name_ref = vst::NameRef::new();
+    name_ref.ident_token = Some(name.to_owned());
+    let mut segment = vst::PathSegment::new();
+    segment.name_ref = Some(Box::new(name_ref));
+    vst::PathExpr::new(vst::Path::new(segment)).into()
+}
+
+/// `!expr`, parenthesizing `expr` when needed
+pub fn vst_not(expr: vst::Expr) -> vst::Expr {
+    let expr =
+        if needs_parens(&expr, Parent::Tight) { vst::ParenExpr::new(expr).into() } else { expr };
+    vst::PrefixExpr::new(vst::PrefixOp::Unary(ast::UnaryOp::Not), expr).into()
+}
+
+/// `expr`, parenthesized unless it can be the receiver of a method call or of indexing as is
+pub fn vst_postfix_operand(expr: vst::Expr) -> vst::Expr {
+    if needs_parens(&expr, Parent::Tight) {
+        vst::ParenExpr::new(expr).into()
+    } else {
+        expr
+    }
+}
+
+/// Replace the expression of `node` built from `target` with `to`, parenthesizing `to` when
+/// precedence requires it where `target` stands
+pub fn vst_replace_expr<N: VstNode>(node: N, target: &ast::Expr, to: vst::Expr) -> N {
+    let to =
+        if needs_parens(&to, position_of(target)) { vst::ParenExpr::new(to).into() } else { to };
+    node.fold_with(&mut ReplaceExpr { target: target.syntax().clone(), to })
+}
+
+struct ReplaceExpr {
+    target: syntax::SyntaxNode,
+    to: vst::Expr,
+}
+
+impl VstFold for ReplaceExpr {
+    fn fold_expr(&mut self, expr: vst::Expr) -> vst::Expr {
+        match expr.cst() {
+            Some(cst) if *cst.syntax() == self.target => self.to.clone(),
+            _ => vst::walk_fold_expr(self, expr),
+        }
+    }
+}
+
+/// The syntactic position of `expr` inside its parent
+fn position_of(expr: &ast::Expr) -> Parent {
+    let Some(parent) = expr.syntax().parent().and_then(ast::Expr::cast) else {
+        return Parent::Free;
+    };
+    match parent {
+        ast::Expr::BinExpr(bin) => match bin.op_kind() {
+            Some(op) => Parent::Operand { op, is_rhs: bin.rhs().as_ref() == Some(expr) },
+            None => Parent::Free,
+        },
+        ast::Expr::IndexExpr(index) if index.index().as_ref() == Some(expr) => Parent::Free,
+        ast::Expr::CallExpr(_)
+        | ast::Expr::MethodCallExpr(_)
+        | ast::Expr::IndexExpr(_)
+        | ast::Expr::FieldExpr(_)
+        | ast::Expr::PrefixExpr(_)
+        | ast::Expr::RefExpr(_)
+        | ast::Expr::CastExpr(_)
+        | ast::Expr::TryExpr(_)
+        | ast::Expr::AwaitExpr(_)
+        | ast::Expr::ViewExpr(_) => Parent::Tight,
+        _ => Parent::Free,
+    }
+}
+
+/// The field `field` of `expr`: the element itself when `expr` is a tuple or struct literal,
+/// `expr.field` otherwise
+fn vst_project(expr: &vst::Expr, field: &str) -> vst::Expr {
+    let mut inner = expr;
+    while let vst::Expr::ParenExpr(p) = inner {
+        inner = &p.expr;
+    }
+    let elem = match inner {
+        vst::Expr::TupleExpr(t) => field.parse::<usize>().ok().and_then(|idx| t.fields.get(idx)),
+        vst::Expr::RecordExpr(r) => {
+            r.record_expr_field_list.fields.iter().find_map(|it| match &it.name_ref {
+                Some(name_ref) => {
+                    (name_ref.ident_token.as_deref() == Some(field)).then_some(&*it.expr)
+                }
+                // shorthand `S { f }`
+                None => match it.expr.as_ref() {
+                    vst::Expr::PathExpr(path) if path_var_name(path) == Some(field) => {
+                        Some(&*it.expr)
+                    }
+                    _ => None,
+                },
+            })
+        }
+        _ => None,
+    };
+    elem.cloned().unwrap_or_else(|| vst_field_expr(expr, field))
+}
+
+/// `expr.field`, parenthesizing `expr` when needed
+fn vst_field_expr(expr: &vst::Expr, field: &str) -> vst::Expr {
+    let mut name_ref = vst::NameRef::new();
+    name_ref.ident_token = Some(field.to_owned());
+    let base = if needs_parens(expr, Parent::Tight) {
+        vst::ParenExpr::new(expr.clone()).into()
+    } else {
+        expr.clone()
+    };
+    vst::FieldExpr::new(base, name_ref).into()
+}
+
+/// The variable name of a single-segment path, like `x` or `self`
+fn path_var_name(path: &vst::PathExpr) -> Option<&str> {
+    if path.path.qualifier.is_some() {
+        return None;
+    }
+    let name_ref = path.path.segment.name_ref.as_ref()?;
+    name_ref.ident_token.as_deref().or(name_ref.self_token.then_some("self"))
+}
+
+fn ident_name(ident: &vst::IdentPat) -> Option<String> {
+    ident.name.ident_token.clone()
+}
+
+/// Collect the identifiers bound by a pattern
+struct PatNames(Vec<vst::IdentPat>);
+
+impl VstVisitor for PatNames {
+    fn visit_ident_pat(&mut self, node: &vst::IdentPat) {
+        self.0.push(node.clone());
+        vst::walk_ident_pat(self, node);
+    }
+}
+
+fn pat_names(pat: &vst::Pat) -> Vec<String> {
+    let mut names = PatNames(vec![]);
+    names.visit_pat(pat);
+    names.0.iter().filter_map(ident_name).collect()
+}
+
+fn params_names(params: &Option<Box<vst::ParamList>>) -> Vec<String> {
+    params
+        .iter()
+        .flat_map(|it| &it.params)
+        .filter_map(|it| it.pat.as_deref())
+        .flat_map(pat_names)
+        .collect()
+}
+
+/// Collect every name used or bound, to pick fresh names
+struct AllNames<'a>(&'a mut FxHashSet<String>);
+
+impl VstVisitor for AllNames<'_> {
+    fn visit_path_expr(&mut self, node: &vst::PathExpr) {
+        self.0.extend(path_var_name(node).map(str::to_owned));
+        vst::walk_path_expr(self, node);
+    }
+
+    fn visit_ident_pat(&mut self, node: &vst::IdentPat) {
+        self.0.extend(ident_name(node));
+        vst::walk_ident_pat(self, node);
+    }
+}
+
+struct FreeVars<'a, 'b> {
+    ctx: &'b AssistContext<'a>,
+    bound: Vec<String>,
+    free: Vec<Binding>,
+}
+
+impl VstVisitor for FreeVars<'_, '_> {
+    fn visit_expr(&mut self, expr: &vst::Expr) {
+        let mark = self.bound.len();
+        match expr {
+            vst::Expr::PathExpr(path) => {
+                let Some(name) = path_var_name(path) else { return };
+                if self.bound.iter().any(|it| it == name) {
+                    return;
+                }
+                let local = match self.ctx.vst_resolve_path(path) {
+                    Some(PathResolution::Local(local)) => Some(local),
+                    Some(_) => return,
+                    None => None,
+                };
+                let binding = Binding { name: name.to_owned(), local };
+                if !self.free.contains(&binding) {
+                    self.free.push(binding);
+                }
+            }
+            vst::Expr::ClosureExpr(closure) => {
+                self.bound.extend(params_names(&closure.param_list));
+                vst::walk_closure_expr(self, closure);
+            }
+            vst::Expr::AssertForallExpr(assert) => {
+                self.bound.extend(params_names(&assert.closure_expr.param_list));
+                vst::walk_assert_forall_expr(self, assert);
+            }
+            vst::Expr::BlockExpr(block) => {
+                for stmt in &block.stmt_list.statements {
+                    match stmt {
+                        vst::Stmt::LetStmt(l) => {
+                            self.visit_expr(&l.initializer);
+                            if let Some(it) = &l.let_else {
+                                self.visit_let_else(it);
+                            }
+                            self.bound.extend(l.pat.iter().flat_map(|it| pat_names(it)));
+                        }
+                        _ => self.visit_stmt(stmt),
+                    }
+                }
+                if let Some(tail) = &block.stmt_list.tail_expr {
+                    self.visit_expr(tail);
+                }
+            }
+            vst::Expr::MatchExpr(m) => {
+                self.visit_expr(&m.expr);
+                for arm in &m.match_arm_list.arms {
+                    self.bound.extend(arm.pat.iter().flat_map(|it| pat_names(it)));
+                    if let Some(guard) = &arm.guard {
+                        self.visit_expr(&guard.condition);
+                    }
+                    self.visit_expr(&arm.expr);
+                    self.bound.truncate(mark);
+                }
+            }
+            vst::Expr::ForExpr(f) => {
+                self.visit_expr(&f.iterable);
+                self.bound.extend(f.pat.iter().flat_map(|it| pat_names(it)));
+                f.loop_clauses.iter().for_each(|it| self.visit_loop_clause(it));
+                self.visit_block_expr(&f.loop_body);
+            }
+            vst::Expr::IfExpr(i) => match &*i.condition {
+                vst::Expr::LetExpr(l) => {
+                    self.visit_expr(&l.expr);
+                    self.bound.extend(l.pat.iter().flat_map(|it| pat_names(it)));
+                    self.visit_block_expr(&i.then_branch);
+                    self.bound.truncate(mark);
+                    if let Some(it) = &i.else_branch {
+                        self.visit_else_branch(it);
+                    }
+                }
+                _ => vst::walk_if_expr(self, i),
+            },
+            _ => vst::walk_expr(self, expr),
+        }
+        self.bound.truncate(mark);
+    }
+
+    // `if`/`while` branches are blocks, but not `Expr`s
+    fn visit_block_expr(&mut self, node: &vst::BlockExpr) {
+        self.visit_expr(&vst::Expr::BlockExpr(Box::new(node.clone())));
+    }
+
+    // items do not capture local variables
+    fn visit_item(&mut self, _: &vst::Item) {}
+}
+
+#[derive(Clone)]
+struct Var {
+    binding: Binding,
+    to: vst::Expr,
+    /// names of the free variables of `to`
+    fv: FxHashSet<String>,
+}
+
+/// The syntactic position of the expression being substituted
+#[derive(Clone, Copy)]
+enum Parent {
+    /// No parentheses needed, like an argument or a statement
+    Free,
+    /// Operand of a postfix or prefix operator
+    Tight,
+    /// Operand of a binary operator
+    Operand { op: ast::BinaryOp, is_rhs: bool },
+}
+
+struct Subst<'a, 'b> {
+    ctx: &'b AssistContext<'a>,
+    vars: Vec<Var>,
+    used: FxHashSet<String>,
+    parent: Parent,
+}
+
+impl Subst<'_, '_> {
+    fn replacement(&self, path: &vst::PathExpr) -> Option<vst::Expr> {
+        let name = path_var_name(path)?;
+        let mut candidates = self.vars.iter().filter(|it| it.binding.name == name).peekable();
+        candidates.peek()?;
+        let var = match self.ctx.vst_resolve_path(path) {
+            Some(PathResolution::Local(local)) => {
+                candidates.find(|it| it.binding.local.map_or(true, |it| it == local))?
+            }
+            Some(_) => return None,
+            None => candidates.next()?,
+        };
+        Some(var.to.clone())
+    }
+
+    /// Bring the variables of `pats` in scope: substitution of shadowed variables stops,
+    /// and binders capturing a free variable of a replacement are renamed.
+    /// The caller restores `self.vars` at the end of the scope.
+    fn bind<'p>(&mut self, pats: impl Iterator<Item = &'p mut vst::Pat>) {
+        for pat in pats {
+            let mut renamer = RenamePat { fresh: vec![] };
+            for name in pat_names(pat) {
+                self.vars.retain(|it| it.binding.name != name);
+                if self.vars.iter().any(|it| it.fv.contains(&name)) {
+                    let fresh = self.fresh_name(&name);
+                    renamer.fresh.push((name, fresh));
+                }
+            }
+            *pat = renamer.fold_pat(pat.clone());
+            for (name, fresh) in renamer.fresh {
+                let to = vst_path_expr(&fresh);
+                let fv = [fresh].into_iter().collect();
+                self.vars.push(Var { binding: Binding { name, local: None }, to, fv });
+            }
+        }
+    }
+
+    fn fresh_name(&mut self, name: &str) -> String {
+        let fresh =
+            (1..).map(|idx| format!("{name}_{idx}")).find(|it| !self.used.contains(it)).unwrap();
+        self.used.insert(fresh.clone());
+        fresh
+    }
+
+    fn fold_with_parent(&mut self, expr: vst::Expr, parent: Parent) -> vst::Expr {
+        let saved = std::mem::replace(&mut self.parent, parent);
+        let res = self.fold_expr(expr);
+        self.parent = saved;
+        res
+    }
+
+    fn fold_closure_params(&mut self, mut closure: vst::ClosureExpr) -> vst::ClosureExpr {
+        if let Some(params) = &mut closure.param_list {
+            self.bind(params.params.iter_mut().filter_map(|it| it.pat.as_deref_mut()));
+        }
+        closure
+    }
+
+    fn fold_block(&mut self, mut block: vst::BlockExpr) -> vst::BlockExpr {
+        let saved = self.vars.clone();
+        let statements = std::mem::take(&mut block.stmt_list.statements);
+        for stmt in statements {
+            let stmt = match stmt {
+                vst::Stmt::LetStmt(mut l) => {
+                    l.initializer = Box::new(self.fold_expr(*l.initializer));
+                    l.let_else = l.let_else.map(|it| Box::new(self.fold_let_else(*it)));
+                    self.bind(l.pat.as_deref_mut().into_iter());
+                    vst::Stmt::LetStmt(l)
+                }
+                _ => self.fold_stmt(stmt),
+            };
+            block.stmt_list.statements.push(stmt);
+        }
+        block.stmt_list.tail_expr =
+            block.stmt_list.tail_expr.map(|it| Box::new(self.fold_expr(*it)));
+        self.vars = saved;
+        block
+    }
+}
+
+impl VstFold for Subst<'_, '_> {
+    fn fold_expr(&mut self, expr: vst::Expr) -> vst::Expr {
+        let parent = std::mem::replace(&mut self.parent, Parent::Free);
+        let saved = self.vars.clone();
+        let res = match expr {
+            vst::Expr::PathExpr(path) => match self.replacement(&path) {
+                Some(to) if needs_parens(&to, parent) => vst::ParenExpr::new(to).into(),
+                Some(to) => to,
+                None => vst::Expr::PathExpr(path),
+            },
+            vst::Expr::BinExpr(mut e) => {
+                let op = e.op;
+                e.lhs =
+                    Box::new(self.fold_with_parent(*e.lhs, Parent::Operand { op, is_rhs: false }));
+                e.rhs =
+                    Box::new(self.fold_with_parent(*e.rhs, Parent::Operand { op, is_rhs: true }));
+                vst::Expr::BinExpr(e)
+            }
+            vst::Expr::CallExpr(mut e) => {
+                e.expr = Box::new(self.fold_with_parent(*e.expr, Parent::Tight));
+                e.arg_list = Box::new(self.fold_arg_list(*e.arg_list));
+                vst::Expr::CallExpr(e)
+            }
+            vst::Expr::MethodCallExpr(mut e) => {
+                e.receiver = Box::new(self.fold_with_parent(*e.receiver, Parent::Tight));
+                e.arg_list = Box::new(self.fold_arg_list(*e.arg_list));
+                vst::Expr::MethodCallExpr(e)
+            }
+            vst::Expr::IndexExpr(mut e) => {
+                e.base = Box::new(self.fold_with_parent(*e.base, Parent::Tight));
+                e.index = Box::new(self.fold_expr(*e.index));
+                vst::Expr::IndexExpr(e)
+            }
+            vst::Expr::FieldExpr(_)
+            | vst::Expr::PrefixExpr(_)
+            | vst::Expr::RefExpr(_)
+            | vst::Expr::CastExpr(_)
+            | vst::Expr::TryExpr(_)
+            | vst::Expr::AwaitExpr(_)
+            | vst::Expr::ViewExpr(_)
+            | vst::Expr::RangeExpr(_) => {
+                self.parent = Parent::Tight;
+                vst::walk_fold_expr(self, expr)
+            }
+            vst::Expr::ClosureExpr(closure) => {
+                let closure = self.fold_closure_params(*closure);
+                vst::Expr::ClosureExpr(Box::new(vst::walk_fold_closure_expr(self, closure)))
+            }
+            vst::Expr::AssertForallExpr(mut assert) => {
+                assert.closure_expr = Box::new(self.fold_closure_params(*assert.closure_expr));
+                vst::Expr::AssertForallExpr(Box::new(vst::walk_fold_assert_forall_expr(
+                    self, *assert,
+                )))
+            }
+            vst::Expr::BlockExpr(block) => vst::Expr::BlockExpr(Box::new(self.fold_block(*block))),
+            vst::Expr::MatchExpr(mut m) => {
+                m.expr = Box::new(self.fold_expr(*m.expr));
+                for arm in &mut m.match_arm_list.arms {
+                    let saved = self.vars.clone();
+                    self.bind(arm.pat.as_deref_mut().into_iter());
+                    arm.guard = arm.guard.take().map(|it| Box::new(self.fold_match_guard(*it)));
+                    *arm.expr = self.fold_expr(*arm.expr.clone());
+                    self.vars = saved;
+                }
+                vst::Expr::MatchExpr(m)
+            }
+            vst::Expr::ForExpr(mut f) => {
+                f.iterable = Box::new(self.fold_expr(*f.iterable));
+                self.bind(f.pat.as_deref_mut().into_iter());
+                f.loop_clauses =
+                    f.loop_clauses.into_iter().map(|it| self.fold_loop_clause(it)).collect();
+                f.loop_body = Box::new(self.fold_block_expr(*f.loop_body));
+                vst::Expr::ForExpr(f)
+            }
+            vst::Expr::IfExpr(mut i) if matches!(*i.condition, vst::Expr::LetExpr(_)) => {
+                let vst::Expr::LetExpr(mut l) = *i.condition else { unreachable!() };
+                l.expr = Box::new(self.fold_expr(*l.expr));
+                i.else_branch = i.else_branch.map(|it| Box::new(self.fold_else_branch(*it)));
+                self.bind(l.pat.as_deref_mut().into_iter());
+                i.condition = Box::new(vst::Expr::LetExpr(l));
+                i.then_branch = Box::new(self.fold_block_expr(*i.then_branch));
+                vst::Expr::IfExpr(i)
+            }
+            _ => vst::walk_fold_expr(self, expr),
+        };
+        self.vars = saved;
+        self.parent = parent;
+        res
+    }
+
+    // `if`/`while` branches are blocks, but not `Expr`s
+    fn fold_block_expr(&mut self, node: vst::BlockExpr) -> vst::BlockExpr {
+        self.fold_block(node)
+    }
+
+    // items do not capture local variables
+    fn fold_item(&mut self, node: vst::Item) -> vst::Item {
+        node
+    }
+}
+
+/// Rename the identifiers bound by a pattern
+struct RenamePat {
+    fresh: Vec<(String, String)>,
+}
+
+impl VstFold for RenamePat {
+    fn fold_ident_pat(&mut self, mut node: vst::IdentPat) -> vst::IdentPat {
+        if let Some((_, fresh)) =
+            self.fresh.iter().find(|(name, _)| node.name.ident_token.as_ref() == Some(name))
+        {
+            node.name.ident_token = Some(fresh.clone());
+        }
+        vst::walk_fold_ident_pat(self, node)
+    }
+}
+
+/// Whether `expr` has to be parenthesized at `parent`
+fn needs_parens(expr: &vst::Expr, parent: Parent) -> bool {
+    use vst::Expr::*;
+    let is_atomic = matches!(
+        expr,
+        PathExpr(_)
+            | Literal(_)
+            | ParenExpr(_)
+            | TupleExpr(_)
+            | ArrayExpr(_)
+            | RecordExpr(_)
+            | CallExpr(_)
+            | MethodCallExpr(_)
+            | FieldExpr(_)
+            | IndexExpr(_)
+            | MacroExpr(_)
+            | TryExpr(_)
+            | AwaitExpr(_)
+            | ViewExpr(_)
+    );
+    match parent {
+        Parent::Free => false,
+        Parent::Tight => !is_atomic,
+        Parent::Operand { op, is_rhs } => match expr {
+            BinExpr(e) => {
+                let (inner, outer) = (bin_op_prec(e.op), bin_op_prec(op));
+                // arithmetic and `&&`/`||` are left-associative; the rest needs parens
+                let left_assoc = matches!(op, ast::BinaryOp::ArithOp(_))
+                    || matches!(op, ast::BinaryOp::LogicOp(ast::LogicOp::And | ast::LogicOp::Or));
+                inner < outer || (inner == outer && (is_rhs || !left_assoc))
+            }
+            PrefixExpr(_) | RefExpr(_) | CastExpr(_) => false,
+            _ => !is_atomic,
+        },
+    }
+}
+
+fn bin_op_prec(op: ast::BinaryOp) -> u8 {
+    use ast::{ArithOp::*, BinaryOp::*, LogicOp::*};
+    match op {
+        Assignment { .. } => 1,
+        LogicOp(Iff) => 2,
+        LogicOp(Imply | RevImply) => 3,
+        LogicOp(Or) => 4,
+        LogicOp(And) => 5,
+        CmpOp(_) => 6,
+        ArithOp(BitOr) => 7,
+        ArithOp(BitXor) => 8,
+        ArithOp(BitAnd) => 9,
+        ArithOp(Shl | Shr) => 10,
+        ArithOp(Add | Sub) => 11,
+        ArithOp(Mul | Div | Rem) => 12,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hir::Semantics;
+    use ide_db::{base_db::FileRange, RootDatabase};
+    use test_fixture::WithFixture;
+
+    use super::*;
+    use crate::tests::TEST_CONFIG;
+
+    /// Free variables of the assertion at the cursor, with whether they were resolved
+    fn check_free_vars(ra_fixture: &str, expected: &[(&str, bool)]) {
+        let (db, file_id, range_or_offset) = RootDatabase::with_range_or_offset(ra_fixture);
+        let frange = FileRange { file_id, range: range_or_offset.into() };
+        let ctx = AssistContext::new(Semantics::new(&db), &TEST_CONFIG, frange, vec![]);
+        let assert = ctx.vst_find_node_at_offset::<vst::AssertExpr, ast::AssertExpr>().unwrap();
+        let free: Vec<_> = ctx
+            .vst_free_vars(&assert.into())
+            .into_iter()
+            .map(|it| (it.name, it.local.is_some()))
+            .collect();
+        let expected: Vec<_> = expected.iter().map(|(name, b)| (name.to_string(), *b)).collect();
+        assert_eq!(free, expected);
+    }
+
+    #[test]
+    fn free_vars_skip_quantifier_binders() {
+        check_free_vars(
+            r#"
+proof fn foo(a: int, b: int) {
+    ass$0ert(forall|x: int| x + a > b && exists|a: int| a > x);
+}
+"#,
+            &[("a", true), ("b", true)],
+        );
+    }
+
+    #[test]
+    fn free_vars_skip_functions_and_block_lets() {
+        check_free_vars(
+            r#"
+spec fn f(x: int) -> int { x }
+proof fn foo(y: int) {
+    let z = y;
+    ass$0ert(f(z) == { let w = z; w });
+}
+"#,
+            &[("z", true)],
+        );
+    }
+}
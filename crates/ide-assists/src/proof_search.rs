@@ -0,0 +1,794 @@
+//! Proof search: trying combinations of proof actions on a failing assertion or postcondition.
+//!
+//! Many proofs go through after a couple of routine steps, e.g. revealing an opaque function and
+//! splitting a conjunction. [`search_proof`] applies such proof actions breadth-first, to the
+//! goal at the cursor and then to the assertions and `ensures` clauses that still fail, and
+//! verifies each candidate speculatively. The search stops at the first candidate that verifies,
+//! or when its [`SearchBudget`] is exhausted.
+//!
+//! The candidates are not part of the analysed crate, so proof actions only get type information
+//! about the signature of the function, which the search never rewrites (e.g. `apply_induction`
+//! asks for the type of a parameter). Actions that cheat, like `by_assume_false`, are left out.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use hir::Semantics;
+use ide_db::{
+    base_db::{FileId, FilePosition, FileRange},
+    source_change::SourceChange,
+    FxHashSet, RootDatabase,
+};
+use syntax::{
+    algo::find_node_at_offset,
+    ast::{self, vst, HasName, LogicOp},
+    AstNode, Edition, TextRange, TextSize,
+};
+use text_edit::TextEdit;
+
+use crate::{
+    handlers::proof_action::{
+        apply_induction::vst_rewriter_apply_induction,
+        convert_imply_to_if::vst_rewriter_imply_to_if,
+        insert_failing_postcondition::vst_rewriter_assert_at_end,
+        intro_forall::vst_rewriter_intro_forall,
+        intro_forall_implies::vst_rewriter_intro_forall_implies,
+        reveal_opaque_above::vst_rewriter_insert_reveal,
+        reveal_opaque_in_by_block::vst_rewriter_assert_to_assert_by_reveal,
+        seq_index_inbound::vst_rewriter_seq_index_inbound,
+        weakest_pre_step::vst_rewriter_wp_move_assertion,
+    },
+    proof_plumber_api::run_verus::VerifResult,
+    AssistConfig, AssistContext,
+};
+
+/// How far a proof search may go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchBudget {
+    /// The most proof actions applied in a row
+    pub max_depth: usize,
+    /// The search stops after this long, also in the middle of verifying a candidate
+    pub timeout: Duration,
+    /// The most candidates verified
+    pub max_candidates: usize,
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        SearchBudget { max_depth: 3, timeout: Duration::from_secs(300), max_candidates: 64 }
+    }
+}
+
+/// A combination of proof actions found by [`search_proof`]
+#[derive(Debug, Clone)]
+pub struct ProofCandidate {
+    /// The proof actions applied, in order
+    pub steps: Vec<&'static str>,
+    pub verified: bool,
+    /// The number of errors Verus reported for the function
+    pub failures: usize,
+    pub source_change: SourceChange,
+}
+
+impl ProofCandidate {
+    pub fn label(&self) -> String {
+        let steps = self.steps.join(", then ");
+        match self.failures {
+            0 => steps,
+            1 => format!("{steps} (1 error left)"),
+            n => format!("{steps} ({n} errors left)"),
+        }
+    }
+}
+
+/// The most partial improvements returned
+const MAX_IMPROVEMENTS: usize = 5;
+
+/// What proof actions are applied to
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Goal {
+    /// The assertion at this position among the assertions of the function
+    Assert(usize),
+    /// The `ensures` clauses at these positions
+    Ensures(Vec<usize>),
+}
+
+/// A rewrite of a candidate: the range to replace, and its replacement
+type Rewrite = (TextRange, String);
+
+/// A proof action, as the rewrites it offers for a failing assertion
+type AssertTactic = fn(&AssistContext<'_>, &vst::AssertExpr) -> Vec<Rewrite>;
+
+/// A proof action, as the rewrites it offers for the failing `ensures` clauses of a function
+type EnsuresTactic = fn(&AssistContext<'_>, &ast::Fn, &[usize]) -> Vec<Rewrite>;
+
+/// The proof actions tried on assertions, cheapest first
+const ASSERT_TACTICS: &[(&str, AssertTactic)] = &[
+    ("insert_reveal", |ctx, assert| {
+        let rewrites = calls(assert)
+            .into_iter()
+            .filter_map(|call| vst_rewriter_insert_reveal(ctx, &call, assert.clone()))
+            .map(|it| it.to_string());
+        replacing(assert, rewrites)
+    }),
+    ("assert_by_reveal", |ctx, assert| {
+        let rewrites = calls(assert)
+            .into_iter()
+            .filter_map(|call| vst_rewriter_assert_to_assert_by_reveal(ctx, &call, assert.clone()));
+        replacing(assert, rewrites)
+    }),
+    ("split_assertion", |_, assert| replacing(assert, split_assertion(assert))),
+    ("move_up_assertion", |ctx, assert| {
+        let stmt_list = assert
+            .cst
+            .as_ref()
+            .and_then(|it| it.syntax().ancestors().find_map(ast::StmtList::cast));
+        let Some(stmt_list) = stmt_list else {
+            return Vec::new();
+        };
+        let Ok(v_stmt_list) = vst::StmtList::try_from(stmt_list.clone()) else {
+            return Vec::new();
+        };
+        vst_rewriter_wp_move_assertion(ctx, v_stmt_list, assert.clone())
+            .map(|it| (stmt_list.syntax().text_range(), it.to_string()))
+            .into_iter()
+            .collect()
+    }),
+    ("intro_forall_implies", |_, assert| {
+        replacing(
+            assert,
+            vst_rewriter_intro_forall_implies(assert.clone()).map(|it| it.to_string()),
+        )
+    }),
+    ("intro_forall", |_, assert| {
+        replacing(assert, vst_rewriter_intro_forall(assert.clone()).map(|it| it.to_string()))
+    }),
+    ("seq_index_inbound", |ctx, assert| {
+        // on the `forall` of the assertion, as `intro_forall` introduces it
+        let Some(forall) = vst_rewriter_intro_forall(assert.clone()) else { return Vec::new() };
+        let rewrites = indexed_paths(assert)
+            .into_iter()
+            .filter_map(|seq| vst_rewriter_seq_index_inbound(ctx, forall.clone(), seq))
+            .map(|inbound| format!("{{ {inbound} {assert}; }}"));
+        replacing(assert, rewrites)
+    }),
+    ("imply_to_if", |_, assert| {
+        replacing(assert, vst_rewriter_imply_to_if(assert.clone()).map(|it| it.to_string()))
+    }),
+];
+
+/// The proof actions tried on failing `ensures` clauses, cheapest first
+const ENSURES_TACTICS: &[(&str, EnsuresTactic)] = &[
+    ("intro_failing_ensures", |_, func, indices| {
+        let (Some(body), Ok(v_func)) = (func.body(), vst::Fn::try_from(func.clone())) else {
+            return Vec::new();
+        };
+        let Some(ensures) = &v_func.ensures_clause else { return Vec::new() };
+        let exprs = indices.iter().filter_map(|&index| ensures.exprs.get(index).cloned()).collect();
+        vst::BlockExpr::try_from(body.clone())
+            .ok()
+            .and_then(|v_body| vst_rewriter_assert_at_end(&v_func, v_body, exprs))
+            .map(|it| (body.syntax().text_range(), it.to_string()))
+            .into_iter()
+            .collect()
+    }),
+    ("apply_induction", |ctx, func, _| {
+        // the function in the analysed crate, which has the same parameters
+        let (Some(original), Some(body)) = (ctx.find_node_at_offset::<ast::Fn>(), func.body())
+        else {
+            return Vec::new();
+        };
+        let params = original.param_list().map_or(0, |it| it.params().count());
+        (0..params)
+            .filter_map(|index| vst_rewriter_apply_induction(ctx, &original, index))
+            .map(|it| (body.syntax().text_range(), it.to_string()))
+            .collect()
+    }),
+];
+
+/// Search for proof actions that make the function at `position` verify.
+///
+/// Returns the first combination that verifies if there is one, and otherwise the combinations
+/// that leave fewer errors than there are now, best first. Returns nothing when the function
+/// already verifies.
+pub fn search_proof(
+    db: &RootDatabase,
+    config: &AssistConfig,
+    position: FilePosition,
+    budget: SearchBudget,
+) -> Vec<ProofCandidate> {
+    let deadline = Instant::now() + budget.timeout;
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let Some(func) = find_node_at_offset::<ast::Fn>(source_file.syntax(), position.offset) else {
+        return Vec::new();
+    };
+    // `try_verus` replaces the function at the cursor
+    let Some(name) = func.name() else { return Vec::new() };
+    let frange = FileRange {
+        file_id: position.file_id,
+        range: TextRange::empty(name.syntax().text_range().start()),
+    };
+    let ctx = AssistContext::new(sema, config, frange, vec![]);
+    let Some(func) = ctx.find_node_at_offset::<ast::Fn>() else { return Vec::new() };
+    let fn_start = func.syntax().text_range().start();
+    let original = func.syntax().to_string();
+    let Ok(vst_fn) = vst::Fn::try_from(func.clone()) else { return Vec::new() };
+    let Some(result) = ctx.try_verus_until(&vst_fn, deadline) else { return Vec::new() };
+    if result.is_success {
+        return Vec::new();
+    }
+    let original_failures = failure_count(&result);
+    // the goal at the cursor is searched even if its failure was not attributed to it
+    let goals = match goal_at(&func, position.offset, &result) {
+        Some(goal) => vec![goal],
+        None => failing_goals(&result),
+    };
+
+    let mut seen = FxHashSet::default();
+    seen.insert(strip_whitespace(&vst_fn.to_string()));
+    let mut queue = VecDeque::from([(original.clone(), Vec::new(), goals)]);
+    let mut improvements: Vec<(String, Vec<&'static str>, usize)> = Vec::new();
+    let mut verified = 0;
+    'search: while let Some((text, steps, goals)) = queue.pop_front() {
+        if steps.len() >= budget.max_depth {
+            continue;
+        }
+        for goal in goals {
+            for (tactic, candidate) in apply(&ctx, &text, &goal) {
+                let Ok(vst_fn) = parse_fn(&candidate) else { continue };
+                if !seen.insert(strip_whitespace(&vst_fn.to_string())) {
+                    continue;
+                }
+                if verified >= budget.max_candidates || Instant::now() >= deadline {
+                    break 'search;
+                }
+                verified += 1;
+                let Some(result) = ctx.try_verus_until(&vst_fn, deadline) else { continue };
+                let mut steps = steps.clone();
+                steps.push(tactic);
+                if result.is_success {
+                    let source_change =
+                        source_change(position.file_id, fn_start, &original, &candidate);
+                    return vec![ProofCandidate {
+                        steps,
+                        verified: true,
+                        failures: 0,
+                        source_change,
+                    }];
+                }
+                match (failure_count(&result), original_failures) {
+                    (Some(failures), Some(original)) if failures < original => {
+                        improvements.push((candidate.clone(), steps.clone(), failures));
+                    }
+                    _ => (),
+                }
+                queue.push_back((candidate, steps, failing_goals(&result)));
+            }
+        }
+    }
+
+    // fewest errors first, then fewest steps
+    improvements.sort_by_key(|(_, steps, failures)| (*failures, steps.len()));
+    improvements
+        .into_iter()
+        .take(MAX_IMPROVEMENTS)
+        .map(|(candidate, steps, failures)| ProofCandidate {
+            steps,
+            verified: false,
+            failures,
+            source_change: source_change(position.file_id, fn_start, &original, &candidate),
+        })
+        .collect()
+}
+
+/// The assertion or `ensures` clauses at `offset` in `func`
+fn goal_at(func: &ast::Fn, offset: TextSize, result: &VerifResult) -> Option<Goal> {
+    let assert = func
+        .syntax()
+        .descendants()
+        .filter_map(ast::AssertExpr::cast)
+        .enumerate()
+        .filter(|(_, it)| it.syntax().text_range().contains(offset))
+        .last();
+    if let Some((index, _)) = assert {
+        return Some(Goal::Assert(index));
+    }
+    let ensures = func.ensures_clause().filter(|it| it.syntax().text_range().contains(offset))?;
+    let failing = result.failing_ensures();
+    if !failing.is_empty() {
+        return Some(Goal::Ensures(failing));
+    }
+    // the clause at the cursor, or all of them when the cursor is on the keyword
+    let exprs: Vec<_> = ensures.exprs().collect();
+    let at_cursor = exprs.iter().position(|it| it.syntax().text_range().contains(offset));
+    Some(Goal::Ensures(match at_cursor {
+        Some(index) => vec![index],
+        None => (0..exprs.len()).collect(),
+    }))
+}
+
+/// The failing assertions and `ensures` clauses of the verified function
+fn failing_goals(result: &VerifResult) -> Vec<Goal> {
+    let mut goals: Vec<Goal> =
+        result.failing_asserts().into_iter().map(|it| Goal::Assert(it.index)).collect();
+    let ensures = result.failing_ensures();
+    if !ensures.is_empty() {
+        goals.push(Goal::Ensures(ensures));
+    }
+    goals
+}
+
+/// The texts of the function `text` with `goal` rewritten by each proof action, with the name of
+/// the proof action
+fn apply(ctx: &AssistContext<'_>, text: &str, goal: &Goal) -> Vec<(&'static str, String)> {
+    let parsed = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+    let Some(func) = parsed.syntax().descendants().find_map(ast::Fn::cast) else {
+        return Vec::new();
+    };
+    let rewrites: Vec<(&'static str, Rewrite)> = match goal {
+        Goal::Assert(index) => {
+            let assert = func.syntax().descendants().filter_map(ast::AssertExpr::cast).nth(*index);
+            let Some(Ok(v_assert)) = assert.map(vst::AssertExpr::try_from) else {
+                return Vec::new();
+            };
+            ASSERT_TACTICS
+                .iter()
+                .flat_map(|&(tactic, rewrite)| {
+                    rewrite(ctx, &v_assert).into_iter().map(move |it| (tactic, it))
+                })
+                .collect()
+        }
+        Goal::Ensures(indices) => ENSURES_TACTICS
+            .iter()
+            .flat_map(|&(tactic, rewrite)| {
+                rewrite(ctx, &func, indices).into_iter().map(move |it| (tactic, it))
+            })
+            .collect(),
+    };
+    rewrites
+        .into_iter()
+        .map(|(tactic, (range, replacement))| {
+            let replacement = ctx
+                .run_fmt_replacing_range(&func, range, replacement.clone())
+                .map_or(replacement, |it| it.trim_end().to_owned());
+            let mut candidate = text.to_owned();
+            candidate.replace_range(std::ops::Range::<usize>::from(range), &replacement);
+            (tactic, candidate)
+        })
+        .collect()
+}
+
+/// The replacements of `assert` by `texts`
+fn replacing(assert: &vst::AssertExpr, texts: impl IntoIterator<Item = String>) -> Vec<Rewrite> {
+    let Some(cst) = &assert.cst else { return Vec::new() };
+    texts.into_iter().map(|it| (cst.syntax().text_range(), it)).collect()
+}
+
+/// The sequences indexed in `assert`, e.g. `s` in `assert(forall|i: int| s[i] > 0)`
+fn indexed_paths(assert: &vst::AssertExpr) -> Vec<vst::PathExpr> {
+    let Some(cst) = &assert.cst else { return Vec::new() };
+    let mut seen = FxHashSet::default();
+    cst.syntax()
+        .descendants()
+        .filter_map(ast::IndexExpr::cast)
+        .filter_map(|it| match it.base()? {
+            ast::Expr::PathExpr(path) => Some(path),
+            _ => None,
+        })
+        .filter(|path| seen.insert(path.syntax().to_string()))
+        .filter_map(|path| vst::PathExpr::try_from(path).ok())
+        .collect()
+}
+
+/// The calls in `assert`, which may be to opaque functions
+fn calls(assert: &vst::AssertExpr) -> Vec<vst::CallExpr> {
+    let Some(cst) = &assert.cst else { return Vec::new() };
+    cst.syntax()
+        .descendants()
+        .filter_map(ast::CallExpr::cast)
+        .filter_map(|call| vst::CallExpr::try_from(call).ok())
+        .collect()
+}
+
+/// Assert the conjuncts of `assert` one by one before asserting it whole:
+/// `assert(a && b)` becomes `{ assert(a); assert(b); assert(a && b); }`, and
+/// `assert(p ==> a && b)` becomes `{ assert(p ==> a); assert(p ==> b); assert(p ==> a && b); }`.
+fn split_assertion(assert: &vst::AssertExpr) -> Option<String> {
+    if assert.by_token {
+        return None;
+    }
+    let split = match &*assert.expr {
+        vst::Expr::BinExpr(be) if be.op == vst::BinaryOp::LogicOp(LogicOp::Imply) => {
+            conjuncts(&be.rhs)
+                .into_iter()
+                .map(|e| {
+                    vst::BinExpr::new(*be.lhs.clone(), vst::BinaryOp::LogicOp(LogicOp::Imply), e)
+                        .into()
+                })
+                .collect()
+        }
+        e => conjuncts(e),
+    };
+    if split.len() < 2 {
+        return None;
+    }
+    let mut stmts = vst::StmtList::new();
+    for e in split {
+        stmts.statements.push(vst::AssertExpr::new(e).into());
+    }
+    stmts.statements.push(assert.clone().into());
+    Some(vst::BlockExpr::new(stmts).to_string())
+}
+
+fn conjuncts(expr: &vst::Expr) -> Vec<vst::Expr> {
+    match expr {
+        vst::Expr::BinExpr(be) if be.op == vst::BinaryOp::LogicOp(LogicOp::And) => {
+            let mut res = conjuncts(&be.lhs);
+            res.extend(conjuncts(&be.rhs));
+            res
+        }
+        vst::Expr::ParenExpr(paren) => conjuncts(&paren.expr),
+        _ => vec![expr.clone()],
+    }
+}
+
+fn parse_fn(text: &str) -> Result<vst::Fn, String> {
+    let parsed = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+    let func = parsed.syntax().descendants().find_map(ast::Fn::cast).ok_or("not a function")?;
+    vst::Fn::try_from(func)
+}
+
+/// The number of errors in the verified function, if Verus reported where they are
+fn failure_count(result: &VerifResult) -> Option<usize> {
+    match result.failures_in_function().count() {
+        0 if !result.is_success => None,
+        count => Some(count),
+    }
+}
+
+/// Replace the part of `original`, the function at `fn_start`, that differs from `candidate`
+fn source_change(
+    file_id: FileId,
+    fn_start: TextSize,
+    original: &str,
+    candidate: &str,
+) -> SourceChange {
+    let prefix = original
+        .char_indices()
+        .zip(candidate.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(original.len().min(candidate.len()), |((idx, _), _)| idx);
+    let suffix = original[prefix..]
+        .chars()
+        .rev()
+        .zip(candidate[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+    let range = TextRange::new(
+        TextSize::from(prefix as u32),
+        TextSize::from((original.len() - suffix) as u32),
+    );
+    let replacement = &candidate[prefix..candidate.len() - suffix];
+    SourceChange::from_text_edit(
+        file_id,
+        TextEdit::replace(range + fn_start, replacement.to_owned()),
+    )
+}
+
+fn strip_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ide_db::base_db::SourceDatabaseExt;
+    use stdx::trim_indent;
+    use test_fixture::WithFixture;
+
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::{MockVerifier, VerificationRequest, Verifier},
+        tests::TEST_CONFIG,
+    };
+
+    fn search(
+        verifier: impl Verifier + 'static,
+        budget: SearchBudget,
+        fixture: &str,
+    ) -> Vec<(String, String)> {
+        let (db, position) = RootDatabase::with_position(fixture);
+        let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+        search_proof(&db, &config, position, budget)
+            .into_iter()
+            .map(|candidate| {
+                let (edit, _) =
+                    candidate.source_change.get_source_and_snippet_edit(position.file_id).unwrap();
+                let mut text = db.file_text(position.file_id).to_string();
+                edit.apply(&mut text);
+                (candidate.label(), text)
+            })
+            .collect()
+    }
+
+    fn check(verifier: MockVerifier, before: &str, label: &str, after: &str) {
+        let found = search(verifier, SearchBudget::default(), before);
+        assert_eq!(found, vec![(label.to_owned(), trim_indent(after))]);
+    }
+
+    #[test]
+    fn finds_a_verifying_combination() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .fails_asserts(
+                "proof fn foo(x: int) requires x == 1 { assert(pos(x) && x < 2); }",
+                &[0],
+            )
+            .fails_asserts(
+                "proof fn foo(x: int) requires x == 1 {
+                    { assert(pos(x)); assert(x < 2); assert(pos(x) && x < 2); };
+                }",
+                &[0],
+            )
+            .verifies(
+                "proof fn foo(x: int) requires x == 1 {
+                    { { reveal(pos); assert(pos(x)); }; assert(x < 2); assert(pos(x) && x < 2); };
+                }",
+            );
+        check(
+            verifier,
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn foo(x: int) requires x == 1 {
+    assert(p$0os(x) && x < 2);
+}
+"#,
+            "split_assertion, then insert_reveal",
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn foo(x: int) requires x == 1 {
+    {
+        {
+            reveal(pos);
+            assert(pos(x));
+        };
+        assert(x < 2);
+        assert(pos(x) && x < 2);
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn proves_failing_postconditions() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .fails_ensures("proof fn foo(x: int) requires x == 1 ensures pos(x) { }", &[0])
+            .fails_asserts(
+                "proof fn foo(x: int) requires x == 1 ensures pos(x) { assert(pos(x)); }",
+                &[0],
+            )
+            .verifies(
+                "proof fn foo(x: int) requires x == 1 ensures pos(x) {
+                    { reveal(pos); assert(pos(x)); };
+                }",
+            );
+        check(
+            verifier,
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn foo(x: int) requires x == 1 ens$0ures pos(x) {
+}
+"#,
+            "intro_failing_ensures, then insert_reveal",
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn foo(x: int) requires x == 1 ensures pos(x) {
+    {
+        reveal(pos);
+        assert(pos(x));
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn applies_induction() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .fails_ensures("proof fn foo(n: nat) ensures triangle(n) >= n decreases n { }", &[0])
+            .verifies(
+                "proof fn foo(n: nat) ensures triangle(n) >= n decreases n {
+                    if n == 0 { } else { foo((n - 1) as nat); };
+                }",
+            );
+        check(
+            verifier,
+            r#"
+spec fn triangle(n: nat) -> nat decreases n { if n == 0 { 0 } else { n + triangle((n - 1) as nat) } }
+proof fn foo(n: nat) ensures$0 triangle(n) >= n decreases n {
+}
+"#,
+            "apply_induction",
+            r#"
+spec fn triangle(n: nat) -> nat decreases n { if n == 0 { 0 } else { n + triangle((n - 1) as nat) } }
+proof fn foo(n: nat) ensures triangle(n) >= n decreases n {
+    if n == 0 {
+    } else {
+        foo((n - 1) as nat);
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn moves_assertions_up() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .fails_asserts("proof fn foo(x: int) { let y = x * x; assert(y >= 0); }", &[0])
+            .verifies(
+                "proof fn foo(x: int) { assert(x * x >= 0); let y = x * x; assert(y >= 0); }",
+            );
+        check(
+            verifier,
+            r#"
+proof fn foo(x: int) {
+    let y = x * x;
+    assert(y >= $00);
+}
+"#,
+            "move_up_assertion",
+            r#"
+proof fn foo(x: int) {
+    assert(x * x >= 0);
+    let y = x * x;
+    assert(y >= 0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn bounds_sequence_indices() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .fails_asserts(
+                "proof fn foo(s: Seq<int>) { assert(forall|i: int| s[i] == s[i]); }",
+                &[0],
+            )
+            .verifies(
+                "proof fn foo(s: Seq<int>) {
+                    {
+                        assert forall|i: int| 0 <= i && i < s.len() implies s[i] == s[i] by {}
+                        assert(forall|i: int| s[i] == s[i]);
+                    };
+                }",
+            );
+        check(
+            verifier,
+            r#"
+proof fn foo(s: Seq<int>) {
+    assert(for$0all|i: int| s[i] == s[i]);
+}
+"#,
+            "seq_index_inbound",
+            r#"
+proof fn foo(s: Seq<int>) {
+    {
+        assert forall|i: int| 0 <= i && i < s.len() implies s[i] == s[i] by {}
+        assert(forall|i: int| s[i] == s[i]);
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ranks_partial_improvements() {
+        let verifier = MockVerifier::new()
+            .failing_by_default()
+            .fails_asserts(
+                "proof fn foo(x: int) { assert(pos(x)); assert(x < 2 && x > 0); }",
+                &[0, 1],
+            )
+            .fails_asserts(
+                "proof fn foo(x: int) { { reveal(pos); assert(pos(x)); }; assert(x < 2 && x > 0); }",
+                &[1],
+            )
+            .fails_asserts(
+                "proof fn foo(x: int) {
+                    assert(pos(x));
+                    { assert(x < 2); assert(x > 0); assert(x < 2 && x > 0); };
+                }",
+                &[0, 1],
+            );
+        let budget = SearchBudget { max_depth: 1, ..SearchBudget::default() };
+        let found = search(
+            verifier,
+            budget,
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn f$0oo(x: int) {
+    assert(pos(x));
+    assert(x < 2 && x > 0);
+}
+"#,
+        );
+        let labels: Vec<String> = found.into_iter().map(|(label, _)| label).collect();
+        // splitting does not reduce the number of errors
+        assert_eq!(labels, vec!["insert_reveal (1 error left)"]);
+    }
+
+    #[test]
+    fn verified_functions_are_left_alone() {
+        let found = search(
+            MockVerifier::new(),
+            SearchBudget::default(),
+            r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn f$0oo(x: int) {
+    assert(pos(x));
+}
+"#,
+        );
+        assert_eq!(found, vec![]);
+    }
+
+    #[test]
+    fn search_stops_at_the_budget() {
+        let fixture = r#"
+#[verifier::opaque]
+spec fn pos(x: int) -> bool { x > 0 }
+proof fn foo(x: int) {
+    assert(p$0os(x) && x < 2);
+}
+"#;
+        // every candidate verifies
+        let verifier = || {
+            MockVerifier::new()
+                .fails_asserts("proof fn foo(x: int) { assert(pos(x) && x < 2); }", &[0])
+        };
+        let budget = SearchBudget { max_candidates: 0, ..SearchBudget::default() };
+        assert_eq!(search(verifier(), budget, fixture), vec![]);
+        let budget = SearchBudget { max_candidates: 1, ..SearchBudget::default() };
+        assert_eq!(search(verifier(), budget, fixture).len(), 1);
+    }
+
+    /// Runs until cancelled
+    #[derive(Debug)]
+    struct Hanging;
+
+    impl Verifier for Hanging {
+        fn verify(&self, request: &VerificationRequest<'_>) -> Option<VerifResult> {
+            let started = Instant::now();
+            while !(request.is_cancelled)() && started.elapsed() < Duration::from_secs(60) {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            None
+        }
+    }
+
+    #[test]
+    fn timeout_stops_a_running_verification() {
+        let budget =
+            SearchBudget { timeout: Duration::from_millis(100), ..SearchBudget::default() };
+        let started = Instant::now();
+        let found = search(Hanging, budget, "proof fn f$0oo() {\n    assert(false);\n}\n");
+        assert_eq!(found, vec![]);
+        assert!(started.elapsed() < Duration::from_secs(30));
+    }
+}
@@ -3,6 +3,8 @@
 #![allow(unused_imports)]
 mod generated;
 
+use std::sync::Arc;
+
 use expect_test::expect;
 use hir::Semantics;
 use ide_db::{
@@ -17,8 +19,8 @@ use test_fixture::WithFixture;
 use test_utils::{assert_eq_text, extract_offset};
 
 use crate::{
-    assists, handlers::Handler, Assist, AssistConfig, AssistContext, AssistKind,
-    AssistResolveStrategy, Assists, SingleResolve, VerusError,
+    assists, handlers::Handler, proof_plumber_api::verifier::MockVerifier, Assist, AssistConfig,
+    AssistContext, AssistKind, AssistResolveStrategy, Assists, SingleResolve, VerusError,
 };
 
 pub(crate) const TEST_CONFIG: AssistConfig = AssistConfig {
@@ -35,6 +37,8 @@ pub(crate) const TEST_CONFIG: AssistConfig = AssistConfig {
     prefer_prelude: true,
     assist_emit_must_use: false,
     term_search_fuel: 400,
+    verifier: None,
+    verification_cache: None,
 };
 
 pub(crate) const TEST_CONFIG_IMPORT_ONE: AssistConfig = AssistConfig {
@@ -51,6 +55,8 @@ pub(crate) const TEST_CONFIG_IMPORT_ONE: AssistConfig = AssistConfig {
     prefer_prelude: true,
     assist_emit_must_use: false,
     term_search_fuel: 400,
+    verifier: None,
+    verification_cache: None,
 };
 
 pub(crate) const TEST_CONFIG_NO_SNIPPET_CAP: AssistConfig = AssistConfig {
@@ -67,6 +73,8 @@ pub(crate) const TEST_CONFIG_NO_SNIPPET_CAP: AssistConfig = AssistConfig {
     prefer_prelude: true,
     assist_emit_must_use: false,
     term_search_fuel: 400,
+    verifier: None,
+    verification_cache: None,
 };
 
 pub(crate) fn with_single_file(text: &str) -> (RootDatabase, FileId) {
@@ -96,6 +104,53 @@ pub(crate) fn check_assist_with_verus_error(
     );
 }
 
+#[track_caller]
+pub(crate) fn check_assist_by_label_with_verus_error(
+    assist: Handler,
+    verus_errors: Vec<VerusError>,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+    label: &str,
+) {
+    let ra_fixture_after = trim_indent(ra_fixture_after);
+    check_with_verus_error(
+        assist,
+        ra_fixture_before,
+        ExpectedResult::After(&ra_fixture_after),
+        Some(label),
+        verus_errors,
+    );
+}
+
+#[track_caller]
+pub(crate) fn check_assist_not_applicable_with_verus_error(
+    assist: Handler,
+    verus_errors: Vec<VerusError>,
+    ra_fixture: &str,
+) {
+    check_with_verus_error(assist, ra_fixture, ExpectedResult::NotApplicable, None, verus_errors);
+}
+
+/// Like [`check_assist`], with proof actions consulting `verifier` instead of Verus
+#[track_caller]
+pub(crate) fn check_assist_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+) {
+    let ra_fixture_after = trim_indent(ra_fixture_after);
+    let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+    check_with_config(
+        config,
+        assist,
+        ra_fixture_before,
+        ExpectedResult::After(&ra_fixture_after),
+        None,
+        vec![],
+    );
+}
+
 #[track_caller]
 pub(crate) fn check_assist_no_snippet_cap(
     assist: Handler,
@@ -142,6 +197,27 @@ pub(crate) fn check_assist_by_label(
     check(assist, ra_fixture_before, ExpectedResult::After(&ra_fixture_after), Some(label));
 }
 
+/// Like [`check_assist_by_label`], with proof actions consulting `verifier` instead of Verus
+#[track_caller]
+pub(crate) fn check_assist_by_label_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+    label: &str,
+) {
+    let ra_fixture_after = trim_indent(ra_fixture_after);
+    let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+    check_with_config(
+        config,
+        assist,
+        ra_fixture_before,
+        ExpectedResult::After(&ra_fixture_after),
+        Some(label),
+        vec![],
+    );
+}
+
 // FIXME: instead of having a separate function here, maybe use
 // `extract_ranges` and mark the target as `<target> </target>` in the
 // fixture?
@@ -155,6 +231,27 @@ pub(crate) fn check_assist_not_applicable(assist: Handler, ra_fixture: &str) {
     check(assist, ra_fixture, ExpectedResult::NotApplicable, None);
 }
 
+#[track_caller]
+pub(crate) fn check_assist_not_applicable_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture: &str,
+) {
+    let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+    check_with_config(config, assist, ra_fixture, ExpectedResult::NotApplicable, None, vec![]);
+}
+
+/// Checks that `assist` is offered, but finds no edit when resolved with `verifier`
+#[track_caller]
+pub(crate) fn check_assist_no_edit_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture: &str,
+) {
+    let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+    check_with_config(config, assist, ra_fixture, ExpectedResult::NoEdit, None, vec![]);
+}
+
 #[track_caller]
 pub(crate) fn check_assist_not_applicable_by_label(assist: Handler, ra_fixture: &str, label: &str) {
     check(assist, ra_fixture, ExpectedResult::NotApplicable, Some(label));
@@ -178,6 +275,17 @@ pub(crate) fn check_assist_unresolved(assist: Handler, ra_fixture: &str) {
     check(assist, ra_fixture, ExpectedResult::Unresolved, None);
 }
 
+/// Like [`check_assist_unresolved`], with proof actions consulting `verifier` instead of Verus
+#[track_caller]
+pub(crate) fn check_assist_unresolved_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture: &str,
+) {
+    let config = AssistConfig { verifier: Some(Arc::new(verifier)), ..TEST_CONFIG };
+    check_with_config(config, assist, ra_fixture, ExpectedResult::Unresolved, None, vec![]);
+}
+
 #[track_caller]
 fn check_doc_test(assist_id: &str, before: &str, after: &str) {
     let after = trim_indent(after);
@@ -222,6 +330,7 @@ fn check_doc_test(assist_id: &str, before: &str, after: &str) {
 enum ExpectedResult<'a> {
     NotApplicable,
     Unresolved,
+    NoEdit,
     After(&'a str),
     Target(&'a str),
 }
@@ -327,10 +436,16 @@ fn check_with_config(
             assist.source_change.is_none(),
             "unresolved assist should not contain source changes"
         ),
+        (Some(assist), ExpectedResult::NoEdit) => {
+            assert!(assist.source_change.is_none(), "assist should not find an edit")
+        }
         (Some(_), ExpectedResult::NotApplicable) => panic!("assist should not be applicable!"),
         (
             None,
-            ExpectedResult::After(_) | ExpectedResult::Target(_) | ExpectedResult::Unresolved,
+            ExpectedResult::After(_)
+            | ExpectedResult::Target(_)
+            | ExpectedResult::Unresolved
+            | ExpectedResult::NoEdit,
         ) => {
             panic!("code action is not applicable")
         }
@@ -36,7 +36,7 @@ use hir::{
     db::HirDatabase,
     import_map::{AssocSearchMode, SearchMode},
     symbols::{FileSymbol, SymbolCollector},
-    Crate, Module,
+    Crate, FnMode, Module,
 };
 use rayon::prelude::*;
 use rustc_hash::FxHashSet;
@@ -53,6 +53,48 @@ pub struct Query {
     case_sensitive: bool,
     only_types: bool,
     libs: bool,
+    fn_mode: Option<FnModeFilter>,
+}
+
+/// Restricts a [`Query`] to functions of a particular Verus mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FnModeFilter {
+    Spec,
+    Proof,
+    Exec,
+    /// `broadcast proof fn`s.
+    Broadcast,
+}
+
+impl FnModeFilter {
+    /// Splits a leading mode marker (`spec:`, `proof:`, `lemma:`, `exec:` or `broadcast:`) off
+    /// a workspace symbol query. A path such as `spec::foo` has no marker.
+    pub fn split_query(query: &str) -> (Option<FnModeFilter>, &str) {
+        const MARKERS: &[(&str, FnModeFilter)] = &[
+            ("spec:", FnModeFilter::Spec),
+            ("proof:", FnModeFilter::Proof),
+            ("lemma:", FnModeFilter::Proof),
+            ("exec:", FnModeFilter::Exec),
+            ("broadcast:", FnModeFilter::Broadcast),
+        ];
+        for &(marker, fn_mode) in MARKERS {
+            match query.strip_prefix(marker) {
+                Some(rest) if !rest.starts_with(':') => return (Some(fn_mode), rest.trim_start()),
+                _ => (),
+            }
+        }
+        (None, query)
+    }
+
+    fn matches(self, symbol: &FileSymbol) -> bool {
+        match (self, symbol.fn_mode) {
+            (FnModeFilter::Spec, Some(FnMode::Spec)) => true,
+            (FnModeFilter::Proof, Some(FnMode::Proof)) => true,
+            (FnModeFilter::Exec, Some(FnMode::Exec)) => true,
+            (FnModeFilter::Broadcast, Some(_)) => symbol.is_broadcast,
+            _ => false,
+        }
+    }
 }
 
 impl Query {
@@ -63,6 +105,7 @@ impl Query {
             lowercased,
             only_types: false,
             libs: false,
+            fn_mode: None,
             mode: SearchMode::Fuzzy,
             assoc_mode: AssocSearchMode::Include,
             case_sensitive: false,
@@ -77,6 +120,11 @@ impl Query {
         self.libs = true;
     }
 
+    /// Only return functions of the given Verus mode.
+    pub fn fn_mode(&mut self, fn_mode: FnModeFilter) {
+        self.fn_mode = Some(fn_mode);
+    }
+
     pub fn fuzzy(&mut self) {
         self.mode = SearchMode::Fuzzy;
     }
@@ -189,6 +237,14 @@ impl<DB> std::ops::Deref for Snap<DB> {
 // That is, `#` switches from "types" to all symbols, `*` switches from the current
 // workspace to dependencies.
 //
+// For Verus code, the query can be prefixed with a function mode to only search
+// functions of that mode:
+//
+// - `spec:len` searches for `spec fn`s matching `len`
+// - `proof:len` (or `lemma:len`) searches for `proof fn`s matching `len`
+// - `exec:len` searches for `exec` functions matching `len`
+// - `broadcast:len` searches for `broadcast proof fn`s matching `len`
+//
 // Note that filtering does not currently work in VSCode due to the editor never
 // sending the special symbols to the language server. Instead, you can configure
 // the filtering via the `rust-analyzer.workspace.symbol.search.scope` and
@@ -376,6 +432,9 @@ impl Query {
                     if non_type_for_type_only_query || !self.matches_assoc_mode(symbol.is_assoc) {
                         continue;
                     }
+                    if self.fn_mode.is_some_and(|fn_mode| !fn_mode.matches(symbol)) {
+                        continue;
+                    }
                     // Hide symbols that start with `__` unless the query starts with `__`
                     if ignore_underscore_prefixed && symbol.name.starts_with("__") {
                         continue;
@@ -521,4 +580,51 @@ struct Duplicate;
 
         expect_file!["./test_data/test_doc_alias.txt"].assert_debug_eq(&symbols);
     }
+
+    #[test]
+    fn test_fn_mode_filter() {
+        let (db, _) = RootDatabase::with_single_file(
+            r#"
+spec fn len_spec() -> nat { 0 }
+proof fn lemma_len() {}
+broadcast proof fn lemma_len_broadcast() {}
+fn len() {}
+struct LenStruct;
+        "#,
+        );
+
+        let symbols: Vec<_> = Crate::from(db.test_crate())
+            .modules(&db)
+            .into_iter()
+            .flat_map(|module_id| SymbolCollector::collect_module(&db, module_id))
+            .collect();
+        let indices = [Arc::new(SymbolIndex::new(symbols))];
+
+        let search = |fn_mode| {
+            let mut query = Query::new("len".to_owned());
+            query.fn_mode(fn_mode);
+            let mut names = vec![];
+            query.search(&indices, |it| names.push(it.name.to_string()));
+            names.sort();
+            names
+        };
+
+        assert_eq!(search(FnModeFilter::Spec), ["len_spec"]);
+        assert_eq!(search(FnModeFilter::Proof), ["lemma_len", "lemma_len_broadcast"]);
+        assert_eq!(search(FnModeFilter::Exec), ["len"]);
+        assert_eq!(search(FnModeFilter::Broadcast), ["lemma_len_broadcast"]);
+    }
+
+    #[test]
+    fn test_fn_mode_filter_split_query() {
+        assert_eq!(FnModeFilter::split_query("spec:len"), (Some(FnModeFilter::Spec), "len"));
+        assert_eq!(FnModeFilter::split_query("lemma: len"), (Some(FnModeFilter::Proof), "len"));
+        assert_eq!(
+            FnModeFilter::split_query("broadcast:len#"),
+            (Some(FnModeFilter::Broadcast), "len#")
+        );
+        assert_eq!(FnModeFilter::split_query("spec::len"), (None, "spec::len"));
+        assert_eq!(FnModeFilter::split_query("exec::len"), (None, "exec::len"));
+        assert_eq!(FnModeFilter::split_query("len"), (None, "len"));
+    }
 }
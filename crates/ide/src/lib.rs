@@ -112,9 +112,12 @@ pub use crate::{
     },
     test_explorer::{TestItem, TestItemKind},
 };
-pub use hir::Semantics;
+pub use hir::{FnMode, Semantics};
 pub use ide_assists::{
-    Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, SingleResolve,
+    proof_plumber_api::verification_cache::{FileVerificationKeys, VerificationCache},
+    Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, CandidateResult, Clause,
+    FunctionContext, FunctionContract, MinimizeCache, MinimizeScope, ProofCandidate, SearchBudget,
+    SingleResolve,
 };
 pub use ide_completion::{
     CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
@@ -131,7 +134,7 @@ pub use ide_db::{
     prime_caches::ParallelPrimeCachesProgress,
     search::{ReferenceCategory, SearchScope},
     source_change::{FileSystemEdit, SnippetEdit, SourceChange},
-    symbol_index::Query,
+    symbol_index::{FnModeFilter, Query},
     RootDatabase, SymbolKind,
 };
 pub use ide_diagnostics::{
@@ -680,6 +683,56 @@ impl Analysis {
         self.with_db(|db| ide_diagnostics::diagnostics(db, config, &resolve, file_id))
     }
 
+    /// Removes the proof steps that the functions in `scope` verify without.
+    pub fn minimize_proof(
+        &self,
+        config: &AssistConfig,
+        position: FilePosition,
+        scope: MinimizeScope,
+        cache: &MinimizeCache,
+    ) -> Cancellable<Option<SourceChange>> {
+        self.with_db(|db| ide_assists::minimize_proof(db, config, position, scope, cache))
+    }
+
+    /// Searches for proof actions that make the function at `position` verify.
+    pub fn search_proof(
+        &self,
+        config: &AssistConfig,
+        position: FilePosition,
+        budget: SearchBudget,
+    ) -> Cancellable<Vec<ProofCandidate>> {
+        self.with_db(|db| ide_assists::search_proof(db, config, position, budget))
+    }
+
+    /// Verifies the function at `position` as `edit` changes it, without changing the file.
+    pub fn verify_candidate(
+        &self,
+        config: &AssistConfig,
+        position: FilePosition,
+        edit: &TextEdit,
+    ) -> Cancellable<Result<CandidateResult, String>> {
+        self.with_db(|db| ide_assists::verify_candidate(db, config, position, edit))
+    }
+
+    /// Describes the function at `position`, and the contracts of the functions it calls.
+    pub fn function_context(&self, position: FilePosition) -> Cancellable<Option<FunctionContext>> {
+        self.with_db(|db| ide_assists::function_context(db, position))
+    }
+
+    /// The verification cache keys of the functions of `file_id`, for the verifier with the
+    /// given fingerprint.
+    pub fn file_verification_keys(
+        &self,
+        file_id: FileId,
+        verifier: u64,
+    ) -> Cancellable<FileVerificationKeys> {
+        self.with_db(|db| {
+            ide_assists::proof_plumber_api::verification_cache::file_verification_keys(
+                db, file_id, verifier,
+            )
+        })
+    }
+
     /// Convenience function to return assists + quick fixes for diagnostics
     pub fn assists_with_fixes(
         &self,
@@ -54,6 +54,11 @@ pub enum RunnableKind {
     Bench { test_id: TestId },
     DocTest { test_id: TestId },
     Bin,
+    // Verus verification of the whole crate, a single module (`--verify-module`) or a single
+    // function (`--verify-function`); a `module` of `None` stands for the crate root module.
+    VerifyCrate,
+    VerifyMod { path: String },
+    Verify { module: Option<String>, function: String },
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -63,6 +68,9 @@ enum RunnableDiscKind {
     DocTest,
     Bench,
     Bin,
+    VerifyCrate,
+    VerifyMod,
+    Verify,
 }
 
 impl RunnableKind {
@@ -73,6 +81,35 @@ impl RunnableKind {
             RunnableKind::DocTest { .. } => RunnableDiscKind::DocTest,
             RunnableKind::Bench { .. } => RunnableDiscKind::Bench,
             RunnableKind::Bin => RunnableDiscKind::Bin,
+            RunnableKind::VerifyCrate => RunnableDiscKind::VerifyCrate,
+            RunnableKind::VerifyMod { .. } => RunnableDiscKind::VerifyMod,
+            RunnableKind::Verify { .. } => RunnableDiscKind::Verify,
+        }
+    }
+
+    pub fn is_verify(&self) -> bool {
+        matches!(
+            self,
+            RunnableKind::VerifyCrate
+                | RunnableKind::VerifyMod { .. }
+                | RunnableKind::Verify { .. }
+        )
+    }
+
+    /// The Verus arguments restricting verification to this runnable's target.
+    pub fn verus_args(&self) -> Vec<String> {
+        match self {
+            RunnableKind::VerifyMod { path } => vec!["--verify-module".to_owned(), path.clone()],
+            RunnableKind::Verify { module, function } => {
+                let mut args = match module {
+                    Some(module) => vec!["--verify-module".to_owned(), module.clone()],
+                    None => vec!["--verify-root".to_owned()],
+                };
+                args.push("--verify-function".to_owned());
+                args.push(function.clone());
+                args
+            }
+            _ => Vec::new(),
         }
     }
 }
@@ -88,11 +125,23 @@ impl Runnable {
             RunnableKind::Bin => {
                 target.map_or_else(|| "run binary".to_owned(), |t| format!("run {t}"))
             }
+            RunnableKind::VerifyCrate => {
+                target.map_or_else(|| "verify crate".to_owned(), |t| format!("verify {t}"))
+            }
+            RunnableKind::VerifyMod { path } => format!("verify-mod {path}"),
+            RunnableKind::Verify { module: Some(module), function } => {
+                format!("verify {module}::{function}")
+            }
+            RunnableKind::Verify { module: None, function } => format!("verify {function}"),
         }
     }
 
     pub fn title(&self) -> String {
-        let mut s = String::from("▶\u{fe0e} Run ");
+        let mut s = String::from(if self.kind.is_verify() {
+            "▶\u{fe0e} Verify "
+        } else {
+            "▶\u{fe0e} Run "
+        });
         if self.use_name_in_title {
             format_to!(s, "{}", self.nav.name);
             if !matches!(self.kind, RunnableKind::Bin) {
@@ -105,6 +154,9 @@ impl Runnable {
             RunnableKind::DocTest { .. } => "Doctest",
             RunnableKind::Bench { .. } => "Bench",
             RunnableKind::Bin => return s,
+            RunnableKind::VerifyCrate => "Crate",
+            RunnableKind::VerifyMod { .. } => "Module",
+            RunnableKind::Verify { .. } => "Function",
         };
         s.push_str(suffix);
         s
@@ -117,6 +169,10 @@ impl Runnable {
 // location**. Super useful for repeatedly running just a single test. Do bind this
 // to a shortcut!
 //
+// For Verus code, this also offers to verify the current crate, module, or `proof`/`exec`
+// function, by invoking `verus` (or `cargo verus` when `verus-analyzer.cargo.verusEnable`
+// is set) with `--verify-module`/`--verify-function`.
+//
 // |===
 // | Editor  | Action Name
 //
@@ -169,6 +225,12 @@ pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
                 .filter(|it| it.nav.file_id == file_id),
             Some(def),
         );
+        let verify_runnable = match def {
+            Definition::Module(it) => verify_runnable_mod(&sema, it),
+            Definition::Function(it) => verify_runnable_fn(&sema, it),
+            _ => None,
+        };
+        add_opt(verify_runnable.filter(|it| it.nav.file_id == file_id), Some(def));
         if let Definition::SelfType(impl_) = def {
             impl_.items(db).into_iter().for_each(|assoc| {
                 let runnable = match assoc {
@@ -178,7 +240,10 @@ pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
                     hir::AssocItem::Const(it) => module_def_doctest(sema.db, it.into()),
                     hir::AssocItem::TypeAlias(it) => module_def_doctest(sema.db, it.into()),
                 };
-                add_opt(runnable, Some(assoc.into()))
+                add_opt(runnable, Some(assoc.into()));
+                if let hir::AssocItem::Function(it) = assoc {
+                    add_opt(verify_runnable_fn(&sema, it), Some(assoc.into()));
+                }
             });
         }
     });
@@ -187,6 +252,10 @@ pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
         .map(|it| runnable_mod_outline_definition(&sema, it))
         .for_each(|it| add_opt(it, None));
 
+    sema.file_to_module_defs(file_id)
+        .map(|it| verify_runnable_mod_outline_definition(&sema, it))
+        .for_each(|it| add_opt(it, None));
+
     res.extend(in_macro_expansion.into_iter().flat_map(|(_, runnables)| {
         let use_name_in_title = runnables.len() != 1;
         runnables.into_iter().map(move |mut r| {
@@ -403,6 +472,134 @@ pub(crate) fn runnable_impl(
     Some(Runnable { use_name_in_title: false, nav, kind: RunnableKind::DocTest { test_id }, cfg })
 }
 
+/// Creates a runnable verifying `def` with Verus, if Verus has anything to check in it.
+pub(crate) fn verify_runnable_fn(
+    sema: &Semantics<'_, RootDatabase>,
+    def: hir::Function,
+) -> Option<Runnable> {
+    if !is_verification_target(sema.db, def) {
+        return None;
+    }
+    let function = match def.as_assoc_item(sema.db).and_then(|it| it.implementing_ty(sema.db)) {
+        Some(ty) => {
+            let adt = ty.as_adt()?;
+            format!(
+                "{}::{}",
+                adt.name(sema.db).display(sema.db),
+                def.name(sema.db).display(sema.db)
+            )
+        }
+        None => def.name(sema.db).display(sema.db).to_string(),
+    };
+    let module = verus_module_path(sema.db, def.module(sema.db));
+
+    let nav = NavigationTarget::from_named(
+        sema.db,
+        def.source(sema.db)?.as_ref().map(|it| it as &dyn ast::HasName),
+        SymbolKind::Function,
+    )
+    .call_site();
+    let cfg = def.attrs(sema.db).cfg();
+    Some(Runnable {
+        use_name_in_title: false,
+        nav,
+        kind: RunnableKind::Verify { module, function },
+        cfg,
+    })
+}
+
+/// Creates a runnable verifying the inline module `def` with Verus.
+pub(crate) fn verify_runnable_mod(
+    sema: &Semantics<'_, RootDatabase>,
+    def: hir::Module,
+) -> Option<Runnable> {
+    if !has_verification_target(sema.db, def) {
+        return None;
+    }
+    let path = verus_module_path(sema.db, def)?;
+    let cfg = def.attrs(sema.db).cfg();
+    let nav = NavigationTarget::from_module_to_decl(sema.db, def).call_site();
+    Some(Runnable { use_name_in_title: false, nav, kind: RunnableKind::VerifyMod { path }, cfg })
+}
+
+/// Creates a verification runnable at the top of outline modules. For the crate root, this
+/// verifies the whole crate.
+fn verify_runnable_mod_outline_definition(
+    sema: &Semantics<'_, RootDatabase>,
+    def: hir::Module,
+) -> Option<Runnable> {
+    def.as_source_file_id(sema.db)?;
+    let kind = if def.is_crate_root() {
+        let has_target = def
+            .krate()
+            .modules(sema.db)
+            .into_iter()
+            .any(|module| has_verification_target(sema.db, module));
+        if !has_target {
+            return None;
+        }
+        RunnableKind::VerifyCrate
+    } else {
+        if !has_verification_target(sema.db, def) {
+            return None;
+        }
+        RunnableKind::VerifyMod { path: verus_module_path(sema.db, def)? }
+    };
+    let cfg = def.attrs(sema.db).cfg();
+    Some(Runnable { use_name_in_title: false, nav: def.to_nav(sema.db).call_site(), kind, cfg })
+}
+
+/// The module path Verus expects for `--verify-module`, or `None` for the crate root.
+fn verus_module_path(db: &RootDatabase, module: hir::Module) -> Option<String> {
+    let path = module
+        .path_to_root(db)
+        .into_iter()
+        .rev()
+        .filter_map(|it| it.name(db))
+        .map(|it| it.display(db).to_string())
+        .join("::");
+    (!path.is_empty()).then_some(path)
+}
+
+/// Proof functions are always checked by Verus, exec functions only when they have a
+/// specification. Spec functions are left out as there is rarely anything to prove about them.
+pub(crate) fn is_verification_target(db: &RootDatabase, def: hir::Function) -> bool {
+    if !def.has_body(db) {
+        return false;
+    }
+    match def.mode(db) {
+        hir::FnMode::Proof => true,
+        hir::FnMode::Spec => false,
+        hir::FnMode::Exec => def.source(db).map_or(false, |src| {
+            let func = src.value;
+            func.requires_clause().is_some()
+                || func.ensures_clause().is_some()
+                || func.returns_clause().is_some()
+        }),
+    }
+}
+
+/// Does `module` directly contain a function that Verus verifies? Submodules are not taken
+/// into account, as `--verify-module` does not verify them either.
+fn has_verification_target(db: &RootDatabase, module: hir::Module) -> bool {
+    let in_impls =
+        module.impl_defs(db).into_iter().flat_map(|impl_| impl_.items(db)).filter_map(|assoc| {
+            match assoc {
+                hir::AssocItem::Function(it) => Some(it),
+                _ => None,
+            }
+        });
+    module
+        .declarations(db)
+        .into_iter()
+        .filter_map(|item| match item {
+            hir::ModuleDef::Function(it) => Some(it),
+            _ => None,
+        })
+        .chain(in_impls)
+        .any(|it| is_verification_target(db, it))
+}
+
 fn has_cfg_test(attrs: AttrsWithOwner) -> bool {
     attrs.cfgs().any(|cfg| matches!(cfg, CfgExpr::Atom(CfgAtom::Flag(s)) if s == "test"))
 }
@@ -605,6 +802,19 @@ mod tests {
         expect.assert_debug_eq(&result);
     }
 
+    fn check_labels(ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let result = analysis
+            .runnables(position.file_id)
+            .unwrap()
+            .into_iter()
+            .map(|runnable| {
+                format!("{} ({})", runnable.label(None), runnable.kind.verus_args().join(" "))
+            })
+            .collect::<Vec<_>>();
+        expect.assert_debug_eq(&result);
+    }
+
     fn check_tests(ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
         let tests = analysis.related_tests(position, None).unwrap();
@@ -894,6 +1104,72 @@ impl<'a, T, const N: usize> Data<'a, T, N> {
             "#]],
         );
     }
+
+    #[test]
+    fn test_runnables_verify() {
+        check(
+            r#"
+//- /lib.rs
+$0
+spec fn not_verified() -> int { 1 }
+
+proof fn lemma_verified() {}
+
+fn exec_unspecified() {}
+
+fn exec_verified(x: u8) -> (r: u8)
+    ensures r == x
+{
+    x
+}
+
+mod verified_mod {
+    proof fn lemma_in_mod() {}
+}
+
+struct S;
+impl S {
+    proof fn lemma_method() {}
+}
+"#,
+            expect![[r#"
+                [
+                    "(VerifyCrate, NavigationTarget { file_id: FileId(0), full_range: 0..264, name: \"\", kind: Module })",
+                    "(Verify, NavigationTarget { file_id: FileId(0), full_range: 38..66, focus_range: 47..61, name: \"lemma_verified\", kind: Function })",
+                    "(Verify, NavigationTarget { file_id: FileId(0), full_range: 94..157, focus_range: 97..110, name: \"exec_verified\", kind: Function })",
+                    "(VerifyMod, NavigationTarget { file_id: FileId(0), full_range: 159..210, focus_range: 163..175, name: \"verified_mod\", kind: Module, description: \"mod verified_mod\" })",
+                    "(Verify, NavigationTarget { file_id: FileId(0), full_range: 182..208, focus_range: 191..203, name: \"lemma_in_mod\", kind: Function })",
+                    "(Verify, NavigationTarget { file_id: FileId(0), full_range: 235..261, focus_range: 244..256, name: \"lemma_method\", kind: Function })",
+                ]
+            "#]],
+        );
+        check_labels(
+            r#"
+//- /lib.rs
+$0
+proof fn lemma_verified() {}
+
+mod verified_mod {
+    proof fn lemma_in_mod() {}
+}
+
+struct S;
+impl S {
+    proof fn lemma_method() {}
+}
+"#,
+            expect![[r#"
+                [
+                    "verify crate ()",
+                    "verify lemma_verified (--verify-root --verify-function lemma_verified)",
+                    "verify-mod verified_mod (--verify-module verified_mod)",
+                    "verify verified_mod::lemma_in_mod (--verify-module verified_mod --verify-function lemma_in_mod)",
+                    "verify S::lemma_method (--verify-root --verify-function S::lemma_method)",
+                ]
+            "#]],
+        );
+    }
+
     #[test]
     fn test_runnables_module() {
         check(
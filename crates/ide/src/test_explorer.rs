@@ -1,4 +1,4 @@
-//! Discovers tests
+//! Discovers tests, and the functions Verus verifies, for the test explorer.
 
 use hir::{Crate, Module, ModuleDef, Semantics};
 use ide_db::{
@@ -7,7 +7,10 @@ use ide_db::{
 };
 use syntax::TextRange;
 
-use crate::{runnables::runnable_fn, NavigationTarget, Runnable, TryToNav};
+use crate::{
+    runnables::{is_verification_target, runnable_fn, verify_runnable_fn},
+    NavigationTarget, Runnable, TryToNav,
+};
 
 #[derive(Debug)]
 pub enum TestItemKind {
@@ -90,24 +93,54 @@ fn discover_tests_in_module(
         let ModuleDef::Function(f) = def else {
             continue;
         };
-        if !f.is_test(db) {
+        let runnable = if f.is_test(db) {
+            runnable_fn(&sema, f)
+        } else if is_verification_target(db, f) {
+            verify_runnable_fn(&sema, f)
+        } else {
             continue;
-        }
-        let nav = f.try_to_nav(db).map(|r| r.call_site);
+        };
         let fn_name = f.name(db).as_str().unwrap_or("[function without name]").to_owned();
-        r.push(TestItem {
-            id: format!("{prefix_id}::{fn_name}"),
-            kind: TestItemKind::Function,
-            label: fn_name,
-            parent: Some(prefix_id.clone()),
-            file: nav.as_ref().map(|n| n.file_id),
-            text_range: nav.as_ref().map(|n| n.focus_or_full_range()),
-            runnable: runnable_fn(&sema, f),
-        });
+        r.push(function_test_item(db, f, fn_name, &prefix_id, runnable));
+    }
+    // Verus verifies methods too, which are listed under their `Self` type name.
+    for impl_ in module.impl_defs(db) {
+        let Some(adt) = impl_.self_ty(db).as_adt() else { continue };
+        for item in impl_.items(db) {
+            let hir::AssocItem::Function(f) = item else { continue };
+            if !is_verification_target(db, f) {
+                continue;
+            }
+            let fn_name = format!(
+                "{}::{}",
+                adt.name(db).display(db),
+                f.name(db).as_str().unwrap_or("[function without name]")
+            );
+            r.push(function_test_item(db, f, fn_name, &prefix_id, verify_runnable_fn(&sema, f)));
+        }
     }
     r
 }
 
+fn function_test_item(
+    db: &RootDatabase,
+    f: hir::Function,
+    fn_name: String,
+    prefix_id: &str,
+    runnable: Option<Runnable>,
+) -> TestItem {
+    let nav = f.try_to_nav(db).map(|r| r.call_site);
+    TestItem {
+        id: format!("{prefix_id}::{fn_name}"),
+        kind: TestItemKind::Function,
+        label: fn_name,
+        parent: Some(prefix_id.to_owned()),
+        file: nav.as_ref().map(|n| n.file_id),
+        text_range: nav.as_ref().map(|n| n.focus_or_full_range()),
+        runnable,
+    }
+}
+
 pub(crate) fn discover_tests_in_crate_by_test_id(
     db: &RootDatabase,
     crate_test_id: &str,
@@ -192,3 +225,60 @@ pub(crate) fn discover_tests_in_crate(db: &RootDatabase, crate_id: CrateId) -> V
     r.extend(discover_tests_in_module(db, module, crate_test_id, false));
     r
 }
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+    use itertools::Itertools;
+
+    use crate::fixture;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (analysis, _) = fixture::file(ra_fixture);
+        let actual = analysis
+            .discover_tests_in_crate_by_test_id("foo")
+            .unwrap()
+            .into_iter()
+            .map(|it| format!("{} {:?}", it.id, it.runnable.map(|r| r.kind)))
+            .join("\n");
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn discovers_tests_and_verified_functions() {
+        check(
+            r#"
+//- /lib.rs crate:foo
+#[test]
+fn test_exec() {}
+
+spec fn not_verified() -> int { 1 }
+
+proof fn lemma_verified() {}
+
+fn exec_verified(x: u8) -> (r: u8)
+    ensures r == x
+{
+    x
+}
+
+mod m {
+    proof fn lemma_in_mod() {}
+
+    struct S;
+    impl S {
+        proof fn lemma_method() {}
+    }
+}
+"#,
+            expect![[r#"
+                foo None
+                foo::m None
+                foo::m::lemma_in_mod Some(Verify { module: Some("m"), function: "lemma_in_mod" })
+                foo::m::S::lemma_method Some(Verify { module: Some("m"), function: "S::lemma_method" })
+                foo::lemma_verified Some(Verify { module: None, function: "lemma_verified" })
+                foo::exec_verified Some(Verify { module: None, function: "exec_verified" })
+                foo::test_exec Some(Test { test_id: Path("test_exec"), attr: TestAttr { ignore: false } })"#]],
+        );
+    }
+}
@@ -403,7 +403,7 @@ fn lhs(p: &mut Parser<'_>, r: Restrictions) -> Option<(CompletedMarker, BlockLik
     let kind =
     if p.at(T![|||]) || p.at(T![&&&]) {
         m = p.start();
-        p.bump_any();
+        p.bump(if p.at(T![|||]) { T![|||] } else { T![&&&] });
         PREFIX_EXPR
     } else {
         match p.current() {
@@ -152,6 +152,8 @@ pub struct PackageData {
     pub rust_version: Option<semver::Version>,
     /// The contents of [package.metadata.rust-analyzer]
     pub metadata: RustAnalyzerPackageMetaData,
+    /// The contents of [package.metadata.verus.ide], on top of [workspace.metadata.verus.ide]
+    pub verus_metadata: VerusPackageMetaData,
 }
 
 #[derive(Deserialize, Default, Debug, Clone, Eq, PartialEq)]
@@ -159,6 +161,58 @@ pub struct RustAnalyzerPackageMetaData {
     pub rustc_private: bool,
 }
 
+/// How Verus should be run on a package.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerusPackageMetaData {
+    /// Whether to run Verus on the package at all.
+    pub verify: bool,
+    /// Extra arguments passed to Verus.
+    pub extra_args: Vec<String>,
+    /// The resource limit passed as `--rlimit`.
+    pub rlimit: Option<u32>,
+    /// Modules to verify, via `--verify-module`, when the crate root itself is edited.
+    pub verify_module: Vec<String>,
+}
+
+impl Default for VerusPackageMetaData {
+    fn default() -> Self {
+        VerusPackageMetaData {
+            verify: true,
+            extra_args: Vec::new(),
+            rlimit: None,
+            verify_module: Vec::new(),
+        }
+    }
+}
+
+impl VerusPackageMetaData {
+    /// The Verus arguments these settings amount to.
+    pub fn verus_args(&self) -> Vec<String> {
+        let mut args = self.extra_args.clone();
+        if let Some(rlimit) = self.rlimit {
+            args.push("--rlimit".to_owned());
+            args.push(rlimit.to_string());
+        }
+        args
+    }
+
+    fn from_metadata(workspace: VerusIdeMetaData, package: VerusIdeMetaData) -> Self {
+        let default = VerusPackageMetaData::default();
+        VerusPackageMetaData {
+            verify: package.verify.or(workspace.verify).unwrap_or(default.verify),
+            extra_args: package
+                .extra_args
+                .or(workspace.extra_args)
+                .map_or(default.extra_args, VerusArgs::into_vec),
+            rlimit: package.rlimit.or(workspace.rlimit),
+            verify_module: package
+                .verify_module
+                .or(workspace.verify_module)
+                .unwrap_or(default.verify_module),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct PackageDependency {
     pub pkg: Package,
@@ -253,6 +307,57 @@ struct PackageMetadata {
     rust_analyzer: Option<RustAnalyzerPackageMetaData>,
 }
 
+// Deserialize helper for the `verus` table of the package and workspace metadata, kept apart from
+// `PackageMetadata` so that a malformed table doesn't take the other one down with it
+#[derive(Deserialize, Default)]
+struct VerusMetadata {
+    #[serde(default)]
+    verus: VerusTable,
+}
+
+#[derive(Deserialize, Default)]
+struct VerusTable {
+    #[serde(default)]
+    ide: VerusIdeMetaData,
+}
+
+#[derive(Deserialize, Default, Clone)]
+struct VerusIdeMetaData {
+    verify: Option<bool>,
+    extra_args: Option<VerusArgs>,
+    rlimit: Option<u32>,
+    verify_module: Option<Vec<String>>,
+}
+
+/// Arguments written either as a list or as a single space-separated string.
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+enum VerusArgs {
+    List(Vec<String>),
+    String(String),
+}
+
+impl VerusMetadata {
+    fn parse(metadata: serde_json::Value) -> VerusMetadata {
+        if metadata.is_null() {
+            return VerusMetadata::default();
+        }
+        from_value(metadata).unwrap_or_else(|e| {
+            tracing::warn!("failed to parse Verus metadata: {e}");
+            VerusMetadata::default()
+        })
+    }
+}
+
+impl VerusArgs {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            VerusArgs::List(args) => args,
+            VerusArgs::String(args) => args.split_whitespace().map(ToOwned::to_owned).collect(),
+        }
+    }
+}
+
 impl CargoWorkspace {
     pub fn fetch_metadata(
         cargo_toml: &ManifestPath,
@@ -341,6 +446,7 @@ impl CargoWorkspace {
         let mut targets = Arena::default();
 
         let ws_members = &meta.workspace_members;
+        let ws_verus_metadata = VerusMetadata::parse(meta.workspace_metadata.take());
 
         meta.packages.sort_by(|a, b| a.id.cmp(&b.id));
         for meta_pkg in meta.packages {
@@ -364,6 +470,7 @@ impl CargoWorkspace {
                 rust_version,
                 ..
             } = meta_pkg;
+            let verus_metadata = VerusMetadata::parse(metadata.clone());
             let meta = from_value::<PackageMetadata>(metadata).unwrap_or_default();
             let edition = match edition {
                 cargo_metadata::Edition::E2015 => Edition::Edition2015,
@@ -402,6 +509,10 @@ impl CargoWorkspace {
                 features: features.into_iter().collect(),
                 active_features: Vec::new(),
                 metadata: meta.rust_analyzer.unwrap_or_default(),
+                verus_metadata: VerusPackageMetaData::from_metadata(
+                    ws_verus_metadata.verus.ide.clone(),
+                    verus_metadata.verus.ide,
+                ),
             });
             let pkg_data = &mut packages[pkg];
             pkg_by_id.insert(id, pkg);
@@ -46,7 +46,7 @@ pub use crate::{
     build_scripts::WorkspaceBuildScripts,
     cargo_workspace::{
         CargoConfig, CargoFeatures, CargoWorkspace, Package, PackageData, PackageDependency,
-        RustLibSource, Target, TargetData, TargetKind,
+        RustLibSource, Target, TargetData, TargetKind, VerusPackageMetaData,
     },
     cfg::CfgOverrides,
     manifest_path::ManifestPath,
@@ -11,7 +11,7 @@ use triomphe::Arc;
 
 use crate::{
     workspace::ProjectWorkspaceKind, CargoWorkspace, CfgOverrides, ManifestPath, ProjectJson,
-    ProjectJsonData, ProjectWorkspace, Sysroot, WorkspaceBuildScripts,
+    ProjectJsonData, ProjectWorkspace, Sysroot, VerusPackageMetaData, WorkspaceBuildScripts,
 };
 
 fn load_cargo(file: &str) -> (CrateGraph, ProcMacroPaths) {
@@ -243,6 +243,45 @@ fn rust_project_is_proc_macro_has_proc_macro_dep() {
     crate_data.dependencies.iter().find(|&dep| dep.name.deref() == "proc_macro").unwrap();
 }
 
+#[test]
+fn cargo_verus_metadata() {
+    let mut meta: Metadata = get_test_json_file("hello-world-metadata.json");
+    meta.workspace_metadata =
+        serde_json::json!({ "verus": { "ide": { "rlimit": 20, "extra_args": "--a  --b" } } });
+    for pkg in &mut meta.packages {
+        pkg.metadata = match pkg.name.as_str() {
+            "hello-world" => serde_json::json!({
+                "verus": { "ide": { "extra_args": ["--log-all"], "verify_module": ["m"] } }
+            }),
+            "libc" => serde_json::json!({ "verus": { "ide": { "verify": false } } }),
+            _ => serde_json::Value::Null,
+        };
+    }
+    let manifest_path =
+        ManifestPath::try_from(AbsPathBuf::try_from(meta.workspace_root.clone()).unwrap()).unwrap();
+    let cargo = CargoWorkspace::new(meta, manifest_path);
+    let verus_metadata = |name: &str| {
+        let pkg = cargo.packages().find(|&pkg| cargo[pkg].name == name).unwrap();
+        cargo[pkg].verus_metadata.clone()
+    };
+
+    let hello_world = verus_metadata("hello-world");
+    assert_eq!(
+        hello_world,
+        VerusPackageMetaData {
+            verify: true,
+            extra_args: vec!["--log-all".to_owned()],
+            rlimit: Some(20),
+            verify_module: vec!["m".to_owned()],
+        }
+    );
+    assert_eq!(hello_world.verus_args(), ["--log-all", "--rlimit", "20"]);
+
+    let libc = verus_metadata("libc");
+    assert!(!libc.verify);
+    assert_eq!(libc.verus_args(), ["--a", "--b", "--rlimit", "20"]);
+}
+
 #[test]
 fn crate_graph_dedup_identical() {
     let (mut crate_graph, proc_macros) =
@@ -28,7 +28,8 @@ use crate::{
     sysroot::{SysrootCrate, SysrootMode},
     target_data_layout::{self, RustcDataLayoutConfig},
     utf8_stdout, CargoConfig, CargoWorkspace, InvocationStrategy, ManifestPath, Package,
-    ProjectJson, ProjectManifest, Sysroot, TargetData, TargetKind, WorkspaceBuildScripts,
+    ProjectJson, ProjectManifest, Sysroot, TargetData, TargetKind, VerusPackageMetaData,
+    WorkspaceBuildScripts,
 };
 
 pub type FileLoader<'a> = &'a mut dyn for<'b> FnMut(&'b AbsPath) -> Option<FileId>;
@@ -527,6 +528,21 @@ impl ProjectWorkspace {
         }
     }
 
+    /// The Verus settings of the local package owning `path`, for Cargo workspaces.
+    pub fn verus_metadata_for(&self, path: &AbsPath) -> Option<&VerusPackageMetaData> {
+        let cargo = match &self.kind {
+            ProjectWorkspaceKind::Cargo { cargo, .. }
+            | ProjectWorkspaceKind::DetachedFile { cargo: Some((cargo, _)), .. } => cargo,
+            _ => return None,
+        };
+        cargo
+            .packages()
+            .map(|pkg| &cargo[pkg])
+            .filter(|pkg| pkg.is_local && path.starts_with(pkg.manifest.parent()))
+            .max_by_key(|pkg| pkg.manifest.parent().as_str().len())
+            .map(|pkg| &pkg.verus_metadata)
+    }
+
     pub fn manifest(&self) -> Option<&ManifestPath> {
         match &self.kind {
             ProjectWorkspaceKind::Cargo { cargo, .. } => Some(cargo.manifest_path()),
@@ -225,24 +225,24 @@ fn run_server() -> anyhow::Result<()> {
         .unwrap_or_else(|| vec![root_path.clone()]);
     let mut config =
         Config::new(root_path, capabilities, workspace_roots, visual_studio_code_version, None);
+    // the configuration is applied even without options, as that is when Verus is looked up
+    let mut change = ConfigChange::default();
     if let Some(json) = initialization_options {
-        let mut change = ConfigChange::default();
         change.change_client_config(json);
-
-        let error_sink: ConfigErrors;
-        (config, error_sink, _) = config.apply_change(change);
-
-        if !error_sink.is_empty() {
-            use lsp_types::{
-                notification::{Notification, ShowMessage},
-                MessageType, ShowMessageParams,
-            };
-            let not = lsp_server::Notification::new(
-                ShowMessage::METHOD.to_owned(),
-                ShowMessageParams { typ: MessageType::WARNING, message: error_sink.to_string() },
-            );
-            connection.sender.send(lsp_server::Message::Notification(not)).unwrap();
-        }
+    }
+    let error_sink: ConfigErrors;
+    (config, error_sink, _) = config.apply_change(change);
+
+    if !error_sink.is_empty() {
+        use lsp_types::{
+            notification::{Notification, ShowMessage},
+            MessageType, ShowMessageParams,
+        };
+        let not = lsp_server::Notification::new(
+            ShowMessage::METHOD.to_owned(),
+            ShowMessageParams { typ: MessageType::WARNING, message: error_sink.to_string() },
+        );
+        connection.sender.send(lsp_server::Message::Notification(not)).unwrap();
     }
 
     let server_capabilities = rust_analyzer::server_capabilities(&config);
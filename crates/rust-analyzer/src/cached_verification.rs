@@ -0,0 +1,195 @@
+//! Skips verifying the parts of a saved file that the verification cache knows to verify.
+//!
+//! On save, the functions of the file are looked up in the verification cache of proof actions.
+//! When they all verify, Verus is not run at all; when only one of them may not, Verus only
+//! verifies that one. Once Verus is done, the functions it reported no error in are added to the
+//! cache.
+
+use ide::{Cancellable, FileId, RunnableKind, TextRange};
+use ide_db::FxHashSet;
+use syntax::{ast, AstNode, Edition};
+use triomphe::Arc;
+
+use crate::{
+    global_state::{GlobalState, GlobalStateSnapshot},
+    line_index::{LineEndings, LineIndex},
+    lsp::to_proto,
+};
+
+/// What to do about a saved file
+pub(crate) enum Plan {
+    /// Everything in the file verifies
+    Skip,
+    /// Run Verus, restricted by `module_args` if they are set
+    Verify { module_args: Option<Vec<String>>, pending: PendingVerification },
+}
+
+/// The cache keys a Verus run on a saved file is about to decide
+#[derive(Debug, Clone)]
+pub(crate) struct PendingVerification {
+    file_id: FileId,
+    /// The text of the file as saved, which is what Verus verifies
+    saved: Arc<str>,
+    endings: LineEndings,
+    /// The keys, with the range of the function they belong to. The key of the rest of the file
+    /// has no range: it is recorded if there are no errors at all.
+    keys: Vec<(u64, Option<TextRange>)>,
+    /// Whether the flycheck has started. Starting it cancels the previous run, and that
+    /// cancellation must not be mistaken for ours.
+    started: bool,
+}
+
+#[derive(Debug)]
+pub(crate) enum CachedVerification {
+    /// A flycheck was started on a saved file
+    Started { flycheck_id: usize, pending: PendingVerification },
+    /// Verus was not run on a saved file, as all of it verifies
+    Skipped { flycheck_id: usize, file_id: FileId },
+}
+
+/// Decides how to verify `file_id` after it was saved, or None when there is no cache to consult
+pub(crate) fn plan(world: &GlobalStateSnapshot, file_id: FileId) -> Cancellable<Option<Plan>> {
+    // the cache only covers the saved file, while all errors are wanted
+    if world.config.verus_report_all_errors() {
+        return Ok(None);
+    }
+    let Some(verifier) = world.assist_config(None).verifier else { return Ok(None) };
+    let cache = world.config.verification_cache();
+    // the keys describe the file as analyzed, which may have been edited since it was saved
+    let Some(path) = world.file_id_to_file_path(file_id).as_path().map(|it| it.to_owned()) else {
+        return Ok(None);
+    };
+    let Ok(saved) = std::fs::read_to_string(&path) else { return Ok(None) };
+    let (saved, endings) = LineEndings::normalize(saved);
+    if *world.analysis.file_text(file_id)? != *saved {
+        return Ok(None);
+    }
+    let crate_root = match world.analysis.crates_for(file_id)?.first() {
+        Some(&krate) => {
+            let root = world.analysis.crate_root(krate)?;
+            world.file_id_to_file_path(root).as_path().map(|it| it.to_owned())
+        }
+        None => None,
+    };
+    let fingerprint = verifier.fingerprint(crate_root.as_deref());
+    let keys = world.analysis.file_verification_keys(file_id, fingerprint)?;
+    let pending = |keys| PendingVerification {
+        file_id,
+        saved: saved.clone().into(),
+        endings,
+        keys,
+        started: false,
+    };
+
+    let targets: Vec<_> = world
+        .analysis
+        .runnables(file_id)?
+        .into_iter()
+        .filter(|it| matches!(it.kind, RunnableKind::Verify { .. }))
+        .collect();
+    let mut unverified = keys.functions.iter().filter(|(_, key)| !cache.is_verified(*key));
+    // functions without a key (e.g. that do not convert to VST) cannot be looked up
+    let without_key = targets
+        .iter()
+        .find(|target| keys.functions.iter().all(|(range, _)| *range != target.nav.full_range));
+
+    let all_keys = || {
+        let functions = keys.functions.iter().map(|&(range, key)| (key, Some(range)));
+        functions.chain([(keys.rest, None)]).collect()
+    };
+    let plan = match (cache.is_verified(keys.rest), unverified.next(), unverified.next()) {
+        (true, None, _) if without_key.is_none() => Plan::Skip,
+        (true, Some(&(range, key)), None) if without_key.is_none() => {
+            // functions that are not verification targets, e.g. spec functions, are still
+            // checked when verifying their module
+            let target = targets.iter().find(|it| it.nav.full_range == range);
+            let keys = match target {
+                Some(_) => vec![(key, Some(range))],
+                None => all_keys(),
+            };
+            Plan::Verify {
+                module_args: target.map(|it| it.kind.verus_args()),
+                pending: pending(keys),
+            }
+        }
+        _ => Plan::Verify { module_args: None, pending: pending(all_keys()) },
+    };
+    Ok(Some(plan))
+}
+
+pub(crate) fn on_task(state: &mut GlobalState, task: CachedVerification) {
+    match task {
+        CachedVerification::Started { flycheck_id, pending } => {
+            state.pending_verifications.insert(flycheck_id, pending);
+        }
+        CachedVerification::Skipped { flycheck_id, file_id } => {
+            // whatever was reported for the file before no longer applies
+            state.diagnostics.clear_check_for(flycheck_id, file_id);
+            state.verus_errors.clear_file(file_id);
+        }
+    }
+}
+
+pub(crate) fn on_flycheck_progress(
+    state: &mut GlobalState,
+    id: usize,
+    progress: &flycheck::Progress,
+) {
+    let Some(pending) = state.pending_verifications.get_mut(&id) else { return };
+    match progress {
+        flycheck::Progress::DidStart => pending.started = true,
+        flycheck::Progress::DidFinish(result) if pending.started => {
+            let Some(pending) = state.pending_verifications.remove(&id) else { return };
+            if result.is_ok() {
+                record(state, id, pending);
+            }
+        }
+        flycheck::Progress::DidCancel if pending.started => {
+            state.pending_verifications.remove(&id);
+        }
+        flycheck::Progress::DidFailToRestart(_) => {
+            state.pending_verifications.remove(&id);
+        }
+        _ => (),
+    }
+}
+
+fn record(state: &GlobalState, flycheck_id: usize, pending: PendingVerification) {
+    let errors: Vec<&lsp_types::Range> = state
+        .diagnostics
+        .check
+        .get(&flycheck_id)
+        .and_then(|it| it.get(&pending.file_id))
+        .into_iter()
+        .flatten()
+        .filter(|diag| diag.severity == Some(lsp_types::DiagnosticSeverity::ERROR))
+        .map(|diag| &diag.range)
+        .collect();
+    // the errors are positions in the saved text, the functions are found there as well
+    let source_file = ast::SourceFile::parse(&pending.saved, Edition::CURRENT).tree();
+    let functions: FxHashSet<TextRange> = source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .map(|func| func.syntax().text_range())
+        .collect();
+    let line_index = LineIndex {
+        index: Arc::new(ide::LineIndex::new(&pending.saved)),
+        endings: pending.endings,
+        encoding: state.config.position_encoding(),
+    };
+    let cache = state.config.verification_cache();
+    for (key, range) in pending.keys {
+        let verified = match range {
+            Some(range) if functions.contains(&range) => {
+                let range = to_proto::range(&line_index, range);
+                errors.iter().all(|error| error.end < range.start || range.end < error.start)
+            }
+            Some(_) => false,
+            None => errors.is_empty(),
+        };
+        if verified {
+            cache.record_verified(key);
+        }
+    }
+}
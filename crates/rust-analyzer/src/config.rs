@@ -15,6 +15,10 @@ use ide::{
     InlayHintsConfig, JoinLinesConfig, MemoryLayoutHoverConfig, MemoryLayoutHoverRenderKind,
     Snippet, SnippetScope, SourceRootId,
 };
+use ide_assists::proof_plumber_api::{
+    verification_cache::VerificationCache,
+    verifier::{Verifier, VerusBinary},
+};
 use ide_db::{
     imports::insert_use::{ImportGranularity, InsertUseConfig, PrefixKind},
     SnippetCap,
@@ -175,6 +179,9 @@ config_data! {
         verus_extraArgs: Vec<String> = vec![],
         /// Setting this to true causes verus-analyzer to report errors in all files, not just the one you are editing.
         verus_reportAllErrorsEnable: bool = false,
+        /// Path to the `verus` binary, or to a directory holding a Verus release. When unset,
+        /// `$VERUS_BINARY_PATH` and then `$PATH` are searched.
+        verus_verusBinary: Option<Utf8PathBuf> = None,
 
         /// Run the check command for diagnostics on save.
         checkOnSave | checkOnSave_enable: bool                         = true,
@@ -703,6 +710,15 @@ pub struct Config {
     source_root_parent_map: Arc<FxHashMap<SourceRootId, SourceRootId>>,
 
     detached_files: Vec<AbsPathBuf>,
+
+    /// The `verus` binary, looked up when the `verus.verusBinary` setting changes rather than on
+    /// every use, together with the setting it was looked up for. `None` until the first
+    /// configuration change, so that the binary is only searched once the client's settings are
+    /// known.
+    verus_binary_path: Option<(Option<Utf8PathBuf>, Option<Utf8PathBuf>)>,
+
+    /// Verification results of proof actions and flycheck, shared by all clones of the config.
+    verification_cache: std::sync::Arc<VerificationCache>,
 }
 
 impl Config {
@@ -893,6 +909,11 @@ impl Config {
         //         error: serde_json::Error::custom("expected a non-empty string"),
         //     });
         // }
+        let setting = config.verus_binary().map(Utf8Path::to_path_buf);
+        if config.verus_binary_path.as_ref().map_or(true, |(looked_up, _)| *looked_up != setting) {
+            let found = toolchain::find_verus(setting.as_deref()).ok();
+            config.verus_binary_path = Some((setting, found));
+        }
         (config, should_update)
     }
 
@@ -1125,6 +1146,10 @@ pub struct RunnablesConfig {
     pub cargo_extra_args: Vec<String>,
     /// Additional arguments for the binary being run, if it is a test or benchmark.
     pub extra_test_binary_args: Vec<String>,
+    /// Whether verification runnables go through `cargo verus` instead of `verus`.
+    pub cargo_verus: bool,
+    /// Extra Verus arguments for verification runnables.
+    pub verus_extra_args: Vec<String>,
 }
 
 /// Configuration for workspace symbol search requests.
@@ -1144,6 +1169,7 @@ pub struct ClientCommandsConfig {
     pub show_reference: bool,
     pub goto_location: bool,
     pub trigger_parameter_hints: bool,
+    pub run_proof_action: bool,
 }
 
 #[derive(Debug)]
@@ -1205,6 +1231,9 @@ impl Config {
         // FIXME @alibektas : Temporary solution. I don't think this is right as at some point we may allow users to specify
         // custom USER_CONFIG_PATHs which may also be relative.
         let user_config_path = VfsPath::from(AbsPathBuf::assert(user_config_path));
+        let verification_cache = std::sync::Arc::new(VerificationCache::new(
+            root_path.join("target").join("verus-analyzer").join("verification-cache.jsonl").into(),
+        ));
         let root_ratoml_path = {
             let mut p = root_path.clone();
             p.push("rust-analyzer.toml");
@@ -1227,6 +1256,8 @@ impl Config {
             root_ratoml: None,
             root_ratoml_path,
             detached_files: Default::default(),
+            verus_binary_path: None,
+            verification_cache,
         }
     }
 
@@ -1276,6 +1307,11 @@ impl Config {
             assist_emit_must_use: self.assist_emitMustUse(source_root).to_owned(),
             prefer_prelude: self.imports_preferPrelude(source_root).to_owned(),
             term_search_fuel: self.assist_termSearch_fuel(source_root).to_owned() as u64,
+            verifier: self.verus_binary_path().map(|path| {
+                std::sync::Arc::new(VerusBinary::new(path.to_owned()))
+                    as std::sync::Arc<dyn Verifier>
+            }),
+            verification_cache: Some(self.verification_cache.clone()),
         }
     }
 
@@ -1946,6 +1982,7 @@ impl Config {
                 }
             }
             Some(_) | None => FlycheckConfig::VerusCommand {
+                verus_binary: self.verus_verusBinary().clone(),
                 verus_args: self.verus_extraArgs().clone(),
                 cargo_verus_enable: *self.cargo_verusEnable(),
                 cargo_options: self.cargo_test_options(),
@@ -1969,6 +2006,23 @@ impl Config {
         *self.checkOnSave()
     }
 
+    pub fn verus_binary(&self) -> Option<&Utf8Path> {
+        self.verus_verusBinary().as_deref()
+    }
+
+    /// The `verus` binary found for [`Config::verus_binary`], if any.
+    pub fn verus_binary_path(&self) -> Option<&Utf8Path> {
+        self.verus_binary_path.as_ref()?.1.as_deref()
+    }
+
+    pub fn verification_cache(&self) -> &std::sync::Arc<VerificationCache> {
+        &self.verification_cache
+    }
+
+    pub fn verus_report_all_errors(&self) -> bool {
+        *self.verus_reportAllErrorsEnable()
+    }
+
     pub fn script_rebuild_on_save(&self) -> bool {
         *self.cargo_buildScripts_rebuildOnSave()
     }
@@ -1978,6 +2032,8 @@ impl Config {
             override_cargo: self.runnables_command().clone(),
             cargo_extra_args: self.runnables_extraArgs().clone(),
             extra_test_binary_args: self.runnables_extraTestBinaryArgs().clone(),
+            cargo_verus: *self.cargo_verusEnable(),
+            verus_extra_args: self.verus_extraArgs().clone(),
         }
     }
 
@@ -2073,6 +2129,7 @@ impl Config {
             show_reference: get("rust-analyzer.showReferences"),
             goto_location: get("rust-analyzer.gotoLocation"),
             trigger_parameter_hints: get("editor.action.triggerParameterHints"),
+            run_proof_action: get("verus-analyzer.runProofAction"),
         }
     }
 
@@ -2738,7 +2795,9 @@ impl FullConfigInput {
     }
 
     fn json_schema() -> serde_json::Value {
-        schema(&Self::schema_fields())
+        let mut fields = Self::schema_fields();
+        fields.retain(|(field, ..)| !CLIENT_DECLARED_FIELDS.contains(field));
+        schema(&fields)
     }
 
     #[cfg(test)]
@@ -2852,6 +2911,9 @@ fn toml_pointer<'a>(toml: &'a toml::Table, pointer: &str) -> Option<&'a toml::Va
     })
 }
 
+/// Settings the VS Code extension declares in `package.json` itself, as it reads them too.
+const CLIENT_DECLARED_FIELDS: &[&str] = &["verus_verusBinary"];
+
 type SchemaField = (&'static str, &'static str, &'static [&'static str], String);
 
 fn schema(fields: &[SchemaField]) -> serde_json::Value {
@@ -3391,6 +3453,30 @@ mod tests {
         text.replace(char::is_whitespace, "")
     }
 
+    #[test]
+    fn verus_binary_looked_up_with_client_config() {
+        let mut config = Config::new(
+            AbsPathBuf::try_from(project_root()).unwrap(),
+            Default::default(),
+            vec![],
+            None,
+            None,
+        );
+        assert_eq!(config.verus_binary_path, None);
+
+        let missing = project_root().join("no-such-verus");
+        let mut change = ConfigChange::default();
+        change.change_client_config(serde_json::json!({
+            "verus": {
+                "verusBinary": missing.display().to_string(),
+        }}));
+
+        (config, _, _) = config.apply_change(change);
+        // a configured binary that does not exist is not replaced by one in `$PATH`
+        assert_eq!(config.verus_binary(), Some(Utf8Path::new(&missing.display().to_string())));
+        assert_eq!(config.verus_binary_path(), None);
+    }
+
     #[test]
     fn proc_macro_srv_null() {
         let mut config = Config::new(
@@ -57,6 +57,17 @@ impl DiagnosticCollection {
         }
     }
 
+    pub(crate) fn clear_check_for(&mut self, flycheck_id: usize, file_id: FileId) {
+        if let Some(it) = Arc::make_mut(&mut self.check_fixes).get_mut(&flycheck_id) {
+            it.remove(&file_id);
+        }
+        if let Some(it) = self.check.get_mut(&flycheck_id) {
+            if it.remove(&file_id).is_some() {
+                self.changes.insert(file_id);
+            }
+        }
+    }
+
     pub(crate) fn clear_check_all(&mut self) {
         Arc::make_mut(&mut self.check_fixes).clear();
         self.changes
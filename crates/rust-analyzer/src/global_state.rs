@@ -9,6 +9,10 @@ use crossbeam_channel::{unbounded, Receiver, Sender};
 use flycheck::FlycheckHandle;
 use hir::ChangeWithProcMacros;
 use ide::{Analysis, AnalysisHost, Cancellable, FileId, SourceRootId};
+use ide_assists::{
+    proof_plumber_api::verifier::{Verifier, VerusBinary},
+    AssistConfig,
+};
 use ide_db::base_db::{CrateId, ProcMacroPaths, SourceDatabaseExt};
 use load_cargo::SourceRootConfig;
 use lsp_types::{SemanticTokens, Url};
@@ -17,8 +21,11 @@ use parking_lot::{
     MappedRwLockReadGuard, Mutex, RwLock, RwLockReadGuard, RwLockUpgradableReadGuard,
     RwLockWriteGuard,
 };
+use paths::AbsPathBuf;
 use proc_macro_api::ProcMacroServer;
-use project_model::{ManifestPath, ProjectWorkspace, ProjectWorkspaceKind, WorkspaceBuildScripts};
+use project_model::{
+    ManifestPath, ProjectWorkspace, ProjectWorkspaceKind, TargetKind, WorkspaceBuildScripts,
+};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{span, Level};
 use triomphe::Arc;
@@ -39,6 +46,7 @@ use crate::{
     reload,
     target_spec::{CargoTargetSpec, ProjectJsonTargetSpec, TargetSpec},
     task_pool::{TaskPool, TaskQueue},
+    verus_interaction::{FileVerusError, VerusErrors},
 };
 
 // Enforces drop order
@@ -88,12 +96,21 @@ pub(crate) struct GlobalState {
     pub(crate) flycheck_sender: Sender<flycheck::Message>,
     pub(crate) flycheck_receiver: Receiver<flycheck::Message>,
     pub(crate) last_flycheck_error: Option<String>,
+    pub(crate) verus_toolchain_warning: Option<String>,
+    pub(crate) verus_toolchain_checked: bool,
 
     // Test explorer
     pub(crate) test_run_session: Option<Vec<flycheck::CargoTestHandle>>,
     pub(crate) test_run_sender: Sender<flycheck::CargoTestMessage>,
     pub(crate) test_run_receiver: Receiver<flycheck::CargoTestMessage>,
     pub(crate) test_run_remaining_jobs: usize,
+    pub(crate) verification_run: Option<crate::verification_run::VerificationRun>,
+    /// The flychecks started on saved files, whose results go into the verification cache
+    pub(crate) pending_verifications:
+        IntMap<usize, crate::cached_verification::PendingVerification>,
+
+    // Proof actions running in the background
+    pub(crate) proof_actions: crate::proof_action::ProofActions,
 
     // VFS
     pub(crate) loader: Handle<Box<dyn vfs::loader::Handle>, Receiver<vfs::loader::Message>>,
@@ -151,7 +168,7 @@ pub(crate) struct GlobalState {
     /// been called.
     pub(crate) deferred_task_queue: TaskQueue,
     // verus
-    pub(crate) verus_errors: Vec<ide_assists::proof_plumber_api::verus_error::VerusError>,
+    pub(crate) verus_errors: VerusErrors,
 }
 
 /// An immutable snapshot of the world's state at a point in time.
@@ -169,7 +186,7 @@ pub(crate) struct GlobalStateSnapshot {
     pub(crate) proc_macros_loaded: bool,
     pub(crate) flycheck: Arc<[FlycheckHandle]>,
     // verus
-    pub(crate) verus_errors: Vec<ide_assists::proof_plumber_api::verus_error::VerusError>,
+    verus_errors: Arc<IntMap<FileId, Vec<FileVerusError>>>,
 }
 
 impl std::panic::UnwindSafe for GlobalStateSnapshot {}
@@ -231,11 +248,17 @@ impl GlobalState {
             flycheck_sender,
             flycheck_receiver,
             last_flycheck_error: None,
+            verus_toolchain_warning: None,
+            verus_toolchain_checked: false,
 
             test_run_session: None,
             test_run_sender,
             test_run_receiver,
             test_run_remaining_jobs: 0,
+            verification_run: None,
+            pending_verifications: IntMap::default(),
+
+            proof_actions: Default::default(),
 
             vfs: Arc::new(RwLock::new((vfs::Vfs::default(), IntMap::default()))),
             vfs_config_version: 0,
@@ -253,7 +276,7 @@ impl GlobalState {
             prime_caches_queue: OpQueue::default(),
 
             deferred_task_queue: task_queue,
-            verus_errors: Vec::new(),
+            verus_errors: VerusErrors::default(),
         };
         // Apply any required database inputs from the config.
         this.update_configuration(config);
@@ -331,7 +354,7 @@ impl GlobalState {
                 bytes.push((file.file_id, text));
             }
             let (vfs, line_endings_map) = &mut *RwLockUpgradableReadGuard::upgrade(guard);
-            bytes.into_iter().for_each(|(file_id, text)| {
+            for (file_id, text) in bytes {
                 let text = match text {
                     None => None,
                     Some((text, line_endings)) => {
@@ -339,8 +362,12 @@ impl GlobalState {
                         Some(text)
                     }
                 };
+                if self.verus_errors.has_errors_for(file_id) {
+                    let old_text = self.analysis_host.raw_database().file_text(file_id);
+                    self.verus_errors.apply_edit(file_id, &old_text, text.as_deref());
+                }
                 change.change_file(file_id, text);
-            });
+            }
             if has_structure_changes {
                 let roots = self.source_root_config.partition(vfs);
                 change.set_roots(roots);
@@ -444,7 +471,7 @@ impl GlobalState {
             proc_macros_loaded: !self.config.expand_proc_macros()
                 || *self.fetch_proc_macros_queue.last_op_result(),
             flycheck: self.flycheck.clone(),
-            verus_errors: self.verus_errors.clone(),
+            verus_errors: self.verus_errors.committed(),
         }
     }
 
@@ -560,6 +587,77 @@ impl GlobalStateSnapshot {
         self.vfs_read().file_path(file_id).clone()
     }
 
+    /// The errors of the last finished verification of `file_id`, moved along with later edits.
+    pub(crate) fn verus_errors(
+        &self,
+        file_id: FileId,
+    ) -> Vec<ide_assists::proof_plumber_api::verus_error::VerusError> {
+        let errors = self.verus_errors.get(&file_id).into_iter().flatten();
+        errors.map(|it| it.error.clone()).collect()
+    }
+
+    /// The assists configuration of `source_root`. Verus is told which crates are binaries, so
+    /// that it verifies everything else as a library, and the Verus arguments of their packages.
+    pub(crate) fn assist_config(&self, source_root: Option<SourceRootId>) -> AssistConfig {
+        let mut config = self.config.assist(source_root);
+        config.verifier = self.config.verus_binary_path().map(|path| {
+            let verus = VerusBinary::new(path.to_owned())
+                .with_bin_roots(self.bin_roots())
+                .with_crate_args(self.crate_verus_args());
+            std::sync::Arc::new(verus) as std::sync::Arc<dyn Verifier>
+        });
+        config
+    }
+
+    /// The `extra_args` and `rlimit` of the Verus metadata of local packages, by crate root
+    fn crate_verus_args(&self) -> Vec<(AbsPathBuf, Vec<String>)> {
+        let mut crate_args = Vec::new();
+        for workspace in self.workspaces.iter() {
+            if let ProjectWorkspaceKind::Cargo { cargo, .. }
+            | ProjectWorkspaceKind::DetachedFile { cargo: Some((cargo, _)), .. } =
+                &workspace.kind
+            {
+                for pkg in cargo.packages().filter(|&pkg| cargo[pkg].is_local) {
+                    let args = cargo[pkg].verus_metadata.verus_args();
+                    if args.is_empty() {
+                        continue;
+                    }
+                    crate_args.extend(
+                        cargo[pkg].targets.iter().map(|&it| (cargo[it].root.clone(), args.clone())),
+                    );
+                }
+            }
+        }
+        crate_args
+    }
+
+    fn bin_roots(&self) -> Vec<AbsPathBuf> {
+        let mut roots = Vec::new();
+        for workspace in self.workspaces.iter() {
+            match &workspace.kind {
+                ProjectWorkspaceKind::Cargo { cargo, .. }
+                | ProjectWorkspaceKind::DetachedFile { cargo: Some((cargo, _)), .. } => {
+                    let targets = cargo.packages().flat_map(|pkg| cargo[pkg].targets.iter());
+                    roots.extend(
+                        targets
+                            .filter(|&&target| cargo[target].kind == TargetKind::Bin)
+                            .map(|&target| cargo[target].root.clone()),
+                    );
+                }
+                ProjectWorkspaceKind::Json(project) => roots.extend(
+                    project
+                        .crates()
+                        .filter(|(_, krate)| {
+                            krate.build.as_ref().is_some_and(|it| it.target_kind == TargetKind::Bin)
+                        })
+                        .map(|(_, krate)| krate.root_module.clone()),
+                ),
+                ProjectWorkspaceKind::DetachedFile { .. } => (),
+            }
+        }
+        roots
+    }
+
     pub(crate) fn target_spec_for_crate(&self, crate_id: CrateId) -> Option<TargetSpec> {
         let file_id = self.analysis.crate_root(crate_id).ok()?;
         let path = self.vfs_read().file_path(file_id).clone();
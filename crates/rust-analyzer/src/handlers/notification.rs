@@ -3,6 +3,8 @@
 
 use std::ops::{Deref, Not as _};
 
+use crossbeam_channel::Sender;
+use flycheck::{FlycheckHandle, VerusPackageOptions};
 use itertools::Itertools;
 use lsp_types::{
     CancelParams, DidChangeConfigurationParams, DidChangeTextDocumentParams,
@@ -13,12 +15,14 @@ use triomphe::Arc;
 use vfs::{AbsPathBuf, ChangeKind, VfsPath};
 
 use crate::{
+    cached_verification::{self, CachedVerification, Plan},
     config::{Config, ConfigChange},
     global_state::GlobalState,
     lsp::{from_proto, utils::apply_document_changes},
     lsp_ext::{self, RunFlycheckParams},
+    main_loop::Task,
     mem_docs::DocumentData,
-    reload,
+    proof_action, reload, verus_interaction,
 };
 
 pub(crate) fn handle_cancel(state: &mut GlobalState, params: CancelParams) -> anyhow::Result<()> {
@@ -42,6 +46,9 @@ pub(crate) fn handle_work_done_progress_cancel(
                 }
             }
         }
+        if s.starts_with("rust-analyzer/proofAction/") {
+            proof_action::cancel(state, s);
+        }
     }
 
     // Just ignore this. It is OK to continue sending progress
@@ -259,13 +266,15 @@ pub(crate) fn handle_did_change_watched_files(
 }
 
 fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
+    state.check_verus_toolchain();
     let _p = tracing::info_span!("run_flycheck").entered();
 
     let file_id = state.vfs.read().0.file_id(&vfs_path);
     if let Some(file_id) = file_id {
+        let scope = (!state.config.verus_report_all_errors()).then_some(file_id);
         let world = state.snapshot();
         let mut updated = false;
-        let task = move || -> std::result::Result<(), ide::Cancelled> {
+        let mut task = move |sender: Sender<Task>| -> std::result::Result<(), ide::Cancelled> {
             // Trigger flychecks for all workspaces that depend on the saved file
             // Crates containing or depending on the saved file
             let crate_ids: Vec<_> = world
@@ -320,13 +329,50 @@ fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
             });
 
             let saved_file = vfs_path.as_path().map(|p| p.to_owned());
+            let verus_package = match &saved_file {
+                Some(path) => verus_interaction::verus_package_options(&world.workspaces, path),
+                None => Some(Default::default()),
+            };
+
+            // Only verify what the verification cache does not know to verify
+            let plan = match verus_package {
+                Some(_) => cached_verification::plan(&world, file_id)?,
+                None => None,
+            };
+            let restart_verus = |flycheck: &FlycheckHandle, package: &VerusPackageOptions| {
+                let file = vfs_path.to_string();
+                let flycheck_id = flycheck.id();
+                sender.send(Task::VerificationRequested { flycheck_id, scope }).unwrap();
+                let (module_args, pending) = match &plan {
+                    Some(Plan::Skip) => {
+                        let skipped = CachedVerification::Skipped { flycheck_id, file_id };
+                        sender.send(Task::CachedVerification(skipped)).unwrap();
+                        return;
+                    }
+                    Some(Plan::Verify { module_args, pending }) => (module_args, Some(pending)),
+                    None => (&None, None),
+                };
+                if let Some(pending) = pending {
+                    let pending = pending.clone();
+                    let started = CachedVerification::Started { flycheck_id, pending };
+                    sender.send(Task::CachedVerification(started)).unwrap();
+                }
+                match module_args {
+                    Some(module_args) => {
+                        flycheck.restart_verus_module(file, package.clone(), module_args.clone())
+                    }
+                    None => flycheck.restart_verus(file, package.clone()),
+                }
+            };
 
             // Find and trigger corresponding flychecks
             for flycheck in world.flycheck.iter() {
                 for (id, package) in workspace_ids.clone() {
                     if id == flycheck.id() {
                         updated = true;
-                        flycheck.restart_verus(vfs_path.to_string());
+                        if let Some(package) = &verus_package {
+                            restart_verus(flycheck, package);
+                        }
                         match package.filter(|_| !world.config.flycheck_workspace()) {
                             Some(package) => flycheck.restart_for_package(package),
                             None => flycheck.restart_workspace(saved_file.clone()),
@@ -338,17 +384,22 @@ fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
             // No specific flycheck was triggered, so let's trigger all of them.
             if !updated {
                 for flycheck in world.flycheck.iter() {
-                    flycheck.restart_verus(vfs_path.to_string());
+                    if let Some(package) = &verus_package {
+                        restart_verus(flycheck, package);
+                    }
                     flycheck.restart_workspace(saved_file.clone());
                 }
             }
             Ok(())
         };
-        state.task_pool.handle.spawn_with_sender(stdx::thread::ThreadIntent::Worker, move |_| {
-            if let Err(e) = std::panic::catch_unwind(task) {
-                tracing::error!("flycheck task panicked: {e:?}")
-            }
-        });
+        state.task_pool.handle.spawn_with_sender(
+            stdx::thread::ThreadIntent::Worker,
+            move |sender| {
+                if let Err(e) = std::panic::catch_unwind(move || task(sender)) {
+                    tracing::error!("flycheck task panicked: {e:?}")
+                }
+            },
+        );
         true
     } else {
         false
@@ -387,7 +438,8 @@ pub(crate) fn handle_run_flycheck(
 }
 
 pub(crate) fn handle_abort_run_test(state: &mut GlobalState, _: ()) -> anyhow::Result<()> {
-    if state.test_run_session.take().is_some() {
+    let had_session = state.test_run_session.take().is_some();
+    if crate::verification_run::cancel(state) || had_session {
         state.send_notification::<lsp_ext::EndRunTest>(());
     }
     Ok(())
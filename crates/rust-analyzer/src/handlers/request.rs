@@ -10,9 +10,10 @@ use std::{
 use anyhow::Context;
 
 use ide::{
-    AnnotationConfig, AssistKind, AssistResolveStrategy, Cancellable, FilePosition, FileRange,
-    HoverAction, HoverGotoTypeData, InlayFieldsToResolve, Query, RangeInfo, ReferenceCategory,
-    Runnable, RunnableKind, SingleResolve, SourceChange, TextEdit,
+    AnnotationConfig, Assist, AssistConfig, AssistKind, AssistResolveStrategy, Cancellable,
+    FilePosition, FileRange, FnModeFilter, HoverAction, HoverGotoTypeData, InlayFieldsToResolve,
+    Query, RangeInfo, ReferenceCategory, Runnable, RunnableKind, SingleResolve, SourceChange,
+    TextEdit,
 };
 use ide_db::SymbolKind;
 use itertools::Itertools;
@@ -39,7 +40,7 @@ use crate::{
     diff::diff,
     global_state::{GlobalState, GlobalStateSnapshot},
     hack_recover_crate_name,
-    line_index::LineEndings,
+    line_index::{LineEndings, LineIndex},
     lsp::{
         ext::InternalTestingFetchConfigParams,
         from_proto, to_proto,
@@ -50,7 +51,10 @@ use crate::{
         self, CrateInfoResult, ExternalDocsPair, ExternalDocsResponse, FetchDependencyListParams,
         FetchDependencyListResult, PositionOrRange, ViewCrateGraphParams, WorkspaceSymbolParams,
     },
+    proof_action,
     target_spec::TargetSpec,
+    verification_run::{self, VerificationItem, VerificationTarget},
+    verus_interaction,
 };
 
 pub(crate) fn handle_workspace_reload(state: &mut GlobalState, _: ()) -> anyhow::Result<()> {
@@ -195,13 +199,28 @@ pub(crate) fn handle_run_test(
     state: &mut GlobalState,
     params: lsp_ext::RunTestParams,
 ) -> anyhow::Result<()> {
-    if let Some(_session) = state.test_run_session.take() {
+    let had_session = state.test_run_session.take().is_some();
+    if verification_run::cancel(state) || had_session {
         state.send_notification::<lsp_ext::EndRunTest>(());
     }
+    // Functions checked by Verus are run through flycheck instead of `cargo test`. A selection
+    // mixing both kinds runs both, and the test run ends once both are done.
+    let (targets, has_cargo_tests) =
+        verification_targets(&state.snapshot(), params.include.as_deref())?;
+    if targets.is_empty() || has_cargo_tests {
+        run_cargo_tests(state, params.include)?;
+    }
+    if !targets.is_empty() {
+        verification_run::start(state, targets);
+    }
+    Ok(())
+}
+
+fn run_cargo_tests(state: &mut GlobalState, include: Option<Vec<String>>) -> anyhow::Result<()> {
     // We detect the lowest common ansector of all included tests, and
     // run it. We ignore excluded tests for now, the client will handle
     // it for us.
-    let lca = match params.include {
+    let lca = match include {
         Some(tests) => tests
             .into_iter()
             .reduce(|x, y| {
@@ -238,10 +257,84 @@ pub(crate) fn handle_run_test(
     }
     // Each process send finished signal twice, once for stdout and once for stderr
     state.test_run_remaining_jobs = 2 * handles.len();
-    state.test_run_session = Some(handles);
+    // Without any, there is no session whose end a verification run would have to wait for.
+    state.test_run_session = (!handles.is_empty()).then_some(handles);
     Ok(())
 }
 
+/// Groups the selected Verus functions into verification targets, also telling whether the
+/// selection contains any `#[test]`.
+fn verification_targets(
+    snap: &GlobalStateSnapshot,
+    include: Option<&[String]>,
+) -> anyhow::Result<(Vec<VerificationTarget>, bool)> {
+    let crates: Vec<String> = match include {
+        Some(ids) => ids
+            .iter()
+            .map(|id| id.split_once("::").map_or(id.as_str(), |it| it.0).to_owned())
+            .unique()
+            .collect(),
+        None => snap.analysis.discover_test_roots()?.into_iter().map(|it| it.id).collect(),
+    };
+    let is_selected = |test_id: &str| {
+        include.map_or(true, |ids| {
+            ids.iter().any(|id| {
+                test_id == id
+                    || test_id.strip_prefix(id.as_str()).is_some_and(|it| it.starts_with("::"))
+            })
+        })
+    };
+    let mut targets = vec![];
+    let mut has_cargo_tests = false;
+    for krate in crates {
+        // Verus verifies a whole module at once, so functions are grouped by module.
+        let mut crate_targets: Vec<VerificationTarget> = vec![];
+        for test in snap.analysis.discover_tests_in_crate_by_test_id(&krate)? {
+            let Some(runnable) = &test.runnable else { continue };
+            if !is_selected(&test.id) {
+                continue;
+            }
+            let module = match &runnable.kind {
+                RunnableKind::Verify { module, .. } => module,
+                RunnableKind::Test { .. } => {
+                    has_cargo_tests = true;
+                    continue;
+                }
+                _ => continue,
+            };
+            let file_id = runnable.nav.file_id;
+            let file_path = snap.file_id_to_file_path(file_id);
+            let package = match file_path.as_path() {
+                Some(path) => verus_interaction::verus_package_options(&snap.workspaces, path),
+                None => Some(Default::default()),
+            };
+            // The package opted out of verification.
+            let Some(package) = package else { continue };
+            let line_index = snap.file_line_index(file_id)?;
+            let item = VerificationItem {
+                test_id: test.id,
+                file_id,
+                range: to_proto::range(&line_index, runnable.nav.full_range),
+            };
+            let module_args = match module {
+                Some(module) => vec!["--verify-module".to_owned(), module.clone()],
+                None => vec!["--verify-root".to_owned()],
+            };
+            match crate_targets.iter_mut().find(|it| it.module_args == module_args) {
+                Some(target) => target.items.push(item),
+                None => crate_targets.push(VerificationTarget {
+                    file_path: file_path.to_string(),
+                    package,
+                    module_args,
+                    items: vec![item],
+                }),
+            }
+        }
+        targets.extend(crate_targets);
+    }
+    Ok((targets, has_cargo_tests))
+}
+
 pub(crate) fn handle_discover_test(
     snap: GlobalStateSnapshot,
     params: lsp_ext::DiscoverTestParams,
@@ -538,10 +631,13 @@ pub(crate) fn handle_workspace_symbol(
     let _p = tracing::info_span!("handle_workspace_symbol").entered();
 
     let config = snap.config.workspace_symbol();
+    let (fn_mode, query) = FnModeFilter::split_query(&params.query);
     let (all_symbols, libs) = decide_search_scope_and_kind(&params, &config);
+    // Mode filters only ever match functions, so they imply searching all symbols.
+    let all_symbols = all_symbols || fn_mode.is_some();
 
     let query = {
-        let query: String = params.query.chars().filter(|&c| c != '#' && c != '*').collect();
+        let query: String = query.chars().filter(|&c| c != '#' && c != '*').collect();
         let mut q = Query::new(query);
         if !all_symbols {
             q.only_types();
@@ -549,6 +645,9 @@ pub(crate) fn handle_workspace_symbol(
         if libs {
             q.libs();
         }
+        if let Some(fn_mode) = fn_mode {
+            q.fn_mode(fn_mode);
+        }
         q
     };
     let mut res = exec_query(&snap, query, config.search_limit)?;
@@ -920,7 +1019,15 @@ pub(crate) fn handle_runnables(
 fn should_skip_for_offset(runnable: &Runnable, offset: Option<TextSize>) -> bool {
     match offset {
         None => false,
-        _ if matches!(&runnable.kind, RunnableKind::TestMod { .. }) => false,
+        _ if matches!(
+            &runnable.kind,
+            RunnableKind::TestMod { .. }
+                | RunnableKind::VerifyMod { .. }
+                | RunnableKind::VerifyCrate
+        ) =>
+        {
+            false
+        }
         Some(offset) => !runnable.nav.full_range.contains_inclusive(offset),
     }
 }
@@ -1235,7 +1342,7 @@ pub(crate) fn handle_code_action(
     let frange = from_proto::file_range(&snap, &params.text_document, params.range)?;
     let source_root = snap.analysis.source_root_id(file_id)?;
 
-    let mut assists_config = snap.config.assist(Some(source_root));
+    let mut assists_config = snap.assist_config(Some(source_root));
     assists_config.allowed = params
         .context
         .only
@@ -1255,15 +1362,24 @@ pub(crate) fn handle_code_action(
         &snap.config.diagnostics(Some(source_root)),
         resolve,
         frange,
-        snap.verus_errors.clone(),
+        snap.verus_errors(frange.file_id),
     )?;
+    let run_proof_action = snap.config.client_commands().run_proof_action;
     for (index, assist) in assists.into_iter().enumerate() {
         let resolve_data = if code_action_resolve_cap {
             Some((index, params.clone(), snap.file_version(file_id)))
         } else {
             None
         };
-        let code_action = to_proto::code_action(&snap, assist, resolve_data)?;
+        let verifying = ide_assists::VERIFYING_ASSISTS.contains(&assist.id.0);
+        let mut code_action = to_proto::code_action(&snap, assist, resolve_data)?;
+        // Resolving these runs Verus, which would block the client; run them in the background.
+        if verifying && run_proof_action {
+            if let Some(data) = code_action.data.take() {
+                code_action.command =
+                    Some(to_proto::command::run_proof_action(&code_action.title, data));
+            }
+        }
 
         // Check if the client supports the necessary `ResourceOperation`s.
         let changes = code_action.edit.as_ref().and_then(|it| it.document_changes.as_ref());
@@ -1305,7 +1421,110 @@ pub(crate) fn handle_code_action_resolve(
         return Err(invalid_params_error("code action without data".to_owned()).into());
     };
 
-    let file_id = from_proto::file_id(&snap, &params.code_action_params.text_document.uri)?;
+    let assist = resolve_assist(&snap, params, |_| ())?;
+    let ca = to_proto::code_action(&snap, assist, None)?;
+    code_action.edit = ca.edit;
+    code_action.command = ca.command;
+
+    if let Some(edit) = code_action.edit.as_ref() {
+        if let Some(changes) = edit.document_changes.as_ref() {
+            for change in changes {
+                if let lsp_ext::SnippetDocumentChangeOperation::Op(res_op) = change {
+                    resource_ops_supported(&snap.config, resolve_resource_op(res_op))?
+                }
+            }
+        }
+    }
+
+    Ok(code_action)
+}
+
+pub(crate) fn handle_run_proof_action(
+    state: &mut GlobalState,
+    params: lsp_ext::CodeActionData,
+) -> anyhow::Result<()> {
+    let _p = tracing::info_span!("handle_run_proof_action").entered();
+    // the assist is resolved, and checked for staleness, in the background
+    proof_action::start(state, params);
+    Ok(())
+}
+
+pub(crate) fn handle_minimize_proof(
+    state: &mut GlobalState,
+    params: lsp_ext::MinimizeProofParams,
+) -> anyhow::Result<()> {
+    let _p = tracing::info_span!("handle_minimize_proof").entered();
+    proof_action::start_minimize(state, params);
+    Ok(())
+}
+
+pub(crate) fn handle_verify_candidate(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::VerifyCandidateParams,
+) -> anyhow::Result<lsp_ext::VerifyCandidateResult> {
+    let _p = tracing::info_span!("handle_verify_candidate").entered();
+    let position = from_proto::file_position(&snap, params.text_document_position)?;
+    let edit = from_proto::file_text_edit(&snap, position.file_id, params.edit)?;
+    let source_root = snap.analysis.source_root_id(position.file_id)?;
+    let config = snap.assist_config(Some(source_root));
+    let result =
+        snap.analysis.verify_candidate(&config, position, &edit)?.map_err(invalid_params_error)?;
+
+    // the failures are in the edited document
+    let mut text = snap.analysis.file_text(position.file_id)?.to_string();
+    edit.apply(&mut text);
+    let line_index = LineIndex {
+        index: Arc::new(ide::LineIndex::new(&text)),
+        ..snap.file_line_index(position.file_id)?
+    };
+    Ok(lsp_ext::VerifyCandidateResult {
+        verified: result.verified,
+        failures: result
+            .failures
+            .into_iter()
+            .map(|it| to_proto::verification_failure(&line_index, it))
+            .collect(),
+        stderr: result.stderr,
+        time_ms: result.time.as_millis() as u64,
+    })
+}
+
+pub(crate) fn handle_function_context(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+) -> anyhow::Result<Option<lsp_ext::FunctionContextResult>> {
+    let _p = tracing::info_span!("handle_function_context").entered();
+    let position = from_proto::file_position(&snap, params)?;
+    let Some(context) = snap.analysis.function_context(position)? else { return Ok(None) };
+    Ok(Some(lsp_ext::FunctionContextResult {
+        function: to_proto::function_contract(&snap, context.contract)?,
+        vst: context.vst,
+        callees: context
+            .callees
+            .into_iter()
+            .map(|it| to_proto::function_contract(&snap, it))
+            .collect::<Cancellable<_>>()?,
+    }))
+}
+
+pub(crate) fn handle_search_proof(
+    state: &mut GlobalState,
+    params: lsp_types::TextDocumentPositionParams,
+) -> anyhow::Result<()> {
+    let _p = tracing::info_span!("handle_search_proof").entered();
+    proof_action::start_search(state, params);
+    Ok(())
+}
+
+/// Computes the edit of the assist a lazily resolved code action stands for.
+///
+/// `configure` may adjust the assist config, e.g. to run Verus with progress reporting.
+pub(crate) fn resolve_assist(
+    snap: &GlobalStateSnapshot,
+    params: lsp_ext::CodeActionData,
+    configure: impl FnOnce(&mut AssistConfig),
+) -> anyhow::Result<Assist> {
+    let file_id = from_proto::file_id(snap, &params.code_action_params.text_document.uri)?;
     if snap.file_version(file_id) != params.version {
         return Err(invalid_params_error("stale code action".to_owned()).into());
     }
@@ -1314,12 +1533,13 @@ pub(crate) fn handle_code_action_resolve(
     let frange = FileRange { file_id, range };
     let source_root = snap.analysis.source_root_id(file_id)?;
 
-    let mut assists_config = snap.config.assist(Some(source_root));
+    let mut assists_config = snap.assist_config(Some(source_root));
     assists_config.allowed = params
         .code_action_params
         .context
         .only
         .map(|it| it.into_iter().filter_map(from_proto::assist_kind).collect());
+    configure(&mut assists_config);
 
     let (assist_index, assist_resolve) = match parse_action_id(&params.id) {
         Ok(parsed_data) => parsed_data,
@@ -1335,22 +1555,22 @@ pub(crate) fn handle_code_action_resolve(
     let expected_assist_id = assist_resolve.assist_id.clone();
     let expected_kind = assist_resolve.assist_kind;
 
-    let assists = snap.analysis.assists_with_fixes(
+    let mut assists = snap.analysis.assists_with_fixes(
         &assists_config,
         &snap.config.diagnostics(Some(source_root)),
         AssistResolveStrategy::Single(assist_resolve),
         frange,
-        snap.verus_errors.clone(),
+        snap.verus_errors(frange.file_id),
     )?;
 
-    let assist = match assists.get(assist_index) {
-        Some(assist) => assist,
-        None => return Err(invalid_params_error(format!(
+    if assist_index >= assists.len() {
+        return Err(invalid_params_error(format!(
             "Failed to find the assist for index {} provided by the resolve request. Resolve request assist id: {}",
             assist_index, params.id,
         ))
-        .into())
-    };
+        .into());
+    }
+    let assist = assists.swap_remove(assist_index);
     if assist.id.0 != expected_assist_id || assist.id.1 != expected_kind {
         return Err(invalid_params_error(format!(
             "Mismatching assist at index {} for the resolve parameters given. Resolve request assist id: {}, actual id: {:?}.",
@@ -1358,21 +1578,7 @@ pub(crate) fn handle_code_action_resolve(
         ))
         .into());
     }
-    let ca = to_proto::code_action(&snap, assist.clone(), None)?;
-    code_action.edit = ca.edit;
-    code_action.command = ca.command;
-
-    if let Some(edit) = code_action.edit.as_ref() {
-        if let Some(changes) = edit.document_changes.as_ref() {
-            for change in changes {
-                if let lsp_ext::SnippetDocumentChangeOperation::Op(res_op) = change {
-                    resource_ops_supported(&snap.config, resolve_resource_op(res_op))?
-                }
-            }
-        }
-    }
-
-    Ok(code_action)
+    Ok(assist)
 }
 
 fn parse_action_id(action_id: &str) -> anyhow::Result<(usize, SingleResolve), String> {
@@ -14,6 +14,7 @@
 pub mod cli;
 mod verus_interaction;
 
+mod cached_verification;
 mod caps;
 mod diagnostics;
 mod diff;
@@ -23,9 +24,11 @@ mod line_index;
 mod main_loop;
 mod mem_docs;
 mod op_queue;
+mod proof_action;
 mod reload;
 mod target_spec;
 mod task_pool;
+mod verification_run;
 mod version;
 
 mod handlers {
@@ -269,6 +269,7 @@ impl Notification for AbortRunTest {
 pub enum TestState {
     Passed,
     Failed { message: String },
+    TimedOut { message: String },
     Skipped,
     Started,
     Enqueued,
@@ -569,6 +570,151 @@ impl Request for CodeActionResolveRequest {
     const METHOD: &'static str = "codeAction/resolve";
 }
 
+/// Resolves a code action that runs Verus in the background. The request returns right away; the
+/// edit is sent with `workspace/applyEdit` once verification is done.
+pub enum RunProofAction {}
+
+impl Request for RunProofAction {
+    type Params = CodeActionData;
+    type Result = ();
+    const METHOD: &'static str = "verus-analyzer/runProofAction";
+}
+
+/// Removes the proof steps that are not needed, in the background like [`RunProofAction`].
+pub enum MinimizeProof {}
+
+impl Request for MinimizeProof {
+    type Params = MinimizeProofParams;
+    type Result = ();
+    const METHOD: &'static str = "verus-analyzer/minimizeProof";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MinimizeProofParams {
+    #[serde(flatten)]
+    pub text_document_position: lsp_types::TextDocumentPositionParams,
+    pub scope: MinimizeProofScope,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum MinimizeProofScope {
+    Function,
+    Module,
+    Crate,
+}
+
+/// Searches for proof actions that make a function verify, in the background like
+/// [`RunProofAction`].
+pub enum SearchProof {}
+
+impl Request for SearchProof {
+    type Params = lsp_types::TextDocumentPositionParams;
+    type Result = ();
+    const METHOD: &'static str = "verus-analyzer/searchProof";
+}
+
+/// Verifies a function as a candidate edit changes it, without changing any file
+pub enum VerifyCandidate {}
+
+impl Request for VerifyCandidate {
+    type Params = VerifyCandidateParams;
+    type Result = VerifyCandidateResult;
+    const METHOD: &'static str = "verus-analyzer/verifyCandidate";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyCandidateParams {
+    /// A position in the function to verify
+    #[serde(flatten)]
+    pub text_document_position: lsp_types::TextDocumentPositionParams,
+    /// The candidate, which may only change the text of the function
+    pub edit: lsp_types::WorkspaceEdit,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyCandidateResult {
+    pub verified: bool,
+    /// The errors Verus reported, with ranges in the document as edited by the candidate
+    pub failures: Vec<VerificationFailure>,
+    /// Verus' error output, as a human would read it
+    pub stderr: String,
+    pub time_ms: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationFailure {
+    pub kind: VerificationFailureKind,
+    pub message: String,
+    pub range: Range,
+    /// Secondary ranges, e.g. the failing `requires` clause of a precondition failure
+    pub related: Vec<Range>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum VerificationFailureKind {
+    Assertion,
+    Precondition,
+    Postcondition,
+    Other,
+}
+
+/// Describes a function and the contracts of the functions it calls, for proof tools
+pub enum FunctionContext {}
+
+impl Request for FunctionContext {
+    type Params = lsp_types::TextDocumentPositionParams;
+    type Result = Option<FunctionContextResult>;
+    const METHOD: &'static str = "verus-analyzer/functionContext";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionContextResult {
+    pub function: FunctionContract,
+    /// The function as proof actions see it, if it converts to their syntax tree
+    pub vst: Option<String>,
+    /// The functions it calls, in order of first call
+    pub callees: Vec<FunctionContract>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionContract {
+    pub name: String,
+    /// The argument to `--verify-function`
+    pub path: String,
+    pub mode: FunctionMode,
+    pub location: lsp_types::Location,
+    pub opaque: bool,
+    pub params: Vec<String>,
+    pub ret: Option<String>,
+    pub requires: Vec<Clause>,
+    pub ensures: Vec<Clause>,
+    pub decreases: Vec<Clause>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum FunctionMode {
+    Spec,
+    Proof,
+    Exec,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Clause {
+    pub text: String,
+    /// In the document of the function
+    pub range: Range,
+}
+
 #[derive(Debug, PartialEq, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CodeAction {
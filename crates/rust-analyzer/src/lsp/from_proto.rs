@@ -1,6 +1,6 @@
 //! Conversion lsp_types types to rust-analyzer specific ones.
 use anyhow::format_err;
-use ide::{Annotation, AnnotationKind, AssistKind, LineCol};
+use ide::{Annotation, AnnotationKind, AssistKind, LineCol, TextEdit};
 use ide_db::{
     base_db::{FileId, FilePosition, FileRange},
     line_index::WideLineCol,
@@ -138,3 +138,56 @@ pub(crate) fn annotation(
     }
     .map(Some)
 }
+
+/// The edit `edit` makes to `file_id`. Edits to other files, and file operations, are errors.
+pub(crate) fn file_text_edit(
+    snap: &GlobalStateSnapshot,
+    file_id: FileId,
+    edit: lsp_types::WorkspaceEdit,
+) -> anyhow::Result<TextEdit> {
+    let mut edits = Vec::new();
+    for (url, text_edits) in edit.changes.into_iter().flatten() {
+        if self::file_id(snap, &url)? != file_id {
+            return Err(format_err!("the edit changes another file: {url}"));
+        }
+        edits.extend(text_edits);
+    }
+    let document_edits = match edit.document_changes {
+        None => Vec::new(),
+        Some(lsp_types::DocumentChanges::Edits(edits)) => edits,
+        Some(lsp_types::DocumentChanges::Operations(ops)) => ops
+            .into_iter()
+            .map(|op| match op {
+                lsp_types::DocumentChangeOperation::Edit(edit) => Ok(edit),
+                lsp_types::DocumentChangeOperation::Op(_) => {
+                    Err(format_err!("the edit creates, renames or deletes files"))
+                }
+            })
+            .collect::<anyhow::Result<_>>()?,
+    };
+    for document_edit in document_edits {
+        let url = document_edit.text_document.uri;
+        if self::file_id(snap, &url)? != file_id {
+            return Err(format_err!("the edit changes another file: {url}"));
+        }
+        edits.extend(document_edit.edits.into_iter().map(|it| match it {
+            lsp_types::OneOf::Left(it) => it,
+            lsp_types::OneOf::Right(it) => it.text_edit,
+        }));
+    }
+
+    let line_index = snap.file_line_index(file_id)?;
+    let mut indels = edits
+        .into_iter()
+        .map(|it| Ok((text_range(&line_index, it.range)?, it.new_text)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    indels.sort_by_key(|(range, _)| range.start());
+    if indels.windows(2).any(|w| w[0].0.end() > w[1].0.start()) {
+        return Err(format_err!("the edit has overlapping text edits"));
+    }
+    let mut builder = TextEdit::builder();
+    for (range, text) in indels {
+        builder.replace(range, text);
+    }
+    Ok(builder.finish())
+}
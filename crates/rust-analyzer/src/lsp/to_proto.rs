@@ -8,11 +8,12 @@ use std::{
 use ide::{
     Annotation, AnnotationKind, Assist, AssistKind, Cancellable, CompletionItem,
     CompletionItemKind, CompletionRelevance, Documentation, FileId, FileRange, FileSystemEdit,
-    Fold, FoldKind, Highlight, HlMod, HlOperator, HlPunct, HlRange, HlTag, Indel,
-    InlayFieldsToResolve, InlayHint, InlayHintLabel, InlayHintLabelPart, InlayKind, Markup,
-    NavigationTarget, ReferenceCategory, RenameError, Runnable, Severity, SignatureHelp,
+    FnMode, Fold, FoldKind, FunctionContract, Highlight, HlMod, HlOperator, HlPunct, HlRange,
+    HlTag, Indel, InlayFieldsToResolve, InlayHint, InlayHintLabel, InlayHintLabelPart, InlayKind,
+    Markup, NavigationTarget, ReferenceCategory, RenameError, Runnable, Severity, SignatureHelp,
     SnippetEdit, SourceChange, StructureNodeKind, SymbolKind, TextEdit, TextRange, TextSize,
 };
+use ide_assists::proof_plumber_api::run_verus::{VerifFailure, VerifFailureKind};
 use ide_db::{rust_doc::format_docs, FxHasher};
 use itertools::Itertools;
 use paths::{Utf8Component, Utf8Prefix};
@@ -32,6 +33,7 @@ use crate::{
     },
     lsp_ext::{self, SnippetTextEdit},
     target_spec::{CargoTargetSpec, TargetSpec},
+    verus_interaction,
 };
 
 pub(crate) fn position(line_index: &LineIndex, offset: TextSize) -> lsp_types::Position {
@@ -1364,6 +1366,18 @@ pub(crate) fn runnable(
     runnable: Runnable,
 ) -> Cancellable<Option<lsp_ext::Runnable>> {
     let config = snap.config.runnables();
+    if runnable.kind.is_verify() {
+        let file_path = snap.file_id_to_file_path(runnable.nav.file_id);
+        let package = match file_path.as_path() {
+            Some(path) => verus_interaction::verus_package_options(&snap.workspaces, path),
+            None => Some(Default::default()),
+        };
+        // The package opted out of verification.
+        let Some(package) = package else { return Ok(None) };
+        if !config.cargo_verus {
+            return verus_runnable(snap, runnable, config.verus_extra_args, package);
+        }
+    }
     let target_spec = TargetSpec::for_file(snap, runnable.nav.file_id)?;
 
     match target_spec {
@@ -1448,6 +1462,54 @@ pub(crate) fn runnable(
     }
 }
 
+/// Verification runnables invoke `verus` on the crate root directly, the same way flycheck does
+/// when `cargo verus` is not enabled.
+fn verus_runnable(
+    snap: &GlobalStateSnapshot,
+    runnable: Runnable,
+    verus_extra_args: Vec<String>,
+    package: flycheck::VerusPackageOptions,
+) -> Cancellable<Option<lsp_ext::Runnable>> {
+    let crate_id = match &*snap.analysis.crates_for(runnable.nav.file_id)? {
+        &[crate_id, ..] => crate_id,
+        _ => return Ok(None),
+    };
+    let root_file = snap.file_id_to_file_path(snap.analysis.crate_root(crate_id)?);
+    let Some(root_file) = root_file.as_path() else { return Ok(None) };
+    let target_spec = snap.target_spec_for_crate(crate_id);
+
+    let is_bin = matches!(
+        target_spec.as_ref().map(TargetSpec::target_kind),
+        Some(project_model::TargetKind::Bin)
+    );
+    let mut args = flycheck::verus_crate_args(root_file.as_str(), is_bin);
+    args.extend(verus_extra_args);
+    args.extend(package.extra_args);
+    args.extend(runnable.kind.verus_args());
+
+    let (target, cwd) = match &target_spec {
+        Some(TargetSpec::Cargo(spec)) => (Some(spec.target.as_str()), spec.cargo_toml.parent()),
+        _ => (None, root_file.parent().unwrap_or(root_file)),
+    };
+    let label = runnable.label(target);
+    let cwd = cwd.to_path_buf().into();
+    let location = location_link(snap, None, runnable.nav)?;
+
+    Ok(Some(lsp_ext::Runnable {
+        label,
+        location: Some(location),
+        kind: lsp_ext::RunnableKind::Shell,
+        args: lsp_ext::RunnableArgs::Shell(ShellRunnableArgs {
+            program: snap
+                .config
+                .verus_binary_path()
+                .map_or_else(|| "verus".to_owned(), |it| it.to_string()),
+            args,
+            cwd,
+        }),
+    }))
+}
+
 pub(crate) fn code_lens(
     acc: &mut Vec<lsp_types::CodeLens>,
     snap: &GlobalStateSnapshot,
@@ -1461,7 +1523,10 @@ pub(crate) fn code_lens(
 
             let title = run.title();
             let can_debug = match run.kind {
-                ide::RunnableKind::DocTest { .. } => false,
+                ide::RunnableKind::DocTest { .. }
+                | ide::RunnableKind::VerifyCrate
+                | ide::RunnableKind::VerifyMod { .. }
+                | ide::RunnableKind::Verify { .. } => false,
                 ide::RunnableKind::TestMod { .. }
                 | ide::RunnableKind::Test { .. }
                 | ide::RunnableKind::Bench { .. }
@@ -1709,6 +1774,17 @@ pub(crate) mod command {
         })
     }
 
+    pub(crate) fn run_proof_action(
+        title: &str,
+        data: lsp_ext::CodeActionData,
+    ) -> lsp_types::Command {
+        lsp_types::Command {
+            title: title.to_owned(),
+            command: "verus-analyzer.runProofAction".into(),
+            arguments: Some(vec![to_value(data).unwrap()]),
+        }
+    }
+
     pub(crate) fn trigger_parameter_hints() -> lsp_types::Command {
         lsp_types::Command {
             title: "triggerParameterHints".into(),
@@ -1746,6 +1822,57 @@ pub(crate) fn markup_content(
     lsp_types::MarkupContent { kind, value }
 }
 
+pub(crate) fn verification_failure(
+    line_index: &LineIndex,
+    failure: VerifFailure,
+) -> lsp_ext::VerificationFailure {
+    let kind = match failure.kind {
+        VerifFailureKind::Assertion => lsp_ext::VerificationFailureKind::Assertion,
+        VerifFailureKind::Precondition => lsp_ext::VerificationFailureKind::Precondition,
+        VerifFailureKind::Postcondition => lsp_ext::VerificationFailureKind::Postcondition,
+        VerifFailureKind::Other => lsp_ext::VerificationFailureKind::Other,
+    };
+    lsp_ext::VerificationFailure {
+        kind,
+        message: failure.message,
+        range: range(line_index, failure.range),
+        related: failure.related.into_iter().map(|it| range(line_index, it)).collect(),
+    }
+}
+
+pub(crate) fn function_contract(
+    snap: &GlobalStateSnapshot,
+    contract: FunctionContract,
+) -> Cancellable<lsp_ext::FunctionContract> {
+    let line_index = snap.file_line_index(contract.file_id)?;
+    let clauses = |clauses: Vec<ide::Clause>| -> Vec<lsp_ext::Clause> {
+        clauses
+            .into_iter()
+            .map(|it| lsp_ext::Clause { text: it.text, range: range(&line_index, it.range) })
+            .collect()
+    };
+    let mode = match contract.mode {
+        FnMode::Spec => lsp_ext::FunctionMode::Spec,
+        FnMode::Proof => lsp_ext::FunctionMode::Proof,
+        FnMode::Exec => lsp_ext::FunctionMode::Exec,
+    };
+    Ok(lsp_ext::FunctionContract {
+        name: contract.name,
+        path: contract.path,
+        mode,
+        location: lsp_types::Location::new(
+            url(snap, contract.file_id),
+            range(&line_index, contract.range),
+        ),
+        opaque: contract.opaque,
+        params: contract.params,
+        ret: contract.ret,
+        requires: clauses(contract.requires),
+        ensures: clauses(contract.ensures),
+        decreases: clauses(contract.decreases),
+    })
+}
+
 pub(crate) fn rename_error(err: RenameError) -> LspError {
     // This is wrong, but we don't have a better alternative I suppose?
     // https://github.com/microsoft/language-server-protocol/issues/1341
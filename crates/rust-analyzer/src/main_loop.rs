@@ -17,6 +17,7 @@ use tracing::{span, Level};
 use vfs::FileId;
 
 use crate::{
+    cached_verification::{self, CachedVerification},
     config::Config,
     diagnostics::{fetch_native_diagnostics, DiagnosticsGeneration},
     dispatch::{NotificationDispatcher, RequestDispatcher},
@@ -27,8 +28,9 @@ use crate::{
         utils::{notification_is, Progress},
     },
     lsp_ext,
+    proof_action::{self, ProofActionProgress},
     reload::{BuildDataProgress, ProcMacroProgress, ProjectWorkspaceProgress},
-    verus_interaction,
+    verification_run, verus_interaction,
 };
 
 pub fn main_loop(config: Config, connection: Connection) -> anyhow::Result<()> {
@@ -91,11 +93,19 @@ pub(crate) enum Task {
     Retry(lsp_server::Request),
     Diagnostics(DiagnosticsGeneration, Vec<(FileId, Vec<lsp_types::Diagnostic>)>),
     DiscoverTest(lsp_ext::DiscoverTestResults),
+    ProofAction(ProofActionProgress),
+    CachedVerification(CachedVerification),
+    /// Verus is about to verify `scope` (or the whole crate if `None`) with the given flycheck.
+    VerificationRequested {
+        flycheck_id: usize,
+        scope: Option<FileId>,
+    },
     PrimeCaches(PrimeCachesProgress),
     FetchWorkspace(ProjectWorkspaceProgress),
     FetchBuildData(BuildDataProgress),
     LoadProcMacros(ProcMacroProgress),
     BuildDepsHaveChanged,
+    VerusToolchain(Option<String>),
 }
 
 #[derive(Debug)]
@@ -684,9 +694,15 @@ impl GlobalState {
                 }
             }
             Task::BuildDepsHaveChanged => self.build_deps_changed = true,
+            Task::VerusToolchain(warning) => self.verus_toolchain_warning = warning,
             Task::DiscoverTest(tests) => {
                 self.send_notification::<lsp_ext::DiscoveredTests>(tests);
             }
+            Task::ProofAction(progress) => proof_action::on_progress(self, progress),
+            Task::CachedVerification(task) => cached_verification::on_task(self, task),
+            Task::VerificationRequested { flycheck_id, scope } => {
+                self.verus_errors.verification_requested(flycheck_id, scope)
+            }
         }
     }
 
@@ -810,8 +826,11 @@ impl GlobalState {
             flycheck::CargoTestMessage::Finished => {
                 self.test_run_remaining_jobs = self.test_run_remaining_jobs.saturating_sub(1);
                 if self.test_run_remaining_jobs == 0 {
-                    self.send_notification::<lsp_ext::EndRunTest>(());
                     self.test_run_session = None;
+                    // Verus may still be verifying the rest of a mixed selection.
+                    if self.verification_run.is_none() {
+                        self.send_notification::<lsp_ext::EndRunTest>(());
+                    }
                 }
             }
             flycheck::CargoTestMessage::Custom { text } => {
@@ -824,10 +843,22 @@ impl GlobalState {
         match message {
             flycheck::Message::AddDiagnostic { id, workspace_root, diagnostic } => {
                 // register verus errors
-                // should flush out errors on save
-                if let Some(verr) = verus_interaction::diagnostic_to_verus_err(&diagnostic) {
-                    self.verus_errors.push(verr)
-                };
+                if let Some((verr, file_names)) =
+                    verus_interaction::diagnostic_to_verus_err(&diagnostic)
+                {
+                    let file_ids: Option<Vec<FileId>> = file_names
+                        .iter()
+                        .map(|file_name| {
+                            let path = vfs::VfsPath::from(workspace_root.join(file_name));
+                            self.vfs.read().0.file_id(&path)
+                        })
+                        .collect();
+                    if let Some(file_ids) = file_ids {
+                        let analysis = self.analysis_host.analysis();
+                        self.verus_errors
+                            .add(id, verr, file_ids, |file_id| analysis.parse(file_id).ok());
+                    }
+                }
 
                 let snap = self.snapshot();
                 let diagnostics = crate::diagnostics::to_proto::map_rust_diagnostic_to_lsp(
@@ -857,14 +888,17 @@ impl GlobalState {
             flycheck::Message::ClearDiagnostics { id } => self.diagnostics.clear_check(id),
 
             flycheck::Message::Progress { id, progress } => {
+                verification_run::on_flycheck_progress(self, id, &progress);
+                cached_verification::on_flycheck_progress(self, id, &progress);
                 let (state, message) = match progress {
                     flycheck::Progress::DidStart => {
                         self.diagnostics.clear_check(id);
-                        self.verus_errors = vec![]; // verus: clear out errors
+                        self.verus_errors.verification_started(id);
                         (Progress::Begin, None)
                     }
                     flycheck::Progress::DidCheckCrate(target) => (Progress::Report, Some(target)),
                     flycheck::Progress::DidCancel => {
+                        self.verus_errors.verification_cancelled(id);
                         self.last_flycheck_error = None;
                         (Progress::End, None)
                     }
@@ -874,6 +908,7 @@ impl GlobalState {
                         return;
                     }
                     flycheck::Progress::DidFinish(result) => {
+                        self.verus_errors.verification_finished(id);
                         self.last_flycheck_error =
                             result.err().map(|err| format!("cargo check failed to start: {err}"));
                         (Progress::End, None)
@@ -950,6 +985,9 @@ impl GlobalState {
             .on_sync_mut::<lsp_ext::MemoryUsage>(handlers::handle_memory_usage)
             .on_sync_mut::<lsp_ext::ShuffleCrateGraph>(handlers::handle_shuffle_crate_graph)
             .on_sync_mut::<lsp_ext::RunTest>(handlers::handle_run_test)
+            .on_sync_mut::<lsp_ext::RunProofAction>(handlers::handle_run_proof_action)
+            .on_sync_mut::<lsp_ext::MinimizeProof>(handlers::handle_minimize_proof)
+            .on_sync_mut::<lsp_ext::SearchProof>(handlers::handle_search_proof)
             // Request handlers which are related to the user typing
             // are run on the main thread to reduce latency:
             .on_sync::<lsp_ext::JoinLines>(handlers::handle_join_lines)
@@ -987,6 +1025,8 @@ impl GlobalState {
             .on::<RETRY, lsp_request::InlayHintRequest>(handlers::handle_inlay_hints)
             .on::<RETRY, lsp_request::InlayHintResolveRequest>(handlers::handle_inlay_hints_resolve)
             .on::<NO_RETRY, lsp_request::CodeLensRequest>(handlers::handle_code_lens)
+            .on::<NO_RETRY, lsp_ext::VerifyCandidate>(handlers::handle_verify_candidate)
+            .on::<NO_RETRY, lsp_ext::FunctionContext>(handlers::handle_function_context)
             .on::<RETRY, lsp_request::CodeLensResolve>(handlers::handle_code_lens_resolve)
             .on::<NO_RETRY, lsp_request::PrepareRenameRequest>(handlers::handle_prepare_rename)
             .on::<NO_RETRY, lsp_request::Rename>(handlers::handle_rename)
@@ -0,0 +1,312 @@
+//! Runs the proof actions that call Verus in the background.
+//!
+//! Resolving such a proof action may run Verus many times, which can take minutes. Instead of
+//! resolving it with `codeAction/resolve`, the client sends `verus-analyzer/runProofAction`. The
+//! assist is then resolved on the task pool, each Verus run is reported as `$/progress`, and the
+//! edit is sent to the client with `workspace/applyEdit`. Cancelling the progress kills Verus.
+//!
+//! `verus-analyzer/minimizeProof` runs the same way, but asks the client to let the user review
+//! the edit before applying it. So does `verus-analyzer/searchProof`, which falls back to letting
+//! the user pick one of the partial improvements it found.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use crossbeam_channel::Sender;
+use ide::{AssistConfig, Cancelled, MinimizeCache, MinimizeScope, SearchBudget, SourceChange};
+use ide_assists::proof_plumber_api::{
+    run_verus::VerifResult,
+    verifier::{VerificationRequest, Verifier},
+};
+use paths::AbsPath;
+use rustc_hash::FxHashMap;
+use stdx::thread::ThreadIntent;
+
+use crate::{
+    global_state::{GlobalState, GlobalStateSnapshot},
+    handlers::request::resolve_assist,
+    lsp::{from_proto, to_proto, utils::Progress},
+    lsp_ext,
+    main_loop::Task,
+};
+
+const TITLE: &str = "Proof action";
+
+#[derive(Default)]
+pub(crate) struct ProofActions {
+    next_id: usize,
+    /// The cancellation flags of the running proof actions, by progress token
+    running: FxHashMap<String, Arc<AtomicBool>>,
+    minimize_cache: Arc<MinimizeCache>,
+    /// The edits the user was last asked to choose from, by title
+    choices: Vec<(String, lsp_types::WorkspaceEdit)>,
+}
+
+/// The edits a proof action found
+#[derive(Debug)]
+pub(crate) enum Outcome<E> {
+    /// The edit to apply
+    Apply(E),
+    /// Edits for the user to choose from, or just `message` if there are none
+    Choose { message: String, choices: Vec<(String, E)> },
+}
+
+#[derive(Debug)]
+pub(crate) enum ProofActionProgress {
+    Report { token: String, message: String },
+    End { token: String, result: Result<Option<Outcome<lsp_types::WorkspaceEdit>>, String> },
+}
+
+pub(crate) fn start(state: &mut GlobalState, params: lsp_ext::CodeActionData) {
+    let assist_id = params.id.split(':').next().unwrap_or_default().to_owned();
+    run(state, assist_id, None, move |snap, configure| {
+        let assist = resolve_assist(snap, params, configure)?;
+        Ok(Some(match assist.source_change {
+            Some(source_change) => Outcome::Apply(source_change),
+            None => Outcome::Choose {
+                message: format!("{}: no edit found", assist.label),
+                choices: Vec::new(),
+            },
+        }))
+    });
+}
+
+pub(crate) fn start_minimize(state: &mut GlobalState, params: lsp_ext::MinimizeProofParams) {
+    let cache = state.proof_actions.minimize_cache.clone();
+    let scope = match params.scope {
+        lsp_ext::MinimizeProofScope::Function => MinimizeScope::Function,
+        lsp_ext::MinimizeProofScope::Module => MinimizeScope::Module,
+        lsp_ext::MinimizeProofScope::Crate => MinimizeScope::Crate,
+    };
+    let review = Some("Remove unneeded proof steps");
+    run(state, "minimize proof".to_owned(), review, move |snap, configure| {
+        let position = from_proto::file_position(snap, params.text_document_position)?;
+        let source_root = snap.analysis.source_root_id(position.file_id)?;
+        let mut config = snap.assist_config(Some(source_root));
+        configure(&mut config);
+        Ok(snap.analysis.minimize_proof(&config, position, scope, &cache)?.map(Outcome::Apply))
+    });
+}
+
+pub(crate) fn start_search(state: &mut GlobalState, params: lsp_types::TextDocumentPositionParams) {
+    let review = Some("Complete the proof");
+    run(state, "search proof".to_owned(), review, move |snap, configure| {
+        let position = from_proto::file_position(snap, params)?;
+        let source_root = snap.analysis.source_root_id(position.file_id)?;
+        let mut config = snap.assist_config(Some(source_root));
+        configure(&mut config);
+        let mut candidates =
+            snap.analysis.search_proof(&config, position, SearchBudget::default())?;
+        if candidates.first().is_some_and(|it| it.verified) {
+            return Ok(Some(Outcome::Apply(candidates.remove(0).source_change)));
+        }
+        let message = match candidates.is_empty() {
+            true => "No combination of proof actions verifies, or leaves fewer errors",
+            false => {
+                "No combination of proof actions verifies. Apply one that leaves fewer errors?"
+            }
+        };
+        let choices = candidates.into_iter().map(|it| (it.label(), it.source_change)).collect();
+        Ok(Some(Outcome::Choose { message: message.to_owned(), choices }))
+    });
+}
+
+/// Runs `job` on the task pool, with a verifier that reports progress and can be cancelled.
+///
+/// The edit is applied when the job is done. With `review`, the client is asked to let the user
+/// review the edits first, under that label.
+fn run(
+    state: &mut GlobalState,
+    message: String,
+    review: Option<&'static str>,
+    job: impl FnOnce(
+            &GlobalStateSnapshot,
+            Box<dyn FnOnce(&mut AssistConfig)>,
+        ) -> anyhow::Result<Option<Outcome<SourceChange>>>
+        + Send
+        + 'static,
+) {
+    let token = format!("rust-analyzer/proofAction/{}", state.proof_actions.next_id);
+    state.proof_actions.next_id += 1;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    state.proof_actions.running.insert(token.clone(), cancelled.clone());
+    state.report_progress(TITLE, Progress::Begin, Some(message), None, Some(token.clone()));
+
+    let snap = state.snapshot();
+    state.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, move |sender| {
+        let configure = Box::new({
+            let (token, sender) = (token.clone(), sender.clone());
+            move |config: &mut AssistConfig| {
+                config.verifier = config.verifier.take().map(|verifier| {
+                    Arc::new(ProgressVerifier {
+                        verifier,
+                        token,
+                        cancelled,
+                        sender: Mutex::new(sender),
+                        runs: AtomicUsize::new(0),
+                    }) as Arc<dyn Verifier>
+                })
+            }
+        });
+        let to_edit = |source_change| -> anyhow::Result<lsp_types::WorkspaceEdit> {
+            let edit = to_proto::snippet_workspace_edit(&snap, source_change)?.into();
+            Ok(match review {
+                Some(label) => for_review(edit, label),
+                None => edit,
+            })
+        };
+        let result = job(&snap, configure)
+            .and_then(|outcome| match outcome {
+                Some(Outcome::Apply(source_change)) => {
+                    Ok(Some(Outcome::Apply(to_edit(source_change)?)))
+                }
+                Some(Outcome::Choose { message, choices }) => {
+                    let choices = choices
+                        .into_iter()
+                        .map(|(title, source_change)| Ok((title, to_edit(source_change)?)))
+                        .collect::<anyhow::Result<_>>()?;
+                    Ok(Some(Outcome::Choose { message, choices }))
+                }
+                None => Ok(None),
+            })
+            .map_err(|e| match e.downcast_ref::<Cancelled>() {
+                Some(_) => "the code changed while it was running".to_owned(),
+                None => e.to_string(),
+            });
+        sender.send(Task::ProofAction(ProofActionProgress::End { token, result })).unwrap();
+    });
+}
+
+/// Mark every text edit of `edit` as needing confirmation, so that the client shows a preview
+fn for_review(mut edit: lsp_types::WorkspaceEdit, label: &str) -> lsp_types::WorkspaceEdit {
+    let annotation = "proofAction".to_owned();
+    if let Some(lsp_types::DocumentChanges::Operations(ops)) = &mut edit.document_changes {
+        for op in ops {
+            let lsp_types::DocumentChangeOperation::Edit(edit) = op else { continue };
+            for text_edit in &mut edit.edits {
+                if let lsp_types::OneOf::Left(it) = text_edit {
+                    *text_edit = lsp_types::OneOf::Right(lsp_types::AnnotatedTextEdit {
+                        text_edit: it.clone(),
+                        annotation_id: annotation.clone(),
+                    });
+                }
+            }
+        }
+    }
+    let change_annotation = lsp_types::ChangeAnnotation {
+        label: label.to_owned(),
+        needs_confirmation: Some(true),
+        description: None,
+    };
+    edit.change_annotations = Some([(annotation, change_annotation)].into_iter().collect());
+    edit
+}
+
+/// Stops the proof action reporting progress with `token`, if there is one.
+pub(crate) fn cancel(state: &mut GlobalState, token: &str) {
+    if let Some(cancelled) = state.proof_actions.running.get(token) {
+        cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+pub(crate) fn on_progress(state: &mut GlobalState, progress: ProofActionProgress) {
+    match progress {
+        ProofActionProgress::Report { token, message } => {
+            if state.proof_actions.running.contains_key(&token) {
+                state.report_progress(TITLE, Progress::Report, Some(message), None, Some(token));
+            }
+        }
+        ProofActionProgress::End { token, result } => {
+            let Some(cancelled) = state.proof_actions.running.remove(&token) else { return };
+            state.report_progress(TITLE, Progress::End, None, None, Some(token));
+            if cancelled.load(Ordering::Relaxed) {
+                return;
+            }
+            match result {
+                Ok(Some(Outcome::Apply(edit))) => apply(state, edit),
+                Ok(Some(Outcome::Choose { message, choices })) if choices.is_empty() => {
+                    state.show_message(lsp_types::MessageType::INFO, message, false)
+                }
+                Ok(Some(Outcome::Choose { message, choices })) => {
+                    let actions = choices
+                        .iter()
+                        .map(|(title, _)| lsp_types::MessageActionItem {
+                            title: title.clone(),
+                            properties: Default::default(),
+                        })
+                        .collect();
+                    state.proof_actions.choices = choices;
+                    state.send_request::<lsp_types::request::ShowMessageRequest>(
+                        lsp_types::ShowMessageRequestParams {
+                            typ: lsp_types::MessageType::INFO,
+                            message,
+                            actions: Some(actions),
+                        },
+                        on_choice,
+                    );
+                }
+                Ok(None) => (),
+                Err(e) => state.show_message(
+                    lsp_types::MessageType::ERROR,
+                    format!("Proof action failed: {e}"),
+                    false,
+                ),
+            }
+        }
+    }
+}
+
+fn apply(state: &mut GlobalState, edit: lsp_types::WorkspaceEdit) {
+    state.send_request::<lsp_types::request::ApplyWorkspaceEdit>(
+        lsp_types::ApplyWorkspaceEditParams { label: Some(TITLE.to_owned()), edit },
+        |_, _| (),
+    )
+}
+
+fn on_choice(state: &mut GlobalState, response: lsp_server::Response) {
+    let choices = std::mem::take(&mut state.proof_actions.choices);
+    let lsp_server::Response { error: None, result: Some(result), .. } = response else { return };
+    let Ok(Some(item)) = serde_json::from_value::<Option<lsp_types::MessageActionItem>>(result)
+    else {
+        return;
+    };
+    if let Some((_, edit)) = choices.into_iter().find(|(title, _)| *title == item.title) {
+        apply(state, edit);
+    }
+}
+
+/// Reports every Verus run of a proof action, and stops them once the proof action is cancelled
+#[derive(Debug)]
+struct ProgressVerifier {
+    verifier: Arc<dyn Verifier>,
+    token: String,
+    cancelled: Arc<AtomicBool>,
+    sender: Mutex<Sender<Task>>,
+    runs: AtomicUsize,
+}
+
+impl Verifier for ProgressVerifier {
+    fn verify(&self, request: &VerificationRequest<'_>) -> Option<VerifResult> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+        let run = self.runs.fetch_add(1, Ordering::Relaxed) + 1;
+        let message = format!("Verus run {run}: {}", request.function_path);
+        let report = ProofActionProgress::Report { token: self.token.clone(), message };
+        self.sender.lock().unwrap().send(Task::ProofAction(report)).unwrap();
+        let is_cancelled = || self.cancelled.load(Ordering::Relaxed) || (request.is_cancelled)();
+        self.verifier.verify(&VerificationRequest {
+            function: request.function,
+            function_path: request.function_path.clone(),
+            file: request.file,
+            krate: request.krate.clone(),
+            is_cancelled: &is_cancelled,
+        })
+    }
+
+    fn fingerprint(&self, crate_root: Option<&AbsPath>) -> u64 {
+        self.verifier.fingerprint(crate_root)
+    }
+}
@@ -134,6 +134,11 @@ impl GlobalState {
             message.push_str(err);
             message.push('\n');
         }
+        if let Some(warning) = &self.verus_toolchain_warning {
+            status.health |= lsp_ext::Health::Warning;
+            message.push_str(warning);
+            message.push_str("\n\n");
+        }
 
         if self.config.linked_or_discovered_projects().is_empty()
             && self.config.detached_files().is_empty()
@@ -683,10 +688,31 @@ impl GlobalState {
         }
     }
 
+    /// Reports a missing or mismatched Verus once per flycheck configuration, when verification
+    /// first runs, instead of failing on every save. `verus --version` runs on the task pool.
+    pub(crate) fn check_verus_toolchain(&mut self) {
+        if std::mem::replace(&mut self.verus_toolchain_checked, true) {
+            return;
+        }
+        let FlycheckConfig::VerusCommand { verus_binary, .. } = self.config.flycheck() else {
+            return;
+        };
+        self.task_pool.handle.spawn(ThreadIntent::Worker, move || {
+            let warning = match toolchain::VerusToolchain::discover(verus_binary.as_deref()) {
+                Ok(toolchain) => toolchain.version_warning(),
+                Err(e) => Some(format!("Failed to find Verus: {e}")),
+            };
+            Task::VerusToolchain(warning)
+        });
+    }
+
     fn reload_flycheck(&mut self) {
         let _p = tracing::info_span!("GlobalState::reload_flycheck").entered();
         let config = self.config.flycheck();
         let sender = self.flycheck_sender.clone();
+        // the toolchain is checked again when verification next runs
+        self.verus_toolchain_checked = false;
+        self.verus_toolchain_warning = None;
         let invocation_strategy = match config {
             FlycheckConfig::CargoCommand { .. } => flycheck::InvocationStrategy::PerWorkspace,
             FlycheckConfig::CustomCommand { invocation_strategy, .. } => invocation_strategy,
@@ -9,7 +9,7 @@ use project_model::{CargoFeatures, ManifestPath, TargetKind};
 use rustc_hash::FxHashSet;
 use vfs::AbsPathBuf;
 
-use crate::global_state::GlobalStateSnapshot;
+use crate::{global_state::GlobalStateSnapshot, verus_interaction};
 
 /// A target represents a thing we can build or test.
 ///
@@ -99,6 +99,9 @@ impl ProjectJsonTargetSpec {
             RunnableKind::TestMod { .. } => None,
             RunnableKind::Bench { .. } => None,
             RunnableKind::DocTest { .. } => None,
+            RunnableKind::VerifyCrate
+            | RunnableKind::VerifyMod { .. }
+            | RunnableKind::Verify { .. } => None,
         }
     }
 }
@@ -110,7 +113,8 @@ impl CargoTargetSpec {
         kind: &RunnableKind,
         cfg: &Option<CfgExpr>,
     ) -> (Vec<String>, Vec<String>) {
-        let extra_test_binary_args = snap.config.runnables().extra_test_binary_args;
+        let runnables_config = snap.config.runnables();
+        let extra_test_binary_args = runnables_config.extra_test_binary_args;
 
         let mut cargo_args = Vec::new();
         let mut executable_args = Vec::new();
@@ -153,6 +157,18 @@ impl CargoTargetSpec {
                 };
                 cargo_args.push(subcommand.to_owned());
             }
+            RunnableKind::VerifyCrate
+            | RunnableKind::VerifyMod { .. }
+            | RunnableKind::Verify { .. } => {
+                cargo_args.push("verus".to_owned());
+                cargo_args.push("verify".to_owned());
+                executable_args.extend(runnables_config.verus_extra_args);
+                let package = spec.as_ref().and_then(|spec| {
+                    verus_interaction::verus_package_options(&snap.workspaces, &spec.cargo_toml)
+                });
+                executable_args.extend(package.into_iter().flat_map(|it| it.extra_args));
+                executable_args.extend(kind.verus_args());
+            }
         }
 
         let (allowed_features, target_required_features) = if let Some(mut spec) = spec {
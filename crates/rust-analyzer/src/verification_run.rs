@@ -0,0 +1,235 @@
+//! Runs Verus on the functions selected in the test explorer.
+//!
+//! Verification goes through the flycheck machinery, one module at a time. Verus does not report
+//! per-function results, so a function passes when its module verified without an error inside
+//! the function's range.
+
+use std::collections::VecDeque;
+
+use ide::FileId;
+
+use crate::{global_state::GlobalState, lsp::ext as lsp_ext};
+
+/// A function shown in the test explorer, together with where it lives.
+pub(crate) struct VerificationItem {
+    pub(crate) test_id: String,
+    pub(crate) file_id: FileId,
+    pub(crate) range: lsp_types::Range,
+}
+
+/// A single Verus invocation, covering every selected function of one module.
+pub(crate) struct VerificationTarget {
+    pub(crate) file_path: String,
+    pub(crate) package: flycheck::VerusPackageOptions,
+    pub(crate) module_args: Vec<String>,
+    pub(crate) items: Vec<VerificationItem>,
+}
+
+pub(crate) struct VerificationRun {
+    queue: VecDeque<VerificationTarget>,
+    current: Option<(usize, VerificationTarget)>,
+    /// Whether the flycheck for `current` has started. Starting a run cancels the previous one,
+    /// and that cancellation must not be mistaken for ours.
+    started: bool,
+}
+
+pub(crate) fn start(state: &mut GlobalState, targets: Vec<VerificationTarget>) {
+    for item in targets.iter().flat_map(|t| &t.items) {
+        send_state(state, &item.test_id, lsp_ext::TestState::Enqueued);
+    }
+    state.verification_run =
+        Some(VerificationRun { queue: targets.into(), current: None, started: false });
+    verify_next(state);
+}
+
+pub(crate) fn on_flycheck_progress(
+    state: &mut GlobalState,
+    id: usize,
+    progress: &flycheck::Progress,
+) {
+    let Some(run) = &mut state.verification_run else { return };
+    let Some((flycheck_id, _)) = &run.current else { return };
+    if *flycheck_id != id {
+        return;
+    }
+    match progress {
+        flycheck::Progress::DidStart => run.started = true,
+        flycheck::Progress::DidFinish(result) if run.started => {
+            let Some((_, target)) = run.current.take() else { return };
+            match result {
+                Ok(()) => report_results(state, id, target),
+                Err(err) => {
+                    let message = format!("Verus failed to run: {err}");
+                    fail_items(state, target.items, &message);
+                }
+            }
+            verify_next(state);
+        }
+        flycheck::Progress::DidFailToRestart(err) => {
+            let Some((_, target)) = run.current.take() else { return };
+            fail_items(state, target.items, err);
+            verify_next(state);
+        }
+        // Something else restarted the flycheck, e.g. a save. Only the module being verified is
+        // lost, the rest of the run goes on.
+        flycheck::Progress::DidCancel if run.started => {
+            let Some((_, target)) = run.current.take() else { return };
+            for item in target.items {
+                send_state(state, &item.test_id, lsp_ext::TestState::Skipped);
+            }
+            verify_next(state);
+        }
+        _ => (),
+    }
+}
+
+/// Stops the current run, marking everything that has not been verified yet as skipped. Returns
+/// whether a run was in progress; ending the test run is left to the caller.
+pub(crate) fn cancel(state: &mut GlobalState) -> bool {
+    let Some(run) = state.verification_run.take() else { return false };
+    let remaining = run.current.map(|(_, target)| target).into_iter().chain(run.queue);
+    for item in remaining.flat_map(|t| t.items) {
+        send_state(state, &item.test_id, lsp_ext::TestState::Skipped);
+    }
+    true
+}
+
+fn verify_next(state: &mut GlobalState) {
+    let Some(run) = &mut state.verification_run else { return };
+    let Some(target) = run.queue.pop_front() else {
+        state.verification_run = None;
+        // A mixed selection also runs `cargo test`, which ends the test run once it finishes.
+        if state.test_run_session.is_none() {
+            state.send_notification::<lsp_ext::EndRunTest>(());
+        }
+        return;
+    };
+    let flychecks = state.flycheck.clone();
+    let Some(flycheck) = flycheck_for(state, &flychecks, &target.file_path) else {
+        fail_items(state, target.items, "no flycheck is configured for this workspace");
+        return verify_next(state);
+    };
+    state.check_verus_toolchain();
+    for item in &target.items {
+        send_state(state, &item.test_id, lsp_ext::TestState::Started);
+    }
+    let scope = target.items.first().map(|item| item.file_id);
+    state.verus_errors.verification_requested(flycheck.id(), scope);
+    flycheck.restart_verus_module(
+        target.file_path.clone(),
+        target.package.clone(),
+        target.module_args.clone(),
+    );
+    if let Some(run) = &mut state.verification_run {
+        run.current = Some((flycheck.id(), target));
+        run.started = false;
+    }
+}
+
+fn flycheck_for<'a>(
+    state: &GlobalState,
+    flychecks: &'a [flycheck::FlycheckHandle],
+    file_path: &str,
+) -> Option<&'a flycheck::FlycheckHandle> {
+    let workspace = state.workspaces.iter().position(|ws| {
+        ws.manifest_or_root().parent().is_some_and(|root| file_path.starts_with(root.as_str()))
+    });
+    workspace
+        .and_then(|idx| flychecks.iter().find(|it| it.id() == idx))
+        .or_else(|| flychecks.first())
+}
+
+fn report_results(state: &mut GlobalState, flycheck_id: usize, target: VerificationTarget) {
+    for item in target.items {
+        let diagnostics = state
+            .diagnostics
+            .check
+            .get(&flycheck_id)
+            .and_then(|it| it.get(&item.file_id))
+            .map_or(&[][..], |it| it.as_slice());
+        let test_state = item_state(item.range, diagnostics);
+        send_state(state, &item.test_id, test_state);
+    }
+}
+
+/// The outcome of a function spanning `range`, from the diagnostics of its file.
+fn item_state(
+    range: lsp_types::Range,
+    diagnostics: &[lsp_types::Diagnostic],
+) -> lsp_ext::TestState {
+    let errors: Vec<&lsp_types::Diagnostic> = diagnostics
+        .iter()
+        .filter(|diag| diag.severity == Some(lsp_types::DiagnosticSeverity::ERROR))
+        .filter(|diag| range.start <= diag.range.start && diag.range.end <= range.end)
+        .collect();
+    let message = errors.iter().map(|diag| diag.message.as_str()).collect::<Vec<_>>().join("\n");
+    if errors.is_empty() {
+        lsp_ext::TestState::Passed
+    } else if errors.iter().any(|diag| is_timeout(&diag.message)) {
+        lsp_ext::TestState::TimedOut { message }
+    } else {
+        lsp_ext::TestState::Failed { message }
+    }
+}
+
+fn is_timeout(message: &str) -> bool {
+    message.contains("rlimit") || message.contains("timed out")
+}
+
+fn fail_items(state: &mut GlobalState, items: Vec<VerificationItem>, message: &str) {
+    for item in items {
+        let test_state = lsp_ext::TestState::Failed { message: message.to_owned() };
+        send_state(state, &item.test_id, test_state);
+    }
+}
+
+fn send_state(state: &mut GlobalState, test_id: &str, test_state: lsp_ext::TestState) {
+    state.send_notification::<lsp_ext::ChangeTestState>(lsp_ext::ChangeTestStateParams {
+        test_id: test_id.to_owned(),
+        state: test_state,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+
+    use super::*;
+
+    fn range(start: u32, end: u32) -> Range {
+        Range::new(Position::new(start, 0), Position::new(end, 0))
+    }
+
+    fn error(line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            range: range(line, line),
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: message.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn item_state_only_counts_errors_inside_the_function() {
+        let diagnostics = [
+            error(1, "assertion failed"),
+            Diagnostic { severity: Some(DiagnosticSeverity::WARNING), ..error(5, "unused") },
+        ];
+        assert!(matches!(item_state(range(4, 8), &diagnostics), lsp_ext::TestState::Passed));
+        assert!(matches!(
+            item_state(range(0, 3), &diagnostics),
+            lsp_ext::TestState::Failed { message } if message == "assertion failed"
+        ));
+    }
+
+    #[test]
+    fn item_state_reports_timeouts() {
+        let diagnostics =
+            [error(1, "postcondition not satisfied"), error(2, "Resource limit (rlimit) exceeded")];
+        assert!(matches!(
+            item_state(range(0, 3), &diagnostics),
+            lsp_ext::TestState::TimedOut { message }
+                if message == "postcondition not satisfied\nResource limit (rlimit) exceeded"
+        ));
+    }
+}
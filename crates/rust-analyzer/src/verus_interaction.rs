@@ -1,61 +1,349 @@
+use ide::FileId;
 use ide_assists::proof_plumber_api::verus_error::{
-    AssertFailure, PostFailure, PreFailure, VerusError,
+    AssertFailure, InvFailure, InvFailureAt, PostFailure, PreFailure, VerusError,
 };
-use syntax::{TextRange, TextSize};
+use nohash_hasher::IntMap;
+use project_model::ProjectWorkspace;
+use syntax::{ast, AstNode, TextRange, TextSize};
+use triomphe::Arc;
+use vfs::AbsPath;
 
-pub(crate) fn diagnostic_to_verus_err(diagnostic: &flycheck::Diagnostic) -> Option<VerusError> {
-    if diagnostic.message.contains("precondition not satisfied") {
-        if diagnostic.spans.len() == 2 {
-            let range0 = TextRange::new(
-                TextSize::from(diagnostic.spans[0].byte_start),
-                TextSize::from(diagnostic.spans[0].byte_end),
-            );
-            let range1 = TextRange::new(
-                TextSize::from(diagnostic.spans[1].byte_start),
-                TextSize::from(diagnostic.spans[1].byte_end),
-            );
-            let verr;
-            if diagnostic.spans[0].is_primary {
-                verr = VerusError::Pre(PreFailure { failing_pre: range1, callsite: range0 });
-            } else {
-                verr = VerusError::Pre(PreFailure { failing_pre: range0, callsite: range1 });
+/// A verifier error, together with the functions it was reported in.
+#[derive(Debug, Clone)]
+pub(crate) struct FileVerusError {
+    pub(crate) error: VerusError,
+    /// The file each range of `error` is in, in the order of `error_ranges`. Only the primary
+    /// range is always in the file the error is recorded for; e.g. a failing precondition may be
+    /// in another file than the call.
+    range_files: Vec<FileId>,
+    /// The error stays valid only as long as none of these functions are edited.
+    fn_ranges: Vec<(FileId, TextRange)>,
+}
+
+/// The verifier errors of every file.
+///
+/// Errors reported by a running verification are kept aside until it finishes, at which point
+/// they replace the errors of the files it verified. Until then, the errors of the previous
+/// verification stay available for the proof actions.
+#[derive(Debug, Default)]
+pub(crate) struct VerusErrors {
+    committed: Arc<IntMap<FileId, Vec<FileVerusError>>>,
+    /// Errors of the running verifications, per flycheck id.
+    pending: IntMap<usize, IntMap<FileId, Vec<FileVerusError>>>,
+    /// The file the last requested verification of each flycheck covers, or `None` if it covers
+    /// the whole crate.
+    scopes: IntMap<usize, Option<FileId>>,
+}
+
+impl VerusErrors {
+    pub(crate) fn committed(&self) -> Arc<IntMap<FileId, Vec<FileVerusError>>> {
+        self.committed.clone()
+    }
+
+    /// Whether any error lies in `file_id`, including errors recorded for another file.
+    pub(crate) fn has_errors_for(&self, file_id: FileId) -> bool {
+        let in_file = |errors: &IntMap<FileId, Vec<FileVerusError>>| {
+            errors.contains_key(&file_id)
+                || errors.values().flatten().any(|it| it.range_files.contains(&file_id))
+        };
+        in_file(&self.committed) || self.pending.values().any(in_file)
+    }
+
+    /// Records which errors the next verification of `flycheck_id` to finish replaces.
+    pub(crate) fn verification_requested(&mut self, flycheck_id: usize, scope: Option<FileId>) {
+        self.scopes.insert(flycheck_id, scope);
+    }
+
+    pub(crate) fn verification_started(&mut self, flycheck_id: usize) {
+        self.pending.remove(&flycheck_id);
+    }
+
+    pub(crate) fn verification_cancelled(&mut self, flycheck_id: usize) {
+        self.pending.remove(&flycheck_id);
+    }
+
+    pub(crate) fn verification_finished(&mut self, flycheck_id: usize) {
+        let pending = self.pending.remove(&flycheck_id).unwrap_or_default();
+        let committed = Arc::make_mut(&mut self.committed);
+        match self.scopes.get(&flycheck_id).copied().flatten() {
+            Some(file_id) => {
+                committed.remove(&file_id);
             }
-            Some(verr)
-        } else {
-            // panic!("pre unexpected num of span");
-            None
+            None => committed.clear(),
         }
-    } else if diagnostic.message.contains("postcondition not satisfied") {
-        if diagnostic.spans.len() == 2 {
-            let range0 = TextRange::new(
-                TextSize::from(diagnostic.spans[0].byte_start),
-                TextSize::from(diagnostic.spans[0].byte_end),
-            );
-            let range1 = TextRange::new(
-                TextSize::from(diagnostic.spans[1].byte_start),
-                TextSize::from(diagnostic.spans[1].byte_end),
-            );
-            let verr;
-            if diagnostic.spans[0].is_primary {
-                verr = VerusError::Post(PostFailure { failing_post: range0, func_name: range1 });
-            } else {
-                verr = VerusError::Post(PostFailure { failing_post: range1, func_name: range0 });
-            }
-            Some(verr)
-        } else {
-            // panic!("post unexpected num of span");
-            None
+        committed.extend(pending);
+    }
+
+    /// Drops the errors of `file_id`, e.g. because it is known to verify.
+    pub(crate) fn clear_file(&mut self, file_id: FileId) {
+        Arc::make_mut(&mut self.committed).remove(&file_id);
+    }
+
+    /// Adds an error reported by the running verification of `flycheck_id`, given the file each
+    /// of its ranges is in. The error is recorded for the file of its primary range.
+    pub(crate) fn add(
+        &mut self,
+        flycheck_id: usize,
+        error: VerusError,
+        range_files: Vec<FileId>,
+        parse: impl Fn(FileId) -> Option<ast::SourceFile>,
+    ) {
+        let Some(&file_id) = range_files.first() else { return };
+        let mut fn_ranges: Vec<(FileId, TextRange)> = error_ranges(&error)
+            .zip(&range_files)
+            .map(|(range, &file_id)| {
+                let fn_range = parse(file_id)
+                    .and_then(|source_file| {
+                        source_file.syntax().token_at_offset(range.start()).right_biased()
+                    })
+                    .and_then(|token| token.parent_ancestors().find_map(ast::Fn::cast))
+                    .map_or(range, |func| func.syntax().text_range());
+                (file_id, fn_range)
+            })
+            .collect();
+        fn_ranges.sort_by_key(|(file_id, range)| (*file_id, range.start(), range.end()));
+        fn_ranges.dedup();
+        let errors = self.pending.entry(flycheck_id).or_default().entry(file_id).or_default();
+        errors.push(FileVerusError { error, range_files, fn_ranges });
+    }
+
+    /// Moves the errors lying in `file_id` along with an edit from `old_text` to `new_text`.
+    ///
+    /// The edit is reduced to the single range between the common prefix and suffix of both
+    /// texts. Errors in functions overlapping that range are dropped, as the verifier's verdict on
+    /// them no longer holds; errors after it are shifted.
+    pub(crate) fn apply_edit(&mut self, file_id: FileId, old_text: &str, new_text: Option<&str>) {
+        let Some(new_text) = new_text else {
+            let drop_file = |files: &mut IntMap<FileId, Vec<FileVerusError>>| {
+                files.remove(&file_id);
+                files.values_mut().for_each(|errors| {
+                    errors.retain(|it| !it.range_files.contains(&file_id));
+                });
+            };
+            drop_file(Arc::make_mut(&mut self.committed));
+            self.pending.values_mut().for_each(drop_file);
+            return;
+        };
+        let prefix = common_prefix_len(old_text.as_bytes(), new_text.as_bytes());
+        let suffix = common_prefix_len(
+            old_text.as_bytes()[prefix..].iter().rev(),
+            new_text.as_bytes()[prefix..].iter().rev(),
+        );
+        let edit_start = TextSize::from(prefix as u32);
+        let edit_end = TextSize::from((old_text.len() - suffix) as u32);
+        let new_end = TextSize::from((new_text.len() - suffix) as u32);
+        if edit_start == edit_end && edit_end == new_end {
+            return;
         }
+
+        let remap = |errors: &mut Vec<FileVerusError>| {
+            errors.retain(|it| {
+                it.fn_ranges.iter().all(|(range_file, range)| {
+                    *range_file != file_id || range.end() <= edit_start || range.start() >= edit_end
+                })
+            });
+            let shift = |range: &mut TextRange| {
+                if range.start() >= edit_end {
+                    *range = TextRange::new(
+                        range.start() - edit_end + new_end,
+                        range.end() - edit_end + new_end,
+                    );
+                }
+            };
+            for it in errors.iter_mut() {
+                it.fn_ranges
+                    .iter_mut()
+                    .filter(|(range_file, _)| *range_file == file_id)
+                    .for_each(|(_, range)| shift(range));
+                error_ranges_mut(&mut it.error)
+                    .zip(&it.range_files)
+                    .filter(|(_, range_file)| **range_file == file_id)
+                    .for_each(|(range, _)| shift(range));
+            }
+        };
+        Arc::make_mut(&mut self.committed).values_mut().for_each(remap);
+        self.pending.values_mut().flat_map(|files| files.values_mut()).for_each(remap);
+    }
+}
+
+fn common_prefix_len<'a>(
+    a: impl IntoIterator<Item = &'a u8>,
+    b: impl IntoIterator<Item = &'a u8>,
+) -> usize {
+    a.into_iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+fn error_ranges(error: &VerusError) -> impl Iterator<Item = TextRange> {
+    let ranges = match error {
+        VerusError::Pre(pre) => vec![pre.callsite, pre.failing_pre],
+        VerusError::Post(post) => vec![post.failing_post, post.func_name],
+        VerusError::Assert(assert) => vec![assert.range],
+        VerusError::Inv(inv) => vec![inv.failing_inv],
+    };
+    ranges.into_iter()
+}
+
+fn error_ranges_mut(error: &mut VerusError) -> impl Iterator<Item = &mut TextRange> {
+    let ranges = match error {
+        VerusError::Pre(pre) => vec![&mut pre.callsite, &mut pre.failing_pre],
+        VerusError::Post(post) => vec![&mut post.failing_post, &mut post.func_name],
+        VerusError::Assert(assert) => vec![&mut assert.range],
+        VerusError::Inv(inv) => vec![&mut inv.failing_inv],
+    };
+    ranges.into_iter()
+}
+
+/// The Verus settings for the package owning `path`, or `None` if its package opted out of
+/// verification. Files outside of any Cargo package use the defaults.
+pub(crate) fn verus_package_options(
+    workspaces: &[ProjectWorkspace],
+    path: &AbsPath,
+) -> Option<flycheck::VerusPackageOptions> {
+    let Some(metadata) = workspaces.iter().find_map(|ws| ws.verus_metadata_for(path)) else {
+        return Some(flycheck::VerusPackageOptions::default());
+    };
+    metadata.verify.then(|| flycheck::VerusPackageOptions {
+        extra_args: metadata.verus_args(),
+        verify_modules: metadata.verify_module.clone(),
+    })
+}
+
+/// Converts a Verus diagnostic into the error the proof actions work with, together with the file
+/// each of its ranges is in, in the order of `error_ranges`. The first one is the primary span.
+pub(crate) fn diagnostic_to_verus_err(
+    diagnostic: &flycheck::Diagnostic,
+) -> Option<(VerusError, Vec<String>)> {
+    let (error, spans) = verus_err(diagnostic)?;
+    Some((error, spans.into_iter().map(|span| span.file_name.clone()).collect()))
+}
+
+fn verus_err(
+    diagnostic: &flycheck::Diagnostic,
+) -> Option<(VerusError, Vec<&flycheck::DiagnosticSpan>)> {
+    let range = |span: &flycheck::DiagnosticSpan| {
+        TextRange::new(TextSize::from(span.byte_start), TextSize::from(span.byte_end))
+    };
+    // The primary span of a failing pre- or postcondition is the call or the failing
+    // postcondition, the other one is the failing precondition or the function.
+    let primary_first = || match &*diagnostic.spans {
+        [first, second] if first.is_primary => Some((first, second)),
+        [first, second] => Some((second, first)),
+        _ => None,
+    };
+    if diagnostic.message.contains("precondition not satisfied") {
+        let (callsite, failing_pre) = primary_first()?;
+        let pre = PreFailure { failing_pre: range(failing_pre), callsite: range(callsite) };
+        Some((VerusError::Pre(pre), vec![callsite, failing_pre]))
+    } else if diagnostic.message.contains("postcondition not satisfied") {
+        let (failing_post, func_name) = primary_first()?;
+        let post = PostFailure { failing_post: range(failing_post), func_name: range(func_name) };
+        Some((VerusError::Post(post), vec![failing_post, func_name]))
     } else if diagnostic.message.contains("assertion failed") {
         // only reading first span now
-        // dbg!(&diagnostic.spans);
-        let range = TextRange::new(
-            TextSize::from(diagnostic.spans[0].byte_start),
-            TextSize::from(diagnostic.spans[0].byte_end),
-        );
-        let verr = VerusError::Assert(AssertFailure { range });
-        Some(verr)
+        let span = diagnostic.spans.first()?;
+        Some((VerusError::Assert(AssertFailure { range: range(span) }), vec![span]))
+    } else if diagnostic.message.contains("invariant not satisfied") {
+        let at = if diagnostic.message.contains("before loop") {
+            InvFailureAt::BeforeLoop
+        } else if diagnostic.message.contains("end of loop body") {
+            InvFailureAt::EndOfBody
+        } else {
+            return None;
+        };
+        // the primary span is the failing invariant
+        let span = diagnostic.spans.iter().find(|span| span.is_primary)?;
+        Some((VerusError::Inv(InvFailure { failing_inv: range(span), at }), vec![span]))
     } else {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ide::Edition;
+
+    use super::*;
+
+    fn assert_at(text: &str, needle: &str) -> VerusError {
+        let start = text.find(needle).unwrap();
+        let range = TextRange::at(TextSize::from(start as u32), TextSize::of(needle));
+        VerusError::Assert(AssertFailure { range })
+    }
+
+    #[test]
+    fn errors_follow_edits() {
+        let file_id = FileId::from_raw(0);
+        let text = "fn a() { assert(x); }\nfn b() { assert(y); }\n";
+        let source_file = ast::SourceFile::parse(text, Edition::CURRENT).tree();
+        let parse = |_| Some(source_file.clone());
+        let mut errors = VerusErrors::default();
+        errors.verification_requested(0, Some(file_id));
+        errors.verification_started(0);
+        errors.add(0, assert_at(text, "x"), vec![file_id], parse);
+        errors.add(0, assert_at(text, "y"), vec![file_id], parse);
+        errors.verification_finished(0);
+
+        // Editing before both functions shifts both errors.
+        let shifted = format!("// hi\n{text}");
+        errors.apply_edit(file_id, text, Some(&shifted));
+        let current: Vec<_> =
+            errors.committed()[&file_id].iter().map(|it| it.error.clone()).collect();
+        assert_eq!(current, vec![assert_at(&shifted, "x"), assert_at(&shifted, "y")]);
+
+        // Editing inside `a` only drops the error in `a`.
+        let edited = shifted.replace("assert(x)", "assert(x && z)");
+        errors.apply_edit(file_id, &shifted, Some(&edited));
+        let current: Vec<_> =
+            errors.committed()[&file_id].iter().map(|it| it.error.clone()).collect();
+        assert_eq!(current, vec![assert_at(&edited, "y")]);
+
+        // A new verification of the file replaces its errors, a cancelled one does not.
+        errors.verification_started(0);
+        errors.verification_cancelled(0);
+        assert_eq!(errors.committed()[&file_id].len(), 1);
+        errors.verification_started(0);
+        errors.verification_finished(0);
+        assert!(!errors.has_errors_for(file_id));
+    }
+
+    #[test]
+    fn errors_across_files() {
+        let (caller_id, callee_id) = (FileId::from_raw(0), FileId::from_raw(1));
+        let caller = "fn a() { b(); }\nfn c() {}\n";
+        let callee = "fn b() requires p() {}\n";
+        let parse = |file_id| {
+            let text = if file_id == caller_id { caller } else { callee };
+            Some(ast::SourceFile::parse(text, Edition::CURRENT).tree())
+        };
+        let pre = |caller: &str, callee: &str| {
+            let at = |text: &str, needle: &str| {
+                TextRange::at(
+                    TextSize::from(text.find(needle).unwrap() as u32),
+                    TextSize::of(needle),
+                )
+            };
+            VerusError::Pre(PreFailure {
+                failing_pre: at(callee, "p()"),
+                callsite: at(caller, "b()"),
+            })
+        };
+        let mut errors = VerusErrors::default();
+        errors.verification_requested(0, None);
+        errors.verification_started(0);
+        errors.add(0, pre(caller, callee), vec![caller_id, callee_id], parse);
+        errors.verification_finished(0);
+        assert!(errors.has_errors_for(callee_id));
+
+        // Edits only shift the ranges in the edited file.
+        let shifted = format!("// hi\n{callee}");
+        errors.apply_edit(callee_id, callee, Some(&shifted));
+        let current: Vec<_> =
+            errors.committed()[&caller_id].iter().map(|it| it.error.clone()).collect();
+        assert_eq!(current, vec![pre(caller, &shifted)]);
+
+        // Editing the callee invalidates the error recorded for the caller.
+        let edited = shifted.replace("p()", "q()");
+        errors.apply_edit(callee_id, &shifted, Some(&edited));
+        assert!(errors.committed()[&caller_id].is_empty());
+    }
+}
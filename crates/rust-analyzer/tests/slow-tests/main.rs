@@ -520,6 +520,48 @@ fn otherpkg() {}
     );
 }
 
+#[test]
+fn test_runnables_verify_package_args() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = Project::with_fixture(
+        r#"
+//- /foo/Cargo.toml
+[package]
+name = "foo"
+version = "0.1.0"
+
+[package.metadata.verus.ide]
+extra_args = "--no-lint"
+
+//- /foo/src/lib.rs
+proof fn lemma() {}
+"#,
+    )
+    .root("foo")
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    let res = server.send_request::<Runnables>(RunnablesParams {
+        text_document: server.doc_id("foo/src/lib.rs"),
+        position: None,
+    });
+    let verify_args: Vec<_> = res
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|it| it["kind"] == "shell")
+        .map(|it| it["args"]["args"].as_array().unwrap().clone())
+        .collect();
+    assert!(!verify_args.is_empty());
+    for args in verify_args {
+        assert!(args.windows(2).any(|it| it == [json!("--crate-type"), json!("lib")]), "{args:?}");
+        assert!(args.contains(&json!("--no-lint")), "{args:?}");
+    }
+}
+
 
 // What happens when we don't have a workspace/Cargo.toml file?
 #[test]
@@ -166,7 +166,9 @@ impl ast::BinExpr {
                 T![<==]=> BinaryOp::LogicOp(LogicOp::RevImply),
 
                 T![==] => BinaryOp::CmpOp(CmpOp::Eq { negated: false }),
-                T![=~=] | T![=~~=] | T![===] => BinaryOp::CmpOp(CmpOp::Eq { negated: false }),   // verus: TODO: Do we need a separate operator for extensional equality?
+                T![===] => BinaryOp::CmpOp(CmpOp::Eq { negated: false }),   // verus
+                T![=~=] => BinaryOp::CmpOp(CmpOp::ExtEq { deep: false }),   // verus
+                T![=~~=] => BinaryOp::CmpOp(CmpOp::ExtEq { deep: true }),   // verus
                 T![!==] => BinaryOp::CmpOp(CmpOp::Eq { negated: true }),   // verus: TODO: Do we need a separate operator for extensional equality?
                 T![!=] => BinaryOp::CmpOp(CmpOp::Eq { negated: true }),
                 T![<=] => BinaryOp::CmpOp(CmpOp::Ord { ordering: Ordering::Less,    strict: false }),
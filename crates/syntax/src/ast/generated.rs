@@ -6,6 +6,8 @@ pub(crate) mod tokens;
 #[rustfmt::skip]
 #[allow(unused_mut)]
 pub mod vst_nodes;
+#[rustfmt::skip]
+pub mod vst_visit;
 
 use crate::{
     AstNode,
@@ -217,6 +217,7 @@ pub struct BlockExpr {
 }
 impl ast::HasAttrs for BlockExpr {}
 impl BlockExpr {
+    pub fn fn_mode(&self) -> Option<FnMode> { support::child(&self.syntax) }
     pub fn label(&self) -> Option<Label> { support::child(&self.syntax) }
     pub fn stmt_list(&self) -> Option<StmtList> { support::child(&self.syntax) }
     pub fn async_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![async]) }
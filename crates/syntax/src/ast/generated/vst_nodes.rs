@@ -156,6 +156,7 @@ pub struct BecomeExpr {
 pub struct BlockExpr {
     pub attrs: Vec<Attr>,
     pub label: Option<Box<Label>>,
+    pub fn_mode: Option<Box<FnMode>>,
     pub try_token: bool,
     pub unsafe_token: bool,
     pub async_token: bool,
@@ -452,6 +453,7 @@ pub struct ForExpr {
     pub in_token: bool,
     pub iter_name: Option<Box<Name>>,
     pub colon_token: bool,
+    pub iterable: Box<Expr>,
     pub loop_clauses: Vec<LoopClause>,
     pub loop_body: Box<BlockExpr>,
     pub cst: Option<super::nodes::ForExpr>,
@@ -734,6 +736,7 @@ pub struct MatchExpr {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MatchGuard {
     pub if_token: bool,
+    pub condition: Box<Expr>,
     pub cst: Option<super::nodes::MatchGuard>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -919,12 +922,6 @@ pub struct PathType {
     pub cst: Option<super::nodes::PathType>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrefixExpr {
-    pub attrs: Vec<Attr>,
-    pub expr: Box<Expr>,
-    pub cst: Option<super::nodes::PrefixExpr>,
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProofFnCharacteristics {
     pub l_brack_token: bool,
     pub fields: Vec<Path>,
@@ -1450,6 +1447,7 @@ pub struct WhileExpr {
     pub attrs: Vec<Attr>,
     pub label: Option<Box<Label>>,
     pub while_token: bool,
+    pub condition: Box<Expr>,
     pub loop_clauses: Vec<LoopClause>,
     pub loop_body: Box<BlockExpr>,
     pub cst: Option<super::nodes::WhileExpr>,
@@ -2002,6 +2000,10 @@ impl TryFrom<super::nodes::BlockExpr> for BlockExpr {
                 Some(it) => Some(Box::new(Label::try_from(it)?)),
                 None => None,
             },
+            fn_mode: match item.fn_mode() {
+                Some(it) => Some(Box::new(FnMode::try_from(it)?)),
+                None => None,
+            },
             try_token: item.try_token().is_some(),
             unsafe_token: item.unsafe_token().is_some(),
             async_token: item.async_token().is_some(),
@@ -2761,6 +2763,11 @@ impl TryFrom<super::nodes::ForExpr> for ForExpr {
                 None => None,
             },
             colon_token: item.colon_token().is_some(),
+            iterable: Box::new(
+                item.iterable()
+                    .ok_or(format!("{}", stringify!(iterable)))
+                    .map(|it| Expr::try_from(it))??,
+            ),
             loop_clauses: item
                 .loop_clauses()
                 .into_iter()
@@ -3469,7 +3476,15 @@ impl TryFrom<super::nodes::MatchExpr> for MatchExpr {
 impl TryFrom<super::nodes::MatchGuard> for MatchGuard {
     type Error = String;
     fn try_from(item: super::nodes::MatchGuard) -> Result<Self, Self::Error> {
-        Ok(Self { if_token: item.if_token().is_some(), cst: Some(item.clone()) })
+        Ok(Self {
+            if_token: item.if_token().is_some(),
+            condition: Box::new(
+                item.condition()
+                    .ok_or(format!("{}", stringify!(condition)))
+                    .map(|it| Expr::try_from(it))??,
+            ),
+            cst: Some(item.clone()),
+        })
     }
 }
 impl TryFrom<super::nodes::MatchesExpr> for MatchesExpr {
@@ -3889,24 +3904,6 @@ impl TryFrom<super::nodes::PathType> for PathType {
         })
     }
 }
-impl TryFrom<super::nodes::PrefixExpr> for PrefixExpr {
-    type Error = String;
-    fn try_from(item: super::nodes::PrefixExpr) -> Result<Self, Self::Error> {
-        Ok(Self {
-            attrs: item
-                .attrs()
-                .into_iter()
-                .map(Attr::try_from)
-                .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
-        })
-    }
-}
 impl TryFrom<super::nodes::ProofFnCharacteristics> for ProofFnCharacteristics {
     type Error = String;
     fn try_from(item: super::nodes::ProofFnCharacteristics) -> Result<Self, Self::Error> {
@@ -5188,6 +5185,11 @@ impl TryFrom<super::nodes::WhileExpr> for WhileExpr {
                 None => None,
             },
             while_token: item.while_token().is_some(),
+            condition: Box::new(
+                item.condition()
+                    .ok_or(format!("{}", stringify!(condition)))
+                    .map(|it| Expr::try_from(it))??,
+            ),
             loop_clauses: item
                 .loop_clauses()
                 .into_iter()
@@ -5921,6 +5923,10 @@ impl std::fmt::Display for BlockExpr {
             s.push_str(&it.to_string());
             s.push_str(" ");
         }
+        if let Some(it) = &self.fn_mode {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
         if self.try_token {
             let mut tmp = stringify!(try_token).to_string();
             tmp.truncate(tmp.len() - 6);
@@ -6135,81 +6141,6 @@ impl std::fmt::Display for CastExpr {
         write!(f, "{s}")
     }
 }
-impl std::fmt::Display for ClosureExpr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
-        if self.for_token {
-            let mut tmp = stringify!(for_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if let Some(it) = &self.generic_param_list {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        if self.const_token {
-            let mut tmp = stringify!(const_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if self.static_token {
-            let mut tmp = stringify!(static_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if self.async_token {
-            let mut tmp = stringify!(async_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if self.move_token {
-            let mut tmp = stringify!(move_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if let Some(it) = &self.proof_fn_with_characteristics {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        if self.forall_token {
-            let mut tmp = stringify!(forall_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if self.exists_token {
-            let mut tmp = stringify!(exists_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        if let Some(it) = &self.param_list {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        if let Some(it) = &self.ret_type {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        if let Some(it) = &self.requires_clause {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        if let Some(it) = &self.ensures_clause {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        s.push_str(&self.body.to_string());
-        s.push_str(" ");
-        write!(f, "{s}")
-    }
-}
 impl std::fmt::Display for Const {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = String::new();
@@ -6378,7 +6309,7 @@ impl std::fmt::Display for DecreasesClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -6391,7 +6322,7 @@ impl std::fmt::Display for DefaultEnsuresClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -6418,7 +6349,7 @@ impl std::fmt::Display for EnsuresClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -6819,6 +6750,8 @@ impl std::fmt::Display for ForExpr {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
+        s.push_str(&self.iterable.to_string());
+        s.push_str(" ");
         s.push_str(
             &self.loop_clauses.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "),
         );
@@ -7097,7 +7030,7 @@ impl std::fmt::Display for InvariantClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -7110,7 +7043,7 @@ impl std::fmt::Display for InvariantExceptBreakClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -7573,6 +7506,8 @@ impl std::fmt::Display for MatchGuard {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
+        s.push_str(&self.condition.to_string());
+        s.push_str(" ");
         write!(f, "{s}")
     }
 }
@@ -8094,15 +8029,6 @@ impl std::fmt::Display for PathType {
         write!(f, "{s}")
     }
 }
-impl std::fmt::Display for PrefixExpr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
-        s.push_str(&self.expr.to_string());
-        s.push_str(" ");
-        write!(f, "{s}")
-    }
-}
 impl std::fmt::Display for ProofFnCharacteristics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = String::new();
@@ -8288,7 +8214,7 @@ impl std::fmt::Display for RecommendsClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         if self.via_token {
             let mut tmp = stringify!(via_token).to_string();
             tmp.truncate(tmp.len() - 6);
@@ -8340,7 +8266,7 @@ impl std::fmt::Display for RecordExprFieldList {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
+        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
         s.push_str(
             &self.fields.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "),
         );
@@ -8579,7 +8505,7 @@ impl std::fmt::Display for RequiresClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -9067,7 +8993,7 @@ impl std::fmt::Display for TriggerAttribute {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -9096,7 +9022,9 @@ impl std::fmt::Display for TupleExpr {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.fields.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(
+            &self.fields.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "),
+        );
         if self.r_paren_token {
             let mut tmp = stringify!(r_paren_token).to_string();
             tmp.truncate(tmp.len() - 6);
@@ -9151,7 +9079,9 @@ impl std::fmt::Display for TuplePat {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.fields.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(
+            &self.fields.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "),
+        );
         if self.r_paren_token {
             let mut tmp = stringify!(r_paren_token).to_string();
             tmp.truncate(tmp.len() - 6);
@@ -9681,6 +9611,8 @@ impl std::fmt::Display for WhileExpr {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
+        s.push_str(&self.condition.to_string());
+        s.push_str(" ");
         s.push_str(
             &self.loop_clauses.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "),
         );
@@ -10791,6 +10723,7 @@ impl BlockExpr {
         Self {
             attrs: vec![],
             label: None,
+            fn_mode: None,
             try_token: false,
             unsafe_token: false,
             async_token: false,
@@ -11105,7 +11038,10 @@ impl FnPtrType {
     }
 }
 impl ForExpr {
-    pub fn new(loop_body: BlockExpr) -> Self {
+    pub fn new<ET0>(iterable: ET0, loop_body: BlockExpr) -> Self
+    where
+        ET0: Into<Expr>,
+    {
         Self {
             attrs: vec![],
             label: None,
@@ -11114,6 +11050,7 @@ impl ForExpr {
             in_token: true,
             iter_name: None,
             colon_token: false,
+            iterable: Box::new(iterable.into()),
             loop_clauses: vec![],
             loop_body: Box::new(loop_body),
             cst: None,
@@ -11419,7 +11356,12 @@ impl MatchExpr {
     }
 }
 impl MatchGuard {
-    pub fn new() -> Self { Self { if_token: true, cst: None } }
+    pub fn new<ET0>(condition: ET0) -> Self
+    where
+        ET0: Into<Expr>,
+    {
+        Self { if_token: true, condition: Box::new(condition.into()), cst: None }
+    }
 }
 impl MatchesExpr {
     pub fn new<ET0>(expr: ET0) -> Self
@@ -11622,14 +11564,6 @@ impl PathSegment {
 impl PathType {
     pub fn new(path: Path) -> Self { Self { path: Box::new(path), cst: None } }
 }
-impl PrefixExpr {
-    pub fn new<ET0>(expr: ET0) -> Self
-    where
-        ET0: Into<Expr>,
-    {
-        Self { attrs: vec![], expr: Box::new(expr.into()), cst: None }
-    }
-}
 impl ProofFnCharacteristics {
     pub fn new() -> Self {
         Self { l_brack_token: true, fields: vec![], r_brack_token: true, cst: None }
@@ -12168,11 +12102,15 @@ impl WherePred {
     }
 }
 impl WhileExpr {
-    pub fn new(loop_body: BlockExpr) -> Self {
+    pub fn new<ET0>(condition: ET0, loop_body: BlockExpr) -> Self
+    where
+        ET0: Into<Expr>,
+    {
         Self {
             attrs: vec![],
             label: None,
             while_token: true,
+            condition: Box::new(condition.into()),
             loop_clauses: vec![],
             loop_body: Box::new(loop_body),
             cst: None,
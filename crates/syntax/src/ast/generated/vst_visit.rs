@@ -0,0 +1,5645 @@
+//! Generated by `sourcegen_vst`, do not edit by hand.
+
+#![allow(unused_mut, unused_variables)]
+use crate::ast::vst::*;
+pub trait VstVisitor {
+    fn visit_abi(&mut self, node: &Abi) { walk_abi(self, node) }
+    fn visit_arg_list(&mut self, node: &ArgList) { walk_arg_list(self, node) }
+    fn visit_array_expr(&mut self, node: &ArrayExpr) { walk_array_expr(self, node) }
+    fn visit_array_type(&mut self, node: &ArrayType) { walk_array_type(self, node) }
+    fn visit_arrow_expr(&mut self, node: &ArrowExpr) { walk_arrow_expr(self, node) }
+    fn visit_asm_expr(&mut self, node: &AsmExpr) { walk_asm_expr(self, node) }
+    fn visit_assert_expr(&mut self, node: &AssertExpr) { walk_assert_expr(self, node) }
+    fn visit_assert_forall_expr(&mut self, node: &AssertForallExpr) {
+        walk_assert_forall_expr(self, node)
+    }
+    fn visit_assoc_item_list(&mut self, node: &AssocItemList) { walk_assoc_item_list(self, node) }
+    fn visit_assoc_type_arg(&mut self, node: &AssocTypeArg) { walk_assoc_type_arg(self, node) }
+    fn visit_assume_expr(&mut self, node: &AssumeExpr) { walk_assume_expr(self, node) }
+    fn visit_assume_specification(&mut self, node: &AssumeSpecification) {
+        walk_assume_specification(self, node)
+    }
+    fn visit_attr(&mut self, node: &Attr) { walk_attr(self, node) }
+    fn visit_await_expr(&mut self, node: &AwaitExpr) { walk_await_expr(self, node) }
+    fn visit_become_expr(&mut self, node: &BecomeExpr) { walk_become_expr(self, node) }
+    fn visit_bin_expr(&mut self, node: &BinExpr) { walk_bin_expr(self, node) }
+    fn visit_block_expr(&mut self, node: &BlockExpr) { walk_block_expr(self, node) }
+    fn visit_box_pat(&mut self, node: &BoxPat) { walk_box_pat(self, node) }
+    fn visit_break_expr(&mut self, node: &BreakExpr) { walk_break_expr(self, node) }
+    fn visit_broadcast_group(&mut self, node: &BroadcastGroup) { walk_broadcast_group(self, node) }
+    fn visit_broadcast_group_identifier(&mut self, node: &BroadcastGroupIdentifier) {
+        walk_broadcast_group_identifier(self, node)
+    }
+    fn visit_broadcast_group_list(&mut self, node: &BroadcastGroupList) {
+        walk_broadcast_group_list(self, node)
+    }
+    fn visit_broadcast_group_member(&mut self, node: &BroadcastGroupMember) {
+        walk_broadcast_group_member(self, node)
+    }
+    fn visit_broadcast_use(&mut self, node: &BroadcastUse) { walk_broadcast_use(self, node) }
+    fn visit_broadcast_use_list(&mut self, node: &BroadcastUseList) {
+        walk_broadcast_use_list(self, node)
+    }
+    fn visit_call_expr(&mut self, node: &CallExpr) { walk_call_expr(self, node) }
+    fn visit_cast_expr(&mut self, node: &CastExpr) { walk_cast_expr(self, node) }
+    fn visit_closure_expr(&mut self, node: &ClosureExpr) { walk_closure_expr(self, node) }
+    fn visit_const(&mut self, node: &Const) { walk_const(self, node) }
+    fn visit_const_arg(&mut self, node: &ConstArg) { walk_const_arg(self, node) }
+    fn visit_const_block_pat(&mut self, node: &ConstBlockPat) { walk_const_block_pat(self, node) }
+    fn visit_const_param(&mut self, node: &ConstParam) { walk_const_param(self, node) }
+    fn visit_continue_expr(&mut self, node: &ContinueExpr) { walk_continue_expr(self, node) }
+    fn visit_data_mode(&mut self, node: &DataMode) { walk_data_mode(self, node) }
+    fn visit_decreases_clause(&mut self, node: &DecreasesClause) {
+        walk_decreases_clause(self, node)
+    }
+    fn visit_default_ensures_clause(&mut self, node: &DefaultEnsuresClause) {
+        walk_default_ensures_clause(self, node)
+    }
+    fn visit_dyn_trait_type(&mut self, node: &DynTraitType) { walk_dyn_trait_type(self, node) }
+    fn visit_ensures_clause(&mut self, node: &EnsuresClause) { walk_ensures_clause(self, node) }
+    fn visit_enum(&mut self, node: &Enum) { walk_enum(self, node) }
+    fn visit_expr_stmt(&mut self, node: &ExprStmt) { walk_expr_stmt(self, node) }
+    fn visit_extern_block(&mut self, node: &ExternBlock) { walk_extern_block(self, node) }
+    fn visit_extern_crate(&mut self, node: &ExternCrate) { walk_extern_crate(self, node) }
+    fn visit_extern_item_list(&mut self, node: &ExternItemList) {
+        walk_extern_item_list(self, node)
+    }
+    fn visit_field_expr(&mut self, node: &FieldExpr) { walk_field_expr(self, node) }
+    fn visit_final_expr(&mut self, node: &FinalExpr) { walk_final_expr(self, node) }
+    fn visit_fn(&mut self, node: &Fn) { walk_fn(self, node) }
+    fn visit_fn_mode(&mut self, node: &FnMode) { walk_fn_mode(self, node) }
+    fn visit_fn_ptr_type(&mut self, node: &FnPtrType) { walk_fn_ptr_type(self, node) }
+    fn visit_for_expr(&mut self, node: &ForExpr) { walk_for_expr(self, node) }
+    fn visit_for_type(&mut self, node: &ForType) { walk_for_type(self, node) }
+    fn visit_format_args_arg(&mut self, node: &FormatArgsArg) { walk_format_args_arg(self, node) }
+    fn visit_format_args_expr(&mut self, node: &FormatArgsExpr) {
+        walk_format_args_expr(self, node)
+    }
+    fn visit_generic_arg_list(&mut self, node: &GenericArgList) {
+        walk_generic_arg_list(self, node)
+    }
+    fn visit_generic_param_list(&mut self, node: &GenericParamList) {
+        walk_generic_param_list(self, node)
+    }
+    fn visit_has_expr(&mut self, node: &HasExpr) { walk_has_expr(self, node) }
+    fn visit_ident_pat(&mut self, node: &IdentPat) { walk_ident_pat(self, node) }
+    fn visit_if_expr(&mut self, node: &IfExpr) { walk_if_expr(self, node) }
+    fn visit_impl(&mut self, node: &Impl) { walk_impl(self, node) }
+    fn visit_impl_trait_type(&mut self, node: &ImplTraitType) { walk_impl_trait_type(self, node) }
+    fn visit_index_expr(&mut self, node: &IndexExpr) { walk_index_expr(self, node) }
+    fn visit_infer_type(&mut self, node: &InferType) { walk_infer_type(self, node) }
+    fn visit_invariant_clause(&mut self, node: &InvariantClause) {
+        walk_invariant_clause(self, node)
+    }
+    fn visit_invariant_except_break_clause(&mut self, node: &InvariantExceptBreakClause) {
+        walk_invariant_except_break_clause(self, node)
+    }
+    fn visit_is_expr(&mut self, node: &IsExpr) { walk_is_expr(self, node) }
+    fn visit_item_list(&mut self, node: &ItemList) { walk_item_list(self, node) }
+    fn visit_label(&mut self, node: &Label) { walk_label(self, node) }
+    fn visit_let_else(&mut self, node: &LetElse) { walk_let_else(self, node) }
+    fn visit_let_expr(&mut self, node: &LetExpr) { walk_let_expr(self, node) }
+    fn visit_let_stmt(&mut self, node: &LetStmt) { walk_let_stmt(self, node) }
+    fn visit_lifetime(&mut self, node: &Lifetime) { walk_lifetime(self, node) }
+    fn visit_lifetime_arg(&mut self, node: &LifetimeArg) { walk_lifetime_arg(self, node) }
+    fn visit_lifetime_param(&mut self, node: &LifetimeParam) { walk_lifetime_param(self, node) }
+    fn visit_literal(&mut self, node: &Literal) { walk_literal(self, node) }
+    fn visit_literal_pat(&mut self, node: &LiteralPat) { walk_literal_pat(self, node) }
+    fn visit_loop_expr(&mut self, node: &LoopExpr) { walk_loop_expr(self, node) }
+    fn visit_macro_call(&mut self, node: &MacroCall) { walk_macro_call(self, node) }
+    fn visit_macro_def(&mut self, node: &MacroDef) { walk_macro_def(self, node) }
+    fn visit_macro_eager_input(&mut self, node: &MacroEagerInput) {
+        walk_macro_eager_input(self, node)
+    }
+    fn visit_macro_expr(&mut self, node: &MacroExpr) { walk_macro_expr(self, node) }
+    fn visit_macro_items(&mut self, node: &MacroItems) { walk_macro_items(self, node) }
+    fn visit_macro_pat(&mut self, node: &MacroPat) { walk_macro_pat(self, node) }
+    fn visit_macro_rules(&mut self, node: &MacroRules) { walk_macro_rules(self, node) }
+    fn visit_macro_stmts(&mut self, node: &MacroStmts) { walk_macro_stmts(self, node) }
+    fn visit_macro_type(&mut self, node: &MacroType) { walk_macro_type(self, node) }
+    fn visit_match_arm(&mut self, node: &MatchArm) { walk_match_arm(self, node) }
+    fn visit_match_arm_list(&mut self, node: &MatchArmList) { walk_match_arm_list(self, node) }
+    fn visit_match_expr(&mut self, node: &MatchExpr) { walk_match_expr(self, node) }
+    fn visit_match_guard(&mut self, node: &MatchGuard) { walk_match_guard(self, node) }
+    fn visit_matches_expr(&mut self, node: &MatchesExpr) { walk_matches_expr(self, node) }
+    fn visit_meta(&mut self, node: &Meta) { walk_meta(self, node) }
+    fn visit_method_call_expr(&mut self, node: &MethodCallExpr) {
+        walk_method_call_expr(self, node)
+    }
+    fn visit_mode_spec_checked(&mut self, node: &ModeSpecChecked) {
+        walk_mode_spec_checked(self, node)
+    }
+    fn visit_module(&mut self, node: &Module) { walk_module(self, node) }
+    fn visit_name(&mut self, node: &Name) { walk_name(self, node) }
+    fn visit_name_ref(&mut self, node: &NameRef) { walk_name_ref(self, node) }
+    fn visit_never_type(&mut self, node: &NeverType) { walk_never_type(self, node) }
+    fn visit_no_unwind_clause(&mut self, node: &NoUnwindClause) {
+        walk_no_unwind_clause(self, node)
+    }
+    fn visit_offset_of_expr(&mut self, node: &OffsetOfExpr) { walk_offset_of_expr(self, node) }
+    fn visit_opens_invariants_clause(&mut self, node: &OpensInvariantsClause) {
+        walk_opens_invariants_clause(self, node)
+    }
+    fn visit_or_pat(&mut self, node: &OrPat) { walk_or_pat(self, node) }
+    fn visit_param(&mut self, node: &Param) { walk_param(self, node) }
+    fn visit_param_list(&mut self, node: &ParamList) { walk_param_list(self, node) }
+    fn visit_paren_expr(&mut self, node: &ParenExpr) { walk_paren_expr(self, node) }
+    fn visit_paren_pat(&mut self, node: &ParenPat) { walk_paren_pat(self, node) }
+    fn visit_paren_type(&mut self, node: &ParenType) { walk_paren_type(self, node) }
+    fn visit_path(&mut self, node: &Path) { walk_path(self, node) }
+    fn visit_path_expr(&mut self, node: &PathExpr) { walk_path_expr(self, node) }
+    fn visit_path_pat(&mut self, node: &PathPat) { walk_path_pat(self, node) }
+    fn visit_path_segment(&mut self, node: &PathSegment) { walk_path_segment(self, node) }
+    fn visit_path_type(&mut self, node: &PathType) { walk_path_type(self, node) }
+    fn visit_prefix_expr(&mut self, node: &PrefixExpr) { walk_prefix_expr(self, node) }
+    fn visit_proof_fn_characteristics(&mut self, node: &ProofFnCharacteristics) {
+        walk_proof_fn_characteristics(self, node)
+    }
+    fn visit_proof_fn_type(&mut self, node: &ProofFnType) { walk_proof_fn_type(self, node) }
+    fn visit_proof_fn_with_characteristics(&mut self, node: &ProofFnWithCharacteristics) {
+        walk_proof_fn_with_characteristics(self, node)
+    }
+    fn visit_prover(&mut self, node: &Prover) { walk_prover(self, node) }
+    fn visit_ptr_type(&mut self, node: &PtrType) { walk_ptr_type(self, node) }
+    fn visit_publish(&mut self, node: &Publish) { walk_publish(self, node) }
+    fn visit_range_expr(&mut self, node: &RangeExpr) { walk_range_expr(self, node) }
+    fn visit_range_pat(&mut self, node: &RangePat) { walk_range_pat(self, node) }
+    fn visit_recommends_clause(&mut self, node: &RecommendsClause) {
+        walk_recommends_clause(self, node)
+    }
+    fn visit_record_expr(&mut self, node: &RecordExpr) { walk_record_expr(self, node) }
+    fn visit_record_expr_field(&mut self, node: &RecordExprField) {
+        walk_record_expr_field(self, node)
+    }
+    fn visit_record_expr_field_list(&mut self, node: &RecordExprFieldList) {
+        walk_record_expr_field_list(self, node)
+    }
+    fn visit_record_field(&mut self, node: &RecordField) { walk_record_field(self, node) }
+    fn visit_record_field_list(&mut self, node: &RecordFieldList) {
+        walk_record_field_list(self, node)
+    }
+    fn visit_record_pat(&mut self, node: &RecordPat) { walk_record_pat(self, node) }
+    fn visit_record_pat_field(&mut self, node: &RecordPatField) {
+        walk_record_pat_field(self, node)
+    }
+    fn visit_record_pat_field_list(&mut self, node: &RecordPatFieldList) {
+        walk_record_pat_field_list(self, node)
+    }
+    fn visit_ref_expr(&mut self, node: &RefExpr) { walk_ref_expr(self, node) }
+    fn visit_ref_pat(&mut self, node: &RefPat) { walk_ref_pat(self, node) }
+    fn visit_ref_type(&mut self, node: &RefType) { walk_ref_type(self, node) }
+    fn visit_rename(&mut self, node: &Rename) { walk_rename(self, node) }
+    fn visit_requires_clause(&mut self, node: &RequiresClause) { walk_requires_clause(self, node) }
+    fn visit_rest_pat(&mut self, node: &RestPat) { walk_rest_pat(self, node) }
+    fn visit_ret_type(&mut self, node: &RetType) { walk_ret_type(self, node) }
+    fn visit_return_expr(&mut self, node: &ReturnExpr) { walk_return_expr(self, node) }
+    fn visit_returns_clause(&mut self, node: &ReturnsClause) { walk_returns_clause(self, node) }
+    fn visit_self_param(&mut self, node: &SelfParam) { walk_self_param(self, node) }
+    fn visit_signature_decreases(&mut self, node: &SignatureDecreases) {
+        walk_signature_decreases(self, node)
+    }
+    fn visit_slice_pat(&mut self, node: &SlicePat) { walk_slice_pat(self, node) }
+    fn visit_slice_type(&mut self, node: &SliceType) { walk_slice_type(self, node) }
+    fn visit_source_file(&mut self, node: &SourceFile) { walk_source_file(self, node) }
+    fn visit_static(&mut self, node: &Static) { walk_static(self, node) }
+    fn visit_stmt_list(&mut self, node: &StmtList) { walk_stmt_list(self, node) }
+    fn visit_struct(&mut self, node: &Struct) { walk_struct(self, node) }
+    fn visit_token_tree(&mut self, node: &TokenTree) { walk_token_tree(self, node) }
+    fn visit_trait(&mut self, node: &Trait) { walk_trait(self, node) }
+    fn visit_trait_alias(&mut self, node: &TraitAlias) { walk_trait_alias(self, node) }
+    fn visit_trigger_attribute(&mut self, node: &TriggerAttribute) {
+        walk_trigger_attribute(self, node)
+    }
+    fn visit_try_expr(&mut self, node: &TryExpr) { walk_try_expr(self, node) }
+    fn visit_tuple_expr(&mut self, node: &TupleExpr) { walk_tuple_expr(self, node) }
+    fn visit_tuple_field(&mut self, node: &TupleField) { walk_tuple_field(self, node) }
+    fn visit_tuple_field_list(&mut self, node: &TupleFieldList) {
+        walk_tuple_field_list(self, node)
+    }
+    fn visit_tuple_pat(&mut self, node: &TuplePat) { walk_tuple_pat(self, node) }
+    fn visit_tuple_struct_pat(&mut self, node: &TupleStructPat) {
+        walk_tuple_struct_pat(self, node)
+    }
+    fn visit_tuple_type(&mut self, node: &TupleType) { walk_tuple_type(self, node) }
+    fn visit_type_alias(&mut self, node: &TypeAlias) { walk_type_alias(self, node) }
+    fn visit_type_arg(&mut self, node: &TypeArg) { walk_type_arg(self, node) }
+    fn visit_type_bound(&mut self, node: &TypeBound) { walk_type_bound(self, node) }
+    fn visit_type_bound_list(&mut self, node: &TypeBoundList) { walk_type_bound_list(self, node) }
+    fn visit_type_param(&mut self, node: &TypeParam) { walk_type_param(self, node) }
+    fn visit_underscore_expr(&mut self, node: &UnderscoreExpr) { walk_underscore_expr(self, node) }
+    fn visit_union(&mut self, node: &Union) { walk_union(self, node) }
+    fn visit_use(&mut self, node: &Use) { walk_use(self, node) }
+    fn visit_use_tree(&mut self, node: &UseTree) { walk_use_tree(self, node) }
+    fn visit_use_tree_list(&mut self, node: &UseTreeList) { walk_use_tree_list(self, node) }
+    fn visit_variant(&mut self, node: &Variant) { walk_variant(self, node) }
+    fn visit_variant_list(&mut self, node: &VariantList) { walk_variant_list(self, node) }
+    fn visit_verus_global(&mut self, node: &VerusGlobal) { walk_verus_global(self, node) }
+    fn visit_view_expr(&mut self, node: &ViewExpr) { walk_view_expr(self, node) }
+    fn visit_visibility(&mut self, node: &Visibility) { walk_visibility(self, node) }
+    fn visit_where_clause(&mut self, node: &WhereClause) { walk_where_clause(self, node) }
+    fn visit_where_pred(&mut self, node: &WherePred) { walk_where_pred(self, node) }
+    fn visit_while_expr(&mut self, node: &WhileExpr) { walk_while_expr(self, node) }
+    fn visit_wildcard_pat(&mut self, node: &WildcardPat) { walk_wildcard_pat(self, node) }
+    fn visit_yeet_expr(&mut self, node: &YeetExpr) { walk_yeet_expr(self, node) }
+    fn visit_yield_expr(&mut self, node: &YieldExpr) { walk_yield_expr(self, node) }
+    fn visit_adt(&mut self, node: &Adt) { walk_adt(self, node) }
+    fn visit_assoc_item(&mut self, node: &AssocItem) { walk_assoc_item(self, node) }
+    fn visit_else_branch(&mut self, node: &ElseBranch) { walk_else_branch(self, node) }
+    fn visit_expr(&mut self, node: &Expr) { walk_expr(self, node) }
+    fn visit_extern_item(&mut self, node: &ExternItem) { walk_extern_item(self, node) }
+    fn visit_field_list(&mut self, node: &FieldList) { walk_field_list(self, node) }
+    fn visit_generic_arg(&mut self, node: &GenericArg) { walk_generic_arg(self, node) }
+    fn visit_generic_param(&mut self, node: &GenericParam) { walk_generic_param(self, node) }
+    fn visit_item(&mut self, node: &Item) { walk_item(self, node) }
+    fn visit_loop_clause(&mut self, node: &LoopClause) { walk_loop_clause(self, node) }
+    fn visit_pat(&mut self, node: &Pat) { walk_pat(self, node) }
+    fn visit_stmt(&mut self, node: &Stmt) { walk_stmt(self, node) }
+    fn visit_type(&mut self, node: &Type) { walk_type(self, node) }
+}
+pub trait VstVisitorMut {
+    fn visit_abi_mut(&mut self, node: &mut Abi) { walk_abi_mut(self, node) }
+    fn visit_arg_list_mut(&mut self, node: &mut ArgList) { walk_arg_list_mut(self, node) }
+    fn visit_array_expr_mut(&mut self, node: &mut ArrayExpr) { walk_array_expr_mut(self, node) }
+    fn visit_array_type_mut(&mut self, node: &mut ArrayType) { walk_array_type_mut(self, node) }
+    fn visit_arrow_expr_mut(&mut self, node: &mut ArrowExpr) { walk_arrow_expr_mut(self, node) }
+    fn visit_asm_expr_mut(&mut self, node: &mut AsmExpr) { walk_asm_expr_mut(self, node) }
+    fn visit_assert_expr_mut(&mut self, node: &mut AssertExpr) { walk_assert_expr_mut(self, node) }
+    fn visit_assert_forall_expr_mut(&mut self, node: &mut AssertForallExpr) {
+        walk_assert_forall_expr_mut(self, node)
+    }
+    fn visit_assoc_item_list_mut(&mut self, node: &mut AssocItemList) {
+        walk_assoc_item_list_mut(self, node)
+    }
+    fn visit_assoc_type_arg_mut(&mut self, node: &mut AssocTypeArg) {
+        walk_assoc_type_arg_mut(self, node)
+    }
+    fn visit_assume_expr_mut(&mut self, node: &mut AssumeExpr) { walk_assume_expr_mut(self, node) }
+    fn visit_assume_specification_mut(&mut self, node: &mut AssumeSpecification) {
+        walk_assume_specification_mut(self, node)
+    }
+    fn visit_attr_mut(&mut self, node: &mut Attr) { walk_attr_mut(self, node) }
+    fn visit_await_expr_mut(&mut self, node: &mut AwaitExpr) { walk_await_expr_mut(self, node) }
+    fn visit_become_expr_mut(&mut self, node: &mut BecomeExpr) { walk_become_expr_mut(self, node) }
+    fn visit_bin_expr_mut(&mut self, node: &mut BinExpr) { walk_bin_expr_mut(self, node) }
+    fn visit_block_expr_mut(&mut self, node: &mut BlockExpr) { walk_block_expr_mut(self, node) }
+    fn visit_box_pat_mut(&mut self, node: &mut BoxPat) { walk_box_pat_mut(self, node) }
+    fn visit_break_expr_mut(&mut self, node: &mut BreakExpr) { walk_break_expr_mut(self, node) }
+    fn visit_broadcast_group_mut(&mut self, node: &mut BroadcastGroup) {
+        walk_broadcast_group_mut(self, node)
+    }
+    fn visit_broadcast_group_identifier_mut(&mut self, node: &mut BroadcastGroupIdentifier) {
+        walk_broadcast_group_identifier_mut(self, node)
+    }
+    fn visit_broadcast_group_list_mut(&mut self, node: &mut BroadcastGroupList) {
+        walk_broadcast_group_list_mut(self, node)
+    }
+    fn visit_broadcast_group_member_mut(&mut self, node: &mut BroadcastGroupMember) {
+        walk_broadcast_group_member_mut(self, node)
+    }
+    fn visit_broadcast_use_mut(&mut self, node: &mut BroadcastUse) {
+        walk_broadcast_use_mut(self, node)
+    }
+    fn visit_broadcast_use_list_mut(&mut self, node: &mut BroadcastUseList) {
+        walk_broadcast_use_list_mut(self, node)
+    }
+    fn visit_call_expr_mut(&mut self, node: &mut CallExpr) { walk_call_expr_mut(self, node) }
+    fn visit_cast_expr_mut(&mut self, node: &mut CastExpr) { walk_cast_expr_mut(self, node) }
+    fn visit_closure_expr_mut(&mut self, node: &mut ClosureExpr) {
+        walk_closure_expr_mut(self, node)
+    }
+    fn visit_const_mut(&mut self, node: &mut Const) { walk_const_mut(self, node) }
+    fn visit_const_arg_mut(&mut self, node: &mut ConstArg) { walk_const_arg_mut(self, node) }
+    fn visit_const_block_pat_mut(&mut self, node: &mut ConstBlockPat) {
+        walk_const_block_pat_mut(self, node)
+    }
+    fn visit_const_param_mut(&mut self, node: &mut ConstParam) { walk_const_param_mut(self, node) }
+    fn visit_continue_expr_mut(&mut self, node: &mut ContinueExpr) {
+        walk_continue_expr_mut(self, node)
+    }
+    fn visit_data_mode_mut(&mut self, node: &mut DataMode) { walk_data_mode_mut(self, node) }
+    fn visit_decreases_clause_mut(&mut self, node: &mut DecreasesClause) {
+        walk_decreases_clause_mut(self, node)
+    }
+    fn visit_default_ensures_clause_mut(&mut self, node: &mut DefaultEnsuresClause) {
+        walk_default_ensures_clause_mut(self, node)
+    }
+    fn visit_dyn_trait_type_mut(&mut self, node: &mut DynTraitType) {
+        walk_dyn_trait_type_mut(self, node)
+    }
+    fn visit_ensures_clause_mut(&mut self, node: &mut EnsuresClause) {
+        walk_ensures_clause_mut(self, node)
+    }
+    fn visit_enum_mut(&mut self, node: &mut Enum) { walk_enum_mut(self, node) }
+    fn visit_expr_stmt_mut(&mut self, node: &mut ExprStmt) { walk_expr_stmt_mut(self, node) }
+    fn visit_extern_block_mut(&mut self, node: &mut ExternBlock) {
+        walk_extern_block_mut(self, node)
+    }
+    fn visit_extern_crate_mut(&mut self, node: &mut ExternCrate) {
+        walk_extern_crate_mut(self, node)
+    }
+    fn visit_extern_item_list_mut(&mut self, node: &mut ExternItemList) {
+        walk_extern_item_list_mut(self, node)
+    }
+    fn visit_field_expr_mut(&mut self, node: &mut FieldExpr) { walk_field_expr_mut(self, node) }
+    fn visit_final_expr_mut(&mut self, node: &mut FinalExpr) { walk_final_expr_mut(self, node) }
+    fn visit_fn_mut(&mut self, node: &mut Fn) { walk_fn_mut(self, node) }
+    fn visit_fn_mode_mut(&mut self, node: &mut FnMode) { walk_fn_mode_mut(self, node) }
+    fn visit_fn_ptr_type_mut(&mut self, node: &mut FnPtrType) { walk_fn_ptr_type_mut(self, node) }
+    fn visit_for_expr_mut(&mut self, node: &mut ForExpr) { walk_for_expr_mut(self, node) }
+    fn visit_for_type_mut(&mut self, node: &mut ForType) { walk_for_type_mut(self, node) }
+    fn visit_format_args_arg_mut(&mut self, node: &mut FormatArgsArg) {
+        walk_format_args_arg_mut(self, node)
+    }
+    fn visit_format_args_expr_mut(&mut self, node: &mut FormatArgsExpr) {
+        walk_format_args_expr_mut(self, node)
+    }
+    fn visit_generic_arg_list_mut(&mut self, node: &mut GenericArgList) {
+        walk_generic_arg_list_mut(self, node)
+    }
+    fn visit_generic_param_list_mut(&mut self, node: &mut GenericParamList) {
+        walk_generic_param_list_mut(self, node)
+    }
+    fn visit_has_expr_mut(&mut self, node: &mut HasExpr) { walk_has_expr_mut(self, node) }
+    fn visit_ident_pat_mut(&mut self, node: &mut IdentPat) { walk_ident_pat_mut(self, node) }
+    fn visit_if_expr_mut(&mut self, node: &mut IfExpr) { walk_if_expr_mut(self, node) }
+    fn visit_impl_mut(&mut self, node: &mut Impl) { walk_impl_mut(self, node) }
+    fn visit_impl_trait_type_mut(&mut self, node: &mut ImplTraitType) {
+        walk_impl_trait_type_mut(self, node)
+    }
+    fn visit_index_expr_mut(&mut self, node: &mut IndexExpr) { walk_index_expr_mut(self, node) }
+    fn visit_infer_type_mut(&mut self, node: &mut InferType) { walk_infer_type_mut(self, node) }
+    fn visit_invariant_clause_mut(&mut self, node: &mut InvariantClause) {
+        walk_invariant_clause_mut(self, node)
+    }
+    fn visit_invariant_except_break_clause_mut(&mut self, node: &mut InvariantExceptBreakClause) {
+        walk_invariant_except_break_clause_mut(self, node)
+    }
+    fn visit_is_expr_mut(&mut self, node: &mut IsExpr) { walk_is_expr_mut(self, node) }
+    fn visit_item_list_mut(&mut self, node: &mut ItemList) { walk_item_list_mut(self, node) }
+    fn visit_label_mut(&mut self, node: &mut Label) { walk_label_mut(self, node) }
+    fn visit_let_else_mut(&mut self, node: &mut LetElse) { walk_let_else_mut(self, node) }
+    fn visit_let_expr_mut(&mut self, node: &mut LetExpr) { walk_let_expr_mut(self, node) }
+    fn visit_let_stmt_mut(&mut self, node: &mut LetStmt) { walk_let_stmt_mut(self, node) }
+    fn visit_lifetime_mut(&mut self, node: &mut Lifetime) { walk_lifetime_mut(self, node) }
+    fn visit_lifetime_arg_mut(&mut self, node: &mut LifetimeArg) {
+        walk_lifetime_arg_mut(self, node)
+    }
+    fn visit_lifetime_param_mut(&mut self, node: &mut LifetimeParam) {
+        walk_lifetime_param_mut(self, node)
+    }
+    fn visit_literal_mut(&mut self, node: &mut Literal) { walk_literal_mut(self, node) }
+    fn visit_literal_pat_mut(&mut self, node: &mut LiteralPat) { walk_literal_pat_mut(self, node) }
+    fn visit_loop_expr_mut(&mut self, node: &mut LoopExpr) { walk_loop_expr_mut(self, node) }
+    fn visit_macro_call_mut(&mut self, node: &mut MacroCall) { walk_macro_call_mut(self, node) }
+    fn visit_macro_def_mut(&mut self, node: &mut MacroDef) { walk_macro_def_mut(self, node) }
+    fn visit_macro_eager_input_mut(&mut self, node: &mut MacroEagerInput) {
+        walk_macro_eager_input_mut(self, node)
+    }
+    fn visit_macro_expr_mut(&mut self, node: &mut MacroExpr) { walk_macro_expr_mut(self, node) }
+    fn visit_macro_items_mut(&mut self, node: &mut MacroItems) { walk_macro_items_mut(self, node) }
+    fn visit_macro_pat_mut(&mut self, node: &mut MacroPat) { walk_macro_pat_mut(self, node) }
+    fn visit_macro_rules_mut(&mut self, node: &mut MacroRules) { walk_macro_rules_mut(self, node) }
+    fn visit_macro_stmts_mut(&mut self, node: &mut MacroStmts) { walk_macro_stmts_mut(self, node) }
+    fn visit_macro_type_mut(&mut self, node: &mut MacroType) { walk_macro_type_mut(self, node) }
+    fn visit_match_arm_mut(&mut self, node: &mut MatchArm) { walk_match_arm_mut(self, node) }
+    fn visit_match_arm_list_mut(&mut self, node: &mut MatchArmList) {
+        walk_match_arm_list_mut(self, node)
+    }
+    fn visit_match_expr_mut(&mut self, node: &mut MatchExpr) { walk_match_expr_mut(self, node) }
+    fn visit_match_guard_mut(&mut self, node: &mut MatchGuard) { walk_match_guard_mut(self, node) }
+    fn visit_matches_expr_mut(&mut self, node: &mut MatchesExpr) {
+        walk_matches_expr_mut(self, node)
+    }
+    fn visit_meta_mut(&mut self, node: &mut Meta) { walk_meta_mut(self, node) }
+    fn visit_method_call_expr_mut(&mut self, node: &mut MethodCallExpr) {
+        walk_method_call_expr_mut(self, node)
+    }
+    fn visit_mode_spec_checked_mut(&mut self, node: &mut ModeSpecChecked) {
+        walk_mode_spec_checked_mut(self, node)
+    }
+    fn visit_module_mut(&mut self, node: &mut Module) { walk_module_mut(self, node) }
+    fn visit_name_mut(&mut self, node: &mut Name) { walk_name_mut(self, node) }
+    fn visit_name_ref_mut(&mut self, node: &mut NameRef) { walk_name_ref_mut(self, node) }
+    fn visit_never_type_mut(&mut self, node: &mut NeverType) { walk_never_type_mut(self, node) }
+    fn visit_no_unwind_clause_mut(&mut self, node: &mut NoUnwindClause) {
+        walk_no_unwind_clause_mut(self, node)
+    }
+    fn visit_offset_of_expr_mut(&mut self, node: &mut OffsetOfExpr) {
+        walk_offset_of_expr_mut(self, node)
+    }
+    fn visit_opens_invariants_clause_mut(&mut self, node: &mut OpensInvariantsClause) {
+        walk_opens_invariants_clause_mut(self, node)
+    }
+    fn visit_or_pat_mut(&mut self, node: &mut OrPat) { walk_or_pat_mut(self, node) }
+    fn visit_param_mut(&mut self, node: &mut Param) { walk_param_mut(self, node) }
+    fn visit_param_list_mut(&mut self, node: &mut ParamList) { walk_param_list_mut(self, node) }
+    fn visit_paren_expr_mut(&mut self, node: &mut ParenExpr) { walk_paren_expr_mut(self, node) }
+    fn visit_paren_pat_mut(&mut self, node: &mut ParenPat) { walk_paren_pat_mut(self, node) }
+    fn visit_paren_type_mut(&mut self, node: &mut ParenType) { walk_paren_type_mut(self, node) }
+    fn visit_path_mut(&mut self, node: &mut Path) { walk_path_mut(self, node) }
+    fn visit_path_expr_mut(&mut self, node: &mut PathExpr) { walk_path_expr_mut(self, node) }
+    fn visit_path_pat_mut(&mut self, node: &mut PathPat) { walk_path_pat_mut(self, node) }
+    fn visit_path_segment_mut(&mut self, node: &mut PathSegment) {
+        walk_path_segment_mut(self, node)
+    }
+    fn visit_path_type_mut(&mut self, node: &mut PathType) { walk_path_type_mut(self, node) }
+    fn visit_prefix_expr_mut(&mut self, node: &mut PrefixExpr) { walk_prefix_expr_mut(self, node) }
+    fn visit_proof_fn_characteristics_mut(&mut self, node: &mut ProofFnCharacteristics) {
+        walk_proof_fn_characteristics_mut(self, node)
+    }
+    fn visit_proof_fn_type_mut(&mut self, node: &mut ProofFnType) {
+        walk_proof_fn_type_mut(self, node)
+    }
+    fn visit_proof_fn_with_characteristics_mut(&mut self, node: &mut ProofFnWithCharacteristics) {
+        walk_proof_fn_with_characteristics_mut(self, node)
+    }
+    fn visit_prover_mut(&mut self, node: &mut Prover) { walk_prover_mut(self, node) }
+    fn visit_ptr_type_mut(&mut self, node: &mut PtrType) { walk_ptr_type_mut(self, node) }
+    fn visit_publish_mut(&mut self, node: &mut Publish) { walk_publish_mut(self, node) }
+    fn visit_range_expr_mut(&mut self, node: &mut RangeExpr) { walk_range_expr_mut(self, node) }
+    fn visit_range_pat_mut(&mut self, node: &mut RangePat) { walk_range_pat_mut(self, node) }
+    fn visit_recommends_clause_mut(&mut self, node: &mut RecommendsClause) {
+        walk_recommends_clause_mut(self, node)
+    }
+    fn visit_record_expr_mut(&mut self, node: &mut RecordExpr) { walk_record_expr_mut(self, node) }
+    fn visit_record_expr_field_mut(&mut self, node: &mut RecordExprField) {
+        walk_record_expr_field_mut(self, node)
+    }
+    fn visit_record_expr_field_list_mut(&mut self, node: &mut RecordExprFieldList) {
+        walk_record_expr_field_list_mut(self, node)
+    }
+    fn visit_record_field_mut(&mut self, node: &mut RecordField) {
+        walk_record_field_mut(self, node)
+    }
+    fn visit_record_field_list_mut(&mut self, node: &mut RecordFieldList) {
+        walk_record_field_list_mut(self, node)
+    }
+    fn visit_record_pat_mut(&mut self, node: &mut RecordPat) { walk_record_pat_mut(self, node) }
+    fn visit_record_pat_field_mut(&mut self, node: &mut RecordPatField) {
+        walk_record_pat_field_mut(self, node)
+    }
+    fn visit_record_pat_field_list_mut(&mut self, node: &mut RecordPatFieldList) {
+        walk_record_pat_field_list_mut(self, node)
+    }
+    fn visit_ref_expr_mut(&mut self, node: &mut RefExpr) { walk_ref_expr_mut(self, node) }
+    fn visit_ref_pat_mut(&mut self, node: &mut RefPat) { walk_ref_pat_mut(self, node) }
+    fn visit_ref_type_mut(&mut self, node: &mut RefType) { walk_ref_type_mut(self, node) }
+    fn visit_rename_mut(&mut self, node: &mut Rename) { walk_rename_mut(self, node) }
+    fn visit_requires_clause_mut(&mut self, node: &mut RequiresClause) {
+        walk_requires_clause_mut(self, node)
+    }
+    fn visit_rest_pat_mut(&mut self, node: &mut RestPat) { walk_rest_pat_mut(self, node) }
+    fn visit_ret_type_mut(&mut self, node: &mut RetType) { walk_ret_type_mut(self, node) }
+    fn visit_return_expr_mut(&mut self, node: &mut ReturnExpr) { walk_return_expr_mut(self, node) }
+    fn visit_returns_clause_mut(&mut self, node: &mut ReturnsClause) {
+        walk_returns_clause_mut(self, node)
+    }
+    fn visit_self_param_mut(&mut self, node: &mut SelfParam) { walk_self_param_mut(self, node) }
+    fn visit_signature_decreases_mut(&mut self, node: &mut SignatureDecreases) {
+        walk_signature_decreases_mut(self, node)
+    }
+    fn visit_slice_pat_mut(&mut self, node: &mut SlicePat) { walk_slice_pat_mut(self, node) }
+    fn visit_slice_type_mut(&mut self, node: &mut SliceType) { walk_slice_type_mut(self, node) }
+    fn visit_source_file_mut(&mut self, node: &mut SourceFile) { walk_source_file_mut(self, node) }
+    fn visit_static_mut(&mut self, node: &mut Static) { walk_static_mut(self, node) }
+    fn visit_stmt_list_mut(&mut self, node: &mut StmtList) { walk_stmt_list_mut(self, node) }
+    fn visit_struct_mut(&mut self, node: &mut Struct) { walk_struct_mut(self, node) }
+    fn visit_token_tree_mut(&mut self, node: &mut TokenTree) { walk_token_tree_mut(self, node) }
+    fn visit_trait_mut(&mut self, node: &mut Trait) { walk_trait_mut(self, node) }
+    fn visit_trait_alias_mut(&mut self, node: &mut TraitAlias) { walk_trait_alias_mut(self, node) }
+    fn visit_trigger_attribute_mut(&mut self, node: &mut TriggerAttribute) {
+        walk_trigger_attribute_mut(self, node)
+    }
+    fn visit_try_expr_mut(&mut self, node: &mut TryExpr) { walk_try_expr_mut(self, node) }
+    fn visit_tuple_expr_mut(&mut self, node: &mut TupleExpr) { walk_tuple_expr_mut(self, node) }
+    fn visit_tuple_field_mut(&mut self, node: &mut TupleField) { walk_tuple_field_mut(self, node) }
+    fn visit_tuple_field_list_mut(&mut self, node: &mut TupleFieldList) {
+        walk_tuple_field_list_mut(self, node)
+    }
+    fn visit_tuple_pat_mut(&mut self, node: &mut TuplePat) { walk_tuple_pat_mut(self, node) }
+    fn visit_tuple_struct_pat_mut(&mut self, node: &mut TupleStructPat) {
+        walk_tuple_struct_pat_mut(self, node)
+    }
+    fn visit_tuple_type_mut(&mut self, node: &mut TupleType) { walk_tuple_type_mut(self, node) }
+    fn visit_type_alias_mut(&mut self, node: &mut TypeAlias) { walk_type_alias_mut(self, node) }
+    fn visit_type_arg_mut(&mut self, node: &mut TypeArg) { walk_type_arg_mut(self, node) }
+    fn visit_type_bound_mut(&mut self, node: &mut TypeBound) { walk_type_bound_mut(self, node) }
+    fn visit_type_bound_list_mut(&mut self, node: &mut TypeBoundList) {
+        walk_type_bound_list_mut(self, node)
+    }
+    fn visit_type_param_mut(&mut self, node: &mut TypeParam) { walk_type_param_mut(self, node) }
+    fn visit_underscore_expr_mut(&mut self, node: &mut UnderscoreExpr) {
+        walk_underscore_expr_mut(self, node)
+    }
+    fn visit_union_mut(&mut self, node: &mut Union) { walk_union_mut(self, node) }
+    fn visit_use_mut(&mut self, node: &mut Use) { walk_use_mut(self, node) }
+    fn visit_use_tree_mut(&mut self, node: &mut UseTree) { walk_use_tree_mut(self, node) }
+    fn visit_use_tree_list_mut(&mut self, node: &mut UseTreeList) {
+        walk_use_tree_list_mut(self, node)
+    }
+    fn visit_variant_mut(&mut self, node: &mut Variant) { walk_variant_mut(self, node) }
+    fn visit_variant_list_mut(&mut self, node: &mut VariantList) {
+        walk_variant_list_mut(self, node)
+    }
+    fn visit_verus_global_mut(&mut self, node: &mut VerusGlobal) {
+        walk_verus_global_mut(self, node)
+    }
+    fn visit_view_expr_mut(&mut self, node: &mut ViewExpr) { walk_view_expr_mut(self, node) }
+    fn visit_visibility_mut(&mut self, node: &mut Visibility) { walk_visibility_mut(self, node) }
+    fn visit_where_clause_mut(&mut self, node: &mut WhereClause) {
+        walk_where_clause_mut(self, node)
+    }
+    fn visit_where_pred_mut(&mut self, node: &mut WherePred) { walk_where_pred_mut(self, node) }
+    fn visit_while_expr_mut(&mut self, node: &mut WhileExpr) { walk_while_expr_mut(self, node) }
+    fn visit_wildcard_pat_mut(&mut self, node: &mut WildcardPat) {
+        walk_wildcard_pat_mut(self, node)
+    }
+    fn visit_yeet_expr_mut(&mut self, node: &mut YeetExpr) { walk_yeet_expr_mut(self, node) }
+    fn visit_yield_expr_mut(&mut self, node: &mut YieldExpr) { walk_yield_expr_mut(self, node) }
+    fn visit_adt_mut(&mut self, node: &mut Adt) { walk_adt_mut(self, node) }
+    fn visit_assoc_item_mut(&mut self, node: &mut AssocItem) { walk_assoc_item_mut(self, node) }
+    fn visit_else_branch_mut(&mut self, node: &mut ElseBranch) { walk_else_branch_mut(self, node) }
+    fn visit_expr_mut(&mut self, node: &mut Expr) { walk_expr_mut(self, node) }
+    fn visit_extern_item_mut(&mut self, node: &mut ExternItem) { walk_extern_item_mut(self, node) }
+    fn visit_field_list_mut(&mut self, node: &mut FieldList) { walk_field_list_mut(self, node) }
+    fn visit_generic_arg_mut(&mut self, node: &mut GenericArg) { walk_generic_arg_mut(self, node) }
+    fn visit_generic_param_mut(&mut self, node: &mut GenericParam) {
+        walk_generic_param_mut(self, node)
+    }
+    fn visit_item_mut(&mut self, node: &mut Item) { walk_item_mut(self, node) }
+    fn visit_loop_clause_mut(&mut self, node: &mut LoopClause) { walk_loop_clause_mut(self, node) }
+    fn visit_pat_mut(&mut self, node: &mut Pat) { walk_pat_mut(self, node) }
+    fn visit_stmt_mut(&mut self, node: &mut Stmt) { walk_stmt_mut(self, node) }
+    fn visit_type_mut(&mut self, node: &mut Type) { walk_type_mut(self, node) }
+}
+pub trait VstFold {
+    fn fold_abi(&mut self, node: Abi) -> Abi { walk_fold_abi(self, node) }
+    fn fold_arg_list(&mut self, node: ArgList) -> ArgList { walk_fold_arg_list(self, node) }
+    fn fold_array_expr(&mut self, node: ArrayExpr) -> ArrayExpr { walk_fold_array_expr(self, node) }
+    fn fold_array_type(&mut self, node: ArrayType) -> ArrayType { walk_fold_array_type(self, node) }
+    fn fold_arrow_expr(&mut self, node: ArrowExpr) -> ArrowExpr { walk_fold_arrow_expr(self, node) }
+    fn fold_asm_expr(&mut self, node: AsmExpr) -> AsmExpr { walk_fold_asm_expr(self, node) }
+    fn fold_assert_expr(&mut self, node: AssertExpr) -> AssertExpr {
+        walk_fold_assert_expr(self, node)
+    }
+    fn fold_assert_forall_expr(&mut self, node: AssertForallExpr) -> AssertForallExpr {
+        walk_fold_assert_forall_expr(self, node)
+    }
+    fn fold_assoc_item_list(&mut self, node: AssocItemList) -> AssocItemList {
+        walk_fold_assoc_item_list(self, node)
+    }
+    fn fold_assoc_type_arg(&mut self, node: AssocTypeArg) -> AssocTypeArg {
+        walk_fold_assoc_type_arg(self, node)
+    }
+    fn fold_assume_expr(&mut self, node: AssumeExpr) -> AssumeExpr {
+        walk_fold_assume_expr(self, node)
+    }
+    fn fold_assume_specification(&mut self, node: AssumeSpecification) -> AssumeSpecification {
+        walk_fold_assume_specification(self, node)
+    }
+    fn fold_attr(&mut self, node: Attr) -> Attr { walk_fold_attr(self, node) }
+    fn fold_await_expr(&mut self, node: AwaitExpr) -> AwaitExpr { walk_fold_await_expr(self, node) }
+    fn fold_become_expr(&mut self, node: BecomeExpr) -> BecomeExpr {
+        walk_fold_become_expr(self, node)
+    }
+    fn fold_bin_expr(&mut self, node: BinExpr) -> BinExpr { walk_fold_bin_expr(self, node) }
+    fn fold_block_expr(&mut self, node: BlockExpr) -> BlockExpr { walk_fold_block_expr(self, node) }
+    fn fold_box_pat(&mut self, node: BoxPat) -> BoxPat { walk_fold_box_pat(self, node) }
+    fn fold_break_expr(&mut self, node: BreakExpr) -> BreakExpr { walk_fold_break_expr(self, node) }
+    fn fold_broadcast_group(&mut self, node: BroadcastGroup) -> BroadcastGroup {
+        walk_fold_broadcast_group(self, node)
+    }
+    fn fold_broadcast_group_identifier(
+        &mut self,
+        node: BroadcastGroupIdentifier,
+    ) -> BroadcastGroupIdentifier {
+        walk_fold_broadcast_group_identifier(self, node)
+    }
+    fn fold_broadcast_group_list(&mut self, node: BroadcastGroupList) -> BroadcastGroupList {
+        walk_fold_broadcast_group_list(self, node)
+    }
+    fn fold_broadcast_group_member(&mut self, node: BroadcastGroupMember) -> BroadcastGroupMember {
+        walk_fold_broadcast_group_member(self, node)
+    }
+    fn fold_broadcast_use(&mut self, node: BroadcastUse) -> BroadcastUse {
+        walk_fold_broadcast_use(self, node)
+    }
+    fn fold_broadcast_use_list(&mut self, node: BroadcastUseList) -> BroadcastUseList {
+        walk_fold_broadcast_use_list(self, node)
+    }
+    fn fold_call_expr(&mut self, node: CallExpr) -> CallExpr { walk_fold_call_expr(self, node) }
+    fn fold_cast_expr(&mut self, node: CastExpr) -> CastExpr { walk_fold_cast_expr(self, node) }
+    fn fold_closure_expr(&mut self, node: ClosureExpr) -> ClosureExpr {
+        walk_fold_closure_expr(self, node)
+    }
+    fn fold_const(&mut self, node: Const) -> Const { walk_fold_const(self, node) }
+    fn fold_const_arg(&mut self, node: ConstArg) -> ConstArg { walk_fold_const_arg(self, node) }
+    fn fold_const_block_pat(&mut self, node: ConstBlockPat) -> ConstBlockPat {
+        walk_fold_const_block_pat(self, node)
+    }
+    fn fold_const_param(&mut self, node: ConstParam) -> ConstParam {
+        walk_fold_const_param(self, node)
+    }
+    fn fold_continue_expr(&mut self, node: ContinueExpr) -> ContinueExpr {
+        walk_fold_continue_expr(self, node)
+    }
+    fn fold_data_mode(&mut self, node: DataMode) -> DataMode { walk_fold_data_mode(self, node) }
+    fn fold_decreases_clause(&mut self, node: DecreasesClause) -> DecreasesClause {
+        walk_fold_decreases_clause(self, node)
+    }
+    fn fold_default_ensures_clause(&mut self, node: DefaultEnsuresClause) -> DefaultEnsuresClause {
+        walk_fold_default_ensures_clause(self, node)
+    }
+    fn fold_dyn_trait_type(&mut self, node: DynTraitType) -> DynTraitType {
+        walk_fold_dyn_trait_type(self, node)
+    }
+    fn fold_ensures_clause(&mut self, node: EnsuresClause) -> EnsuresClause {
+        walk_fold_ensures_clause(self, node)
+    }
+    fn fold_enum(&mut self, node: Enum) -> Enum { walk_fold_enum(self, node) }
+    fn fold_expr_stmt(&mut self, node: ExprStmt) -> ExprStmt { walk_fold_expr_stmt(self, node) }
+    fn fold_extern_block(&mut self, node: ExternBlock) -> ExternBlock {
+        walk_fold_extern_block(self, node)
+    }
+    fn fold_extern_crate(&mut self, node: ExternCrate) -> ExternCrate {
+        walk_fold_extern_crate(self, node)
+    }
+    fn fold_extern_item_list(&mut self, node: ExternItemList) -> ExternItemList {
+        walk_fold_extern_item_list(self, node)
+    }
+    fn fold_field_expr(&mut self, node: FieldExpr) -> FieldExpr { walk_fold_field_expr(self, node) }
+    fn fold_final_expr(&mut self, node: FinalExpr) -> FinalExpr { walk_fold_final_expr(self, node) }
+    fn fold_fn(&mut self, node: Fn) -> Fn { walk_fold_fn(self, node) }
+    fn fold_fn_mode(&mut self, node: FnMode) -> FnMode { walk_fold_fn_mode(self, node) }
+    fn fold_fn_ptr_type(&mut self, node: FnPtrType) -> FnPtrType {
+        walk_fold_fn_ptr_type(self, node)
+    }
+    fn fold_for_expr(&mut self, node: ForExpr) -> ForExpr { walk_fold_for_expr(self, node) }
+    fn fold_for_type(&mut self, node: ForType) -> ForType { walk_fold_for_type(self, node) }
+    fn fold_format_args_arg(&mut self, node: FormatArgsArg) -> FormatArgsArg {
+        walk_fold_format_args_arg(self, node)
+    }
+    fn fold_format_args_expr(&mut self, node: FormatArgsExpr) -> FormatArgsExpr {
+        walk_fold_format_args_expr(self, node)
+    }
+    fn fold_generic_arg_list(&mut self, node: GenericArgList) -> GenericArgList {
+        walk_fold_generic_arg_list(self, node)
+    }
+    fn fold_generic_param_list(&mut self, node: GenericParamList) -> GenericParamList {
+        walk_fold_generic_param_list(self, node)
+    }
+    fn fold_has_expr(&mut self, node: HasExpr) -> HasExpr { walk_fold_has_expr(self, node) }
+    fn fold_ident_pat(&mut self, node: IdentPat) -> IdentPat { walk_fold_ident_pat(self, node) }
+    fn fold_if_expr(&mut self, node: IfExpr) -> IfExpr { walk_fold_if_expr(self, node) }
+    fn fold_impl(&mut self, node: Impl) -> Impl { walk_fold_impl(self, node) }
+    fn fold_impl_trait_type(&mut self, node: ImplTraitType) -> ImplTraitType {
+        walk_fold_impl_trait_type(self, node)
+    }
+    fn fold_index_expr(&mut self, node: IndexExpr) -> IndexExpr { walk_fold_index_expr(self, node) }
+    fn fold_infer_type(&mut self, node: InferType) -> InferType { walk_fold_infer_type(self, node) }
+    fn fold_invariant_clause(&mut self, node: InvariantClause) -> InvariantClause {
+        walk_fold_invariant_clause(self, node)
+    }
+    fn fold_invariant_except_break_clause(
+        &mut self,
+        node: InvariantExceptBreakClause,
+    ) -> InvariantExceptBreakClause {
+        walk_fold_invariant_except_break_clause(self, node)
+    }
+    fn fold_is_expr(&mut self, node: IsExpr) -> IsExpr { walk_fold_is_expr(self, node) }
+    fn fold_item_list(&mut self, node: ItemList) -> ItemList { walk_fold_item_list(self, node) }
+    fn fold_label(&mut self, node: Label) -> Label { walk_fold_label(self, node) }
+    fn fold_let_else(&mut self, node: LetElse) -> LetElse { walk_fold_let_else(self, node) }
+    fn fold_let_expr(&mut self, node: LetExpr) -> LetExpr { walk_fold_let_expr(self, node) }
+    fn fold_let_stmt(&mut self, node: LetStmt) -> LetStmt { walk_fold_let_stmt(self, node) }
+    fn fold_lifetime(&mut self, node: Lifetime) -> Lifetime { walk_fold_lifetime(self, node) }
+    fn fold_lifetime_arg(&mut self, node: LifetimeArg) -> LifetimeArg {
+        walk_fold_lifetime_arg(self, node)
+    }
+    fn fold_lifetime_param(&mut self, node: LifetimeParam) -> LifetimeParam {
+        walk_fold_lifetime_param(self, node)
+    }
+    fn fold_literal(&mut self, node: Literal) -> Literal { walk_fold_literal(self, node) }
+    fn fold_literal_pat(&mut self, node: LiteralPat) -> LiteralPat {
+        walk_fold_literal_pat(self, node)
+    }
+    fn fold_loop_expr(&mut self, node: LoopExpr) -> LoopExpr { walk_fold_loop_expr(self, node) }
+    fn fold_macro_call(&mut self, node: MacroCall) -> MacroCall { walk_fold_macro_call(self, node) }
+    fn fold_macro_def(&mut self, node: MacroDef) -> MacroDef { walk_fold_macro_def(self, node) }
+    fn fold_macro_eager_input(&mut self, node: MacroEagerInput) -> MacroEagerInput {
+        walk_fold_macro_eager_input(self, node)
+    }
+    fn fold_macro_expr(&mut self, node: MacroExpr) -> MacroExpr { walk_fold_macro_expr(self, node) }
+    fn fold_macro_items(&mut self, node: MacroItems) -> MacroItems {
+        walk_fold_macro_items(self, node)
+    }
+    fn fold_macro_pat(&mut self, node: MacroPat) -> MacroPat { walk_fold_macro_pat(self, node) }
+    fn fold_macro_rules(&mut self, node: MacroRules) -> MacroRules {
+        walk_fold_macro_rules(self, node)
+    }
+    fn fold_macro_stmts(&mut self, node: MacroStmts) -> MacroStmts {
+        walk_fold_macro_stmts(self, node)
+    }
+    fn fold_macro_type(&mut self, node: MacroType) -> MacroType { walk_fold_macro_type(self, node) }
+    fn fold_match_arm(&mut self, node: MatchArm) -> MatchArm { walk_fold_match_arm(self, node) }
+    fn fold_match_arm_list(&mut self, node: MatchArmList) -> MatchArmList {
+        walk_fold_match_arm_list(self, node)
+    }
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr { walk_fold_match_expr(self, node) }
+    fn fold_match_guard(&mut self, node: MatchGuard) -> MatchGuard {
+        walk_fold_match_guard(self, node)
+    }
+    fn fold_matches_expr(&mut self, node: MatchesExpr) -> MatchesExpr {
+        walk_fold_matches_expr(self, node)
+    }
+    fn fold_meta(&mut self, node: Meta) -> Meta { walk_fold_meta(self, node) }
+    fn fold_method_call_expr(&mut self, node: MethodCallExpr) -> MethodCallExpr {
+        walk_fold_method_call_expr(self, node)
+    }
+    fn fold_mode_spec_checked(&mut self, node: ModeSpecChecked) -> ModeSpecChecked {
+        walk_fold_mode_spec_checked(self, node)
+    }
+    fn fold_module(&mut self, node: Module) -> Module { walk_fold_module(self, node) }
+    fn fold_name(&mut self, node: Name) -> Name { walk_fold_name(self, node) }
+    fn fold_name_ref(&mut self, node: NameRef) -> NameRef { walk_fold_name_ref(self, node) }
+    fn fold_never_type(&mut self, node: NeverType) -> NeverType { walk_fold_never_type(self, node) }
+    fn fold_no_unwind_clause(&mut self, node: NoUnwindClause) -> NoUnwindClause {
+        walk_fold_no_unwind_clause(self, node)
+    }
+    fn fold_offset_of_expr(&mut self, node: OffsetOfExpr) -> OffsetOfExpr {
+        walk_fold_offset_of_expr(self, node)
+    }
+    fn fold_opens_invariants_clause(
+        &mut self,
+        node: OpensInvariantsClause,
+    ) -> OpensInvariantsClause {
+        walk_fold_opens_invariants_clause(self, node)
+    }
+    fn fold_or_pat(&mut self, node: OrPat) -> OrPat { walk_fold_or_pat(self, node) }
+    fn fold_param(&mut self, node: Param) -> Param { walk_fold_param(self, node) }
+    fn fold_param_list(&mut self, node: ParamList) -> ParamList { walk_fold_param_list(self, node) }
+    fn fold_paren_expr(&mut self, node: ParenExpr) -> ParenExpr { walk_fold_paren_expr(self, node) }
+    fn fold_paren_pat(&mut self, node: ParenPat) -> ParenPat { walk_fold_paren_pat(self, node) }
+    fn fold_paren_type(&mut self, node: ParenType) -> ParenType { walk_fold_paren_type(self, node) }
+    fn fold_path(&mut self, node: Path) -> Path { walk_fold_path(self, node) }
+    fn fold_path_expr(&mut self, node: PathExpr) -> PathExpr { walk_fold_path_expr(self, node) }
+    fn fold_path_pat(&mut self, node: PathPat) -> PathPat { walk_fold_path_pat(self, node) }
+    fn fold_path_segment(&mut self, node: PathSegment) -> PathSegment {
+        walk_fold_path_segment(self, node)
+    }
+    fn fold_path_type(&mut self, node: PathType) -> PathType { walk_fold_path_type(self, node) }
+    fn fold_prefix_expr(&mut self, node: PrefixExpr) -> PrefixExpr {
+        walk_fold_prefix_expr(self, node)
+    }
+    fn fold_proof_fn_characteristics(
+        &mut self,
+        node: ProofFnCharacteristics,
+    ) -> ProofFnCharacteristics {
+        walk_fold_proof_fn_characteristics(self, node)
+    }
+    fn fold_proof_fn_type(&mut self, node: ProofFnType) -> ProofFnType {
+        walk_fold_proof_fn_type(self, node)
+    }
+    fn fold_proof_fn_with_characteristics(
+        &mut self,
+        node: ProofFnWithCharacteristics,
+    ) -> ProofFnWithCharacteristics {
+        walk_fold_proof_fn_with_characteristics(self, node)
+    }
+    fn fold_prover(&mut self, node: Prover) -> Prover { walk_fold_prover(self, node) }
+    fn fold_ptr_type(&mut self, node: PtrType) -> PtrType { walk_fold_ptr_type(self, node) }
+    fn fold_publish(&mut self, node: Publish) -> Publish { walk_fold_publish(self, node) }
+    fn fold_range_expr(&mut self, node: RangeExpr) -> RangeExpr { walk_fold_range_expr(self, node) }
+    fn fold_range_pat(&mut self, node: RangePat) -> RangePat { walk_fold_range_pat(self, node) }
+    fn fold_recommends_clause(&mut self, node: RecommendsClause) -> RecommendsClause {
+        walk_fold_recommends_clause(self, node)
+    }
+    fn fold_record_expr(&mut self, node: RecordExpr) -> RecordExpr {
+        walk_fold_record_expr(self, node)
+    }
+    fn fold_record_expr_field(&mut self, node: RecordExprField) -> RecordExprField {
+        walk_fold_record_expr_field(self, node)
+    }
+    fn fold_record_expr_field_list(&mut self, node: RecordExprFieldList) -> RecordExprFieldList {
+        walk_fold_record_expr_field_list(self, node)
+    }
+    fn fold_record_field(&mut self, node: RecordField) -> RecordField {
+        walk_fold_record_field(self, node)
+    }
+    fn fold_record_field_list(&mut self, node: RecordFieldList) -> RecordFieldList {
+        walk_fold_record_field_list(self, node)
+    }
+    fn fold_record_pat(&mut self, node: RecordPat) -> RecordPat { walk_fold_record_pat(self, node) }
+    fn fold_record_pat_field(&mut self, node: RecordPatField) -> RecordPatField {
+        walk_fold_record_pat_field(self, node)
+    }
+    fn fold_record_pat_field_list(&mut self, node: RecordPatFieldList) -> RecordPatFieldList {
+        walk_fold_record_pat_field_list(self, node)
+    }
+    fn fold_ref_expr(&mut self, node: RefExpr) -> RefExpr { walk_fold_ref_expr(self, node) }
+    fn fold_ref_pat(&mut self, node: RefPat) -> RefPat { walk_fold_ref_pat(self, node) }
+    fn fold_ref_type(&mut self, node: RefType) -> RefType { walk_fold_ref_type(self, node) }
+    fn fold_rename(&mut self, node: Rename) -> Rename { walk_fold_rename(self, node) }
+    fn fold_requires_clause(&mut self, node: RequiresClause) -> RequiresClause {
+        walk_fold_requires_clause(self, node)
+    }
+    fn fold_rest_pat(&mut self, node: RestPat) -> RestPat { walk_fold_rest_pat(self, node) }
+    fn fold_ret_type(&mut self, node: RetType) -> RetType { walk_fold_ret_type(self, node) }
+    fn fold_return_expr(&mut self, node: ReturnExpr) -> ReturnExpr {
+        walk_fold_return_expr(self, node)
+    }
+    fn fold_returns_clause(&mut self, node: ReturnsClause) -> ReturnsClause {
+        walk_fold_returns_clause(self, node)
+    }
+    fn fold_self_param(&mut self, node: SelfParam) -> SelfParam { walk_fold_self_param(self, node) }
+    fn fold_signature_decreases(&mut self, node: SignatureDecreases) -> SignatureDecreases {
+        walk_fold_signature_decreases(self, node)
+    }
+    fn fold_slice_pat(&mut self, node: SlicePat) -> SlicePat { walk_fold_slice_pat(self, node) }
+    fn fold_slice_type(&mut self, node: SliceType) -> SliceType { walk_fold_slice_type(self, node) }
+    fn fold_source_file(&mut self, node: SourceFile) -> SourceFile {
+        walk_fold_source_file(self, node)
+    }
+    fn fold_static(&mut self, node: Static) -> Static { walk_fold_static(self, node) }
+    fn fold_stmt_list(&mut self, node: StmtList) -> StmtList { walk_fold_stmt_list(self, node) }
+    fn fold_struct(&mut self, node: Struct) -> Struct { walk_fold_struct(self, node) }
+    fn fold_token_tree(&mut self, node: TokenTree) -> TokenTree { walk_fold_token_tree(self, node) }
+    fn fold_trait(&mut self, node: Trait) -> Trait { walk_fold_trait(self, node) }
+    fn fold_trait_alias(&mut self, node: TraitAlias) -> TraitAlias {
+        walk_fold_trait_alias(self, node)
+    }
+    fn fold_trigger_attribute(&mut self, node: TriggerAttribute) -> TriggerAttribute {
+        walk_fold_trigger_attribute(self, node)
+    }
+    fn fold_try_expr(&mut self, node: TryExpr) -> TryExpr { walk_fold_try_expr(self, node) }
+    fn fold_tuple_expr(&mut self, node: TupleExpr) -> TupleExpr { walk_fold_tuple_expr(self, node) }
+    fn fold_tuple_field(&mut self, node: TupleField) -> TupleField {
+        walk_fold_tuple_field(self, node)
+    }
+    fn fold_tuple_field_list(&mut self, node: TupleFieldList) -> TupleFieldList {
+        walk_fold_tuple_field_list(self, node)
+    }
+    fn fold_tuple_pat(&mut self, node: TuplePat) -> TuplePat { walk_fold_tuple_pat(self, node) }
+    fn fold_tuple_struct_pat(&mut self, node: TupleStructPat) -> TupleStructPat {
+        walk_fold_tuple_struct_pat(self, node)
+    }
+    fn fold_tuple_type(&mut self, node: TupleType) -> TupleType { walk_fold_tuple_type(self, node) }
+    fn fold_type_alias(&mut self, node: TypeAlias) -> TypeAlias { walk_fold_type_alias(self, node) }
+    fn fold_type_arg(&mut self, node: TypeArg) -> TypeArg { walk_fold_type_arg(self, node) }
+    fn fold_type_bound(&mut self, node: TypeBound) -> TypeBound { walk_fold_type_bound(self, node) }
+    fn fold_type_bound_list(&mut self, node: TypeBoundList) -> TypeBoundList {
+        walk_fold_type_bound_list(self, node)
+    }
+    fn fold_type_param(&mut self, node: TypeParam) -> TypeParam { walk_fold_type_param(self, node) }
+    fn fold_underscore_expr(&mut self, node: UnderscoreExpr) -> UnderscoreExpr {
+        walk_fold_underscore_expr(self, node)
+    }
+    fn fold_union(&mut self, node: Union) -> Union { walk_fold_union(self, node) }
+    fn fold_use(&mut self, node: Use) -> Use { walk_fold_use(self, node) }
+    fn fold_use_tree(&mut self, node: UseTree) -> UseTree { walk_fold_use_tree(self, node) }
+    fn fold_use_tree_list(&mut self, node: UseTreeList) -> UseTreeList {
+        walk_fold_use_tree_list(self, node)
+    }
+    fn fold_variant(&mut self, node: Variant) -> Variant { walk_fold_variant(self, node) }
+    fn fold_variant_list(&mut self, node: VariantList) -> VariantList {
+        walk_fold_variant_list(self, node)
+    }
+    fn fold_verus_global(&mut self, node: VerusGlobal) -> VerusGlobal {
+        walk_fold_verus_global(self, node)
+    }
+    fn fold_view_expr(&mut self, node: ViewExpr) -> ViewExpr { walk_fold_view_expr(self, node) }
+    fn fold_visibility(&mut self, node: Visibility) -> Visibility {
+        walk_fold_visibility(self, node)
+    }
+    fn fold_where_clause(&mut self, node: WhereClause) -> WhereClause {
+        walk_fold_where_clause(self, node)
+    }
+    fn fold_where_pred(&mut self, node: WherePred) -> WherePred { walk_fold_where_pred(self, node) }
+    fn fold_while_expr(&mut self, node: WhileExpr) -> WhileExpr { walk_fold_while_expr(self, node) }
+    fn fold_wildcard_pat(&mut self, node: WildcardPat) -> WildcardPat {
+        walk_fold_wildcard_pat(self, node)
+    }
+    fn fold_yeet_expr(&mut self, node: YeetExpr) -> YeetExpr { walk_fold_yeet_expr(self, node) }
+    fn fold_yield_expr(&mut self, node: YieldExpr) -> YieldExpr { walk_fold_yield_expr(self, node) }
+    fn fold_adt(&mut self, node: Adt) -> Adt { walk_fold_adt(self, node) }
+    fn fold_assoc_item(&mut self, node: AssocItem) -> AssocItem { walk_fold_assoc_item(self, node) }
+    fn fold_else_branch(&mut self, node: ElseBranch) -> ElseBranch {
+        walk_fold_else_branch(self, node)
+    }
+    fn fold_expr(&mut self, node: Expr) -> Expr { walk_fold_expr(self, node) }
+    fn fold_extern_item(&mut self, node: ExternItem) -> ExternItem {
+        walk_fold_extern_item(self, node)
+    }
+    fn fold_field_list(&mut self, node: FieldList) -> FieldList { walk_fold_field_list(self, node) }
+    fn fold_generic_arg(&mut self, node: GenericArg) -> GenericArg {
+        walk_fold_generic_arg(self, node)
+    }
+    fn fold_generic_param(&mut self, node: GenericParam) -> GenericParam {
+        walk_fold_generic_param(self, node)
+    }
+    fn fold_item(&mut self, node: Item) -> Item { walk_fold_item(self, node) }
+    fn fold_loop_clause(&mut self, node: LoopClause) -> LoopClause {
+        walk_fold_loop_clause(self, node)
+    }
+    fn fold_pat(&mut self, node: Pat) -> Pat { walk_fold_pat(self, node) }
+    fn fold_stmt(&mut self, node: Stmt) -> Stmt { walk_fold_stmt(self, node) }
+    fn fold_type(&mut self, node: Type) -> Type { walk_fold_type(self, node) }
+}
+pub fn walk_abi<V: VstVisitor + ?Sized>(v: &mut V, node: &Abi) {}
+pub fn walk_abi_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Abi) {}
+pub fn walk_fold_abi<F: VstFold + ?Sized>(f: &mut F, mut node: Abi) -> Abi { node }
+pub fn walk_arg_list<V: VstVisitor + ?Sized>(v: &mut V, node: &ArgList) {
+    for it in &node.args {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_arg_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ArgList) {
+    for it in &mut node.args {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_arg_list<F: VstFold + ?Sized>(f: &mut F, mut node: ArgList) -> ArgList {
+    node.args = node.args.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_array_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ArrayExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_array_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ArrayExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_array_expr<F: VstFold + ?Sized>(f: &mut F, mut node: ArrayExpr) -> ArrayExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_array_type<V: VstVisitor + ?Sized>(v: &mut V, node: &ArrayType) {
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    v.visit_const_arg(&node.const_arg);
+}
+pub fn walk_array_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ArrayType) {
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    v.visit_const_arg_mut(&mut node.const_arg);
+}
+pub fn walk_fold_array_type<F: VstFold + ?Sized>(f: &mut F, mut node: ArrayType) -> ArrayType {
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.const_arg = Box::new(f.fold_const_arg(*node.const_arg));
+    node
+}
+pub fn walk_arrow_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ArrowExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.name_ref {
+        v.visit_name_ref(it);
+    }
+}
+pub fn walk_arrow_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ArrowExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.name_ref {
+        v.visit_name_ref_mut(it);
+    }
+}
+pub fn walk_fold_arrow_expr<F: VstFold + ?Sized>(f: &mut F, mut node: ArrowExpr) -> ArrowExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.name_ref = node.name_ref.map(|it| Box::new(f.fold_name_ref(*it)));
+    node
+}
+pub fn walk_asm_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &AsmExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_asm_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AsmExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_asm_expr<F: VstFold + ?Sized>(f: &mut F, mut node: AsmExpr) -> AsmExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_assert_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &AssertExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.name {
+        v.visit_name(it);
+    }
+    if let Some(it) = &node.requires_clause {
+        v.visit_requires_clause(it);
+    }
+    if let Some(it) = &node.block_expr {
+        v.visit_block_expr(it);
+    }
+}
+pub fn walk_assert_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AssertExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.name {
+        v.visit_name_mut(it);
+    }
+    if let Some(it) = &mut node.requires_clause {
+        v.visit_requires_clause_mut(it);
+    }
+    if let Some(it) = &mut node.block_expr {
+        v.visit_block_expr_mut(it);
+    }
+}
+pub fn walk_fold_assert_expr<F: VstFold + ?Sized>(f: &mut F, mut node: AssertExpr) -> AssertExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.name = node.name.map(|it| Box::new(f.fold_name(*it)));
+    node.requires_clause = node.requires_clause.map(|it| Box::new(f.fold_requires_clause(*it)));
+    node.block_expr = node.block_expr.map(|it| Box::new(f.fold_block_expr(*it)));
+    node
+}
+pub fn walk_assert_forall_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &AssertForallExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_closure_expr(&node.closure_expr);
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+    v.visit_block_expr(&node.block_expr);
+}
+pub fn walk_assert_forall_expr_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut AssertForallExpr,
+) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_closure_expr_mut(&mut node.closure_expr);
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+    v.visit_block_expr_mut(&mut node.block_expr);
+}
+pub fn walk_fold_assert_forall_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: AssertForallExpr,
+) -> AssertForallExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.closure_expr = Box::new(f.fold_closure_expr(*node.closure_expr));
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node.block_expr = Box::new(f.fold_block_expr(*node.block_expr));
+    node
+}
+pub fn walk_assoc_item_list<V: VstVisitor + ?Sized>(v: &mut V, node: &AssocItemList) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.assoc_items {
+        v.visit_assoc_item(it);
+    }
+}
+pub fn walk_assoc_item_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AssocItemList) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.assoc_items {
+        v.visit_assoc_item_mut(it);
+    }
+}
+pub fn walk_fold_assoc_item_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: AssocItemList,
+) -> AssocItemList {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.assoc_items = node.assoc_items.into_iter().map(|it| f.fold_assoc_item(it)).collect();
+    node
+}
+pub fn walk_assoc_type_arg<V: VstVisitor + ?Sized>(v: &mut V, node: &AssocTypeArg) {
+    v.visit_name_ref(&node.name_ref);
+    if let Some(it) = &node.generic_arg_list {
+        v.visit_generic_arg_list(it);
+    }
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    if let Some(it) = &node.const_arg {
+        v.visit_const_arg(it);
+    }
+}
+pub fn walk_assoc_type_arg_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AssocTypeArg) {
+    v.visit_name_ref_mut(&mut node.name_ref);
+    if let Some(it) = &mut node.generic_arg_list {
+        v.visit_generic_arg_list_mut(it);
+    }
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    if let Some(it) = &mut node.const_arg {
+        v.visit_const_arg_mut(it);
+    }
+}
+pub fn walk_fold_assoc_type_arg<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: AssocTypeArg,
+) -> AssocTypeArg {
+    node.name_ref = Box::new(f.fold_name_ref(*node.name_ref));
+    node.generic_arg_list = node.generic_arg_list.map(|it| Box::new(f.fold_generic_arg_list(*it)));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.const_arg = node.const_arg.map(|it| Box::new(f.fold_const_arg(*it)));
+    node
+}
+pub fn walk_assume_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &AssumeExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_assume_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AssumeExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_assume_expr<F: VstFold + ?Sized>(f: &mut F, mut node: AssumeExpr) -> AssumeExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_assume_specification<V: VstVisitor + ?Sized>(v: &mut V, node: &AssumeSpecification) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    v.visit_path(&node.path);
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    if let Some(it) = &node.requires_clause {
+        v.visit_requires_clause(it);
+    }
+    if let Some(it) = &node.recommends_clause {
+        v.visit_recommends_clause(it);
+    }
+    if let Some(it) = &node.ensures_clause {
+        v.visit_ensures_clause(it);
+    }
+    if let Some(it) = &node.returns_clause {
+        v.visit_returns_clause(it);
+    }
+    if let Some(it) = &node.signature_decreases {
+        v.visit_signature_decreases(it);
+    }
+    if let Some(it) = &node.opens_invariants_clause {
+        v.visit_opens_invariants_clause(it);
+    }
+    if let Some(it) = &node.no_unwind_clause {
+        v.visit_no_unwind_clause(it);
+    }
+}
+pub fn walk_assume_specification_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut AssumeSpecification,
+) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    if let Some(it) = &mut node.requires_clause {
+        v.visit_requires_clause_mut(it);
+    }
+    if let Some(it) = &mut node.recommends_clause {
+        v.visit_recommends_clause_mut(it);
+    }
+    if let Some(it) = &mut node.ensures_clause {
+        v.visit_ensures_clause_mut(it);
+    }
+    if let Some(it) = &mut node.returns_clause {
+        v.visit_returns_clause_mut(it);
+    }
+    if let Some(it) = &mut node.signature_decreases {
+        v.visit_signature_decreases_mut(it);
+    }
+    if let Some(it) = &mut node.opens_invariants_clause {
+        v.visit_opens_invariants_clause_mut(it);
+    }
+    if let Some(it) = &mut node.no_unwind_clause {
+        v.visit_no_unwind_clause_mut(it);
+    }
+}
+pub fn walk_fold_assume_specification<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: AssumeSpecification,
+) -> AssumeSpecification {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.path = Box::new(f.fold_path(*node.path));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.requires_clause = node.requires_clause.map(|it| Box::new(f.fold_requires_clause(*it)));
+    node.recommends_clause =
+        node.recommends_clause.map(|it| Box::new(f.fold_recommends_clause(*it)));
+    node.ensures_clause = node.ensures_clause.map(|it| Box::new(f.fold_ensures_clause(*it)));
+    node.returns_clause = node.returns_clause.map(|it| Box::new(f.fold_returns_clause(*it)));
+    node.signature_decreases =
+        node.signature_decreases.map(|it| Box::new(f.fold_signature_decreases(*it)));
+    node.opens_invariants_clause =
+        node.opens_invariants_clause.map(|it| Box::new(f.fold_opens_invariants_clause(*it)));
+    node.no_unwind_clause = node.no_unwind_clause.map(|it| Box::new(f.fold_no_unwind_clause(*it)));
+    node
+}
+pub fn walk_attr<V: VstVisitor + ?Sized>(v: &mut V, node: &Attr) {
+    if let Some(it) = &node.trigger_attribute {
+        v.visit_trigger_attribute(it);
+    }
+    if let Some(it) = &node.meta {
+        v.visit_meta(it);
+    }
+}
+pub fn walk_attr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Attr) {
+    if let Some(it) = &mut node.trigger_attribute {
+        v.visit_trigger_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.meta {
+        v.visit_meta_mut(it);
+    }
+}
+pub fn walk_fold_attr<F: VstFold + ?Sized>(f: &mut F, mut node: Attr) -> Attr {
+    node.trigger_attribute =
+        node.trigger_attribute.map(|it| Box::new(f.fold_trigger_attribute(*it)));
+    node.meta = node.meta.map(|it| Box::new(f.fold_meta(*it)));
+    node
+}
+pub fn walk_await_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &AwaitExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_await_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AwaitExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_await_expr<F: VstFold + ?Sized>(f: &mut F, mut node: AwaitExpr) -> AwaitExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_become_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &BecomeExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_become_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BecomeExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_become_expr<F: VstFold + ?Sized>(f: &mut F, mut node: BecomeExpr) -> BecomeExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_bin_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &BinExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.lhs);
+    v.visit_expr(&node.rhs);
+}
+pub fn walk_bin_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BinExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.lhs);
+    v.visit_expr_mut(&mut node.rhs);
+}
+pub fn walk_fold_bin_expr<F: VstFold + ?Sized>(f: &mut F, mut node: BinExpr) -> BinExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.lhs = Box::new(f.fold_expr(*node.lhs));
+    node.rhs = Box::new(f.fold_expr(*node.rhs));
+    node
+}
+pub fn walk_block_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &BlockExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    if let Some(it) = &node.fn_mode {
+        v.visit_fn_mode(it);
+    }
+    v.visit_stmt_list(&node.stmt_list);
+}
+pub fn walk_block_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BlockExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    if let Some(it) = &mut node.fn_mode {
+        v.visit_fn_mode_mut(it);
+    }
+    v.visit_stmt_list_mut(&mut node.stmt_list);
+}
+pub fn walk_fold_block_expr<F: VstFold + ?Sized>(f: &mut F, mut node: BlockExpr) -> BlockExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.label = node.label.map(|it| Box::new(f.fold_label(*it)));
+    node.fn_mode = node.fn_mode.map(|it| Box::new(f.fold_fn_mode(*it)));
+    node.stmt_list = Box::new(f.fold_stmt_list(*node.stmt_list));
+    node
+}
+pub fn walk_box_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &BoxPat) {
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_box_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BoxPat) {
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_box_pat<F: VstFold + ?Sized>(f: &mut F, mut node: BoxPat) -> BoxPat {
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node
+}
+pub fn walk_break_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &BreakExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_break_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BreakExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_break_expr<F: VstFold + ?Sized>(f: &mut F, mut node: BreakExpr) -> BreakExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.lifetime = node.lifetime.map(|it| Box::new(f.fold_lifetime(*it)));
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_broadcast_group<V: VstVisitor + ?Sized>(v: &mut V, node: &BroadcastGroup) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_broadcast_group_identifier(&node.broadcast_group_identifier);
+    v.visit_broadcast_group_list(&node.broadcast_group_list);
+}
+pub fn walk_broadcast_group_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BroadcastGroup) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_broadcast_group_identifier_mut(&mut node.broadcast_group_identifier);
+    v.visit_broadcast_group_list_mut(&mut node.broadcast_group_list);
+}
+pub fn walk_fold_broadcast_group<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: BroadcastGroup,
+) -> BroadcastGroup {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.broadcast_group_identifier =
+        Box::new(f.fold_broadcast_group_identifier(*node.broadcast_group_identifier));
+    node.broadcast_group_list = Box::new(f.fold_broadcast_group_list(*node.broadcast_group_list));
+    node
+}
+pub fn walk_broadcast_group_identifier<V: VstVisitor + ?Sized>(
+    v: &mut V,
+    node: &BroadcastGroupIdentifier,
+) {
+}
+pub fn walk_broadcast_group_identifier_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut BroadcastGroupIdentifier,
+) {
+}
+pub fn walk_fold_broadcast_group_identifier<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: BroadcastGroupIdentifier,
+) -> BroadcastGroupIdentifier {
+    node
+}
+pub fn walk_broadcast_group_list<V: VstVisitor + ?Sized>(v: &mut V, node: &BroadcastGroupList) {
+    for it in &node.broadcast_group_members {
+        v.visit_broadcast_group_member(it);
+    }
+}
+pub fn walk_broadcast_group_list_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut BroadcastGroupList,
+) {
+    for it in &mut node.broadcast_group_members {
+        v.visit_broadcast_group_member_mut(it);
+    }
+}
+pub fn walk_fold_broadcast_group_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: BroadcastGroupList,
+) -> BroadcastGroupList {
+    node.broadcast_group_members = node
+        .broadcast_group_members
+        .into_iter()
+        .map(|it| f.fold_broadcast_group_member(it))
+        .collect();
+    node
+}
+pub fn walk_broadcast_group_member<V: VstVisitor + ?Sized>(v: &mut V, node: &BroadcastGroupMember) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_path(&node.path);
+}
+pub fn walk_broadcast_group_member_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut BroadcastGroupMember,
+) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+}
+pub fn walk_fold_broadcast_group_member<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: BroadcastGroupMember,
+) -> BroadcastGroupMember {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.path = Box::new(f.fold_path(*node.path));
+    node
+}
+pub fn walk_broadcast_use<V: VstVisitor + ?Sized>(v: &mut V, node: &BroadcastUse) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_broadcast_use_list(&node.broadcast_use_list);
+}
+pub fn walk_broadcast_use_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut BroadcastUse) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_broadcast_use_list_mut(&mut node.broadcast_use_list);
+}
+pub fn walk_fold_broadcast_use<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: BroadcastUse,
+) -> BroadcastUse {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.broadcast_use_list = Box::new(f.fold_broadcast_use_list(*node.broadcast_use_list));
+    node
+}
+pub fn walk_broadcast_use_list<V: VstVisitor + ?Sized>(v: &mut V, node: &BroadcastUseList) {
+    for it in &node.paths {
+        v.visit_path(it);
+    }
+}
+pub fn walk_broadcast_use_list_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut BroadcastUseList,
+) {
+    for it in &mut node.paths {
+        v.visit_path_mut(it);
+    }
+}
+pub fn walk_fold_broadcast_use_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: BroadcastUseList,
+) -> BroadcastUseList {
+    node.paths = node.paths.into_iter().map(|it| f.fold_path(it)).collect();
+    node
+}
+pub fn walk_call_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &CallExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    v.visit_arg_list(&node.arg_list);
+}
+pub fn walk_call_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut CallExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    v.visit_arg_list_mut(&mut node.arg_list);
+}
+pub fn walk_fold_call_expr<F: VstFold + ?Sized>(f: &mut F, mut node: CallExpr) -> CallExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.arg_list = Box::new(f.fold_arg_list(*node.arg_list));
+    node
+}
+pub fn walk_cast_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &CastExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_cast_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut CastExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_cast_expr<F: VstFold + ?Sized>(f: &mut F, mut node: CastExpr) -> CastExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_closure_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ClosureExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.proof_fn_with_characteristics {
+        v.visit_proof_fn_with_characteristics(it);
+    }
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+    if let Some(it) = &node.requires_clause {
+        v.visit_requires_clause(it);
+    }
+    if let Some(it) = &node.ensures_clause {
+        v.visit_ensures_clause(it);
+    }
+    v.visit_expr(&node.body);
+}
+pub fn walk_closure_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ClosureExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.proof_fn_with_characteristics {
+        v.visit_proof_fn_with_characteristics_mut(it);
+    }
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+    if let Some(it) = &mut node.requires_clause {
+        v.visit_requires_clause_mut(it);
+    }
+    if let Some(it) = &mut node.ensures_clause {
+        v.visit_ensures_clause_mut(it);
+    }
+    v.visit_expr_mut(&mut node.body);
+}
+pub fn walk_fold_closure_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ClosureExpr,
+) -> ClosureExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.proof_fn_with_characteristics = node
+        .proof_fn_with_characteristics
+        .map(|it| Box::new(f.fold_proof_fn_with_characteristics(*it)));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node.requires_clause = node.requires_clause.map(|it| Box::new(f.fold_requires_clause(*it)));
+    node.ensures_clause = node.ensures_clause.map(|it| Box::new(f.fold_ensures_clause(*it)));
+    node.body = Box::new(f.fold_expr(*node.body));
+    node
+}
+pub fn walk_const<V: VstVisitor + ?Sized>(v: &mut V, node: &Const) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.name {
+        v.visit_name(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    if let Some(it) = &node.body {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.ensures_clause {
+        v.visit_ensures_clause(it);
+    }
+    if let Some(it) = &node.block_expr {
+        v.visit_block_expr(it);
+    }
+}
+pub fn walk_const_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Const) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.name {
+        v.visit_name_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    if let Some(it) = &mut node.body {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.ensures_clause {
+        v.visit_ensures_clause_mut(it);
+    }
+    if let Some(it) = &mut node.block_expr {
+        v.visit_block_expr_mut(it);
+    }
+}
+pub fn walk_fold_const<F: VstFold + ?Sized>(f: &mut F, mut node: Const) -> Const {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = node.name.map(|it| Box::new(f.fold_name(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.body = node.body.map(|it| Box::new(f.fold_expr(*it)));
+    node.ensures_clause = node.ensures_clause.map(|it| Box::new(f.fold_ensures_clause(*it)));
+    node.block_expr = node.block_expr.map(|it| Box::new(f.fold_block_expr(*it)));
+    node
+}
+pub fn walk_const_arg<V: VstVisitor + ?Sized>(v: &mut V, node: &ConstArg) {
+    v.visit_expr(&node.expr);
+}
+pub fn walk_const_arg_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ConstArg) {
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_const_arg<F: VstFold + ?Sized>(f: &mut F, mut node: ConstArg) -> ConstArg {
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_const_block_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &ConstBlockPat) {
+    v.visit_block_expr(&node.block_expr);
+}
+pub fn walk_const_block_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ConstBlockPat) {
+    v.visit_block_expr_mut(&mut node.block_expr);
+}
+pub fn walk_fold_const_block_pat<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ConstBlockPat,
+) -> ConstBlockPat {
+    node.block_expr = Box::new(f.fold_block_expr(*node.block_expr));
+    node
+}
+pub fn walk_const_param<V: VstVisitor + ?Sized>(v: &mut V, node: &ConstParam) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    if let Some(it) = &node.default_val {
+        v.visit_const_arg(it);
+    }
+}
+pub fn walk_const_param_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ConstParam) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    if let Some(it) = &mut node.default_val {
+        v.visit_const_arg_mut(it);
+    }
+}
+pub fn walk_fold_const_param<F: VstFold + ?Sized>(f: &mut F, mut node: ConstParam) -> ConstParam {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.name = Box::new(f.fold_name(*node.name));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.default_val = node.default_val.map(|it| Box::new(f.fold_const_arg(*it)));
+    node
+}
+pub fn walk_continue_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ContinueExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+}
+pub fn walk_continue_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ContinueExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+}
+pub fn walk_fold_continue_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ContinueExpr,
+) -> ContinueExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.lifetime = node.lifetime.map(|it| Box::new(f.fold_lifetime(*it)));
+    node
+}
+pub fn walk_data_mode<V: VstVisitor + ?Sized>(v: &mut V, node: &DataMode) {}
+pub fn walk_data_mode_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut DataMode) {}
+pub fn walk_fold_data_mode<F: VstFold + ?Sized>(f: &mut F, mut node: DataMode) -> DataMode { node }
+pub fn walk_decreases_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &DecreasesClause) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_decreases_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut DecreasesClause) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_decreases_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: DecreasesClause,
+) -> DecreasesClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_default_ensures_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &DefaultEnsuresClause) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_default_ensures_clause_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut DefaultEnsuresClause,
+) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_default_ensures_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: DefaultEnsuresClause,
+) -> DefaultEnsuresClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_dyn_trait_type<V: VstVisitor + ?Sized>(v: &mut V, node: &DynTraitType) {
+    v.visit_type_bound_list(&node.type_bound_list);
+}
+pub fn walk_dyn_trait_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut DynTraitType) {
+    v.visit_type_bound_list_mut(&mut node.type_bound_list);
+}
+pub fn walk_fold_dyn_trait_type<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: DynTraitType,
+) -> DynTraitType {
+    node.type_bound_list = Box::new(f.fold_type_bound_list(*node.type_bound_list));
+    node
+}
+pub fn walk_ensures_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &EnsuresClause) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_ensures_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut EnsuresClause) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_ensures_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: EnsuresClause,
+) -> EnsuresClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_enum<V: VstVisitor + ?Sized>(v: &mut V, node: &Enum) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.data_mode {
+        v.visit_data_mode(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    v.visit_variant_list(&node.variant_list);
+}
+pub fn walk_enum_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Enum) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.data_mode {
+        v.visit_data_mode_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    v.visit_variant_list_mut(&mut node.variant_list);
+}
+pub fn walk_fold_enum<F: VstFold + ?Sized>(f: &mut F, mut node: Enum) -> Enum {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.data_mode = node.data_mode.map(|it| Box::new(f.fold_data_mode(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.variant_list = Box::new(f.fold_variant_list(*node.variant_list));
+    node
+}
+pub fn walk_expr_stmt<V: VstVisitor + ?Sized>(v: &mut V, node: &ExprStmt) {
+    v.visit_expr(&node.expr);
+}
+pub fn walk_expr_stmt_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ExprStmt) {
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_expr_stmt<F: VstFold + ?Sized>(f: &mut F, mut node: ExprStmt) -> ExprStmt {
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_extern_block<V: VstVisitor + ?Sized>(v: &mut V, node: &ExternBlock) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_abi(&node.abi);
+    v.visit_extern_item_list(&node.extern_item_list);
+}
+pub fn walk_extern_block_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ExternBlock) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_abi_mut(&mut node.abi);
+    v.visit_extern_item_list_mut(&mut node.extern_item_list);
+}
+pub fn walk_fold_extern_block<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ExternBlock,
+) -> ExternBlock {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.abi = Box::new(f.fold_abi(*node.abi));
+    node.extern_item_list = Box::new(f.fold_extern_item_list(*node.extern_item_list));
+    node
+}
+pub fn walk_extern_crate<V: VstVisitor + ?Sized>(v: &mut V, node: &ExternCrate) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name_ref(&node.name_ref);
+    if let Some(it) = &node.rename {
+        v.visit_rename(it);
+    }
+}
+pub fn walk_extern_crate_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ExternCrate) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_ref_mut(&mut node.name_ref);
+    if let Some(it) = &mut node.rename {
+        v.visit_rename_mut(it);
+    }
+}
+pub fn walk_fold_extern_crate<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ExternCrate,
+) -> ExternCrate {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name_ref = Box::new(f.fold_name_ref(*node.name_ref));
+    node.rename = node.rename.map(|it| Box::new(f.fold_rename(*it)));
+    node
+}
+pub fn walk_extern_item_list<V: VstVisitor + ?Sized>(v: &mut V, node: &ExternItemList) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.extern_items {
+        v.visit_extern_item(it);
+    }
+}
+pub fn walk_extern_item_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ExternItemList) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.extern_items {
+        v.visit_extern_item_mut(it);
+    }
+}
+pub fn walk_fold_extern_item_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ExternItemList,
+) -> ExternItemList {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.extern_items = node.extern_items.into_iter().map(|it| f.fold_extern_item(it)).collect();
+    node
+}
+pub fn walk_field_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &FieldExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    v.visit_name_ref(&node.name_ref);
+}
+pub fn walk_field_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FieldExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    v.visit_name_ref_mut(&mut node.name_ref);
+}
+pub fn walk_fold_field_expr<F: VstFold + ?Sized>(f: &mut F, mut node: FieldExpr) -> FieldExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.name_ref = Box::new(f.fold_name_ref(*node.name_ref));
+    node
+}
+pub fn walk_final_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &FinalExpr) {
+    v.visit_expr(&node.expr);
+}
+pub fn walk_final_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FinalExpr) {
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_final_expr<F: VstFold + ?Sized>(f: &mut F, mut node: FinalExpr) -> FinalExpr {
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_fn<V: VstVisitor + ?Sized>(v: &mut V, node: &Fn) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.publish {
+        v.visit_publish(it);
+    }
+    if let Some(it) = &node.abi {
+        v.visit_abi(it);
+    }
+    if let Some(it) = &node.fn_mode {
+        v.visit_fn_mode(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    if let Some(it) = &node.prover {
+        v.visit_prover(it);
+    }
+    if let Some(it) = &node.requires_clause {
+        v.visit_requires_clause(it);
+    }
+    if let Some(it) = &node.recommends_clause {
+        v.visit_recommends_clause(it);
+    }
+    if let Some(it) = &node.ensures_clause {
+        v.visit_ensures_clause(it);
+    }
+    if let Some(it) = &node.default_ensures_clause {
+        v.visit_default_ensures_clause(it);
+    }
+    if let Some(it) = &node.returns_clause {
+        v.visit_returns_clause(it);
+    }
+    if let Some(it) = &node.signature_decreases {
+        v.visit_signature_decreases(it);
+    }
+    if let Some(it) = &node.opens_invariants_clause {
+        v.visit_opens_invariants_clause(it);
+    }
+    if let Some(it) = &node.no_unwind_clause {
+        v.visit_no_unwind_clause(it);
+    }
+    if let Some(it) = &node.body {
+        v.visit_block_expr(it);
+    }
+}
+pub fn walk_fn_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Fn) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.publish {
+        v.visit_publish_mut(it);
+    }
+    if let Some(it) = &mut node.abi {
+        v.visit_abi_mut(it);
+    }
+    if let Some(it) = &mut node.fn_mode {
+        v.visit_fn_mode_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    if let Some(it) = &mut node.prover {
+        v.visit_prover_mut(it);
+    }
+    if let Some(it) = &mut node.requires_clause {
+        v.visit_requires_clause_mut(it);
+    }
+    if let Some(it) = &mut node.recommends_clause {
+        v.visit_recommends_clause_mut(it);
+    }
+    if let Some(it) = &mut node.ensures_clause {
+        v.visit_ensures_clause_mut(it);
+    }
+    if let Some(it) = &mut node.default_ensures_clause {
+        v.visit_default_ensures_clause_mut(it);
+    }
+    if let Some(it) = &mut node.returns_clause {
+        v.visit_returns_clause_mut(it);
+    }
+    if let Some(it) = &mut node.signature_decreases {
+        v.visit_signature_decreases_mut(it);
+    }
+    if let Some(it) = &mut node.opens_invariants_clause {
+        v.visit_opens_invariants_clause_mut(it);
+    }
+    if let Some(it) = &mut node.no_unwind_clause {
+        v.visit_no_unwind_clause_mut(it);
+    }
+    if let Some(it) = &mut node.body {
+        v.visit_block_expr_mut(it);
+    }
+}
+pub fn walk_fold_fn<F: VstFold + ?Sized>(f: &mut F, mut node: Fn) -> Fn {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.publish = node.publish.map(|it| Box::new(f.fold_publish(*it)));
+    node.abi = node.abi.map(|it| Box::new(f.fold_abi(*it)));
+    node.fn_mode = node.fn_mode.map(|it| Box::new(f.fold_fn_mode(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.prover = node.prover.map(|it| Box::new(f.fold_prover(*it)));
+    node.requires_clause = node.requires_clause.map(|it| Box::new(f.fold_requires_clause(*it)));
+    node.recommends_clause =
+        node.recommends_clause.map(|it| Box::new(f.fold_recommends_clause(*it)));
+    node.ensures_clause = node.ensures_clause.map(|it| Box::new(f.fold_ensures_clause(*it)));
+    node.default_ensures_clause =
+        node.default_ensures_clause.map(|it| Box::new(f.fold_default_ensures_clause(*it)));
+    node.returns_clause = node.returns_clause.map(|it| Box::new(f.fold_returns_clause(*it)));
+    node.signature_decreases =
+        node.signature_decreases.map(|it| Box::new(f.fold_signature_decreases(*it)));
+    node.opens_invariants_clause =
+        node.opens_invariants_clause.map(|it| Box::new(f.fold_opens_invariants_clause(*it)));
+    node.no_unwind_clause = node.no_unwind_clause.map(|it| Box::new(f.fold_no_unwind_clause(*it)));
+    node.body = node.body.map(|it| Box::new(f.fold_block_expr(*it)));
+    node
+}
+pub fn walk_fn_mode<V: VstVisitor + ?Sized>(v: &mut V, node: &FnMode) {
+    if let Some(it) = &node.mode_spec_checked {
+        v.visit_mode_spec_checked(it);
+    }
+}
+pub fn walk_fn_mode_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FnMode) {
+    if let Some(it) = &mut node.mode_spec_checked {
+        v.visit_mode_spec_checked_mut(it);
+    }
+}
+pub fn walk_fold_fn_mode<F: VstFold + ?Sized>(f: &mut F, mut node: FnMode) -> FnMode {
+    node.mode_spec_checked =
+        node.mode_spec_checked.map(|it| Box::new(f.fold_mode_spec_checked(*it)));
+    node
+}
+pub fn walk_fn_ptr_type<V: VstVisitor + ?Sized>(v: &mut V, node: &FnPtrType) {
+    if let Some(it) = &node.abi {
+        v.visit_abi(it);
+    }
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+}
+pub fn walk_fn_ptr_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FnPtrType) {
+    if let Some(it) = &mut node.abi {
+        v.visit_abi_mut(it);
+    }
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+}
+pub fn walk_fold_fn_ptr_type<F: VstFold + ?Sized>(f: &mut F, mut node: FnPtrType) -> FnPtrType {
+    node.abi = node.abi.map(|it| Box::new(f.fold_abi(*it)));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node
+}
+pub fn walk_for_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ForExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+    if let Some(it) = &node.iter_name {
+        v.visit_name(it);
+    }
+    v.visit_expr(&node.iterable);
+    for it in &node.loop_clauses {
+        v.visit_loop_clause(it);
+    }
+    v.visit_block_expr(&node.loop_body);
+}
+pub fn walk_for_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ForExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+    if let Some(it) = &mut node.iter_name {
+        v.visit_name_mut(it);
+    }
+    v.visit_expr_mut(&mut node.iterable);
+    for it in &mut node.loop_clauses {
+        v.visit_loop_clause_mut(it);
+    }
+    v.visit_block_expr_mut(&mut node.loop_body);
+}
+pub fn walk_fold_for_expr<F: VstFold + ?Sized>(f: &mut F, mut node: ForExpr) -> ForExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.label = node.label.map(|it| Box::new(f.fold_label(*it)));
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node.iter_name = node.iter_name.map(|it| Box::new(f.fold_name(*it)));
+    node.iterable = Box::new(f.fold_expr(*node.iterable));
+    node.loop_clauses = node.loop_clauses.into_iter().map(|it| f.fold_loop_clause(it)).collect();
+    node.loop_body = Box::new(f.fold_block_expr(*node.loop_body));
+    node
+}
+pub fn walk_for_type<V: VstVisitor + ?Sized>(v: &mut V, node: &ForType) {
+    v.visit_generic_param_list(&node.generic_param_list);
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_for_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ForType) {
+    v.visit_generic_param_list_mut(&mut node.generic_param_list);
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_for_type<F: VstFold + ?Sized>(f: &mut F, mut node: ForType) -> ForType {
+    node.generic_param_list = Box::new(f.fold_generic_param_list(*node.generic_param_list));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_format_args_arg<V: VstVisitor + ?Sized>(v: &mut V, node: &FormatArgsArg) {
+    if let Some(it) = &node.name {
+        v.visit_name(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_format_args_arg_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FormatArgsArg) {
+    if let Some(it) = &mut node.name {
+        v.visit_name_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_format_args_arg<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: FormatArgsArg,
+) -> FormatArgsArg {
+    node.name = node.name.map(|it| Box::new(f.fold_name(*it)));
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_format_args_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &FormatArgsExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.template);
+    for it in &node.args {
+        v.visit_format_args_arg(it);
+    }
+}
+pub fn walk_format_args_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FormatArgsExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.template);
+    for it in &mut node.args {
+        v.visit_format_args_arg_mut(it);
+    }
+}
+pub fn walk_fold_format_args_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: FormatArgsExpr,
+) -> FormatArgsExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.template = Box::new(f.fold_expr(*node.template));
+    node.args = node.args.into_iter().map(|it| f.fold_format_args_arg(it)).collect();
+    node
+}
+pub fn walk_generic_arg_list<V: VstVisitor + ?Sized>(v: &mut V, node: &GenericArgList) {
+    for it in &node.generic_args {
+        v.visit_generic_arg(it);
+    }
+}
+pub fn walk_generic_arg_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut GenericArgList) {
+    for it in &mut node.generic_args {
+        v.visit_generic_arg_mut(it);
+    }
+}
+pub fn walk_fold_generic_arg_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: GenericArgList,
+) -> GenericArgList {
+    node.generic_args = node.generic_args.into_iter().map(|it| f.fold_generic_arg(it)).collect();
+    node
+}
+pub fn walk_generic_param_list<V: VstVisitor + ?Sized>(v: &mut V, node: &GenericParamList) {
+    for it in &node.generic_params {
+        v.visit_generic_param(it);
+    }
+}
+pub fn walk_generic_param_list_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut GenericParamList,
+) {
+    for it in &mut node.generic_params {
+        v.visit_generic_param_mut(it);
+    }
+}
+pub fn walk_fold_generic_param_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: GenericParamList,
+) -> GenericParamList {
+    node.generic_params =
+        node.generic_params.into_iter().map(|it| f.fold_generic_param(it)).collect();
+    node
+}
+pub fn walk_has_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &HasExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.collection);
+    v.visit_expr(&node.elt);
+}
+pub fn walk_has_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut HasExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.collection);
+    v.visit_expr_mut(&mut node.elt);
+}
+pub fn walk_fold_has_expr<F: VstFold + ?Sized>(f: &mut F, mut node: HasExpr) -> HasExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.collection = Box::new(f.fold_expr(*node.collection));
+    node.elt = Box::new(f.fold_expr(*node.elt));
+    node
+}
+pub fn walk_ident_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &IdentPat) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_ident_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut IdentPat) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_ident_pat<F: VstFold + ?Sized>(f: &mut F, mut node: IdentPat) -> IdentPat {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.name = Box::new(f.fold_name(*node.name));
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node
+}
+pub fn walk_if_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &IfExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.condition);
+    v.visit_block_expr(&node.then_branch);
+    if let Some(it) = &node.else_branch {
+        v.visit_else_branch(it);
+    }
+}
+pub fn walk_if_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut IfExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.condition);
+    v.visit_block_expr_mut(&mut node.then_branch);
+    if let Some(it) = &mut node.else_branch {
+        v.visit_else_branch_mut(it);
+    }
+}
+pub fn walk_fold_if_expr<F: VstFold + ?Sized>(f: &mut F, mut node: IfExpr) -> IfExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.condition = Box::new(f.fold_expr(*node.condition));
+    node.then_branch = Box::new(f.fold_block_expr(*node.then_branch));
+    node.else_branch = node.else_branch.map(|it| Box::new(f.fold_else_branch(*it)));
+    node
+}
+pub fn walk_impl<V: VstVisitor + ?Sized>(v: &mut V, node: &Impl) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    v.visit_assoc_item_list(&node.assoc_item_list);
+}
+pub fn walk_impl_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Impl) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    v.visit_assoc_item_list_mut(&mut node.assoc_item_list);
+}
+pub fn walk_fold_impl<F: VstFold + ?Sized>(f: &mut F, mut node: Impl) -> Impl {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.assoc_item_list = Box::new(f.fold_assoc_item_list(*node.assoc_item_list));
+    node
+}
+pub fn walk_impl_trait_type<V: VstVisitor + ?Sized>(v: &mut V, node: &ImplTraitType) {
+    v.visit_type_bound_list(&node.type_bound_list);
+}
+pub fn walk_impl_trait_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ImplTraitType) {
+    v.visit_type_bound_list_mut(&mut node.type_bound_list);
+}
+pub fn walk_fold_impl_trait_type<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ImplTraitType,
+) -> ImplTraitType {
+    node.type_bound_list = Box::new(f.fold_type_bound_list(*node.type_bound_list));
+    node
+}
+pub fn walk_index_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &IndexExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.base);
+    v.visit_expr(&node.index);
+}
+pub fn walk_index_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut IndexExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.base);
+    v.visit_expr_mut(&mut node.index);
+}
+pub fn walk_fold_index_expr<F: VstFold + ?Sized>(f: &mut F, mut node: IndexExpr) -> IndexExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.base = Box::new(f.fold_expr(*node.base));
+    node.index = Box::new(f.fold_expr(*node.index));
+    node
+}
+pub fn walk_infer_type<V: VstVisitor + ?Sized>(v: &mut V, node: &InferType) {}
+pub fn walk_infer_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut InferType) {}
+pub fn walk_fold_infer_type<F: VstFold + ?Sized>(f: &mut F, mut node: InferType) -> InferType {
+    node
+}
+pub fn walk_invariant_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &InvariantClause) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_invariant_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut InvariantClause) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_invariant_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: InvariantClause,
+) -> InvariantClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_invariant_except_break_clause<V: VstVisitor + ?Sized>(
+    v: &mut V,
+    node: &InvariantExceptBreakClause,
+) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_invariant_except_break_clause_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut InvariantExceptBreakClause,
+) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_invariant_except_break_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: InvariantExceptBreakClause,
+) -> InvariantExceptBreakClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_is_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &IsExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_is_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut IsExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_is_expr<F: VstFold + ?Sized>(f: &mut F, mut node: IsExpr) -> IsExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_item_list<V: VstVisitor + ?Sized>(v: &mut V, node: &ItemList) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.items {
+        v.visit_item(it);
+    }
+}
+pub fn walk_item_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ItemList) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.items {
+        v.visit_item_mut(it);
+    }
+}
+pub fn walk_fold_item_list<F: VstFold + ?Sized>(f: &mut F, mut node: ItemList) -> ItemList {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.items = node.items.into_iter().map(|it| f.fold_item(it)).collect();
+    node
+}
+pub fn walk_label<V: VstVisitor + ?Sized>(v: &mut V, node: &Label) {
+    v.visit_lifetime(&node.lifetime);
+}
+pub fn walk_label_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Label) {
+    v.visit_lifetime_mut(&mut node.lifetime);
+}
+pub fn walk_fold_label<F: VstFold + ?Sized>(f: &mut F, mut node: Label) -> Label {
+    node.lifetime = Box::new(f.fold_lifetime(*node.lifetime));
+    node
+}
+pub fn walk_let_else<V: VstVisitor + ?Sized>(v: &mut V, node: &LetElse) {
+    v.visit_block_expr(&node.block_expr);
+}
+pub fn walk_let_else_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LetElse) {
+    v.visit_block_expr_mut(&mut node.block_expr);
+}
+pub fn walk_fold_let_else<F: VstFold + ?Sized>(f: &mut F, mut node: LetElse) -> LetElse {
+    node.block_expr = Box::new(f.fold_block_expr(*node.block_expr));
+    node
+}
+pub fn walk_let_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &LetExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_let_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LetExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_let_expr<F: VstFold + ?Sized>(f: &mut F, mut node: LetExpr) -> LetExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_let_stmt<V: VstVisitor + ?Sized>(v: &mut V, node: &LetStmt) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    v.visit_expr(&node.initializer);
+    if let Some(it) = &node.let_else {
+        v.visit_let_else(it);
+    }
+}
+pub fn walk_let_stmt_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LetStmt) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    v.visit_expr_mut(&mut node.initializer);
+    if let Some(it) = &mut node.let_else {
+        v.visit_let_else_mut(it);
+    }
+}
+pub fn walk_fold_let_stmt<F: VstFold + ?Sized>(f: &mut F, mut node: LetStmt) -> LetStmt {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.initializer = Box::new(f.fold_expr(*node.initializer));
+    node.let_else = node.let_else.map(|it| Box::new(f.fold_let_else(*it)));
+    node
+}
+pub fn walk_lifetime<V: VstVisitor + ?Sized>(v: &mut V, node: &Lifetime) {}
+pub fn walk_lifetime_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Lifetime) {}
+pub fn walk_fold_lifetime<F: VstFold + ?Sized>(f: &mut F, mut node: Lifetime) -> Lifetime { node }
+pub fn walk_lifetime_arg<V: VstVisitor + ?Sized>(v: &mut V, node: &LifetimeArg) {
+    v.visit_lifetime(&node.lifetime);
+}
+pub fn walk_lifetime_arg_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LifetimeArg) {
+    v.visit_lifetime_mut(&mut node.lifetime);
+}
+pub fn walk_fold_lifetime_arg<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: LifetimeArg,
+) -> LifetimeArg {
+    node.lifetime = Box::new(f.fold_lifetime(*node.lifetime));
+    node
+}
+pub fn walk_lifetime_param<V: VstVisitor + ?Sized>(v: &mut V, node: &LifetimeParam) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_lifetime(&node.lifetime);
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+}
+pub fn walk_lifetime_param_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LifetimeParam) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_lifetime_mut(&mut node.lifetime);
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+}
+pub fn walk_fold_lifetime_param<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: LifetimeParam,
+) -> LifetimeParam {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.lifetime = Box::new(f.fold_lifetime(*node.lifetime));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node
+}
+pub fn walk_literal<V: VstVisitor + ?Sized>(v: &mut V, node: &Literal) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+}
+pub fn walk_literal_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Literal) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+}
+pub fn walk_fold_literal<F: VstFold + ?Sized>(f: &mut F, mut node: Literal) -> Literal {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node
+}
+pub fn walk_literal_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &LiteralPat) {
+    v.visit_literal(&node.literal);
+}
+pub fn walk_literal_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LiteralPat) {
+    v.visit_literal_mut(&mut node.literal);
+}
+pub fn walk_fold_literal_pat<F: VstFold + ?Sized>(f: &mut F, mut node: LiteralPat) -> LiteralPat {
+    node.literal = Box::new(f.fold_literal(*node.literal));
+    node
+}
+pub fn walk_loop_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &LoopExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    for it in &node.loop_clauses {
+        v.visit_loop_clause(it);
+    }
+    v.visit_block_expr(&node.loop_body);
+}
+pub fn walk_loop_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LoopExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    for it in &mut node.loop_clauses {
+        v.visit_loop_clause_mut(it);
+    }
+    v.visit_block_expr_mut(&mut node.loop_body);
+}
+pub fn walk_fold_loop_expr<F: VstFold + ?Sized>(f: &mut F, mut node: LoopExpr) -> LoopExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.label = node.label.map(|it| Box::new(f.fold_label(*it)));
+    node.loop_clauses = node.loop_clauses.into_iter().map(|it| f.fold_loop_clause(it)).collect();
+    node.loop_body = Box::new(f.fold_block_expr(*node.loop_body));
+    node
+}
+pub fn walk_macro_call<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroCall) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_path(&node.path);
+    v.visit_token_tree(&node.token_tree);
+}
+pub fn walk_macro_call_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroCall) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+    v.visit_token_tree_mut(&mut node.token_tree);
+}
+pub fn walk_fold_macro_call<F: VstFold + ?Sized>(f: &mut F, mut node: MacroCall) -> MacroCall {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.path = Box::new(f.fold_path(*node.path));
+    node.token_tree = Box::new(f.fold_token_tree(*node.token_tree));
+    node
+}
+pub fn walk_macro_def<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroDef) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.args {
+        v.visit_token_tree(it);
+    }
+    v.visit_token_tree(&node.body);
+}
+pub fn walk_macro_def_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroDef) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.args {
+        v.visit_token_tree_mut(it);
+    }
+    v.visit_token_tree_mut(&mut node.body);
+}
+pub fn walk_fold_macro_def<F: VstFold + ?Sized>(f: &mut F, mut node: MacroDef) -> MacroDef {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.args = node.args.map(|it| Box::new(f.fold_token_tree(*it)));
+    node.body = Box::new(f.fold_token_tree(*node.body));
+    node
+}
+pub fn walk_macro_eager_input<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroEagerInput) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_macro_eager_input_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut MacroEagerInput,
+) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_macro_eager_input<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: MacroEagerInput,
+) -> MacroEagerInput {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_macro_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroExpr) {
+    v.visit_macro_call(&node.macro_call);
+}
+pub fn walk_macro_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroExpr) {
+    v.visit_macro_call_mut(&mut node.macro_call);
+}
+pub fn walk_fold_macro_expr<F: VstFold + ?Sized>(f: &mut F, mut node: MacroExpr) -> MacroExpr {
+    node.macro_call = Box::new(f.fold_macro_call(*node.macro_call));
+    node
+}
+pub fn walk_macro_items<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroItems) {
+    for it in &node.items {
+        v.visit_item(it);
+    }
+}
+pub fn walk_macro_items_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroItems) {
+    for it in &mut node.items {
+        v.visit_item_mut(it);
+    }
+}
+pub fn walk_fold_macro_items<F: VstFold + ?Sized>(f: &mut F, mut node: MacroItems) -> MacroItems {
+    node.items = node.items.into_iter().map(|it| f.fold_item(it)).collect();
+    node
+}
+pub fn walk_macro_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroPat) {
+    v.visit_macro_call(&node.macro_call);
+}
+pub fn walk_macro_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroPat) {
+    v.visit_macro_call_mut(&mut node.macro_call);
+}
+pub fn walk_fold_macro_pat<F: VstFold + ?Sized>(f: &mut F, mut node: MacroPat) -> MacroPat {
+    node.macro_call = Box::new(f.fold_macro_call(*node.macro_call));
+    node
+}
+pub fn walk_macro_rules<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroRules) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    v.visit_token_tree(&node.token_tree);
+}
+pub fn walk_macro_rules_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroRules) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    v.visit_token_tree_mut(&mut node.token_tree);
+}
+pub fn walk_fold_macro_rules<F: VstFold + ?Sized>(f: &mut F, mut node: MacroRules) -> MacroRules {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.token_tree = Box::new(f.fold_token_tree(*node.token_tree));
+    node
+}
+pub fn walk_macro_stmts<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroStmts) {
+    for it in &node.statements {
+        v.visit_stmt(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_macro_stmts_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroStmts) {
+    for it in &mut node.statements {
+        v.visit_stmt_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_macro_stmts<F: VstFold + ?Sized>(f: &mut F, mut node: MacroStmts) -> MacroStmts {
+    node.statements = node.statements.into_iter().map(|it| f.fold_stmt(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_macro_type<V: VstVisitor + ?Sized>(v: &mut V, node: &MacroType) {
+    v.visit_macro_call(&node.macro_call);
+}
+pub fn walk_macro_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MacroType) {
+    v.visit_macro_call_mut(&mut node.macro_call);
+}
+pub fn walk_fold_macro_type<F: VstFold + ?Sized>(f: &mut F, mut node: MacroType) -> MacroType {
+    node.macro_call = Box::new(f.fold_macro_call(*node.macro_call));
+    node
+}
+pub fn walk_match_arm<V: VstVisitor + ?Sized>(v: &mut V, node: &MatchArm) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+    if let Some(it) = &node.guard {
+        v.visit_match_guard(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_match_arm_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MatchArm) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+    if let Some(it) = &mut node.guard {
+        v.visit_match_guard_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_match_arm<F: VstFold + ?Sized>(f: &mut F, mut node: MatchArm) -> MatchArm {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node.guard = node.guard.map(|it| Box::new(f.fold_match_guard(*it)));
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_match_arm_list<V: VstVisitor + ?Sized>(v: &mut V, node: &MatchArmList) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.arms {
+        v.visit_match_arm(it);
+    }
+}
+pub fn walk_match_arm_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MatchArmList) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.arms {
+        v.visit_match_arm_mut(it);
+    }
+}
+pub fn walk_fold_match_arm_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: MatchArmList,
+) -> MatchArmList {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.arms = node.arms.into_iter().map(|it| f.fold_match_arm(it)).collect();
+    node
+}
+pub fn walk_match_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &MatchExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    v.visit_match_arm_list(&node.match_arm_list);
+}
+pub fn walk_match_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MatchExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    v.visit_match_arm_list_mut(&mut node.match_arm_list);
+}
+pub fn walk_fold_match_expr<F: VstFold + ?Sized>(f: &mut F, mut node: MatchExpr) -> MatchExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.match_arm_list = Box::new(f.fold_match_arm_list(*node.match_arm_list));
+    node
+}
+pub fn walk_match_guard<V: VstVisitor + ?Sized>(v: &mut V, node: &MatchGuard) {
+    v.visit_expr(&node.condition);
+}
+pub fn walk_match_guard_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MatchGuard) {
+    v.visit_expr_mut(&mut node.condition);
+}
+pub fn walk_fold_match_guard<F: VstFold + ?Sized>(f: &mut F, mut node: MatchGuard) -> MatchGuard {
+    node.condition = Box::new(f.fold_expr(*node.condition));
+    node
+}
+pub fn walk_matches_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &MatchesExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_matches_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MatchesExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_matches_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: MatchesExpr,
+) -> MatchesExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node
+}
+pub fn walk_meta<V: VstVisitor + ?Sized>(v: &mut V, node: &Meta) {
+    if let Some(it) = &node.path {
+        v.visit_path(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.token_tree {
+        v.visit_token_tree(it);
+    }
+}
+pub fn walk_meta_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Meta) {
+    if let Some(it) = &mut node.path {
+        v.visit_path_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.token_tree {
+        v.visit_token_tree_mut(it);
+    }
+}
+pub fn walk_fold_meta<F: VstFold + ?Sized>(f: &mut F, mut node: Meta) -> Meta {
+    node.path = node.path.map(|it| Box::new(f.fold_path(*it)));
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node.token_tree = node.token_tree.map(|it| Box::new(f.fold_token_tree(*it)));
+    node
+}
+pub fn walk_method_call_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &MethodCallExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.receiver);
+    v.visit_name_ref(&node.name_ref);
+    if let Some(it) = &node.generic_arg_list {
+        v.visit_generic_arg_list(it);
+    }
+    v.visit_arg_list(&node.arg_list);
+}
+pub fn walk_method_call_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut MethodCallExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.receiver);
+    v.visit_name_ref_mut(&mut node.name_ref);
+    if let Some(it) = &mut node.generic_arg_list {
+        v.visit_generic_arg_list_mut(it);
+    }
+    v.visit_arg_list_mut(&mut node.arg_list);
+}
+pub fn walk_fold_method_call_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: MethodCallExpr,
+) -> MethodCallExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.receiver = Box::new(f.fold_expr(*node.receiver));
+    node.name_ref = Box::new(f.fold_name_ref(*node.name_ref));
+    node.generic_arg_list = node.generic_arg_list.map(|it| Box::new(f.fold_generic_arg_list(*it)));
+    node.arg_list = Box::new(f.fold_arg_list(*node.arg_list));
+    node
+}
+pub fn walk_mode_spec_checked<V: VstVisitor + ?Sized>(v: &mut V, node: &ModeSpecChecked) {}
+pub fn walk_mode_spec_checked_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut ModeSpecChecked,
+) {
+}
+pub fn walk_fold_mode_spec_checked<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ModeSpecChecked,
+) -> ModeSpecChecked {
+    node
+}
+pub fn walk_module<V: VstVisitor + ?Sized>(v: &mut V, node: &Module) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.item_list {
+        v.visit_item_list(it);
+    }
+}
+pub fn walk_module_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Module) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.item_list {
+        v.visit_item_list_mut(it);
+    }
+}
+pub fn walk_fold_module<F: VstFold + ?Sized>(f: &mut F, mut node: Module) -> Module {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.item_list = node.item_list.map(|it| Box::new(f.fold_item_list(*it)));
+    node
+}
+pub fn walk_name<V: VstVisitor + ?Sized>(v: &mut V, node: &Name) {}
+pub fn walk_name_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Name) {}
+pub fn walk_fold_name<F: VstFold + ?Sized>(f: &mut F, mut node: Name) -> Name { node }
+pub fn walk_name_ref<V: VstVisitor + ?Sized>(v: &mut V, node: &NameRef) {}
+pub fn walk_name_ref_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut NameRef) {}
+pub fn walk_fold_name_ref<F: VstFold + ?Sized>(f: &mut F, mut node: NameRef) -> NameRef { node }
+pub fn walk_never_type<V: VstVisitor + ?Sized>(v: &mut V, node: &NeverType) {}
+pub fn walk_never_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut NeverType) {}
+pub fn walk_fold_never_type<F: VstFold + ?Sized>(f: &mut F, mut node: NeverType) -> NeverType {
+    node
+}
+pub fn walk_no_unwind_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &NoUnwindClause) {
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_no_unwind_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut NoUnwindClause) {
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_no_unwind_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: NoUnwindClause,
+) -> NoUnwindClause {
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_offset_of_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &OffsetOfExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    for it in &node.fields {
+        v.visit_name_ref(it);
+    }
+}
+pub fn walk_offset_of_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut OffsetOfExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    for it in &mut node.fields {
+        v.visit_name_ref_mut(it);
+    }
+}
+pub fn walk_fold_offset_of_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: OffsetOfExpr,
+) -> OffsetOfExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.fields = node.fields.into_iter().map(|it| f.fold_name_ref(it)).collect();
+    node
+}
+pub fn walk_opens_invariants_clause<V: VstVisitor + ?Sized>(
+    v: &mut V,
+    node: &OpensInvariantsClause,
+) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_opens_invariants_clause_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut OpensInvariantsClause,
+) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_opens_invariants_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: OpensInvariantsClause,
+) -> OpensInvariantsClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_or_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &OrPat) {
+    for it in &node.pats {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_or_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut OrPat) {
+    for it in &mut node.pats {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_or_pat<F: VstFold + ?Sized>(f: &mut F, mut node: OrPat) -> OrPat {
+    node.pats = node.pats.into_iter().map(|it| f.fold_pat(it)).collect();
+    node
+}
+pub fn walk_param<V: VstVisitor + ?Sized>(v: &mut V, node: &Param) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_param_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Param) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_param<F: VstFold + ?Sized>(f: &mut F, mut node: Param) -> Param {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_param_list<V: VstVisitor + ?Sized>(v: &mut V, node: &ParamList) {
+    if let Some(it) = &node.self_param {
+        v.visit_self_param(it);
+    }
+    for it in &node.params {
+        v.visit_param(it);
+    }
+}
+pub fn walk_param_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ParamList) {
+    if let Some(it) = &mut node.self_param {
+        v.visit_self_param_mut(it);
+    }
+    for it in &mut node.params {
+        v.visit_param_mut(it);
+    }
+}
+pub fn walk_fold_param_list<F: VstFold + ?Sized>(f: &mut F, mut node: ParamList) -> ParamList {
+    node.self_param = node.self_param.map(|it| Box::new(f.fold_self_param(*it)));
+    node.params = node.params.into_iter().map(|it| f.fold_param(it)).collect();
+    node
+}
+pub fn walk_paren_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ParenExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_paren_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ParenExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_paren_expr<F: VstFold + ?Sized>(f: &mut F, mut node: ParenExpr) -> ParenExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_paren_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &ParenPat) {
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_paren_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ParenPat) {
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_paren_pat<F: VstFold + ?Sized>(f: &mut F, mut node: ParenPat) -> ParenPat {
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node
+}
+pub fn walk_paren_type<V: VstVisitor + ?Sized>(v: &mut V, node: &ParenType) {
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_paren_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ParenType) {
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_paren_type<F: VstFold + ?Sized>(f: &mut F, mut node: ParenType) -> ParenType {
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_path<V: VstVisitor + ?Sized>(v: &mut V, node: &Path) {
+    if let Some(it) = &node.qualifier {
+        v.visit_path(it);
+    }
+    v.visit_path_segment(&node.segment);
+}
+pub fn walk_path_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Path) {
+    if let Some(it) = &mut node.qualifier {
+        v.visit_path_mut(it);
+    }
+    v.visit_path_segment_mut(&mut node.segment);
+}
+pub fn walk_fold_path<F: VstFold + ?Sized>(f: &mut F, mut node: Path) -> Path {
+    node.qualifier = node.qualifier.map(|it| Box::new(f.fold_path(*it)));
+    node.segment = Box::new(f.fold_path_segment(*node.segment));
+    node
+}
+pub fn walk_path_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &PathExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_path(&node.path);
+}
+pub fn walk_path_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut PathExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+}
+pub fn walk_fold_path_expr<F: VstFold + ?Sized>(f: &mut F, mut node: PathExpr) -> PathExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.path = Box::new(f.fold_path(*node.path));
+    node
+}
+pub fn walk_path_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &PathPat) {
+    v.visit_path(&node.path);
+}
+pub fn walk_path_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut PathPat) {
+    v.visit_path_mut(&mut node.path);
+}
+pub fn walk_fold_path_pat<F: VstFold + ?Sized>(f: &mut F, mut node: PathPat) -> PathPat {
+    node.path = Box::new(f.fold_path(*node.path));
+    node
+}
+pub fn walk_path_segment<V: VstVisitor + ?Sized>(v: &mut V, node: &PathSegment) {
+    if let Some(it) = &node.name_ref {
+        v.visit_name_ref(it);
+    }
+    if let Some(it) = &node.generic_arg_list {
+        v.visit_generic_arg_list(it);
+    }
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    if let Some(it) = &node.path_type {
+        v.visit_path_type(it);
+    }
+}
+pub fn walk_path_segment_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut PathSegment) {
+    if let Some(it) = &mut node.name_ref {
+        v.visit_name_ref_mut(it);
+    }
+    if let Some(it) = &mut node.generic_arg_list {
+        v.visit_generic_arg_list_mut(it);
+    }
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    if let Some(it) = &mut node.path_type {
+        v.visit_path_type_mut(it);
+    }
+}
+pub fn walk_fold_path_segment<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: PathSegment,
+) -> PathSegment {
+    node.name_ref = node.name_ref.map(|it| Box::new(f.fold_name_ref(*it)));
+    node.generic_arg_list = node.generic_arg_list.map(|it| Box::new(f.fold_generic_arg_list(*it)));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.path_type = node.path_type.map(|it| Box::new(f.fold_path_type(*it)));
+    node
+}
+pub fn walk_path_type<V: VstVisitor + ?Sized>(v: &mut V, node: &PathType) {
+    v.visit_path(&node.path);
+}
+pub fn walk_path_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut PathType) {
+    v.visit_path_mut(&mut node.path);
+}
+pub fn walk_fold_path_type<F: VstFold + ?Sized>(f: &mut F, mut node: PathType) -> PathType {
+    node.path = Box::new(f.fold_path(*node.path));
+    node
+}
+pub fn walk_prefix_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &PrefixExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_prefix_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut PrefixExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_prefix_expr<F: VstFold + ?Sized>(f: &mut F, mut node: PrefixExpr) -> PrefixExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_proof_fn_characteristics<V: VstVisitor + ?Sized>(
+    v: &mut V,
+    node: &ProofFnCharacteristics,
+) {
+    for it in &node.fields {
+        v.visit_path(it);
+    }
+}
+pub fn walk_proof_fn_characteristics_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut ProofFnCharacteristics,
+) {
+    for it in &mut node.fields {
+        v.visit_path_mut(it);
+    }
+}
+pub fn walk_fold_proof_fn_characteristics<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ProofFnCharacteristics,
+) -> ProofFnCharacteristics {
+    node.fields = node.fields.into_iter().map(|it| f.fold_path(it)).collect();
+    node
+}
+pub fn walk_proof_fn_type<V: VstVisitor + ?Sized>(v: &mut V, node: &ProofFnType) {
+    if let Some(it) = &node.proof_fn_characteristics {
+        v.visit_proof_fn_characteristics(it);
+    }
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.param_list {
+        v.visit_param_list(it);
+    }
+    if let Some(it) = &node.ret_type {
+        v.visit_ret_type(it);
+    }
+}
+pub fn walk_proof_fn_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ProofFnType) {
+    if let Some(it) = &mut node.proof_fn_characteristics {
+        v.visit_proof_fn_characteristics_mut(it);
+    }
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.param_list {
+        v.visit_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.ret_type {
+        v.visit_ret_type_mut(it);
+    }
+}
+pub fn walk_fold_proof_fn_type<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ProofFnType,
+) -> ProofFnType {
+    node.proof_fn_characteristics =
+        node.proof_fn_characteristics.map(|it| Box::new(f.fold_proof_fn_characteristics(*it)));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.param_list = node.param_list.map(|it| Box::new(f.fold_param_list(*it)));
+    node.ret_type = node.ret_type.map(|it| Box::new(f.fold_ret_type(*it)));
+    node
+}
+pub fn walk_proof_fn_with_characteristics<V: VstVisitor + ?Sized>(
+    v: &mut V,
+    node: &ProofFnWithCharacteristics,
+) {
+    if let Some(it) = &node.proof_fn_characteristics {
+        v.visit_proof_fn_characteristics(it);
+    }
+}
+pub fn walk_proof_fn_with_characteristics_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut ProofFnWithCharacteristics,
+) {
+    if let Some(it) = &mut node.proof_fn_characteristics {
+        v.visit_proof_fn_characteristics_mut(it);
+    }
+}
+pub fn walk_fold_proof_fn_with_characteristics<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ProofFnWithCharacteristics,
+) -> ProofFnWithCharacteristics {
+    node.proof_fn_characteristics =
+        node.proof_fn_characteristics.map(|it| Box::new(f.fold_proof_fn_characteristics(*it)));
+    node
+}
+pub fn walk_prover<V: VstVisitor + ?Sized>(v: &mut V, node: &Prover) { v.visit_name(&node.name); }
+pub fn walk_prover_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Prover) {
+    v.visit_name_mut(&mut node.name);
+}
+pub fn walk_fold_prover<F: VstFold + ?Sized>(f: &mut F, mut node: Prover) -> Prover {
+    node.name = Box::new(f.fold_name(*node.name));
+    node
+}
+pub fn walk_ptr_type<V: VstVisitor + ?Sized>(v: &mut V, node: &PtrType) {
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_ptr_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut PtrType) {
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_ptr_type<F: VstFold + ?Sized>(f: &mut F, mut node: PtrType) -> PtrType {
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_publish<V: VstVisitor + ?Sized>(v: &mut V, node: &Publish) {
+    if let Some(it) = &node.path {
+        v.visit_path(it);
+    }
+}
+pub fn walk_publish_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Publish) {
+    if let Some(it) = &mut node.path {
+        v.visit_path_mut(it);
+    }
+}
+pub fn walk_fold_publish<F: VstFold + ?Sized>(f: &mut F, mut node: Publish) -> Publish {
+    node.path = node.path.map(|it| Box::new(f.fold_path(*it)));
+    node
+}
+pub fn walk_range_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &RangeExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+}
+pub fn walk_range_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RangeExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+}
+pub fn walk_fold_range_expr<F: VstFold + ?Sized>(f: &mut F, mut node: RangeExpr) -> RangeExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node
+}
+pub fn walk_range_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &RangePat) {}
+pub fn walk_range_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RangePat) {}
+pub fn walk_fold_range_pat<F: VstFold + ?Sized>(f: &mut F, mut node: RangePat) -> RangePat { node }
+pub fn walk_recommends_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &RecommendsClause) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_recommends_clause_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut RecommendsClause,
+) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_recommends_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecommendsClause,
+) -> RecommendsClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_record_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordExpr) {
+    v.visit_path(&node.path);
+    v.visit_record_expr_field_list(&node.record_expr_field_list);
+}
+pub fn walk_record_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RecordExpr) {
+    v.visit_path_mut(&mut node.path);
+    v.visit_record_expr_field_list_mut(&mut node.record_expr_field_list);
+}
+pub fn walk_fold_record_expr<F: VstFold + ?Sized>(f: &mut F, mut node: RecordExpr) -> RecordExpr {
+    node.path = Box::new(f.fold_path(*node.path));
+    node.record_expr_field_list =
+        Box::new(f.fold_record_expr_field_list(*node.record_expr_field_list));
+    node
+}
+pub fn walk_record_expr_field<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordExprField) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.name_ref {
+        v.visit_name_ref(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_record_expr_field_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut RecordExprField,
+) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.name_ref {
+        v.visit_name_ref_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_record_expr_field<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecordExprField,
+) -> RecordExprField {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.name_ref = node.name_ref.map(|it| Box::new(f.fold_name_ref(*it)));
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_record_expr_field_list<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordExprFieldList) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.fields {
+        v.visit_record_expr_field(it);
+    }
+    if let Some(it) = &node.spread {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_record_expr_field_list_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut RecordExprFieldList,
+) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.fields {
+        v.visit_record_expr_field_mut(it);
+    }
+    if let Some(it) = &mut node.spread {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_record_expr_field_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecordExprFieldList,
+) -> RecordExprFieldList {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.fields = node.fields.into_iter().map(|it| f.fold_record_expr_field(it)).collect();
+    node.spread = node.spread.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_record_field<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordField) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.data_mode {
+        v.visit_data_mode(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_record_field_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RecordField) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.data_mode {
+        v.visit_data_mode_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_record_field<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecordField,
+) -> RecordField {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.data_mode = node.data_mode.map(|it| Box::new(f.fold_data_mode(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_record_field_list<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordFieldList) {
+    for it in &node.fields {
+        v.visit_record_field(it);
+    }
+}
+pub fn walk_record_field_list_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut RecordFieldList,
+) {
+    for it in &mut node.fields {
+        v.visit_record_field_mut(it);
+    }
+}
+pub fn walk_fold_record_field_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecordFieldList,
+) -> RecordFieldList {
+    node.fields = node.fields.into_iter().map(|it| f.fold_record_field(it)).collect();
+    node
+}
+pub fn walk_record_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordPat) {
+    v.visit_path(&node.path);
+    v.visit_record_pat_field_list(&node.record_pat_field_list);
+}
+pub fn walk_record_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RecordPat) {
+    v.visit_path_mut(&mut node.path);
+    v.visit_record_pat_field_list_mut(&mut node.record_pat_field_list);
+}
+pub fn walk_fold_record_pat<F: VstFold + ?Sized>(f: &mut F, mut node: RecordPat) -> RecordPat {
+    node.path = Box::new(f.fold_path(*node.path));
+    node.record_pat_field_list =
+        Box::new(f.fold_record_pat_field_list(*node.record_pat_field_list));
+    node
+}
+pub fn walk_record_pat_field<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordPatField) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.name_ref {
+        v.visit_name_ref(it);
+    }
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_record_pat_field_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RecordPatField) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.name_ref {
+        v.visit_name_ref_mut(it);
+    }
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_record_pat_field<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecordPatField,
+) -> RecordPatField {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.name_ref = node.name_ref.map(|it| Box::new(f.fold_name_ref(*it)));
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node
+}
+pub fn walk_record_pat_field_list<V: VstVisitor + ?Sized>(v: &mut V, node: &RecordPatFieldList) {
+    for it in &node.fields {
+        v.visit_record_pat_field(it);
+    }
+    if let Some(it) = &node.rest_pat {
+        v.visit_rest_pat(it);
+    }
+}
+pub fn walk_record_pat_field_list_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut RecordPatFieldList,
+) {
+    for it in &mut node.fields {
+        v.visit_record_pat_field_mut(it);
+    }
+    if let Some(it) = &mut node.rest_pat {
+        v.visit_rest_pat_mut(it);
+    }
+}
+pub fn walk_fold_record_pat_field_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RecordPatFieldList,
+) -> RecordPatFieldList {
+    node.fields = node.fields.into_iter().map(|it| f.fold_record_pat_field(it)).collect();
+    node.rest_pat = node.rest_pat.map(|it| Box::new(f.fold_rest_pat(*it)));
+    node
+}
+pub fn walk_ref_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &RefExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_ref_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RefExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_ref_expr<F: VstFold + ?Sized>(f: &mut F, mut node: RefExpr) -> RefExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_ref_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &RefPat) {
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_ref_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RefPat) {
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_ref_pat<F: VstFold + ?Sized>(f: &mut F, mut node: RefPat) -> RefPat {
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node
+}
+pub fn walk_ref_type<V: VstVisitor + ?Sized>(v: &mut V, node: &RefType) {
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_ref_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RefType) {
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_ref_type<F: VstFold + ?Sized>(f: &mut F, mut node: RefType) -> RefType {
+    node.lifetime = node.lifetime.map(|it| Box::new(f.fold_lifetime(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_rename<V: VstVisitor + ?Sized>(v: &mut V, node: &Rename) {
+    if let Some(it) = &node.name {
+        v.visit_name(it);
+    }
+}
+pub fn walk_rename_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Rename) {
+    if let Some(it) = &mut node.name {
+        v.visit_name_mut(it);
+    }
+}
+pub fn walk_fold_rename<F: VstFold + ?Sized>(f: &mut F, mut node: Rename) -> Rename {
+    node.name = node.name.map(|it| Box::new(f.fold_name(*it)));
+    node
+}
+pub fn walk_requires_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &RequiresClause) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_requires_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RequiresClause) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_requires_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: RequiresClause,
+) -> RequiresClause {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_rest_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &RestPat) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+}
+pub fn walk_rest_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RestPat) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+}
+pub fn walk_fold_rest_pat<F: VstFold + ?Sized>(f: &mut F, mut node: RestPat) -> RestPat {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node
+}
+pub fn walk_ret_type<V: VstVisitor + ?Sized>(v: &mut V, node: &RetType) {
+    if let Some(it) = &node.pat {
+        v.visit_pat(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_ret_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut RetType) {
+    if let Some(it) = &mut node.pat {
+        v.visit_pat_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_ret_type<F: VstFold + ?Sized>(f: &mut F, mut node: RetType) -> RetType {
+    node.pat = node.pat.map(|it| Box::new(f.fold_pat(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_return_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ReturnExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_return_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ReturnExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_return_expr<F: VstFold + ?Sized>(f: &mut F, mut node: ReturnExpr) -> ReturnExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_returns_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &ReturnsClause) {
+    v.visit_expr(&node.expr);
+}
+pub fn walk_returns_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ReturnsClause) {
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_returns_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: ReturnsClause,
+) -> ReturnsClause {
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_self_param<V: VstVisitor + ?Sized>(v: &mut V, node: &SelfParam) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    if let Some(it) = &node.name {
+        v.visit_name(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_self_param_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut SelfParam) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    if let Some(it) = &mut node.name {
+        v.visit_name_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_self_param<F: VstFold + ?Sized>(f: &mut F, mut node: SelfParam) -> SelfParam {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.lifetime = node.lifetime.map(|it| Box::new(f.fold_lifetime(*it)));
+    node.name = node.name.map(|it| Box::new(f.fold_name(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_signature_decreases<V: VstVisitor + ?Sized>(v: &mut V, node: &SignatureDecreases) {
+    v.visit_decreases_clause(&node.decreases_clause);
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_signature_decreases_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut SignatureDecreases,
+) {
+    v.visit_decreases_clause_mut(&mut node.decreases_clause);
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_signature_decreases<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: SignatureDecreases,
+) -> SignatureDecreases {
+    node.decreases_clause = Box::new(f.fold_decreases_clause(*node.decreases_clause));
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_slice_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &SlicePat) {
+    for it in &node.pats {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_slice_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut SlicePat) {
+    for it in &mut node.pats {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_slice_pat<F: VstFold + ?Sized>(f: &mut F, mut node: SlicePat) -> SlicePat {
+    node.pats = node.pats.into_iter().map(|it| f.fold_pat(it)).collect();
+    node
+}
+pub fn walk_slice_type<V: VstVisitor + ?Sized>(v: &mut V, node: &SliceType) {
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_slice_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut SliceType) {
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_slice_type<F: VstFold + ?Sized>(f: &mut F, mut node: SliceType) -> SliceType {
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_source_file<V: VstVisitor + ?Sized>(v: &mut V, node: &SourceFile) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.items {
+        v.visit_item(it);
+    }
+}
+pub fn walk_source_file_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut SourceFile) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.items {
+        v.visit_item_mut(it);
+    }
+}
+pub fn walk_fold_source_file<F: VstFold + ?Sized>(f: &mut F, mut node: SourceFile) -> SourceFile {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.items = node.items.into_iter().map(|it| f.fold_item(it)).collect();
+    node
+}
+pub fn walk_static<V: VstVisitor + ?Sized>(v: &mut V, node: &Static) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.name {
+        v.visit_name(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    if let Some(it) = &node.body {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.ensures_clause {
+        v.visit_ensures_clause(it);
+    }
+    if let Some(it) = &node.block_expr {
+        v.visit_block_expr(it);
+    }
+}
+pub fn walk_static_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Static) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.name {
+        v.visit_name_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    if let Some(it) = &mut node.body {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.ensures_clause {
+        v.visit_ensures_clause_mut(it);
+    }
+    if let Some(it) = &mut node.block_expr {
+        v.visit_block_expr_mut(it);
+    }
+}
+pub fn walk_fold_static<F: VstFold + ?Sized>(f: &mut F, mut node: Static) -> Static {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = node.name.map(|it| Box::new(f.fold_name(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.body = node.body.map(|it| Box::new(f.fold_expr(*it)));
+    node.ensures_clause = node.ensures_clause.map(|it| Box::new(f.fold_ensures_clause(*it)));
+    node.block_expr = node.block_expr.map(|it| Box::new(f.fold_block_expr(*it)));
+    node
+}
+pub fn walk_stmt_list<V: VstVisitor + ?Sized>(v: &mut V, node: &StmtList) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.statements {
+        v.visit_stmt(it);
+    }
+    if let Some(it) = &node.tail_expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_stmt_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut StmtList) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.statements {
+        v.visit_stmt_mut(it);
+    }
+    if let Some(it) = &mut node.tail_expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_stmt_list<F: VstFold + ?Sized>(f: &mut F, mut node: StmtList) -> StmtList {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.statements = node.statements.into_iter().map(|it| f.fold_stmt(it)).collect();
+    node.tail_expr = node.tail_expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_struct<V: VstVisitor + ?Sized>(v: &mut V, node: &Struct) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.data_mode {
+        v.visit_data_mode(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    if let Some(it) = &node.field_list {
+        v.visit_field_list(it);
+    }
+}
+pub fn walk_struct_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Struct) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.data_mode {
+        v.visit_data_mode_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    if let Some(it) = &mut node.field_list {
+        v.visit_field_list_mut(it);
+    }
+}
+pub fn walk_fold_struct<F: VstFold + ?Sized>(f: &mut F, mut node: Struct) -> Struct {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.data_mode = node.data_mode.map(|it| Box::new(f.fold_data_mode(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.field_list = node.field_list.map(|it| Box::new(f.fold_field_list(*it)));
+    node
+}
+pub fn walk_token_tree<V: VstVisitor + ?Sized>(v: &mut V, node: &TokenTree) {}
+pub fn walk_token_tree_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TokenTree) {}
+pub fn walk_fold_token_tree<F: VstFold + ?Sized>(f: &mut F, mut node: TokenTree) -> TokenTree {
+    node
+}
+pub fn walk_trait<V: VstVisitor + ?Sized>(v: &mut V, node: &Trait) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    v.visit_assoc_item_list(&node.assoc_item_list);
+}
+pub fn walk_trait_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Trait) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    v.visit_assoc_item_list_mut(&mut node.assoc_item_list);
+}
+pub fn walk_fold_trait<F: VstFold + ?Sized>(f: &mut F, mut node: Trait) -> Trait {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.assoc_item_list = Box::new(f.fold_assoc_item_list(*node.assoc_item_list));
+    node
+}
+pub fn walk_trait_alias<V: VstVisitor + ?Sized>(v: &mut V, node: &TraitAlias) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+}
+pub fn walk_trait_alias_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TraitAlias) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+}
+pub fn walk_fold_trait_alias<F: VstFold + ?Sized>(f: &mut F, mut node: TraitAlias) -> TraitAlias {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node
+}
+pub fn walk_trigger_attribute<V: VstVisitor + ?Sized>(v: &mut V, node: &TriggerAttribute) {
+    for it in &node.exprs {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_trigger_attribute_mut<V: VstVisitorMut + ?Sized>(
+    v: &mut V,
+    node: &mut TriggerAttribute,
+) {
+    for it in &mut node.exprs {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_trigger_attribute<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: TriggerAttribute,
+) -> TriggerAttribute {
+    node.exprs = node.exprs.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_try_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &TryExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_try_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TryExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_try_expr<F: VstFold + ?Sized>(f: &mut F, mut node: TryExpr) -> TryExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_tuple_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &TupleExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    for it in &node.fields {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_tuple_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TupleExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    for it in &mut node.fields {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_tuple_expr<F: VstFold + ?Sized>(f: &mut F, mut node: TupleExpr) -> TupleExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.fields = node.fields.into_iter().map(|it| f.fold_expr(it)).collect();
+    node
+}
+pub fn walk_tuple_field<V: VstVisitor + ?Sized>(v: &mut V, node: &TupleField) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_tuple_field_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TupleField) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_tuple_field<F: VstFold + ?Sized>(f: &mut F, mut node: TupleField) -> TupleField {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_tuple_field_list<V: VstVisitor + ?Sized>(v: &mut V, node: &TupleFieldList) {
+    for it in &node.fields {
+        v.visit_tuple_field(it);
+    }
+}
+pub fn walk_tuple_field_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TupleFieldList) {
+    for it in &mut node.fields {
+        v.visit_tuple_field_mut(it);
+    }
+}
+pub fn walk_fold_tuple_field_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: TupleFieldList,
+) -> TupleFieldList {
+    node.fields = node.fields.into_iter().map(|it| f.fold_tuple_field(it)).collect();
+    node
+}
+pub fn walk_tuple_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &TuplePat) {
+    for it in &node.fields {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_tuple_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TuplePat) {
+    for it in &mut node.fields {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_tuple_pat<F: VstFold + ?Sized>(f: &mut F, mut node: TuplePat) -> TuplePat {
+    node.fields = node.fields.into_iter().map(|it| f.fold_pat(it)).collect();
+    node
+}
+pub fn walk_tuple_struct_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &TupleStructPat) {
+    v.visit_path(&node.path);
+    for it in &node.fields {
+        v.visit_pat(it);
+    }
+}
+pub fn walk_tuple_struct_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TupleStructPat) {
+    v.visit_path_mut(&mut node.path);
+    for it in &mut node.fields {
+        v.visit_pat_mut(it);
+    }
+}
+pub fn walk_fold_tuple_struct_pat<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: TupleStructPat,
+) -> TupleStructPat {
+    node.path = Box::new(f.fold_path(*node.path));
+    node.fields = node.fields.into_iter().map(|it| f.fold_pat(it)).collect();
+    node
+}
+pub fn walk_tuple_type<V: VstVisitor + ?Sized>(v: &mut V, node: &TupleType) {
+    for it in &node.fields {
+        v.visit_type(it);
+    }
+}
+pub fn walk_tuple_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TupleType) {
+    for it in &mut node.fields {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_tuple_type<F: VstFold + ?Sized>(f: &mut F, mut node: TupleType) -> TupleType {
+    node.fields = node.fields.into_iter().map(|it| f.fold_type(it)).collect();
+    node
+}
+pub fn walk_type_alias<V: VstVisitor + ?Sized>(v: &mut V, node: &TypeAlias) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_type_alias_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TypeAlias) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_type_alias<F: VstFold + ?Sized>(f: &mut F, mut node: TypeAlias) -> TypeAlias {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_type_arg<V: VstVisitor + ?Sized>(v: &mut V, node: &TypeArg) {
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_type_arg_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TypeArg) {
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_type_arg<F: VstFold + ?Sized>(f: &mut F, mut node: TypeArg) -> TypeArg {
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_type_bound<V: VstVisitor + ?Sized>(v: &mut V, node: &TypeBound) {
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_type_bound_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TypeBound) {
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_type_bound<F: VstFold + ?Sized>(f: &mut F, mut node: TypeBound) -> TypeBound {
+    node.lifetime = node.lifetime.map(|it| Box::new(f.fold_lifetime(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_type_bound_list<V: VstVisitor + ?Sized>(v: &mut V, node: &TypeBoundList) {
+    for it in &node.bounds {
+        v.visit_type_bound(it);
+    }
+}
+pub fn walk_type_bound_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TypeBoundList) {
+    for it in &mut node.bounds {
+        v.visit_type_bound_mut(it);
+    }
+}
+pub fn walk_fold_type_bound_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: TypeBoundList,
+) -> TypeBoundList {
+    node.bounds = node.bounds.into_iter().map(|it| f.fold_type_bound(it)).collect();
+    node
+}
+pub fn walk_type_param<V: VstVisitor + ?Sized>(v: &mut V, node: &TypeParam) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+    if let Some(it) = &node.default_type {
+        v.visit_type(it);
+    }
+}
+pub fn walk_type_param_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut TypeParam) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+    if let Some(it) = &mut node.default_type {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_type_param<F: VstFold + ?Sized>(f: &mut F, mut node: TypeParam) -> TypeParam {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.name = Box::new(f.fold_name(*node.name));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node.default_type = node.default_type.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_underscore_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &UnderscoreExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+}
+pub fn walk_underscore_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut UnderscoreExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+}
+pub fn walk_fold_underscore_expr<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: UnderscoreExpr,
+) -> UnderscoreExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node
+}
+pub fn walk_union<V: VstVisitor + ?Sized>(v: &mut V, node: &Union) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+    v.visit_record_field_list(&node.record_field_list);
+}
+pub fn walk_union_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Union) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+    v.visit_record_field_list_mut(&mut node.record_field_list);
+}
+pub fn walk_fold_union<F: VstFold + ?Sized>(f: &mut F, mut node: Union) -> Union {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.where_clause = node.where_clause.map(|it| Box::new(f.fold_where_clause(*it)));
+    node.record_field_list = Box::new(f.fold_record_field_list(*node.record_field_list));
+    node
+}
+pub fn walk_use<V: VstVisitor + ?Sized>(v: &mut V, node: &Use) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_use_tree(&node.use_tree);
+}
+pub fn walk_use_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Use) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_use_tree_mut(&mut node.use_tree);
+}
+pub fn walk_fold_use<F: VstFold + ?Sized>(f: &mut F, mut node: Use) -> Use {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.use_tree = Box::new(f.fold_use_tree(*node.use_tree));
+    node
+}
+pub fn walk_use_tree<V: VstVisitor + ?Sized>(v: &mut V, node: &UseTree) {
+    if let Some(it) = &node.path {
+        v.visit_path(it);
+    }
+    if let Some(it) = &node.use_tree_list {
+        v.visit_use_tree_list(it);
+    }
+    if let Some(it) = &node.rename {
+        v.visit_rename(it);
+    }
+}
+pub fn walk_use_tree_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut UseTree) {
+    if let Some(it) = &mut node.path {
+        v.visit_path_mut(it);
+    }
+    if let Some(it) = &mut node.use_tree_list {
+        v.visit_use_tree_list_mut(it);
+    }
+    if let Some(it) = &mut node.rename {
+        v.visit_rename_mut(it);
+    }
+}
+pub fn walk_fold_use_tree<F: VstFold + ?Sized>(f: &mut F, mut node: UseTree) -> UseTree {
+    node.path = node.path.map(|it| Box::new(f.fold_path(*it)));
+    node.use_tree_list = node.use_tree_list.map(|it| Box::new(f.fold_use_tree_list(*it)));
+    node.rename = node.rename.map(|it| Box::new(f.fold_rename(*it)));
+    node
+}
+pub fn walk_use_tree_list<V: VstVisitor + ?Sized>(v: &mut V, node: &UseTreeList) {
+    for it in &node.use_trees {
+        v.visit_use_tree(it);
+    }
+}
+pub fn walk_use_tree_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut UseTreeList) {
+    for it in &mut node.use_trees {
+        v.visit_use_tree_mut(it);
+    }
+}
+pub fn walk_fold_use_tree_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: UseTreeList,
+) -> UseTreeList {
+    node.use_trees = node.use_trees.into_iter().map(|it| f.fold_use_tree(it)).collect();
+    node
+}
+pub fn walk_variant<V: VstVisitor + ?Sized>(v: &mut V, node: &Variant) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.visibility {
+        v.visit_visibility(it);
+    }
+    v.visit_name(&node.name);
+    if let Some(it) = &node.field_list {
+        v.visit_field_list(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_variant_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Variant) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.visibility {
+        v.visit_visibility_mut(it);
+    }
+    v.visit_name_mut(&mut node.name);
+    if let Some(it) = &mut node.field_list {
+        v.visit_field_list_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_variant<F: VstFold + ?Sized>(f: &mut F, mut node: Variant) -> Variant {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.visibility = node.visibility.map(|it| Box::new(f.fold_visibility(*it)));
+    node.name = Box::new(f.fold_name(*node.name));
+    node.field_list = node.field_list.map(|it| Box::new(f.fold_field_list(*it)));
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_variant_list<V: VstVisitor + ?Sized>(v: &mut V, node: &VariantList) {
+    for it in &node.variants {
+        v.visit_variant(it);
+    }
+}
+pub fn walk_variant_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut VariantList) {
+    for it in &mut node.variants {
+        v.visit_variant_mut(it);
+    }
+}
+pub fn walk_fold_variant_list<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: VariantList,
+) -> VariantList {
+    node.variants = node.variants.into_iter().map(|it| f.fold_variant(it)).collect();
+    node
+}
+pub fn walk_verus_global<V: VstVisitor + ?Sized>(v: &mut V, node: &VerusGlobal) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+}
+pub fn walk_verus_global_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut VerusGlobal) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+}
+pub fn walk_fold_verus_global<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: VerusGlobal,
+) -> VerusGlobal {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node
+}
+pub fn walk_view_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &ViewExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    v.visit_expr(&node.expr);
+}
+pub fn walk_view_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ViewExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    v.visit_expr_mut(&mut node.expr);
+}
+pub fn walk_fold_view_expr<F: VstFold + ?Sized>(f: &mut F, mut node: ViewExpr) -> ViewExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = Box::new(f.fold_expr(*node.expr));
+    node
+}
+pub fn walk_visibility<V: VstVisitor + ?Sized>(v: &mut V, node: &Visibility) {
+    if let Some(it) = &node.path {
+        v.visit_path(it);
+    }
+}
+pub fn walk_visibility_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Visibility) {
+    if let Some(it) = &mut node.path {
+        v.visit_path_mut(it);
+    }
+}
+pub fn walk_fold_visibility<F: VstFold + ?Sized>(f: &mut F, mut node: Visibility) -> Visibility {
+    node.path = node.path.map(|it| Box::new(f.fold_path(*it)));
+    node
+}
+pub fn walk_where_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &WhereClause) {
+    for it in &node.predicates {
+        v.visit_where_pred(it);
+    }
+}
+pub fn walk_where_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut WhereClause) {
+    for it in &mut node.predicates {
+        v.visit_where_pred_mut(it);
+    }
+}
+pub fn walk_fold_where_clause<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: WhereClause,
+) -> WhereClause {
+    node.predicates = node.predicates.into_iter().map(|it| f.fold_where_pred(it)).collect();
+    node
+}
+pub fn walk_where_pred<V: VstVisitor + ?Sized>(v: &mut V, node: &WherePred) {
+    if let Some(it) = &node.generic_param_list {
+        v.visit_generic_param_list(it);
+    }
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    if let Some(it) = &node.ty {
+        v.visit_type(it);
+    }
+    if let Some(it) = &node.type_bound_list {
+        v.visit_type_bound_list(it);
+    }
+}
+pub fn walk_where_pred_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut WherePred) {
+    if let Some(it) = &mut node.generic_param_list {
+        v.visit_generic_param_list_mut(it);
+    }
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(it);
+    }
+    if let Some(it) = &mut node.type_bound_list {
+        v.visit_type_bound_list_mut(it);
+    }
+}
+pub fn walk_fold_where_pred<F: VstFold + ?Sized>(f: &mut F, mut node: WherePred) -> WherePred {
+    node.generic_param_list =
+        node.generic_param_list.map(|it| Box::new(f.fold_generic_param_list(*it)));
+    node.lifetime = node.lifetime.map(|it| Box::new(f.fold_lifetime(*it)));
+    node.ty = node.ty.map(|it| Box::new(f.fold_type(*it)));
+    node.type_bound_list = node.type_bound_list.map(|it| Box::new(f.fold_type_bound_list(*it)));
+    node
+}
+pub fn walk_while_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &WhileExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    v.visit_expr(&node.condition);
+    for it in &node.loop_clauses {
+        v.visit_loop_clause(it);
+    }
+    v.visit_block_expr(&node.loop_body);
+}
+pub fn walk_while_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut WhileExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    v.visit_expr_mut(&mut node.condition);
+    for it in &mut node.loop_clauses {
+        v.visit_loop_clause_mut(it);
+    }
+    v.visit_block_expr_mut(&mut node.loop_body);
+}
+pub fn walk_fold_while_expr<F: VstFold + ?Sized>(f: &mut F, mut node: WhileExpr) -> WhileExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.label = node.label.map(|it| Box::new(f.fold_label(*it)));
+    node.condition = Box::new(f.fold_expr(*node.condition));
+    node.loop_clauses = node.loop_clauses.into_iter().map(|it| f.fold_loop_clause(it)).collect();
+    node.loop_body = Box::new(f.fold_block_expr(*node.loop_body));
+    node
+}
+pub fn walk_wildcard_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &WildcardPat) {}
+pub fn walk_wildcard_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut WildcardPat) {}
+pub fn walk_fold_wildcard_pat<F: VstFold + ?Sized>(
+    f: &mut F,
+    mut node: WildcardPat,
+) -> WildcardPat {
+    node
+}
+pub fn walk_yeet_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &YeetExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_yeet_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut YeetExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_yeet_expr<F: VstFold + ?Sized>(f: &mut F, mut node: YeetExpr) -> YeetExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_yield_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &YieldExpr) {
+    for it in &node.attrs {
+        v.visit_attr(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(it);
+    }
+}
+pub fn walk_yield_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut YieldExpr) {
+    for it in &mut node.attrs {
+        v.visit_attr_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(it);
+    }
+}
+pub fn walk_fold_yield_expr<F: VstFold + ?Sized>(f: &mut F, mut node: YieldExpr) -> YieldExpr {
+    node.attrs = node.attrs.into_iter().map(|it| f.fold_attr(it)).collect();
+    node.expr = node.expr.map(|it| Box::new(f.fold_expr(*it)));
+    node
+}
+pub fn walk_adt<V: VstVisitor + ?Sized>(v: &mut V, node: &Adt) {
+    match node {
+        Adt::Enum(it) => v.visit_enum(it),
+        Adt::Struct(it) => v.visit_struct(it),
+        Adt::Union(it) => v.visit_union(it),
+    }
+}
+pub fn walk_adt_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Adt) {
+    match node {
+        Adt::Enum(it) => v.visit_enum_mut(it),
+        Adt::Struct(it) => v.visit_struct_mut(it),
+        Adt::Union(it) => v.visit_union_mut(it),
+    }
+}
+pub fn walk_fold_adt<F: VstFold + ?Sized>(f: &mut F, node: Adt) -> Adt {
+    match node {
+        Adt::Enum(it) => Adt::Enum(Box::new(f.fold_enum(*it))),
+        Adt::Struct(it) => Adt::Struct(Box::new(f.fold_struct(*it))),
+        Adt::Union(it) => Adt::Union(Box::new(f.fold_union(*it))),
+    }
+}
+pub fn walk_assoc_item<V: VstVisitor + ?Sized>(v: &mut V, node: &AssocItem) {
+    match node {
+        AssocItem::BroadcastGroup(it) => v.visit_broadcast_group(it),
+        AssocItem::Const(it) => v.visit_const(it),
+        AssocItem::Fn(it) => v.visit_fn(it),
+        AssocItem::MacroCall(it) => v.visit_macro_call(it),
+        AssocItem::TypeAlias(it) => v.visit_type_alias(it),
+    }
+}
+pub fn walk_assoc_item_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut AssocItem) {
+    match node {
+        AssocItem::BroadcastGroup(it) => v.visit_broadcast_group_mut(it),
+        AssocItem::Const(it) => v.visit_const_mut(it),
+        AssocItem::Fn(it) => v.visit_fn_mut(it),
+        AssocItem::MacroCall(it) => v.visit_macro_call_mut(it),
+        AssocItem::TypeAlias(it) => v.visit_type_alias_mut(it),
+    }
+}
+pub fn walk_fold_assoc_item<F: VstFold + ?Sized>(f: &mut F, node: AssocItem) -> AssocItem {
+    match node {
+        AssocItem::BroadcastGroup(it) => {
+            AssocItem::BroadcastGroup(Box::new(f.fold_broadcast_group(*it)))
+        }
+        AssocItem::Const(it) => AssocItem::Const(Box::new(f.fold_const(*it))),
+        AssocItem::Fn(it) => AssocItem::Fn(Box::new(f.fold_fn(*it))),
+        AssocItem::MacroCall(it) => AssocItem::MacroCall(Box::new(f.fold_macro_call(*it))),
+        AssocItem::TypeAlias(it) => AssocItem::TypeAlias(Box::new(f.fold_type_alias(*it))),
+    }
+}
+pub fn walk_else_branch<V: VstVisitor + ?Sized>(v: &mut V, node: &ElseBranch) {
+    match node {
+        ElseBranch::Block(it) => v.visit_block_expr(it),
+        ElseBranch::IfExpr(it) => v.visit_if_expr(it),
+    }
+}
+pub fn walk_else_branch_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ElseBranch) {
+    match node {
+        ElseBranch::Block(it) => v.visit_block_expr_mut(it),
+        ElseBranch::IfExpr(it) => v.visit_if_expr_mut(it),
+    }
+}
+pub fn walk_fold_else_branch<F: VstFold + ?Sized>(f: &mut F, node: ElseBranch) -> ElseBranch {
+    match node {
+        ElseBranch::Block(it) => ElseBranch::Block(Box::new(f.fold_block_expr(*it))),
+        ElseBranch::IfExpr(it) => ElseBranch::IfExpr(Box::new(f.fold_if_expr(*it))),
+    }
+}
+pub fn walk_expr<V: VstVisitor + ?Sized>(v: &mut V, node: &Expr) {
+    match node {
+        Expr::ArrayExpr(it) => v.visit_array_expr(it),
+        Expr::ArrowExpr(it) => v.visit_arrow_expr(it),
+        Expr::AsmExpr(it) => v.visit_asm_expr(it),
+        Expr::AssertExpr(it) => v.visit_assert_expr(it),
+        Expr::AssertForallExpr(it) => v.visit_assert_forall_expr(it),
+        Expr::AssumeExpr(it) => v.visit_assume_expr(it),
+        Expr::AwaitExpr(it) => v.visit_await_expr(it),
+        Expr::BecomeExpr(it) => v.visit_become_expr(it),
+        Expr::BinExpr(it) => v.visit_bin_expr(it),
+        Expr::BlockExpr(it) => v.visit_block_expr(it),
+        Expr::BreakExpr(it) => v.visit_break_expr(it),
+        Expr::CallExpr(it) => v.visit_call_expr(it),
+        Expr::CastExpr(it) => v.visit_cast_expr(it),
+        Expr::ClosureExpr(it) => v.visit_closure_expr(it),
+        Expr::ContinueExpr(it) => v.visit_continue_expr(it),
+        Expr::FieldExpr(it) => v.visit_field_expr(it),
+        Expr::FinalExpr(it) => v.visit_final_expr(it),
+        Expr::ForExpr(it) => v.visit_for_expr(it),
+        Expr::FormatArgsExpr(it) => v.visit_format_args_expr(it),
+        Expr::HasExpr(it) => v.visit_has_expr(it),
+        Expr::IfExpr(it) => v.visit_if_expr(it),
+        Expr::IndexExpr(it) => v.visit_index_expr(it),
+        Expr::IsExpr(it) => v.visit_is_expr(it),
+        Expr::LetExpr(it) => v.visit_let_expr(it),
+        Expr::Literal(it) => v.visit_literal(it),
+        Expr::LoopExpr(it) => v.visit_loop_expr(it),
+        Expr::MacroExpr(it) => v.visit_macro_expr(it),
+        Expr::MatchExpr(it) => v.visit_match_expr(it),
+        Expr::MatchesExpr(it) => v.visit_matches_expr(it),
+        Expr::MethodCallExpr(it) => v.visit_method_call_expr(it),
+        Expr::OffsetOfExpr(it) => v.visit_offset_of_expr(it),
+        Expr::ParenExpr(it) => v.visit_paren_expr(it),
+        Expr::PathExpr(it) => v.visit_path_expr(it),
+        Expr::PrefixExpr(it) => v.visit_prefix_expr(it),
+        Expr::RangeExpr(it) => v.visit_range_expr(it),
+        Expr::RecordExpr(it) => v.visit_record_expr(it),
+        Expr::RefExpr(it) => v.visit_ref_expr(it),
+        Expr::ReturnExpr(it) => v.visit_return_expr(it),
+        Expr::TryExpr(it) => v.visit_try_expr(it),
+        Expr::TupleExpr(it) => v.visit_tuple_expr(it),
+        Expr::UnderscoreExpr(it) => v.visit_underscore_expr(it),
+        Expr::ViewExpr(it) => v.visit_view_expr(it),
+        Expr::WhileExpr(it) => v.visit_while_expr(it),
+        Expr::YeetExpr(it) => v.visit_yeet_expr(it),
+        Expr::YieldExpr(it) => v.visit_yield_expr(it),
+    }
+}
+pub fn walk_expr_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Expr) {
+    match node {
+        Expr::ArrayExpr(it) => v.visit_array_expr_mut(it),
+        Expr::ArrowExpr(it) => v.visit_arrow_expr_mut(it),
+        Expr::AsmExpr(it) => v.visit_asm_expr_mut(it),
+        Expr::AssertExpr(it) => v.visit_assert_expr_mut(it),
+        Expr::AssertForallExpr(it) => v.visit_assert_forall_expr_mut(it),
+        Expr::AssumeExpr(it) => v.visit_assume_expr_mut(it),
+        Expr::AwaitExpr(it) => v.visit_await_expr_mut(it),
+        Expr::BecomeExpr(it) => v.visit_become_expr_mut(it),
+        Expr::BinExpr(it) => v.visit_bin_expr_mut(it),
+        Expr::BlockExpr(it) => v.visit_block_expr_mut(it),
+        Expr::BreakExpr(it) => v.visit_break_expr_mut(it),
+        Expr::CallExpr(it) => v.visit_call_expr_mut(it),
+        Expr::CastExpr(it) => v.visit_cast_expr_mut(it),
+        Expr::ClosureExpr(it) => v.visit_closure_expr_mut(it),
+        Expr::ContinueExpr(it) => v.visit_continue_expr_mut(it),
+        Expr::FieldExpr(it) => v.visit_field_expr_mut(it),
+        Expr::FinalExpr(it) => v.visit_final_expr_mut(it),
+        Expr::ForExpr(it) => v.visit_for_expr_mut(it),
+        Expr::FormatArgsExpr(it) => v.visit_format_args_expr_mut(it),
+        Expr::HasExpr(it) => v.visit_has_expr_mut(it),
+        Expr::IfExpr(it) => v.visit_if_expr_mut(it),
+        Expr::IndexExpr(it) => v.visit_index_expr_mut(it),
+        Expr::IsExpr(it) => v.visit_is_expr_mut(it),
+        Expr::LetExpr(it) => v.visit_let_expr_mut(it),
+        Expr::Literal(it) => v.visit_literal_mut(it),
+        Expr::LoopExpr(it) => v.visit_loop_expr_mut(it),
+        Expr::MacroExpr(it) => v.visit_macro_expr_mut(it),
+        Expr::MatchExpr(it) => v.visit_match_expr_mut(it),
+        Expr::MatchesExpr(it) => v.visit_matches_expr_mut(it),
+        Expr::MethodCallExpr(it) => v.visit_method_call_expr_mut(it),
+        Expr::OffsetOfExpr(it) => v.visit_offset_of_expr_mut(it),
+        Expr::ParenExpr(it) => v.visit_paren_expr_mut(it),
+        Expr::PathExpr(it) => v.visit_path_expr_mut(it),
+        Expr::PrefixExpr(it) => v.visit_prefix_expr_mut(it),
+        Expr::RangeExpr(it) => v.visit_range_expr_mut(it),
+        Expr::RecordExpr(it) => v.visit_record_expr_mut(it),
+        Expr::RefExpr(it) => v.visit_ref_expr_mut(it),
+        Expr::ReturnExpr(it) => v.visit_return_expr_mut(it),
+        Expr::TryExpr(it) => v.visit_try_expr_mut(it),
+        Expr::TupleExpr(it) => v.visit_tuple_expr_mut(it),
+        Expr::UnderscoreExpr(it) => v.visit_underscore_expr_mut(it),
+        Expr::ViewExpr(it) => v.visit_view_expr_mut(it),
+        Expr::WhileExpr(it) => v.visit_while_expr_mut(it),
+        Expr::YeetExpr(it) => v.visit_yeet_expr_mut(it),
+        Expr::YieldExpr(it) => v.visit_yield_expr_mut(it),
+    }
+}
+pub fn walk_fold_expr<F: VstFold + ?Sized>(f: &mut F, node: Expr) -> Expr {
+    match node {
+        Expr::ArrayExpr(it) => Expr::ArrayExpr(Box::new(f.fold_array_expr(*it))),
+        Expr::ArrowExpr(it) => Expr::ArrowExpr(Box::new(f.fold_arrow_expr(*it))),
+        Expr::AsmExpr(it) => Expr::AsmExpr(Box::new(f.fold_asm_expr(*it))),
+        Expr::AssertExpr(it) => Expr::AssertExpr(Box::new(f.fold_assert_expr(*it))),
+        Expr::AssertForallExpr(it) => {
+            Expr::AssertForallExpr(Box::new(f.fold_assert_forall_expr(*it)))
+        }
+        Expr::AssumeExpr(it) => Expr::AssumeExpr(Box::new(f.fold_assume_expr(*it))),
+        Expr::AwaitExpr(it) => Expr::AwaitExpr(Box::new(f.fold_await_expr(*it))),
+        Expr::BecomeExpr(it) => Expr::BecomeExpr(Box::new(f.fold_become_expr(*it))),
+        Expr::BinExpr(it) => Expr::BinExpr(Box::new(f.fold_bin_expr(*it))),
+        Expr::BlockExpr(it) => Expr::BlockExpr(Box::new(f.fold_block_expr(*it))),
+        Expr::BreakExpr(it) => Expr::BreakExpr(Box::new(f.fold_break_expr(*it))),
+        Expr::CallExpr(it) => Expr::CallExpr(Box::new(f.fold_call_expr(*it))),
+        Expr::CastExpr(it) => Expr::CastExpr(Box::new(f.fold_cast_expr(*it))),
+        Expr::ClosureExpr(it) => Expr::ClosureExpr(Box::new(f.fold_closure_expr(*it))),
+        Expr::ContinueExpr(it) => Expr::ContinueExpr(Box::new(f.fold_continue_expr(*it))),
+        Expr::FieldExpr(it) => Expr::FieldExpr(Box::new(f.fold_field_expr(*it))),
+        Expr::FinalExpr(it) => Expr::FinalExpr(Box::new(f.fold_final_expr(*it))),
+        Expr::ForExpr(it) => Expr::ForExpr(Box::new(f.fold_for_expr(*it))),
+        Expr::FormatArgsExpr(it) => Expr::FormatArgsExpr(Box::new(f.fold_format_args_expr(*it))),
+        Expr::HasExpr(it) => Expr::HasExpr(Box::new(f.fold_has_expr(*it))),
+        Expr::IfExpr(it) => Expr::IfExpr(Box::new(f.fold_if_expr(*it))),
+        Expr::IndexExpr(it) => Expr::IndexExpr(Box::new(f.fold_index_expr(*it))),
+        Expr::IsExpr(it) => Expr::IsExpr(Box::new(f.fold_is_expr(*it))),
+        Expr::LetExpr(it) => Expr::LetExpr(Box::new(f.fold_let_expr(*it))),
+        Expr::Literal(it) => Expr::Literal(Box::new(f.fold_literal(*it))),
+        Expr::LoopExpr(it) => Expr::LoopExpr(Box::new(f.fold_loop_expr(*it))),
+        Expr::MacroExpr(it) => Expr::MacroExpr(Box::new(f.fold_macro_expr(*it))),
+        Expr::MatchExpr(it) => Expr::MatchExpr(Box::new(f.fold_match_expr(*it))),
+        Expr::MatchesExpr(it) => Expr::MatchesExpr(Box::new(f.fold_matches_expr(*it))),
+        Expr::MethodCallExpr(it) => Expr::MethodCallExpr(Box::new(f.fold_method_call_expr(*it))),
+        Expr::OffsetOfExpr(it) => Expr::OffsetOfExpr(Box::new(f.fold_offset_of_expr(*it))),
+        Expr::ParenExpr(it) => Expr::ParenExpr(Box::new(f.fold_paren_expr(*it))),
+        Expr::PathExpr(it) => Expr::PathExpr(Box::new(f.fold_path_expr(*it))),
+        Expr::PrefixExpr(it) => Expr::PrefixExpr(Box::new(f.fold_prefix_expr(*it))),
+        Expr::RangeExpr(it) => Expr::RangeExpr(Box::new(f.fold_range_expr(*it))),
+        Expr::RecordExpr(it) => Expr::RecordExpr(Box::new(f.fold_record_expr(*it))),
+        Expr::RefExpr(it) => Expr::RefExpr(Box::new(f.fold_ref_expr(*it))),
+        Expr::ReturnExpr(it) => Expr::ReturnExpr(Box::new(f.fold_return_expr(*it))),
+        Expr::TryExpr(it) => Expr::TryExpr(Box::new(f.fold_try_expr(*it))),
+        Expr::TupleExpr(it) => Expr::TupleExpr(Box::new(f.fold_tuple_expr(*it))),
+        Expr::UnderscoreExpr(it) => Expr::UnderscoreExpr(Box::new(f.fold_underscore_expr(*it))),
+        Expr::ViewExpr(it) => Expr::ViewExpr(Box::new(f.fold_view_expr(*it))),
+        Expr::WhileExpr(it) => Expr::WhileExpr(Box::new(f.fold_while_expr(*it))),
+        Expr::YeetExpr(it) => Expr::YeetExpr(Box::new(f.fold_yeet_expr(*it))),
+        Expr::YieldExpr(it) => Expr::YieldExpr(Box::new(f.fold_yield_expr(*it))),
+    }
+}
+pub fn walk_extern_item<V: VstVisitor + ?Sized>(v: &mut V, node: &ExternItem) {
+    match node {
+        ExternItem::Fn(it) => v.visit_fn(it),
+        ExternItem::MacroCall(it) => v.visit_macro_call(it),
+        ExternItem::Static(it) => v.visit_static(it),
+        ExternItem::TypeAlias(it) => v.visit_type_alias(it),
+    }
+}
+pub fn walk_extern_item_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut ExternItem) {
+    match node {
+        ExternItem::Fn(it) => v.visit_fn_mut(it),
+        ExternItem::MacroCall(it) => v.visit_macro_call_mut(it),
+        ExternItem::Static(it) => v.visit_static_mut(it),
+        ExternItem::TypeAlias(it) => v.visit_type_alias_mut(it),
+    }
+}
+pub fn walk_fold_extern_item<F: VstFold + ?Sized>(f: &mut F, node: ExternItem) -> ExternItem {
+    match node {
+        ExternItem::Fn(it) => ExternItem::Fn(Box::new(f.fold_fn(*it))),
+        ExternItem::MacroCall(it) => ExternItem::MacroCall(Box::new(f.fold_macro_call(*it))),
+        ExternItem::Static(it) => ExternItem::Static(Box::new(f.fold_static(*it))),
+        ExternItem::TypeAlias(it) => ExternItem::TypeAlias(Box::new(f.fold_type_alias(*it))),
+    }
+}
+pub fn walk_field_list<V: VstVisitor + ?Sized>(v: &mut V, node: &FieldList) {
+    match node {
+        FieldList::RecordFieldList(it) => v.visit_record_field_list(it),
+        FieldList::TupleFieldList(it) => v.visit_tuple_field_list(it),
+    }
+}
+pub fn walk_field_list_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut FieldList) {
+    match node {
+        FieldList::RecordFieldList(it) => v.visit_record_field_list_mut(it),
+        FieldList::TupleFieldList(it) => v.visit_tuple_field_list_mut(it),
+    }
+}
+pub fn walk_fold_field_list<F: VstFold + ?Sized>(f: &mut F, node: FieldList) -> FieldList {
+    match node {
+        FieldList::RecordFieldList(it) => {
+            FieldList::RecordFieldList(Box::new(f.fold_record_field_list(*it)))
+        }
+        FieldList::TupleFieldList(it) => {
+            FieldList::TupleFieldList(Box::new(f.fold_tuple_field_list(*it)))
+        }
+    }
+}
+pub fn walk_generic_arg<V: VstVisitor + ?Sized>(v: &mut V, node: &GenericArg) {
+    match node {
+        GenericArg::AssocTypeArg(it) => v.visit_assoc_type_arg(it),
+        GenericArg::ConstArg(it) => v.visit_const_arg(it),
+        GenericArg::LifetimeArg(it) => v.visit_lifetime_arg(it),
+        GenericArg::TypeArg(it) => v.visit_type_arg(it),
+    }
+}
+pub fn walk_generic_arg_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut GenericArg) {
+    match node {
+        GenericArg::AssocTypeArg(it) => v.visit_assoc_type_arg_mut(it),
+        GenericArg::ConstArg(it) => v.visit_const_arg_mut(it),
+        GenericArg::LifetimeArg(it) => v.visit_lifetime_arg_mut(it),
+        GenericArg::TypeArg(it) => v.visit_type_arg_mut(it),
+    }
+}
+pub fn walk_fold_generic_arg<F: VstFold + ?Sized>(f: &mut F, node: GenericArg) -> GenericArg {
+    match node {
+        GenericArg::AssocTypeArg(it) => {
+            GenericArg::AssocTypeArg(Box::new(f.fold_assoc_type_arg(*it)))
+        }
+        GenericArg::ConstArg(it) => GenericArg::ConstArg(Box::new(f.fold_const_arg(*it))),
+        GenericArg::LifetimeArg(it) => GenericArg::LifetimeArg(Box::new(f.fold_lifetime_arg(*it))),
+        GenericArg::TypeArg(it) => GenericArg::TypeArg(Box::new(f.fold_type_arg(*it))),
+    }
+}
+pub fn walk_generic_param<V: VstVisitor + ?Sized>(v: &mut V, node: &GenericParam) {
+    match node {
+        GenericParam::ConstParam(it) => v.visit_const_param(it),
+        GenericParam::LifetimeParam(it) => v.visit_lifetime_param(it),
+        GenericParam::TypeParam(it) => v.visit_type_param(it),
+    }
+}
+pub fn walk_generic_param_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut GenericParam) {
+    match node {
+        GenericParam::ConstParam(it) => v.visit_const_param_mut(it),
+        GenericParam::LifetimeParam(it) => v.visit_lifetime_param_mut(it),
+        GenericParam::TypeParam(it) => v.visit_type_param_mut(it),
+    }
+}
+pub fn walk_fold_generic_param<F: VstFold + ?Sized>(f: &mut F, node: GenericParam) -> GenericParam {
+    match node {
+        GenericParam::ConstParam(it) => GenericParam::ConstParam(Box::new(f.fold_const_param(*it))),
+        GenericParam::LifetimeParam(it) => {
+            GenericParam::LifetimeParam(Box::new(f.fold_lifetime_param(*it)))
+        }
+        GenericParam::TypeParam(it) => GenericParam::TypeParam(Box::new(f.fold_type_param(*it))),
+    }
+}
+pub fn walk_item<V: VstVisitor + ?Sized>(v: &mut V, node: &Item) {
+    match node {
+        Item::AssumeSpecification(it) => v.visit_assume_specification(it),
+        Item::BroadcastGroup(it) => v.visit_broadcast_group(it),
+        Item::BroadcastUse(it) => v.visit_broadcast_use(it),
+        Item::Const(it) => v.visit_const(it),
+        Item::Enum(it) => v.visit_enum(it),
+        Item::ExternBlock(it) => v.visit_extern_block(it),
+        Item::ExternCrate(it) => v.visit_extern_crate(it),
+        Item::Fn(it) => v.visit_fn(it),
+        Item::Impl(it) => v.visit_impl(it),
+        Item::MacroCall(it) => v.visit_macro_call(it),
+        Item::MacroDef(it) => v.visit_macro_def(it),
+        Item::MacroRules(it) => v.visit_macro_rules(it),
+        Item::Module(it) => v.visit_module(it),
+        Item::Static(it) => v.visit_static(it),
+        Item::Struct(it) => v.visit_struct(it),
+        Item::Trait(it) => v.visit_trait(it),
+        Item::TraitAlias(it) => v.visit_trait_alias(it),
+        Item::TypeAlias(it) => v.visit_type_alias(it),
+        Item::Union(it) => v.visit_union(it),
+        Item::Use(it) => v.visit_use(it),
+        Item::VerusGlobal(it) => v.visit_verus_global(it),
+    }
+}
+pub fn walk_item_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Item) {
+    match node {
+        Item::AssumeSpecification(it) => v.visit_assume_specification_mut(it),
+        Item::BroadcastGroup(it) => v.visit_broadcast_group_mut(it),
+        Item::BroadcastUse(it) => v.visit_broadcast_use_mut(it),
+        Item::Const(it) => v.visit_const_mut(it),
+        Item::Enum(it) => v.visit_enum_mut(it),
+        Item::ExternBlock(it) => v.visit_extern_block_mut(it),
+        Item::ExternCrate(it) => v.visit_extern_crate_mut(it),
+        Item::Fn(it) => v.visit_fn_mut(it),
+        Item::Impl(it) => v.visit_impl_mut(it),
+        Item::MacroCall(it) => v.visit_macro_call_mut(it),
+        Item::MacroDef(it) => v.visit_macro_def_mut(it),
+        Item::MacroRules(it) => v.visit_macro_rules_mut(it),
+        Item::Module(it) => v.visit_module_mut(it),
+        Item::Static(it) => v.visit_static_mut(it),
+        Item::Struct(it) => v.visit_struct_mut(it),
+        Item::Trait(it) => v.visit_trait_mut(it),
+        Item::TraitAlias(it) => v.visit_trait_alias_mut(it),
+        Item::TypeAlias(it) => v.visit_type_alias_mut(it),
+        Item::Union(it) => v.visit_union_mut(it),
+        Item::Use(it) => v.visit_use_mut(it),
+        Item::VerusGlobal(it) => v.visit_verus_global_mut(it),
+    }
+}
+pub fn walk_fold_item<F: VstFold + ?Sized>(f: &mut F, node: Item) -> Item {
+    match node {
+        Item::AssumeSpecification(it) => {
+            Item::AssumeSpecification(Box::new(f.fold_assume_specification(*it)))
+        }
+        Item::BroadcastGroup(it) => Item::BroadcastGroup(Box::new(f.fold_broadcast_group(*it))),
+        Item::BroadcastUse(it) => Item::BroadcastUse(Box::new(f.fold_broadcast_use(*it))),
+        Item::Const(it) => Item::Const(Box::new(f.fold_const(*it))),
+        Item::Enum(it) => Item::Enum(Box::new(f.fold_enum(*it))),
+        Item::ExternBlock(it) => Item::ExternBlock(Box::new(f.fold_extern_block(*it))),
+        Item::ExternCrate(it) => Item::ExternCrate(Box::new(f.fold_extern_crate(*it))),
+        Item::Fn(it) => Item::Fn(Box::new(f.fold_fn(*it))),
+        Item::Impl(it) => Item::Impl(Box::new(f.fold_impl(*it))),
+        Item::MacroCall(it) => Item::MacroCall(Box::new(f.fold_macro_call(*it))),
+        Item::MacroDef(it) => Item::MacroDef(Box::new(f.fold_macro_def(*it))),
+        Item::MacroRules(it) => Item::MacroRules(Box::new(f.fold_macro_rules(*it))),
+        Item::Module(it) => Item::Module(Box::new(f.fold_module(*it))),
+        Item::Static(it) => Item::Static(Box::new(f.fold_static(*it))),
+        Item::Struct(it) => Item::Struct(Box::new(f.fold_struct(*it))),
+        Item::Trait(it) => Item::Trait(Box::new(f.fold_trait(*it))),
+        Item::TraitAlias(it) => Item::TraitAlias(Box::new(f.fold_trait_alias(*it))),
+        Item::TypeAlias(it) => Item::TypeAlias(Box::new(f.fold_type_alias(*it))),
+        Item::Union(it) => Item::Union(Box::new(f.fold_union(*it))),
+        Item::Use(it) => Item::Use(Box::new(f.fold_use(*it))),
+        Item::VerusGlobal(it) => Item::VerusGlobal(Box::new(f.fold_verus_global(*it))),
+    }
+}
+pub fn walk_loop_clause<V: VstVisitor + ?Sized>(v: &mut V, node: &LoopClause) {
+    match node {
+        LoopClause::DecreasesClause(it) => v.visit_decreases_clause(it),
+        LoopClause::EnsuresClause(it) => v.visit_ensures_clause(it),
+        LoopClause::InvariantClause(it) => v.visit_invariant_clause(it),
+        LoopClause::InvariantExceptBreakClause(it) => v.visit_invariant_except_break_clause(it),
+    }
+}
+pub fn walk_loop_clause_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut LoopClause) {
+    match node {
+        LoopClause::DecreasesClause(it) => v.visit_decreases_clause_mut(it),
+        LoopClause::EnsuresClause(it) => v.visit_ensures_clause_mut(it),
+        LoopClause::InvariantClause(it) => v.visit_invariant_clause_mut(it),
+        LoopClause::InvariantExceptBreakClause(it) => v.visit_invariant_except_break_clause_mut(it),
+    }
+}
+pub fn walk_fold_loop_clause<F: VstFold + ?Sized>(f: &mut F, node: LoopClause) -> LoopClause {
+    match node {
+        LoopClause::DecreasesClause(it) => {
+            LoopClause::DecreasesClause(Box::new(f.fold_decreases_clause(*it)))
+        }
+        LoopClause::EnsuresClause(it) => {
+            LoopClause::EnsuresClause(Box::new(f.fold_ensures_clause(*it)))
+        }
+        LoopClause::InvariantClause(it) => {
+            LoopClause::InvariantClause(Box::new(f.fold_invariant_clause(*it)))
+        }
+        LoopClause::InvariantExceptBreakClause(it) => LoopClause::InvariantExceptBreakClause(
+            Box::new(f.fold_invariant_except_break_clause(*it)),
+        ),
+    }
+}
+pub fn walk_pat<V: VstVisitor + ?Sized>(v: &mut V, node: &Pat) {
+    match node {
+        Pat::BoxPat(it) => v.visit_box_pat(it),
+        Pat::ConstBlockPat(it) => v.visit_const_block_pat(it),
+        Pat::IdentPat(it) => v.visit_ident_pat(it),
+        Pat::LiteralPat(it) => v.visit_literal_pat(it),
+        Pat::MacroPat(it) => v.visit_macro_pat(it),
+        Pat::OrPat(it) => v.visit_or_pat(it),
+        Pat::ParenPat(it) => v.visit_paren_pat(it),
+        Pat::PathPat(it) => v.visit_path_pat(it),
+        Pat::RangePat(it) => v.visit_range_pat(it),
+        Pat::RecordPat(it) => v.visit_record_pat(it),
+        Pat::RefPat(it) => v.visit_ref_pat(it),
+        Pat::RestPat(it) => v.visit_rest_pat(it),
+        Pat::SlicePat(it) => v.visit_slice_pat(it),
+        Pat::TuplePat(it) => v.visit_tuple_pat(it),
+        Pat::TupleStructPat(it) => v.visit_tuple_struct_pat(it),
+        Pat::WildcardPat(it) => v.visit_wildcard_pat(it),
+    }
+}
+pub fn walk_pat_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Pat) {
+    match node {
+        Pat::BoxPat(it) => v.visit_box_pat_mut(it),
+        Pat::ConstBlockPat(it) => v.visit_const_block_pat_mut(it),
+        Pat::IdentPat(it) => v.visit_ident_pat_mut(it),
+        Pat::LiteralPat(it) => v.visit_literal_pat_mut(it),
+        Pat::MacroPat(it) => v.visit_macro_pat_mut(it),
+        Pat::OrPat(it) => v.visit_or_pat_mut(it),
+        Pat::ParenPat(it) => v.visit_paren_pat_mut(it),
+        Pat::PathPat(it) => v.visit_path_pat_mut(it),
+        Pat::RangePat(it) => v.visit_range_pat_mut(it),
+        Pat::RecordPat(it) => v.visit_record_pat_mut(it),
+        Pat::RefPat(it) => v.visit_ref_pat_mut(it),
+        Pat::RestPat(it) => v.visit_rest_pat_mut(it),
+        Pat::SlicePat(it) => v.visit_slice_pat_mut(it),
+        Pat::TuplePat(it) => v.visit_tuple_pat_mut(it),
+        Pat::TupleStructPat(it) => v.visit_tuple_struct_pat_mut(it),
+        Pat::WildcardPat(it) => v.visit_wildcard_pat_mut(it),
+    }
+}
+pub fn walk_fold_pat<F: VstFold + ?Sized>(f: &mut F, node: Pat) -> Pat {
+    match node {
+        Pat::BoxPat(it) => Pat::BoxPat(Box::new(f.fold_box_pat(*it))),
+        Pat::ConstBlockPat(it) => Pat::ConstBlockPat(Box::new(f.fold_const_block_pat(*it))),
+        Pat::IdentPat(it) => Pat::IdentPat(Box::new(f.fold_ident_pat(*it))),
+        Pat::LiteralPat(it) => Pat::LiteralPat(Box::new(f.fold_literal_pat(*it))),
+        Pat::MacroPat(it) => Pat::MacroPat(Box::new(f.fold_macro_pat(*it))),
+        Pat::OrPat(it) => Pat::OrPat(Box::new(f.fold_or_pat(*it))),
+        Pat::ParenPat(it) => Pat::ParenPat(Box::new(f.fold_paren_pat(*it))),
+        Pat::PathPat(it) => Pat::PathPat(Box::new(f.fold_path_pat(*it))),
+        Pat::RangePat(it) => Pat::RangePat(Box::new(f.fold_range_pat(*it))),
+        Pat::RecordPat(it) => Pat::RecordPat(Box::new(f.fold_record_pat(*it))),
+        Pat::RefPat(it) => Pat::RefPat(Box::new(f.fold_ref_pat(*it))),
+        Pat::RestPat(it) => Pat::RestPat(Box::new(f.fold_rest_pat(*it))),
+        Pat::SlicePat(it) => Pat::SlicePat(Box::new(f.fold_slice_pat(*it))),
+        Pat::TuplePat(it) => Pat::TuplePat(Box::new(f.fold_tuple_pat(*it))),
+        Pat::TupleStructPat(it) => Pat::TupleStructPat(Box::new(f.fold_tuple_struct_pat(*it))),
+        Pat::WildcardPat(it) => Pat::WildcardPat(Box::new(f.fold_wildcard_pat(*it))),
+    }
+}
+pub fn walk_stmt<V: VstVisitor + ?Sized>(v: &mut V, node: &Stmt) {
+    match node {
+        Stmt::ExprStmt(it) => v.visit_expr_stmt(it),
+        Stmt::Item(it) => v.visit_item(it),
+        Stmt::LetStmt(it) => v.visit_let_stmt(it),
+    }
+}
+pub fn walk_stmt_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Stmt) {
+    match node {
+        Stmt::ExprStmt(it) => v.visit_expr_stmt_mut(it),
+        Stmt::Item(it) => v.visit_item_mut(it),
+        Stmt::LetStmt(it) => v.visit_let_stmt_mut(it),
+    }
+}
+pub fn walk_fold_stmt<F: VstFold + ?Sized>(f: &mut F, node: Stmt) -> Stmt {
+    match node {
+        Stmt::ExprStmt(it) => Stmt::ExprStmt(Box::new(f.fold_expr_stmt(*it))),
+        Stmt::Item(it) => Stmt::Item(Box::new(f.fold_item(*it))),
+        Stmt::LetStmt(it) => Stmt::LetStmt(Box::new(f.fold_let_stmt(*it))),
+    }
+}
+pub fn walk_type<V: VstVisitor + ?Sized>(v: &mut V, node: &Type) {
+    match node {
+        Type::ArrayType(it) => v.visit_array_type(it),
+        Type::DynTraitType(it) => v.visit_dyn_trait_type(it),
+        Type::FnPtrType(it) => v.visit_fn_ptr_type(it),
+        Type::ForType(it) => v.visit_for_type(it),
+        Type::ImplTraitType(it) => v.visit_impl_trait_type(it),
+        Type::InferType(it) => v.visit_infer_type(it),
+        Type::MacroType(it) => v.visit_macro_type(it),
+        Type::NeverType(it) => v.visit_never_type(it),
+        Type::ParenType(it) => v.visit_paren_type(it),
+        Type::PathType(it) => v.visit_path_type(it),
+        Type::ProofFnType(it) => v.visit_proof_fn_type(it),
+        Type::PtrType(it) => v.visit_ptr_type(it),
+        Type::RefType(it) => v.visit_ref_type(it),
+        Type::SliceType(it) => v.visit_slice_type(it),
+        Type::TupleType(it) => v.visit_tuple_type(it),
+    }
+}
+pub fn walk_type_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut Type) {
+    match node {
+        Type::ArrayType(it) => v.visit_array_type_mut(it),
+        Type::DynTraitType(it) => v.visit_dyn_trait_type_mut(it),
+        Type::FnPtrType(it) => v.visit_fn_ptr_type_mut(it),
+        Type::ForType(it) => v.visit_for_type_mut(it),
+        Type::ImplTraitType(it) => v.visit_impl_trait_type_mut(it),
+        Type::InferType(it) => v.visit_infer_type_mut(it),
+        Type::MacroType(it) => v.visit_macro_type_mut(it),
+        Type::NeverType(it) => v.visit_never_type_mut(it),
+        Type::ParenType(it) => v.visit_paren_type_mut(it),
+        Type::PathType(it) => v.visit_path_type_mut(it),
+        Type::ProofFnType(it) => v.visit_proof_fn_type_mut(it),
+        Type::PtrType(it) => v.visit_ptr_type_mut(it),
+        Type::RefType(it) => v.visit_ref_type_mut(it),
+        Type::SliceType(it) => v.visit_slice_type_mut(it),
+        Type::TupleType(it) => v.visit_tuple_type_mut(it),
+    }
+}
+pub fn walk_fold_type<F: VstFold + ?Sized>(f: &mut F, node: Type) -> Type {
+    match node {
+        Type::ArrayType(it) => Type::ArrayType(Box::new(f.fold_array_type(*it))),
+        Type::DynTraitType(it) => Type::DynTraitType(Box::new(f.fold_dyn_trait_type(*it))),
+        Type::FnPtrType(it) => Type::FnPtrType(Box::new(f.fold_fn_ptr_type(*it))),
+        Type::ForType(it) => Type::ForType(Box::new(f.fold_for_type(*it))),
+        Type::ImplTraitType(it) => Type::ImplTraitType(Box::new(f.fold_impl_trait_type(*it))),
+        Type::InferType(it) => Type::InferType(Box::new(f.fold_infer_type(*it))),
+        Type::MacroType(it) => Type::MacroType(Box::new(f.fold_macro_type(*it))),
+        Type::NeverType(it) => Type::NeverType(Box::new(f.fold_never_type(*it))),
+        Type::ParenType(it) => Type::ParenType(Box::new(f.fold_paren_type(*it))),
+        Type::PathType(it) => Type::PathType(Box::new(f.fold_path_type(*it))),
+        Type::ProofFnType(it) => Type::ProofFnType(Box::new(f.fold_proof_fn_type(*it))),
+        Type::PtrType(it) => Type::PtrType(Box::new(f.fold_ptr_type(*it))),
+        Type::RefType(it) => Type::RefType(Box::new(f.fold_ref_type(*it))),
+        Type::SliceType(it) => Type::SliceType(Box::new(f.fold_slice_type(*it))),
+        Type::TupleType(it) => Type::TupleType(Box::new(f.fold_tuple_type(*it))),
+    }
+}
@@ -44,6 +44,8 @@ pub enum LogicOp {
 pub enum CmpOp {
     Eq { negated: bool },
     Ord { ordering: Ordering, strict: bool },
+    // verus: extensional equality `=~=`, or `=~~=` when `deep`
+    ExtEq { deep: bool },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -106,6 +108,8 @@ impl fmt::Display for CmpOp {
             CmpOp::Ord { ordering: Ordering::Less, strict: true } => "<",
             CmpOp::Ord { ordering: Ordering::Greater, strict: false } => ">=",
             CmpOp::Ord { ordering: Ordering::Greater, strict: true } => ">",
+            CmpOp::ExtEq { deep: false } => "=~=",
+            CmpOp::ExtEq { deep: true } => "=~~=",
         };
         f.write_str(res)
     }
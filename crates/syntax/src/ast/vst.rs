@@ -1,8 +1,11 @@
 // defines VST handwritten nodes
 
-pub use crate::ast::{self, generated::vst_nodes::*, operators::BinaryOp};
+pub use crate::ast::{
+    self, generated::vst_nodes::*, generated::vst_visit::*, operators::BinaryOp, operators::UnaryOp,
+};
 
 pub use super::{generated, HasAttrs};
+use crate::T;
 
 pub(crate) fn token_ascii(name: &String) -> &str {
     match name.as_str() {
@@ -59,6 +62,45 @@ impl std::fmt::Display for BinExpr {
     }
 }
 
+/// Operator of a `PrefixExpr`: Rust's unary operators plus Verus's leading `&&&`/`|||`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixOp {
+    Unary(UnaryOp),
+    BigAnd,
+    BigOr,
+}
+
+impl std::fmt::Display for PrefixOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let res = match self {
+            PrefixOp::Unary(UnaryOp::Deref) => "*",
+            PrefixOp::Unary(UnaryOp::Not) => "!",
+            PrefixOp::Unary(UnaryOp::Neg) => "-",
+            PrefixOp::BigAnd => "&&& ",
+            PrefixOp::BigOr => "||| ",
+        };
+        f.write_str(res)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrefixExpr {
+    pub attrs: Vec<Attr>,
+    pub op: PrefixOp,
+    pub expr: Box<Expr>,
+    pub cst: Option<generated::nodes::PrefixExpr>,
+}
+
+impl std::fmt::Display for PrefixExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.op.to_string());
+        s.push_str(&self.expr.to_string());
+        write!(f, "{s}")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IfExpr {
     pub attrs: Vec<Attr>,
@@ -224,6 +266,32 @@ impl BinExpr {
     }
 }
 
+impl TryFrom<generated::nodes::PrefixExpr> for PrefixExpr {
+    type Error = String;
+    fn try_from(item: generated::nodes::PrefixExpr) -> Result<Self, Self::Error> {
+        let op = match item.op_token().ok_or("op_token")?.kind() {
+            T![&&&] => PrefixOp::BigAnd,
+            T![|||] => PrefixOp::BigOr,
+            _ => PrefixOp::Unary(item.op_kind().ok_or("op_kind")?),
+        };
+        Ok(Self {
+            attrs: item.attrs().map(Attr::try_from).collect::<Result<Vec<Attr>, String>>()?,
+            op,
+            expr: Box::new(Expr::try_from(item.expr().ok_or("expr")?)?),
+            cst: Some(item.clone()),
+        })
+    }
+}
+
+impl PrefixExpr {
+    pub fn new<ET0>(op: PrefixOp, expr: ET0) -> Self
+    where
+        ET0: Into<Expr>,
+    {
+        PrefixExpr { attrs: vec![], op, expr: Box::new(expr.into()), cst: None }
+    }
+}
+
 impl TryFrom<super::expr_ext::ElseBranch> for ElseBranch {
     type Error = String;
     fn try_from(item: super::expr_ext::ElseBranch) -> Result<Self, Self::Error> {
@@ -392,6 +460,64 @@ impl std::fmt::Display for AssertExpr {
     }
 }
 
+impl std::fmt::Display for ClosureExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        // inner attributes, e.g. `#![trigger f(i)]` of a quantifier, go right before the body
+        let (inner, outer): (Vec<&Attr>, Vec<&Attr>) =
+            self.attrs.iter().partition(|it| it.excl_token);
+        s.push_str(&outer.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        if self.for_token {
+            s.push_str("for ");
+        }
+        if let Some(it) = &self.generic_param_list {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        for (present, token) in [
+            (self.const_token, "const "),
+            (self.static_token, "static "),
+            (self.async_token, "async "),
+            (self.move_token, "move "),
+        ] {
+            if present {
+                s.push_str(token);
+            }
+        }
+        if let Some(it) = &self.proof_fn_with_characteristics {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        if self.forall_token {
+            s.push_str("forall ");
+        }
+        if self.exists_token {
+            s.push_str("exists ");
+        }
+        if let Some(it) = &self.param_list {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        if let Some(it) = &self.ret_type {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        if let Some(it) = &self.requires_clause {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        if let Some(it) = &self.ensures_clause {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        s.push_str(&inner.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(" ");
+        s.push_str(&self.body.to_string());
+        s.push_str(" ");
+        write!(f, "{s}")
+    }
+}
+
 impl TryFrom<generated::nodes::AssertForallExpr> for AssertForallExpr {
     type Error = String;
     fn try_from(item: generated::nodes::AssertForallExpr) -> Result<Self, Self::Error> {
@@ -410,9 +536,7 @@ impl TryFrom<generated::nodes::AssertForallExpr> for AssertForallExpr {
             implies_token: item.implies_token().is_some(),
             expr: match item.exprs().nth(1) {
                 // TODO: bug in item.expr() it gives closure_expr
-                Some(it) => {
-                    Some(Box::new(Expr::try_from(it)?))
-                }
+                Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
             by_token: item.by_token().is_some(),
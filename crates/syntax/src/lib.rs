@@ -529,8 +529,6 @@ fn verus_core(source_code: &str) {
     }
 }
 
-
-
 #[test]
 fn verus_walkthrough0() {
     let source_code = "verus!{
@@ -2097,7 +2095,6 @@ exec static F: u64 ensures false {
     verus_core(source_code);
 }
 
-
 #[test]
 fn verus_fn_signatures() {
     let source_code = "verus!{
@@ -2145,6 +2142,110 @@ spec fn test_rec2(x: int, y: int) -> int
     verus_core(source_code);
 }
 
+#[test]
+fn vst_visitor_and_fold() {
+    use ast::vst::{self, VstFold, VstVisitor};
+    use ast::HasModuleItem;
+
+    struct Names(Vec<String>);
+    impl VstVisitor for Names {
+        fn visit_name_ref(&mut self, node: &vst::NameRef) {
+            self.0.extend(node.ident_token.clone());
+        }
+    }
+
+    struct Rename;
+    impl VstFold for Rename {
+        fn fold_name_ref(&mut self, mut node: vst::NameRef) -> vst::NameRef {
+            if node.ident_token.as_deref() == Some("y") {
+                node.ident_token = Some("z".to_owned());
+            }
+            node
+        }
+    }
+
+    let source_code = "
+verus!{
+spec fn test_rec2(x: int, y: int) -> int
+    decreases x, y
+{
+    if y > 0 {
+        1 + test_rec2(x, y - 1)
+    } else {
+        x[y]
+    }
+}
+} // verus!";
+    let file = SourceFile::parse(source_code, Edition::Edition2024).tree();
+    let item: vst_nodes::Item = file.items().next().unwrap().try_into().unwrap();
+
+    let mut names = Names(Vec::new());
+    names.visit_item(&item);
+    assert_eq!(names.0, ["int", "int", "int", "x", "y", "y", "test_rec2", "x", "y", "x", "y"]);
+
+    let item = Rename.fold_item(item);
+    let mut names = Names(Vec::new());
+    names.visit_item(&item);
+    assert_eq!(names.0, ["int", "int", "int", "x", "z", "z", "test_rec2", "x", "z", "x", "z"]);
+}
+
+#[test]
+fn vst_loop_headers() {
+    use ast::HasModuleItem;
+
+    let source_code = "
+verus!{
+fn loops(v: Vec<u64>, n: u64) {
+    let mut i = 0;
+    while i < n
+        invariant i <= n,
+    {
+        i = i + 1;
+    }
+    for x in iter: v {
+        match x {
+            y if y > 3 => {}
+            _ => {}
+        }
+    }
+}
+} // verus!";
+    let file = SourceFile::parse(source_code, Edition::Edition2024).tree();
+    let item: vst_nodes::Item = file.items().next().unwrap().try_into().unwrap();
+    let printed = item.to_string().replace(char::is_whitespace, "");
+    for header in ["whilei<n", "initer:v", "ify>3"] {
+        assert!(printed.contains(header), "{header} missing from {printed}");
+    }
+}
+
+#[test]
+fn vst_proof_block_and_prefix_ops() {
+    use ast::HasModuleItem;
+
+    let source_code = "
+verus!{
+fn f(b: bool, x: int) {
+    proof {
+        assert(!b || -x <= 0);
+        assert({
+            let y = x;
+            ||| y > 0
+            ||| b
+        });
+    }
+}
+} // verus!";
+    let file = SourceFile::parse(source_code, Edition::Edition2024).tree();
+    let proof_block =
+        file.syntax().descendants().filter_map(ast::BlockExpr::cast).find_map(|it| it.fn_mode());
+    assert!(proof_block.and_then(|it| it.proof_token()).is_some());
+    let item: vst_nodes::Item = file.items().next().unwrap().try_into().unwrap();
+    let printed = item.to_string().replace(char::is_whitespace, "");
+    for part in ["proof{assert(!b||-x<=0);", "lety=x;|||y>0"] {
+        assert!(printed.contains(part), "{part} missing from {printed}");
+    }
+}
+
 #[test]
 fn verus_real_literals() {
     let source_code = "verus!{
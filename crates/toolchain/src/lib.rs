@@ -1,11 +1,18 @@
-//! Discovery of `cargo` & `rustc` executables.
+//! Discovery of `cargo` & `rustc` executables, and of the Verus toolchain.
 
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
+mod verus;
+
 use std::{env, iter, path::PathBuf};
 
 use camino::{Utf8Path, Utf8PathBuf};
 
+pub use crate::verus::{
+    find_verus, VerusToolchain, VerusVersion, SUPPORTED_VERUS_VERSION, VERUS_BINARY_PATH,
+    VERUS_JSON_ARGS,
+};
+
 #[derive(Copy, Clone)]
 pub enum Tool {
     Cargo,
@@ -0,0 +1,161 @@
+//! Discovery of the `verus` & `cargo-verus` executables.
+
+use std::{env, fmt, process::Command};
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::{cargo_proxy, lookup_in_path, probe_for_binary};
+
+/// The environment variable the VS Code extension uses to tell us where it installed Verus.
+pub const VERUS_BINARY_PATH: &str = "VERUS_BINARY_PATH";
+
+/// The newest Verus release whose syntax our parser understands.
+///
+/// Bump this whenever the grammar catches up with a new Verus release.
+pub const SUPPORTED_VERUS_VERSION: VerusVersion = VerusVersion { year: 2025, month: 1, day: 15 };
+
+/// The trailing arguments of every Verus invocation whose diagnostics we read: rustc gets asked
+/// for JSON diagnostics, which Verus prints to stderr, one per line.
+pub const VERUS_JSON_ARGS: [&str; 2] = ["--", "--error-format=json"];
+
+/// The directories a Verus release unpacks into, one per supported platform.
+const RELEASE_DIRS: &[&str] =
+    &["verus-x86-linux", "verus-x86-macos", "verus-arm64-macos", "verus-x86-win"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerusToolchain {
+    pub verus: Utf8PathBuf,
+    pub cargo_verus: Option<Utf8PathBuf>,
+}
+
+impl VerusToolchain {
+    /// Locates the `verus` binary and, if available, the `cargo-verus` next to it.
+    ///
+    /// The `verus` binary is looked up in the following places:
+    /// 1) `configured`, the `verus-analyzer.verus.verusBinary` setting
+    /// 2) the `$VERUS_BINARY_PATH` environment variable
+    /// 3) `$PATH/verus`
+    ///
+    /// The first two may point either at the binary itself or at a directory holding a Verus
+    /// release or a Verus source checkout. Unlike `$PATH`, an explicitly given location that does
+    /// not contain a usable binary is an error.
+    ///
+    /// `cargo-verus` is looked up next to the (symlink-resolved) `verus` binary first, as that is
+    /// where a release puts it, then in `$PATH` and `$CARGO_HOME/bin`.
+    pub fn discover(configured: Option<&Utf8Path>) -> Result<VerusToolchain, String> {
+        let verus = find_verus(configured)?;
+        let cargo_verus = find_cargo_verus(&verus);
+        Ok(VerusToolchain { verus, cargo_verus })
+    }
+
+    /// Asks `verus --version` for the version of this toolchain.
+    pub fn version(&self) -> Result<VerusVersion, String> {
+        let output = Command::new(&self.verus)
+            .arg("--version")
+            .output()
+            .map_err(|e| format!("failed to run `{} --version`: {e}", self.verus))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        stdout.lines().find_map(|line| line.trim().strip_prefix("Version:")).map_or_else(
+            || Err(format!("`{} --version` did not report a version", self.verus)),
+            |version| VerusVersion::parse(version.trim()),
+        )
+    }
+
+    /// Checks the installed Verus against the grammar we parse, returning a warning for the user
+    /// if they may disagree.
+    pub fn version_warning(&self) -> Option<String> {
+        match self.version() {
+            Ok(version) if version > SUPPORTED_VERUS_VERSION => Some(format!(
+                "Verus {version} at `{}` is newer than the Verus syntax this verus-analyzer \
+                 understands ({SUPPORTED_VERUS_VERSION}). Newer syntax may be reported as errors; \
+                 consider updating verus-analyzer.",
+                self.verus
+            )),
+            Ok(_) => None,
+            Err(e) => Some(format!("Could not determine the Verus version: {e}")),
+        }
+    }
+}
+
+/// A Verus release version, `0.YYYY.MM.DD.<commit>`. Only the date is used for comparisons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VerusVersion {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl VerusVersion {
+    pub fn parse(version: &str) -> Result<VerusVersion, String> {
+        let mut parts = version.split('.').skip(1).map(str::parse::<u32>);
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) => {
+                Ok(VerusVersion { year, month, day })
+            }
+            _ => Err(format!("unrecognized Verus version `{version}`")),
+        }
+    }
+}
+
+impl fmt::Display for VerusVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0.{}.{:02}.{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns the `verus` binary to run, see [`VerusToolchain::discover`].
+pub fn find_verus(configured: Option<&Utf8Path>) -> Result<Utf8PathBuf, String> {
+    if let Some(path) = configured {
+        return verus_at(path)
+            .ok_or_else(|| format!("no Verus binary found at the configured path `{path}`"));
+    }
+    if let Some(path) = env::var_os(VERUS_BINARY_PATH) {
+        let path = Utf8PathBuf::try_from(std::path::PathBuf::from(path))
+            .map_err(|e| format!("${VERUS_BINARY_PATH} is not valid UTF-8: {e}"))?;
+        return verus_at(&path)
+            .ok_or_else(|| format!("no Verus binary found at ${VERUS_BINARY_PATH} (`{path}`)"));
+    }
+    lookup_in_path("verus").ok_or_else(|| {
+        format!(
+            "could not find a Verus binary; set `verus-analyzer.verus.verusBinary` or \
+             ${VERUS_BINARY_PATH}, or add `verus` to $PATH"
+        )
+    })
+}
+
+/// `path` is either the binary itself or a directory laid out like a Verus release.
+fn verus_at(path: &Utf8Path) -> Option<Utf8PathBuf> {
+    if !path.is_dir() {
+        return probe_for_binary(path.to_owned());
+    }
+    let candidates = RELEASE_DIRS
+        .iter()
+        .map(|dir| path.join(dir))
+        .chain([path.join("target-verus/release"), path.join("source/target-verus/release")]);
+    std::iter::once(path.to_owned())
+        .chain(candidates)
+        .find_map(|dir| probe_for_binary(dir.join("verus")))
+}
+
+fn find_cargo_verus(verus: &Utf8Path) -> Option<Utf8PathBuf> {
+    let verus = verus.canonicalize_utf8().unwrap_or_else(|_| verus.to_owned());
+    verus
+        .parent()
+        .and_then(|dir| probe_for_binary(dir.join("cargo-verus")))
+        .or_else(|| lookup_in_path("cargo-verus"))
+        .or_else(|| cargo_proxy("cargo-verus"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        let version = VerusVersion::parse("0.2024.09.12.1a2b3c4").unwrap();
+        assert_eq!(version, VerusVersion { year: 2024, month: 9, day: 12 });
+        assert_eq!(version.to_string(), "0.2024.09.12");
+        assert!(version < SUPPORTED_VERUS_VERSION);
+        assert!(VerusVersion::parse("rolling").is_err());
+    }
+}
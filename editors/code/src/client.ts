@@ -227,10 +227,12 @@ export async function createClient(
                             { index: number; items: vscode.CodeAction[] }
                         >();
                         for (const item of values) {
-                            // In our case we expect to get code edits only from diagnostics
+                            // In our case we expect to get code edits only from diagnostics,
+                            // and commands only for proof actions that run in the background
                             if (lc.CodeAction.is(item)) {
                                 assert(
-                                    !item.command,
+                                    !item.command ||
+                                        item.command.command === "verus-analyzer.runProofAction",
                                     "We don't expect to receive commands in CodeActions",
                                 );
                                 const action = await client.protocol2CodeConverter.asCodeAction(
@@ -347,6 +349,7 @@ class ExperimentalFeatures implements lc.StaticFeature {
                     "verus-analyzer.debugSingle",
                     "verus-analyzer.showReferences",
                     "verus-analyzer.gotoLocation",
+                    "verus-analyzer.runProofAction",
                     "editor.action.triggerParameterHints",
                 ],
             },
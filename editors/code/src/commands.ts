@@ -1036,6 +1036,48 @@ export function resolveCodeAction(ctx: CtxInit): Cmd {
     };
 }
 
+export function runProofAction(ctx: CtxInit): Cmd {
+    return async (params: ra.CodeActionData) => {
+        // The server verifies in the background, reporting progress, and applies the edit itself
+        await ctx.client.sendRequest(ra.runProofAction, params);
+    };
+}
+
+export function minimizeProof(ctx: CtxInit): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        if (!editor) return;
+        const scopes = [
+            { label: "Function", description: "the function at the cursor", scope: "function" },
+            { label: "Module", description: "every function in this file", scope: "module" },
+            { label: "Crate", description: "every function in this crate", scope: "crate" },
+        ] as const;
+        const picked = await vscode.window.showQuickPick(scopes, {
+            placeHolder: "Remove the proof steps that are not needed from",
+        });
+        if (!picked) return;
+        const client = ctx.client;
+        await client.sendRequest(ra.minimizeProof, {
+            textDocument: client.code2ProtocolConverter.asTextDocumentIdentifier(editor.document),
+            position: client.code2ProtocolConverter.asPosition(editor.selection.active),
+            scope: picked.scope,
+        });
+    };
+}
+
+export function searchProof(ctx: CtxInit): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        if (!editor) return;
+        const client = ctx.client;
+        // The server searches in the background, and offers what it found once done
+        await client.sendRequest(ra.searchProof, {
+            textDocument: client.code2ProtocolConverter.asTextDocumentIdentifier(editor.document),
+            position: client.code2ProtocolConverter.asPosition(editor.selection.active),
+        });
+    };
+}
+
 function asWorkspaceSnippetEdit(
     ctx: CtxInit,
     item: lc.WorkspaceEdit,
@@ -42,6 +42,15 @@ export const relatedTests = new lc.RequestType<lc.TextDocumentPositionParams, Te
 export const reloadWorkspace = new lc.RequestType0<null, void>("verus-analyzer/reloadWorkspace");
 export const rebuildProcMacros = new lc.RequestType0<null, void>("verus-analyzer/rebuildProcMacros");
 
+export const runProofAction = new lc.RequestType<CodeActionData, void, void>(
+    "verus-analyzer/runProofAction",
+);
+export const minimizeProof = new lc.RequestType<MinimizeProofParams, void, void>(
+    "verus-analyzer/minimizeProof",
+);
+export const searchProof = new lc.RequestType<lc.TextDocumentPositionParams, void, void>(
+    "verus-analyzer/searchProof",
+);
 export const runFlycheck = new lc.NotificationType<{
     textDocument: lc.TextDocumentIdentifier | null;
 }>("verus-analyzer/runFlycheck");
@@ -90,6 +99,7 @@ export type DiscoverTestResults = {
 };
 export type TestState =
     | { tag: "failed"; message: string }
+    | { tag: "timedOut"; message: string }
     | { tag: "passed" }
     | { tag: "started" }
     | { tag: "enqueued" }
@@ -111,6 +121,14 @@ export const changeTestState = new lc.NotificationType<ChangeTestStateParams>(
     "experimental/changeTestState",
 );
 
+export type CodeActionData = {
+    codeActionParams: lc.CodeActionParams;
+    id: string;
+    version: number | null;
+};
+export type MinimizeProofParams = lc.TextDocumentPositionParams & {
+    scope: "function" | "module" | "crate";
+};
 export type AnalyzerStatusParams = { textDocument?: lc.TextDocumentIdentifier };
 
 export interface FetchDependencyListParams {}
@@ -173,6 +173,8 @@ function createCommands(): Record<string, CommandFactory> {
         cancelFlycheck: { enabled: commands.cancelFlycheck },
         clearFlycheck: { enabled: commands.clearFlycheck },
         runFlycheck: { enabled: commands.runFlycheck },
+        minimizeProof: { enabled: commands.minimizeProof },
+        searchProof: { enabled: commands.searchProof },
         ssr: { enabled: commands.ssr },
         serverVersion: { enabled: commands.serverVersion },
         viewMemoryLayout: { enabled: commands.viewMemoryLayout },
@@ -186,6 +188,7 @@ function createCommands(): Record<string, CommandFactory> {
         hoverRefCommandProxy: { enabled: commands.hoverRefCommandProxy },
         resolveCodeAction: { enabled: commands.resolveCodeAction },
         runSingle: { enabled: commands.runSingle },
+        runProofAction: { enabled: commands.runProofAction },
         showReferences: { enabled: commands.showReferences },
         triggerParameterHints: { enabled: commands.triggerParameterHints },
         openLogs: { enabled: commands.openLogs },
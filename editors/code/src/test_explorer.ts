@@ -185,6 +185,8 @@ export const prepareTestExplorer = (
             const test = idToTestMap.get(results.testId)!;
             if (results.state.tag === "failed") {
                 currentTestRun!.failed(test, new vscode.TestMessage(results.state.message));
+            } else if (results.state.tag === "timedOut") {
+                currentTestRun!.errored(test, new vscode.TestMessage(results.state.message));
             } else if (results.state.tag === "passed") {
                 currentTestRun!.passed(test);
             } else if (results.state.tag === "started") {
@@ -49,6 +49,10 @@ pub(crate) fn generate(check: bool) {
     let ast_nodes = sourcegen_vst::generate_vst(KINDS_SRC, &ast);
     let ast_nodes_file = project_root().join("crates/syntax/src/ast/generated/vst_nodes.rs");
     ensure_file_contents(ast_nodes_file.as_path(), &ast_nodes, check);
+
+    let vst_visit = sourcegen_vst::generate_vst_visit(&ast);
+    let vst_visit_file = project_root().join("crates/syntax/src/ast/generated/vst_visit.rs");
+    ensure_file_contents(vst_visit_file.as_path(), &vst_visit, check);
 }
 
 fn generate_tokens(grammar: &AstSrc) -> String {
@@ -735,7 +739,9 @@ fn lower_rule(
                     | "iterable"
                     | "condition"
             );
-            if manually_implemented {
+            // TOST nodes still need the loop condition, the iterable and the match guard
+            let kept_in_vst = is_vst && matches!(l.as_str(), "iterable" | "condition");
+            if manually_implemented && !kept_in_vst {
                 return;
             }
             lower_rule(acc, grammar, Some(l), rule, is_vst, inside_opt, inside_alt);
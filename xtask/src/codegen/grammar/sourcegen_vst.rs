@@ -20,9 +20,9 @@ use crate::codegen;
 //     ("HasArgList", &["arg_list"]),
 // ];
 
-const HAND_WRITTEN: &[&str] = &["BinExpr", "IfExpr", "Literal", "IndexExpr"];
+const HAND_WRITTEN: &[&str] = &["BinExpr", "PrefixExpr", "IfExpr", "Literal", "IndexExpr"];
 
-const HAND_WRITTEN_PRINT_ONLY: &[&str] = &["ParamList", "ArgList", "AssertExpr"];
+const HAND_WRITTEN_PRINT_ONLY: &[&str] = &["ParamList", "ArgList", "AssertExpr", "ClosureExpr"];
 const HAND_WRITTEN_NEW_ONLY: &[&str] = &["ExprStmt", "MatchArm"];
 const HAND_WRITTEN_INTO_ONLY: &[&str] = &["AssertForallExpr"];
 
@@ -32,6 +32,16 @@ const LIST_AUTO_GEN_SEP_COMMA: &[&str] = &[
     "TupleFieldList",
     "RecordExprFieldList",
     "RecordPatFieldList",
+    "TupleExpr",
+    "TuplePat",
+    "RequiresClause",
+    "EnsuresClause",
+    "DefaultEnsuresClause",
+    "InvariantClause",
+    "InvariantExceptBreakClause",
+    "RecommendsClause",
+    "DecreasesClause",
+    "TriggerAttribute",
 ];
 const LIST_AUTO_GEN_SEP_NEWLINE: &[&str] = &["StmtList"];
 
@@ -333,7 +343,8 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
 
                 if field.is_many() {
                     let sep;
-                    if LIST_AUTO_GEN_SEP_COMMA.contains(&node.name.as_str()) {
+                    // attributes are never comma-separated, only the node's own list is
+                    if LIST_AUTO_GEN_SEP_COMMA.contains(&node.name.as_str()) && name != "attrs" {
                         sep = ", ";
                     } else if LIST_AUTO_GEN_SEP_NEWLINE.contains(&node.name.as_str()) {
                         sep = "\n    ";
@@ -568,6 +579,208 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
     codegen::add_preamble("sourcegen_vst", codegen::reformat(ast.to_string()))
 }
 
+// Child slots of the hand-written nodes in `ast::vst`, which do not follow the ungrammar layout.
+const HAND_WRITTEN_CHILDREN: &[(&str, &[(&str, &str, Card)])] = &[
+    (
+        "BinExpr",
+        &[("attrs", "Attr", Card::Many), ("lhs", "Expr", Card::One), ("rhs", "Expr", Card::One)],
+    ),
+    ("PrefixExpr", &[("attrs", "Attr", Card::Many), ("expr", "Expr", Card::One)]),
+    (
+        "IfExpr",
+        &[
+            ("attrs", "Attr", Card::Many),
+            ("condition", "Expr", Card::One),
+            ("then_branch", "BlockExpr", Card::One),
+            ("else_branch", "ElseBranch", Card::Opt),
+        ],
+    ),
+    ("Literal", &[("attrs", "Attr", Card::Many)]),
+    (
+        "IndexExpr",
+        &[("attrs", "Attr", Card::Many), ("base", "Expr", Card::One), ("index", "Expr", Card::One)],
+    ),
+];
+
+// `ElseBranch` is a hand-written enum that is not part of the ungrammar.
+const HAND_WRITTEN_ENUMS: &[(&str, &[(&str, &str)])] =
+    &[("ElseBranch", &[("Block", "BlockExpr"), ("IfExpr", "IfExpr")])];
+
+#[derive(Clone, Copy)]
+enum Card {
+    Many,
+    One,
+    Opt,
+}
+
+// Generate `VstVisitor`, `VstVisitorMut` and `VstFold`.
+// Every node and enum gets a trait method whose default implementation calls the matching
+// `walk_*` function, which recurses into all child nodes (tokens are skipped).
+pub(crate) fn generate_vst_visit(grammar: &AstSrc) -> String {
+    let mut structs: Vec<(String, Vec<(String, String, Card)>)> = grammar
+        .nodes
+        .iter()
+        .map(|node| {
+            if let Some((_, children)) =
+                HAND_WRITTEN_CHILDREN.iter().find(|(name, _)| *name == node.name)
+            {
+                let children = children
+                    .iter()
+                    .map(|(field, ty, card)| (field.to_string(), ty.to_string(), *card))
+                    .collect();
+                return (node.name.clone(), children);
+            }
+            let children = node
+                .fields
+                .iter()
+                .filter(|field| field.token_kind().is_none())
+                .map(|field| {
+                    let card = if field.is_many() {
+                        Card::Many
+                    } else if field.is_one() {
+                        Card::One
+                    } else {
+                        Card::Opt
+                    };
+                    (field.method_name().to_string(), field.ty().to_string(), card)
+                })
+                .collect();
+            (node.name.clone(), children)
+        })
+        .collect();
+    structs.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut enums: Vec<(String, Vec<(String, String)>)> = grammar
+        .enums
+        .iter()
+        .map(|en| (en.name.clone(), en.variants.iter().map(|v| (v.clone(), v.clone())).collect()))
+        .chain(HAND_WRITTEN_ENUMS.iter().map(|(name, variants)| {
+            (
+                name.to_string(),
+                variants.iter().map(|(v, ty)| (v.to_string(), ty.to_string())).collect(),
+            )
+        }))
+        .collect();
+    enums.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let visit = |ty: &str| format_ident!("visit_{}", super::to_lower_snake_case(ty));
+    let visit_mut = |ty: &str| format_ident!("visit_{}_mut", super::to_lower_snake_case(ty));
+    let fold = |ty: &str| format_ident!("fold_{}", super::to_lower_snake_case(ty));
+    let walk = |ty: &str| format_ident!("walk_{}", super::to_lower_snake_case(ty));
+    let walk_mut = |ty: &str| format_ident!("walk_{}_mut", super::to_lower_snake_case(ty));
+    let walk_fold = |ty: &str| format_ident!("walk_fold_{}", super::to_lower_snake_case(ty));
+
+    let names = structs.iter().map(|(name, _)| name).chain(enums.iter().map(|(name, _)| name));
+    let (visitor_methods, (visitor_mut_methods, fold_methods)): (Vec<_>, (Vec<_>, Vec<_>)) = names
+        .map(|name| {
+            let ty = format_ident!("{}", name);
+            let (visit, visit_mut, fold) = (visit(name), visit_mut(name), fold(name));
+            let (walk, walk_mut, walk_fold) = (walk(name), walk_mut(name), walk_fold(name));
+            (
+                quote! { fn #visit(&mut self, node: &#ty) { #walk(self, node) } },
+                (
+                    quote! { fn #visit_mut(&mut self, node: &mut #ty) { #walk_mut(self, node) } },
+                    quote! { fn #fold(&mut self, node: #ty) -> #ty { #walk_fold(self, node) } },
+                ),
+            )
+        })
+        .unzip();
+
+    let struct_walks = structs.iter().map(|(name, children)| {
+        let ty = format_ident!("{}", name);
+        let (walk, walk_mut, walk_fold) = (walk(name), walk_mut(name), walk_fold(name));
+        let (visits, (visits_mut, folds)): (Vec<_>, (Vec<_>, Vec<_>)) = children
+            .iter()
+            .map(|(field, child, card)| {
+                let field = format_ident!("{}", field);
+                let (visit, visit_mut, fold) = (visit(child), visit_mut(child), fold(child));
+                match card {
+                    Card::Many => (
+                        quote! { for it in &node.#field { v.#visit(it); } },
+                        (
+                            quote! { for it in &mut node.#field { v.#visit_mut(it); } },
+                            quote! {
+                                node.#field = node.#field.into_iter().map(|it| f.#fold(it)).collect();
+                            },
+                        ),
+                    ),
+                    Card::One => (
+                        quote! { v.#visit(&node.#field); },
+                        (
+                            quote! { v.#visit_mut(&mut node.#field); },
+                            quote! { node.#field = Box::new(f.#fold(*node.#field)); },
+                        ),
+                    ),
+                    Card::Opt => (
+                        quote! { if let Some(it) = &node.#field { v.#visit(it); } },
+                        (
+                            quote! { if let Some(it) = &mut node.#field { v.#visit_mut(it); } },
+                            quote! { node.#field = node.#field.map(|it| Box::new(f.#fold(*it))); },
+                        ),
+                    ),
+                }
+            })
+            .unzip();
+        quote! {
+            pub fn #walk<V: VstVisitor + ?Sized>(v: &mut V, node: &#ty) {
+                #(#visits)*
+            }
+            pub fn #walk_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut #ty) {
+                #(#visits_mut)*
+            }
+            pub fn #walk_fold<F: VstFold + ?Sized>(f: &mut F, mut node: #ty) -> #ty {
+                #(#folds)*
+                node
+            }
+        }
+    });
+
+    let enum_walks = enums.iter().map(|(name, variants)| {
+        let ty = format_ident!("{}", name);
+        let (walk, walk_mut, walk_fold) = (walk(name), walk_mut(name), walk_fold(name));
+        let vars: Vec<_> = variants.iter().map(|(var, _)| format_ident!("{}", var)).collect();
+        let visits: Vec<_> = variants.iter().map(|(_, child)| visit(child)).collect();
+        let visits_mut: Vec<_> = variants.iter().map(|(_, child)| visit_mut(child)).collect();
+        let folds: Vec<_> = variants.iter().map(|(_, child)| fold(child)).collect();
+        quote! {
+            pub fn #walk<V: VstVisitor + ?Sized>(v: &mut V, node: &#ty) {
+                match node {
+                    #(#ty::#vars(it) => v.#visits(it),)*
+                }
+            }
+            pub fn #walk_mut<V: VstVisitorMut + ?Sized>(v: &mut V, node: &mut #ty) {
+                match node {
+                    #(#ty::#vars(it) => v.#visits_mut(it),)*
+                }
+            }
+            pub fn #walk_fold<F: VstFold + ?Sized>(f: &mut F, node: #ty) -> #ty {
+                match node {
+                    #(#ty::#vars(it) => #ty::#vars(Box::new(f.#folds(*it))),)*
+                }
+            }
+        }
+    });
+
+    let ast = quote! {
+        #![allow(unused_mut, unused_variables)]
+        use crate::ast::vst::*;
+
+        pub trait VstVisitor {
+            #(#visitor_methods)*
+        }
+        pub trait VstVisitorMut {
+            #(#visitor_mut_methods)*
+        }
+        pub trait VstFold {
+            #(#fold_methods)*
+        }
+        #(#struct_walks)*
+        #(#enum_walks)*
+    };
+
+    codegen::add_preamble("sourcegen_vst", codegen::reformat(ast.to_string()))
+}
+
 /*
 impl Stmt {
     pub fn cst(&self) -> Option<super::nodes::Stmt> {